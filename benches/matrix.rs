@@ -0,0 +1,70 @@
+//! Benchmarks for the matrix exercises. Run with `cargo bench --bench matrix`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_ds_learning::matrix::{
+    find_postition_sorted_square_matrix, find_postition_sorted_square_matrix_naive, Matrix,
+};
+
+fn sorted_square_matrix_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sorted_square_matrix_search");
+    for n in [64, 256, 1024] {
+        let matrix = Matrix::from_fn(n, n, |i, j| (i * n + j) as f64);
+        // The bottom-left corner is the naive scan's worst hit, and a
+        // missing value forces both searches to finish their walk
+        let targets = [((n - 1) * n) as f64, -1.0];
+
+        group.bench_with_input(BenchmarkId::new("staircase", n), &matrix, |b, m| {
+            b.iter(|| {
+                for val in targets {
+                    let _ = black_box(find_postition_sorted_square_matrix(m, black_box(val)));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("naive", n), &matrix, |b, m| {
+            b.iter(|| {
+                for val in targets {
+                    let _ = black_box(find_postition_sorted_square_matrix_naive(m, black_box(val)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn strassen_vs_naive(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matrix_multiplication_512");
+    group.sample_size(10);
+    let a = Matrix::random(512, 512, -1.0..1.0, 1);
+    let b = Matrix::random(512, 512, -1.0..1.0, 2);
+
+    group.bench_function("naive", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+    group.bench_function("strassen", |bench| {
+        bench.iter(|| black_box(&a).mul_strassen(black_box(&b)))
+    });
+    group.finish();
+}
+
+fn simd_vs_scalar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matrix_multiplication_simd");
+    for n in [64, 256] {
+        let a = Matrix::random(n, n, -1.0..1.0, 1);
+        let b = Matrix::random(n, n, -1.0..1.0, 2);
+        group.bench_function(BenchmarkId::new("scalar", n), |bench| {
+            bench.iter(|| black_box(&a) * black_box(&b))
+        });
+        group.bench_function(BenchmarkId::new("simd", n), |bench| {
+            bench.iter(|| black_box(&a).mul_simd(black_box(&b)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    sorted_square_matrix_search,
+    strassen_vs_naive,
+    simd_vs_scalar
+);
+criterion_main!(benches);
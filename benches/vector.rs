@@ -0,0 +1,152 @@
+//! Benchmarks for the vector exercises. Run with `cargo bench --bench vector`.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rust_ds_learning::vector::inputs::random_vec;
+use rust_ds_learning::vector::sorting::{counting_sort, radix_sort};
+use rust_ds_learning::vector::{
+    kth_smallest, max_subarray_sum, max_subarray_sum_naive, merge_k_sorted,
+    merge_k_sorted_pairwise, sliding_window_maximum, sliding_window_maximum_naive,
+};
+
+/// O(n · k) rescanning of every window against the O(n) monotonic deque
+fn sliding_window_maximum_naive_vs_deque(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sliding_window_maximum_100k");
+    let input = random_vec(100_000, -1_000..1_000, 5);
+    for k in [4, 64, 1024] {
+        group.bench_with_input(BenchmarkId::new("naive", k), &k, |b, &k| {
+            b.iter(|| sliding_window_maximum_naive(black_box(&input), k))
+        });
+        group.bench_with_input(BenchmarkId::new("deque", k), &k, |b, &k| {
+            b.iter(|| sliding_window_maximum(black_box(&input), k))
+        });
+    }
+    group.finish();
+}
+
+/// O(n²) enumeration of every subarray against Kadane's single pass
+fn max_subarray_sum_naive_vs_kadane(c: &mut Criterion) {
+    let mut group = c.benchmark_group("max_subarray_sum");
+    for len in [100, 1_000, 10_000] {
+        let input = random_vec(len, -100..100, 6);
+        group.bench_with_input(BenchmarkId::new("naive", len), &input, |b, input| {
+            b.iter(|| max_subarray_sum_naive(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("kadane", len), &input, |b, input| {
+            b.iter(|| max_subarray_sum(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+/// Quickselect's expected O(n) against the O(n log n) of sorting first
+fn kth_smallest_vs_sort(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kth_smallest_median");
+    for len in [1_000, 10_000, 100_000] {
+        let input = random_vec(len, i32::MIN..i32::MAX, 1);
+        let k = len / 2;
+
+        group.bench_with_input(BenchmarkId::new("quickselect", len), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |nums| kth_smallest(nums, black_box(k)).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("sort", len), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |nums| {
+                    nums.sort_unstable();
+                    nums[black_box(k) - 1]
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(
+            BenchmarkId::new("std_select_nth", len),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |nums| *nums.select_nth_unstable(black_box(k) - 1).1,
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Heap-based against pairwise merging, for few long and many short lists
+fn merge_k_sorted_heap_vs_pairwise(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge_k_sorted_100k");
+    for k in [4, 64, 1024] {
+        let lists: Vec<Vec<i32>> = (0..k)
+            .map(|seed| {
+                let mut list = random_vec(100_000 / k, i32::MIN..i32::MAX, seed as u64);
+                list.sort_unstable();
+                list
+            })
+            .collect();
+
+        group.bench_with_input(BenchmarkId::new("heap", k), &lists, |b, lists| {
+            b.iter(|| merge_k_sorted(black_box(lists)))
+        });
+        group.bench_with_input(BenchmarkId::new("pairwise", k), &lists, |b, lists| {
+            b.iter(|| merge_k_sorted_pairwise(black_box(lists)))
+        });
+    }
+    group.finish();
+}
+
+/// Non-comparison sorts against `sort_unstable` on large uniform inputs
+fn integer_sorts_vs_sort_unstable(c: &mut Criterion) {
+    let mut group = c.benchmark_group("integer_sorts_1m");
+    group.sample_size(10);
+    let len = 1_000_000;
+
+    // Counting sort shines when the key range is small
+    let max = 1_000;
+    let small_keys = random_vec(len, 0..max + 1, 3);
+    group.bench_function("counting_sort/u32_up_to_1000", |b| {
+        b.iter_batched_ref(
+            || small_keys.clone(),
+            |v| counting_sort(v, max).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("sort_unstable/u32_up_to_1000", |b| {
+        b.iter_batched_ref(
+            || small_keys.clone(),
+            |v| v.sort_unstable(),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let wide_keys = random_vec(len, 0..u64::MAX, 4);
+    group.bench_function("radix_sort/u64", |b| {
+        b.iter_batched_ref(
+            || wide_keys.clone(),
+            |v| radix_sort(v),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("sort_unstable/u64", |b| {
+        b.iter_batched_ref(
+            || wide_keys.clone(),
+            |v| v.sort_unstable(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    sliding_window_maximum_naive_vs_deque,
+    max_subarray_sum_naive_vs_kadane,
+    kth_smallest_vs_sort,
+    merge_k_sorted_heap_vs_pairwise,
+    integer_sorts_vs_sort_unstable
+);
+criterion_main!(benches);
@@ -0,0 +1,12 @@
+//! Prints a timing/allocation table for the standard comparison workload.
+//!
+//! Run with `cargo run --release --example compare_collections`.
+
+use rust_ds_learning::compare::{run_all, CountingAllocator, Workload};
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+fn main() {
+    println!("{}", run_all(&Workload::default()));
+}
@@ -0,0 +1,45 @@
+//! Measures how many allocations `Cow` saves when escaping mostly-clean text.
+//!
+//! Run with `cargo run --release --example cow_allocations`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rust_ds_learning::borrowing::{escape_html, escape_html_owned};
+use rust_ds_learning::compare::CountingAllocator;
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+fn main() {
+    // One line in ten needs escaping
+    let lines: Vec<String> = (0..100_000)
+        .map(|i| {
+            if i % 10 == 0 {
+                format!("row {i}: a < b && b > c")
+            } else {
+                format!("row {i}: nothing special here")
+            }
+        })
+        .collect();
+
+    let before = CountingAllocator::allocations();
+    let start = Instant::now();
+    for line in &lines {
+        black_box(escape_html_owned(line));
+    }
+    let owned_time = start.elapsed();
+    let owned = CountingAllocator::allocations() - before;
+
+    let before = CountingAllocator::allocations();
+    let start = Instant::now();
+    for line in &lines {
+        black_box(escape_html(line));
+    }
+    let cow_time = start.elapsed();
+    let cow = CountingAllocator::allocations() - before;
+
+    println!("{} lines, 10% need escaping", lines.len());
+    println!("escape_html_owned: {owned:>7} allocations in {owned_time:.2?}");
+    println!("escape_html (Cow): {cow:>7} allocations in {cow_time:.2?}");
+}
@@ -0,0 +1,485 @@
+//! Classic linked structures rebuilt on top of [`Arena`]
+//!
+//! Each structure stores its nodes in an arena and links them with [`Id`]
+//! handles. Back-pointers (`prev`, `parent`) and cycles are just more
+//! handles, so none of these need `Rc`, `RefCell` or `unsafe`.
+
+use std::collections::VecDeque;
+
+use super::storage::{Arena, Id};
+use crate::{ExerciseError, Result};
+
+/// Node of an [`ArenaList`]
+#[derive(Debug, Clone)]
+pub struct ListNode<T> {
+    pub value: T,
+    prev: Option<Id<ListNode<T>>>,
+    next: Option<Id<ListNode<T>>>,
+}
+
+/// # Doubly Linked List
+///
+/// ## Problem Statement
+/// Build a doubly linked list supporting O(1) insertion and removal at a
+/// known node, plus in-place reversal.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::arena::ArenaList;
+/// let mut list = ArenaList::new();
+/// list.push_back(1);
+/// let two = list.push_back(2);
+/// list.push_back(3);
+/// list.remove(two);
+/// list.reverse();
+/// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 1]);
+/// ```
+///
+/// ## Approach
+/// Every node holds `prev`/`next` handles into the arena. With references
+/// this would need `Rc<RefCell<_>>` forward links and `Weak` back links; with
+/// handles both directions are symmetric.
+///
+/// ## Complexity
+/// - Time: O(1) for push/insert/remove, O(n) for reverse and iteration
+/// - Space: O(total nodes ever allocated)
+///
+/// ## Key Points
+/// 1. Removing unlinks a node but its slot stays allocated until the list
+///    is dropped: the usual arena trade-off of memory for simplicity
+/// 2. Reversal just swaps `prev`/`next` in every node and then head/tail
+///
+/// ## Common Pitfalls
+/// 1. Forgetting to update `head`/`tail` when the removed node is an end
+/// 2. Removing the same node twice, or inserting after a removed node
+///    (both guarded by the `linked` check)
+#[derive(Debug, Clone)]
+pub struct ArenaList<T> {
+    nodes: Arena<ListNode<T>>,
+    head: Option<Id<ListNode<T>>>,
+    tail: Option<Id<ListNode<T>>>,
+    len: usize,
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        Self {
+            nodes: Arena::new(),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+}
+
+impl<T> ArenaList<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|id| &self.nodes[id].value)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|id| &self.nodes[id].value)
+    }
+
+    pub fn get(&self, id: Id<ListNode<T>>) -> &T {
+        &self.nodes[id].value
+    }
+
+    pub fn push_back(&mut self, value: T) -> Id<ListNode<T>> {
+        let id = self.nodes.alloc(ListNode {
+            value,
+            prev: self.tail,
+            next: None,
+        });
+        match self.tail {
+            Some(tail) => self.nodes[tail].next = Some(id),
+            None => self.head = Some(id),
+        }
+        self.tail = Some(id);
+        self.len += 1;
+        id
+    }
+
+    pub fn push_front(&mut self, value: T) -> Id<ListNode<T>> {
+        let id = self.nodes.alloc(ListNode {
+            value,
+            prev: None,
+            next: self.head,
+        });
+        match self.head {
+            Some(head) => self.nodes[head].prev = Some(id),
+            None => self.tail = Some(id),
+        }
+        self.head = Some(id);
+        self.len += 1;
+        id
+    }
+
+    /// Inserts `value` right after the node `after`
+    ///
+    /// # Errors
+    /// `InvalidInput` if `after` has been removed from the list: linking
+    /// a new node to it would leave the node unreachable from `head` while
+    /// still counting it in `len`.
+    pub fn insert_after(&mut self, after: Id<ListNode<T>>, value: T) -> Result<Id<ListNode<T>>> {
+        if !self.linked(after) {
+            return Err(ExerciseError::InvalidInput(
+                "cannot insert after a node that is no longer in the list".to_string(),
+            ));
+        }
+        let next = self.nodes[after].next;
+        let id = self.nodes.alloc(ListNode {
+            value,
+            prev: Some(after),
+            next,
+        });
+        self.nodes[after].next = Some(id);
+        match next {
+            Some(next) => self.nodes[next].prev = Some(id),
+            None => self.tail = Some(id),
+        }
+        self.len += 1;
+        Ok(id)
+    }
+
+    /// Unlinks `id` from the list. Returns `false` if it was already unlinked.
+    pub fn remove(&mut self, id: Id<ListNode<T>>) -> bool {
+        if !self.linked(id) {
+            return false;
+        }
+        let ListNode { prev, next, .. } = self.nodes[id];
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.tail = prev,
+        }
+        let node = &mut self.nodes[id];
+        node.prev = None;
+        node.next = None;
+        self.len -= 1;
+        true
+    }
+
+    /// Reverses the list in place by swapping every node's links
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+        while let Some(id) = current {
+            let node = &mut self.nodes[id];
+            std::mem::swap(&mut node.prev, &mut node.next);
+            // After the swap, the old `next` lives in `prev`
+            current = node.prev;
+        }
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.head, |&id| self.nodes[id].next).map(|id| &self.nodes[id].value)
+    }
+
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.tail, |&id| self.nodes[id].prev).map(|id| &self.nodes[id].value)
+    }
+
+    /// Handle of the first node holding `value`, searching from the front
+    pub fn find(&self, value: &T) -> Option<Id<ListNode<T>>>
+    where
+        T: PartialEq,
+    {
+        std::iter::successors(self.head, |&id| self.nodes[id].next)
+            .find(|&id| self.nodes[id].value == *value)
+    }
+
+    fn linked(&self, id: Id<ListNode<T>>) -> bool {
+        let node = &self.nodes[id];
+        node.prev.is_some() || node.next.is_some() || self.head == Some(id)
+    }
+}
+
+/// Node of an [`ArenaTree`]
+#[derive(Debug, Clone)]
+pub struct TreeNode<T> {
+    pub value: T,
+    parent: Option<Id<TreeNode<T>>>,
+    children: Vec<Id<TreeNode<T>>>,
+}
+
+/// # Rooted Tree with Parent Links
+///
+/// ## Problem Statement
+/// Build an n-ary tree where every node can reach both its children and its
+/// parent, and answer depth and lowest-common-ancestor queries.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::arena::ArenaTree;
+/// let mut tree = ArenaTree::new("root");
+/// let a = tree.add_child(tree.root(), "a");
+/// let b = tree.add_child(tree.root(), "b");
+/// let a1 = tree.add_child(a, "a1");
+/// assert_eq!(tree.depth(a1), 2);
+/// assert_eq!(tree.lowest_common_ancestor(a1, b), tree.root());
+/// ```
+///
+/// ## Approach
+/// Parent links make the tree a cyclic structure as far as ownership is
+/// concerned. In the arena they are plain handles.
+/// The LCA query lifts the deeper node until both are at the same depth, then
+/// lifts both together until they meet.
+///
+/// ## Complexity
+/// - Time: O(1) for `add_child`, O(depth) for `depth` and LCA, O(n) traversal
+/// - Space: O(n)
+///
+/// ## Common Pitfalls
+/// 1. Comparing values instead of handles when looking for the LCA
+/// 2. Recursion depth on degenerate (path-shaped) trees; `preorder` uses an
+///    explicit stack
+#[derive(Debug, Clone)]
+pub struct ArenaTree<T> {
+    nodes: Arena<TreeNode<T>>,
+    root: Id<TreeNode<T>>,
+}
+
+impl<T> ArenaTree<T> {
+    pub fn new(root_value: T) -> Self {
+        let mut nodes = Arena::new();
+        let root = nodes.alloc(TreeNode {
+            value: root_value,
+            parent: None,
+            children: Vec::new(),
+        });
+        Self { nodes, root }
+    }
+
+    pub fn root(&self) -> Id<TreeNode<T>> {
+        self.root
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Always `false`: a tree has at least its root
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get(&self, id: Id<TreeNode<T>>) -> &T {
+        &self.nodes[id].value
+    }
+
+    pub fn add_child(&mut self, parent: Id<TreeNode<T>>, value: T) -> Id<TreeNode<T>> {
+        let id = self.nodes.alloc(TreeNode {
+            value,
+            parent: Some(parent),
+            children: Vec::new(),
+        });
+        self.nodes[parent].children.push(id);
+        id
+    }
+
+    pub fn parent(&self, id: Id<TreeNode<T>>) -> Option<Id<TreeNode<T>>> {
+        self.nodes[id].parent
+    }
+
+    pub fn children(&self, id: Id<TreeNode<T>>) -> &[Id<TreeNode<T>>] {
+        &self.nodes[id].children
+    }
+
+    /// Number of edges between `id` and the root
+    pub fn depth(&self, id: Id<TreeNode<T>>) -> usize {
+        self.path_to_root(id).len() - 1
+    }
+
+    /// `id`, its parent, its grandparent, ..., the root
+    pub fn path_to_root(&self, id: Id<TreeNode<T>>) -> Vec<Id<TreeNode<T>>> {
+        std::iter::successors(Some(id), |&node| self.nodes[node].parent).collect()
+    }
+
+    pub fn lowest_common_ancestor(
+        &self,
+        mut a: Id<TreeNode<T>>,
+        mut b: Id<TreeNode<T>>,
+    ) -> Id<TreeNode<T>> {
+        let (mut depth_a, mut depth_b) = (self.depth(a), self.depth(b));
+        while depth_a > depth_b {
+            a = self.nodes[a].parent.unwrap();
+            depth_a -= 1;
+        }
+        while depth_b > depth_a {
+            b = self.nodes[b].parent.unwrap();
+            depth_b -= 1;
+        }
+        while a != b {
+            // Same depth and distinct, so neither is the root
+            a = self.nodes[a].parent.unwrap();
+            b = self.nodes[b].parent.unwrap();
+        }
+        a
+    }
+
+    /// Node handles in depth-first pre-order, children left to right
+    pub fn preorder(&self) -> Vec<Id<TreeNode<T>>> {
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = vec![self.root];
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.nodes[id].children.iter().rev());
+        }
+        order
+    }
+}
+
+/// Node of an [`ArenaGraph`]
+#[derive(Debug, Clone)]
+pub struct GraphNode<T> {
+    pub value: T,
+    edges: Vec<Id<GraphNode<T>>>,
+}
+
+/// # Directed Graph
+///
+/// ## Problem Statement
+/// Build a directed graph that may contain cycles, compute BFS distances from
+/// a start node and detect whether any cycle exists.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::arena::ArenaGraph;
+/// let mut graph = ArenaGraph::new();
+/// let a = graph.add_node('a');
+/// let b = graph.add_node('b');
+/// graph.add_edge(a, b);
+/// assert!(!graph.has_cycle());
+/// graph.add_edge(b, a);
+/// assert!(graph.has_cycle());
+/// ```
+///
+/// ## Approach
+/// Adjacency lists of handles. BFS uses a `VecDeque` frontier; cycle
+/// detection is an iterative three-colour DFS (white/grey/black) where
+/// reaching a grey node means we found a back edge.
+///
+/// ## Complexity
+/// - Time: O(V + E) for both BFS and cycle detection
+/// - Space: O(V + E)
+///
+/// ## Common Pitfalls
+/// 1. Using a plain "visited" flag for cycle detection in directed graphs:
+///    a node reached twice via different paths is not necessarily a cycle
+/// 2. Forgetting to start the DFS from every node in disconnected graphs
+#[derive(Debug, Clone)]
+pub struct ArenaGraph<T> {
+    nodes: Arena<GraphNode<T>>,
+}
+
+impl<T> Default for ArenaGraph<T> {
+    fn default() -> Self {
+        Self {
+            nodes: Arena::new(),
+        }
+    }
+}
+
+impl<T> ArenaGraph<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get(&self, id: Id<GraphNode<T>>) -> &T {
+        &self.nodes[id].value
+    }
+
+    pub fn add_node(&mut self, value: T) -> Id<GraphNode<T>> {
+        self.nodes.alloc(GraphNode {
+            value,
+            edges: Vec::new(),
+        })
+    }
+
+    /// Adds a directed edge; self-loops and parallel edges are allowed
+    pub fn add_edge(&mut self, from: Id<GraphNode<T>>, to: Id<GraphNode<T>>) {
+        self.nodes[from].edges.push(to);
+    }
+
+    pub fn neighbors(&self, id: Id<GraphNode<T>>) -> &[Id<GraphNode<T>>] {
+        &self.nodes[id].edges
+    }
+
+    /// Shortest edge count from `start` to every node, indexed by
+    /// [`Id::index`]; `None` for unreachable nodes
+    pub fn bfs_distances(&self, start: Id<GraphNode<T>>) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.nodes.len()];
+        distances[start.index()] = Some(0);
+        let mut frontier = VecDeque::from([start]);
+        while let Some(id) = frontier.pop_front() {
+            let next_distance = distances[id.index()].map(|d| d + 1);
+            for &next in &self.nodes[id].edges {
+                if distances[next.index()].is_none() {
+                    distances[next.index()] = next_distance;
+                    frontier.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Color {
+            White,
+            Grey,
+            Black,
+        }
+
+        let mut colors = vec![Color::White; self.nodes.len()];
+        for (start, _) in self.nodes.iter() {
+            if colors[start.index()] != Color::White {
+                continue;
+            }
+            // Each stack entry is a node and how many of its edges we've tried
+            let mut stack = vec![(start, 0)];
+            colors[start.index()] = Color::Grey;
+            while let Some((id, edge)) = stack.pop() {
+                match self.nodes[id].edges.get(edge) {
+                    Some(&next) => {
+                        stack.push((id, edge + 1));
+                        match colors[next.index()] {
+                            Color::Grey => return true,
+                            Color::White => {
+                                colors[next.index()] = Color::Grey;
+                                stack.push((next, 0));
+                            }
+                            Color::Black => {}
+                        }
+                    }
+                    None => colors[id.index()] = Color::Black,
+                }
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,25 @@
+//! Arena allocation module
+//!
+//! Linked structures with back-pointers (doubly linked lists, trees with
+//! parent links, graphs with cycles) are painful with `Box` and `&`
+//! references, and `Rc<RefCell<_>>` moves the checks to run time. An arena
+//! owns every node in one `Vec` and hands out copyable index handles
+//! instead, so nodes can point at each other freely in entirely safe Rust.
+//!
+//! ```
+//! use rust_ds_learning::arena::Arena;
+//!
+//! let mut arena = Arena::new();
+//! let a = arena.alloc("a");
+//! let b = arena.alloc("b");
+//! assert_eq!(arena[a], "a");
+//! assert_ne!(a, b);
+//! ```
+
+mod exercises;
+mod storage;
+#[cfg(test)]
+mod tests;
+
+pub use exercises::*;
+pub use storage::*;
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+/// Handle to a value stored in an [`Arena<T>`].
+///
+/// The type parameter stops a handle from one arena type being used with
+/// another. Handles are plain indices, so they are `Copy` regardless of `T`.
+pub struct Id<T> {
+    index: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    /// Position of the value in its arena
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+// Manual impls: deriving would wrongly require `T: Clone`, `T: Eq`, ...
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Id({})", self.index)
+    }
+}
+
+/// An append-only typed arena.
+///
+/// Values are never removed individually; the whole arena is freed at once
+/// when it is dropped. This is what makes handles safe to keep around: an
+/// `Id` handed out by an arena stays valid for that arena's whole lifetime.
+#[derive(Debug, Clone)]
+pub struct Arena<T> {
+    items: Vec<T>,
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Stores `value` and returns its handle
+    pub fn alloc(&mut self, value: T) -> Id<T> {
+        let index = self.items.len();
+        self.items.push(value);
+        Id {
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    /// `None` only for handles that came from a different, smaller arena
+    pub fn get(&self, id: Id<T>) -> Option<&T> {
+        self.items.get(id.index)
+    }
+
+    pub fn get_mut(&mut self, id: Id<T>) -> Option<&mut T> {
+        self.items.get_mut(id.index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// All values with their handles, in allocation order
+    pub fn iter(&self) -> impl Iterator<Item = (Id<T>, &T)> {
+        self.items.iter().enumerate().map(|(index, value)| {
+            (
+                Id {
+                    index,
+                    _marker: PhantomData,
+                },
+                value,
+            )
+        })
+    }
+}
+
+impl<T> Index<Id<T>> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, id: Id<T>) -> &T {
+        &self.items[id.index]
+    }
+}
+
+impl<T> IndexMut<Id<T>> for Arena<T> {
+    fn index_mut(&mut self, id: Id<T>) -> &mut T {
+        &mut self.items[id.index]
+    }
+}
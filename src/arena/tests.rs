@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use crate::arena::{Arena, ArenaGraph, ArenaList, ArenaTree, Id, TreeNode};
+
+mod arena_tests {
+    use super::*;
+
+    #[test]
+    fn test_alloc_and_index() {
+        let mut arena = Arena::new();
+        let a = arena.alloc(String::from("a"));
+        let b = arena.alloc(String::from("b"));
+        arena[b].push('!');
+        assert_eq!(arena[a], "a");
+        assert_eq!(arena.get(b).map(String::as_str), Some("b!"));
+        assert_eq!(arena.len(), 2);
+        assert_eq!((a.index(), b.index()), (0, 1));
+    }
+
+    #[test]
+    fn test_handles_are_copy_and_hashable_for_any_t() {
+        struct NotClone;
+        let mut arena = Arena::new();
+        let id = arena.alloc(NotClone);
+        let copy = id;
+        let set: HashSet<_> = [id, copy].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        assert_eq!(format!("{id:?}"), "Id(0)");
+    }
+
+    #[test]
+    fn test_foreign_handle() {
+        let mut big = Arena::new();
+        big.alloc(1);
+        let far = big.alloc(2);
+        let mut small = Arena::new();
+        small.alloc(3);
+        assert_eq!(small.get(far), None);
+        assert!(small.get_mut(far).is_none());
+    }
+
+    #[test]
+    fn test_iter_in_allocation_order() {
+        let mut arena = Arena::with_capacity(3);
+        assert!(arena.is_empty());
+        let ids: Vec<_> = (0..3).map(|i| arena.alloc(i * 10)).collect();
+        let collected: Vec<_> = arena.iter().collect();
+        assert_eq!(collected, vec![(ids[0], &0), (ids[1], &10), (ids[2], &20)]);
+    }
+}
+
+mod list_tests {
+    use super::*;
+
+    fn to_vec(list: &ArenaList<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_both_ends() {
+        let mut list = ArenaList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(to_vec(&list), vec![1, 2, 3]);
+        assert_eq!(list.iter_rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_insert_after() {
+        let mut list = ArenaList::new();
+        let one = list.push_back(1);
+        let three = list.push_back(3);
+        list.insert_after(one, 2).unwrap();
+        list.insert_after(three, 4).unwrap();
+        assert_eq!(to_vec(&list), vec![1, 2, 3, 4]);
+        assert_eq!(list.back(), Some(&4));
+    }
+
+    #[test]
+    fn test_insert_after_removed_node_is_rejected() {
+        let mut list = ArenaList::new();
+        let one = list.push_back(1);
+        let two = list.push_back(2);
+        let three = list.push_back(3);
+        assert!(list.remove(two));
+        assert!(list.insert_after(two, 9).is_err());
+        assert!(list.remove(three));
+        assert!(list.insert_after(three, 9).is_err());
+        assert_eq!(to_vec(&list), vec![1]);
+        assert_eq!((list.front(), list.back()), (Some(&1), Some(&1)));
+        assert_eq!(list.len(), 1);
+        // The sole remaining node is still a valid anchor
+        list.insert_after(one, 2).unwrap();
+        assert_eq!(to_vec(&list), vec![1, 2]);
+        assert_eq!(list.iter_rev().copied().collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_remove_middle_and_ends() {
+        let mut list = ArenaList::new();
+        let ids: Vec<_> = (1..=5).map(|x| list.push_back(x)).collect();
+        assert!(list.remove(ids[2]));
+        assert!(list.remove(ids[0]));
+        assert!(list.remove(ids[4]));
+        assert_eq!(to_vec(&list), vec![2, 4]);
+        assert_eq!(list.iter_rev().copied().collect::<Vec<_>>(), vec![4, 2]);
+        assert!(!list.remove(ids[2]), "double remove must be a no-op");
+        assert_eq!(list.len(), 2);
+        assert_eq!(*list.get(ids[2]), 3, "removed values stay in the arena");
+    }
+
+    #[test]
+    fn test_remove_only_element() {
+        let mut list = ArenaList::new();
+        let id = list.push_back(1);
+        assert!(list.remove(id));
+        assert!(list.is_empty());
+        assert_eq!((list.front(), list.back()), (None, None));
+        assert!(!list.remove(id));
+        list.push_back(2);
+        assert_eq!(to_vec(&list), vec![2]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = ArenaList::new();
+        list.reverse();
+        assert!(list.is_empty());
+        for x in 1..=4 {
+            list.push_back(x);
+        }
+        list.reverse();
+        assert_eq!(to_vec(&list), vec![4, 3, 2, 1]);
+        assert_eq!(
+            list.iter_rev().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        list.push_back(0);
+        assert_eq!(to_vec(&list), vec![4, 3, 2, 1, 0]);
+    }
+}
+
+mod tree_tests {
+    use super::*;
+
+    type NodeId = Id<TreeNode<&'static str>>;
+
+    fn sample() -> (ArenaTree<&'static str>, Vec<NodeId>) {
+        //        root
+        //       /    \
+        //      a      b
+        //     / \      \
+        //    a1  a2     b1
+        //    |
+        //    a11
+        let mut tree = ArenaTree::new("root");
+        let root = tree.root();
+        let a = tree.add_child(root, "a");
+        let b = tree.add_child(root, "b");
+        let a1 = tree.add_child(a, "a1");
+        let a2 = tree.add_child(a, "a2");
+        let b1 = tree.add_child(b, "b1");
+        let a11 = tree.add_child(a1, "a11");
+        (tree, vec![root, a, b, a1, a2, b1, a11])
+    }
+
+    #[test]
+    fn test_parent_and_children() {
+        let (tree, ids) = sample();
+        assert_eq!(tree.parent(ids[0]), None);
+        assert_eq!(tree.parent(ids[3]), Some(ids[1]));
+        assert_eq!(tree.children(ids[1]), &[ids[3], ids[4]]);
+        assert_eq!(tree.len(), 7);
+        assert!(!tree.is_empty());
+    }
+
+    #[test]
+    fn test_depth_and_path() {
+        let (tree, ids) = sample();
+        assert_eq!(tree.depth(ids[0]), 0);
+        assert_eq!(tree.depth(ids[6]), 3);
+        let path: Vec<_> = tree
+            .path_to_root(ids[6])
+            .into_iter()
+            .map(|id| *tree.get(id))
+            .collect();
+        assert_eq!(path, vec!["a11", "a1", "a", "root"]);
+    }
+
+    #[test]
+    fn test_lowest_common_ancestor() {
+        let (tree, ids) = sample();
+        assert_eq!(tree.lowest_common_ancestor(ids[6], ids[4]), ids[1]);
+        assert_eq!(tree.lowest_common_ancestor(ids[6], ids[5]), ids[0]);
+        assert_eq!(tree.lowest_common_ancestor(ids[3], ids[6]), ids[3]);
+        assert_eq!(tree.lowest_common_ancestor(ids[2], ids[2]), ids[2]);
+    }
+
+    #[test]
+    fn test_preorder() {
+        let (tree, _) = sample();
+        let values: Vec<_> = tree
+            .preorder()
+            .into_iter()
+            .map(|id| *tree.get(id))
+            .collect();
+        assert_eq!(values, vec!["root", "a", "a1", "a11", "a2", "b", "b1"]);
+    }
+
+    #[test]
+    fn test_deep_tree_does_not_overflow() {
+        let mut tree = ArenaTree::new(0);
+        let mut last = tree.root();
+        for i in 1..100_000 {
+            last = tree.add_child(last, i);
+        }
+        assert_eq!(tree.preorder().len(), 100_000);
+        assert_eq!(tree.depth(last), 99_999);
+    }
+}
+
+mod graph_tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs_distances() {
+        let mut graph = ArenaGraph::new();
+        let ids: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(ids[0], ids[1]);
+        graph.add_edge(ids[0], ids[2]);
+        graph.add_edge(ids[1], ids[3]);
+        graph.add_edge(ids[2], ids[3]);
+        graph.add_edge(ids[3], ids[0]);
+        assert_eq!(
+            graph.bfs_distances(ids[0]),
+            vec![Some(0), Some(1), Some(1), Some(2), None]
+        );
+        assert_eq!(graph.neighbors(ids[0]), &[ids[1], ids[2]]);
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let mut graph = ArenaGraph::new();
+        assert!(!graph.has_cycle());
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        // Diamond-free DAG with a node reachable twice: not a cycle
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+        assert!(!graph.has_cycle());
+        graph.add_edge(c, a);
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn test_self_loop_and_disconnected_cycle() {
+        let mut graph = ArenaGraph::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, b);
+        graph.add_edge(c, c);
+        assert!(graph.has_cycle());
+        assert_eq!(graph.len(), 3);
+    }
+}
@@ -1,11 +1,487 @@
+use crate::{ExerciseError, Result};
+
+/// # Find Element
+///
+/// ## Problem Statement
+/// Return the index of the first element of `arr` equal to `el`, or `None`
+/// if there is none.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::find_element;
+/// assert_eq!(find_element(&[4, 2, 7, 2], &2), Some(1));
+/// assert_eq!(find_element(&["a", "b"], &"c"), None);
+/// ```
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. `Option<usize>` states "not found" in the type instead of through a
+///    sentinel value, and `usize` can index any slice
+/// 2. Only `PartialEq` is needed, so this works for any comparable type
+pub fn find_element<T: PartialEq>(arr: &[T], el: &T) -> Option<usize> {
+    arr.iter().position(|x| x == el)
+}
+
+/// Returns the index of `el` as an `i32`, or `-1` if it is absent.
+///
+/// The `-1` sentinel is easy to forget to check, and indices past
+/// `i32::MAX` cannot be represented at all (the conversion panics).
+#[deprecated(
+    since = "0.1.0",
+    note = "use `find_element`, which returns `Option<usize>`"
+)]
 pub fn find_element_arr(arr: &[i32], el: i32) -> i32 {
     if arr.is_empty() {
         return -1;
     }
-    for i in 0..arr.len() {
-        if arr[i] == el {
+    for (i, &x) in arr.iter().enumerate() {
+        if x == el {
             return i.try_into().unwrap();
         }
     }
-    return -1;
+    -1
+}
+
+/// # Find Peak Element
+///
+/// ## Problem Statement
+/// A *peak* is an element at least as large as each of its neighbours,
+/// with positions outside the array counting as `-∞`. Return the index of
+/// any peak, or `None` for an empty array.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::find_peak;
+/// assert_eq!(find_peak(&[1, 2, 3, 1]), Some(2));
+/// assert_eq!(find_peak(&[1, 2, 3, 4]), Some(3)); // the edge counts
+/// assert_eq!(find_peak(&[]), None);
+/// ```
+///
+/// ## Why a Peak Always Exists
+/// The largest element of a non-empty array is at least as large as
+/// everything, in particular its neighbours, so it is a peak. Finding the
+/// maximum takes O(n), but any peak will do, and that needs much less.
+///
+/// ## Approach
+/// Binary search on the slope. Keep a window `lo..=hi` that is guaranteed
+/// to contain a peak: to its left the array steps *up* into `lo`, and to
+/// its right it steps *down* (or stays level) out of `hi`, with the array
+/// edges counting as `-∞`. The maximum of the window is then a peak of the
+/// whole array. Comparing `mid` with `mid + 1`:
+/// - `arr[mid] < arr[mid + 1]`: uphill to the right, so `lo = mid + 1`
+///   keeps the "step up into `lo`" property
+/// - otherwise: level or downhill, so `hi = mid` keeps the "no step up out
+///   of `hi`" property
+///
+/// When the window shrinks to one element, that element is a peak.
+///
+/// ## Complexity
+/// - Time: O(log n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. The array need not be sorted; binary search only needs a rule that
+///    provably keeps an answer inside the window
+/// 2. With plateaus a *strict* peak may not exist (`[1, 1, 1]`), which is
+///    why peaks here compare with `>=`
+///
+/// ## Common Pitfalls
+/// 1. Comparing `mid` with `mid - 1` while rounding `mid` down, which can
+///    underflow at index 0 and loop forever on two elements
+pub fn find_peak(arr: &[i32]) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+    let (mut lo, mut hi) = (0, arr.len() - 1);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if arr[mid] < arr[mid + 1] {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(lo)
+}
+
+/// # Missing Number
+///
+/// ## Problem Statement
+/// `arr` holds `n` distinct numbers taken from `0..=n`, so exactly one
+/// number of that range is missing. Return it.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::{find_missing, find_missing_sum};
+/// assert_eq!(find_missing(&[3, 0, 1]), 2);
+/// assert_eq!(find_missing(&[0, 1]), 2);
+/// assert_eq!(find_missing_sum(&[9, 6, 4, 2, 3, 5, 7, 0, 1]), 8);
+/// ```
+///
+/// ## Approach
+/// XOR every index `0..=n` and every element together. Each number that is
+/// present appears twice and cancels (`x ^ x == 0`), leaving the missing
+/// one. [`find_missing_sum`] uses subtraction instead: the expected total
+/// `n(n + 1) / 2` minus the actual total.
+///
+/// ## Why O(1) Space Is Achievable
+/// Marking numbers as seen needs an n-bit table. But only one number is
+/// unknown, and a single accumulator can carry that much information as
+/// long as every present number can be removed from it again: XOR and
+/// addition are both invertible, so each present number is cancelled by
+/// its counterpart in the full range.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. XOR never overflows; the sum does for large `n` unless computed in a
+///    wider type, as [`find_missing_sum`] does with `u64`
+/// 2. If the input breaks the precondition (duplicates, values above `n`)
+///    the result is meaningless; neither method can detect that in O(1)
+///    space
+pub fn find_missing(arr: &[u32]) -> u32 {
+    arr.iter()
+        .zip(0u32..)
+        .fold(arr.len() as u32, |acc, (&value, index)| acc ^ value ^ index)
+}
+
+/// Same result as [`find_missing`], by subtracting the actual total from
+/// the expected total `n(n + 1) / 2`, both in `u64`. Time: O(n); Space: O(1).
+///
+/// The subtraction wraps, so input that breaks the precondition gives a
+/// meaningless result rather than an overflow panic.
+pub fn find_missing_sum(arr: &[u32]) -> u32 {
+    let n = arr.len() as u64;
+    let actual: u64 = arr.iter().map(|&value| u64::from(value)).sum();
+    (n * (n + 1) / 2).wrapping_sub(actual) as u32
+}
+
+/// # Find the Duplicate Number
+///
+/// ## Problem Statement
+/// `arr` holds `n + 1` numbers, each in `1..=n`. By the pigeonhole principle
+/// at least one value repeats. Return a repeated value without modifying
+/// the array.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::find_duplicate;
+/// assert_eq!(find_duplicate(&[1, 3, 4, 2, 2]).unwrap(), 2);
+/// assert_eq!(find_duplicate(&[3, 1, 3, 4, 2]).unwrap(), 3);
+/// assert_eq!(find_duplicate(&[2, 2, 2, 2]).unwrap(), 2);
+/// assert!(find_duplicate(&[1, 5, 2]).is_err()); // 5 is out of range
+/// ```
+///
+/// ## Approach
+/// Read the array as a linked list: index `i` points to index `arr[i]`.
+/// Starting from index 0, which no value points back to, the walk must
+/// eventually revisit an index, and the index where the cycle is entered
+/// is the one that two different indices point to, i.e. a duplicate.
+/// Floyd's cycle detection finds it:
+/// 1. The tortoise moves one step and the hare two until they meet inside
+///    the cycle
+/// 2. Restart the tortoise from index 0 and move both one step at a time;
+///    they meet at the cycle entrance
+///
+/// ## Why O(1) Space Is Achievable
+/// A `HashSet` of seen values costs O(n) space, and sorting or marking
+/// values as negative modifies the input. Index-chasing stores the graph
+/// implicitly in the array itself, so two cursors are all the extra state
+/// needed.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Starting the walk at an index that lies on the cycle: the entrance
+///    is then undefined. Index 0 is safe because no value is 0
+/// 2. Returning the meeting point of phase 1, which is somewhere in the
+///    cycle but not necessarily the duplicate
+///
+/// ## Errors
+/// `InvalidInput` if `arr` has fewer than two elements or a value outside
+/// `1..=n`, since the walk would then leave the array.
+pub fn find_duplicate(arr: &[u32]) -> Result<u32> {
+    let n = arr.len().saturating_sub(1);
+    if arr.len() < 2 {
+        return Err(ExerciseError::InvalidInput(
+            "need at least two values to have a duplicate".to_string(),
+        ));
+    }
+    if let Some(&value) = arr.iter().find(|&&value| value == 0 || value as usize > n) {
+        return Err(ExerciseError::InvalidInput(format!(
+            "value {value} is outside 1..={n}"
+        )));
+    }
+
+    let next = |index: usize| arr[index] as usize;
+    let (mut tortoise, mut hare) = (next(0), next(next(0)));
+    while tortoise != hare {
+        tortoise = next(tortoise);
+        hare = next(next(hare));
+    }
+    tortoise = 0;
+    while tortoise != hare {
+        tortoise = next(tortoise);
+        hare = next(hare);
+    }
+    Ok(hare as u32)
+}
+
+/// # Pair With Sum in a Sorted Array
+///
+/// ## Problem Statement
+/// Given an ascending array and a target, return indices `i < j` with
+/// `sorted[i] + sorted[j] == target`, or `None`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::pair_with_sum_sorted;
+/// assert_eq!(pair_with_sum_sorted(&[1, 3, 4, 6, 9], 10), Some((0, 4)));
+/// assert_eq!(pair_with_sum_sorted(&[1, 3, 4, 6, 9], 8), None);
+/// ```
+///
+/// ## Approach
+/// Two pointers start at both ends. A sum below the target moves `left`
+/// right, a sum above it moves `right` left; the walk is shared with
+/// [`two_sum_sorted`](crate::vector::two_pointers::two_sum_sorted).
+///
+/// ## Why the Scan Can't Skip a Valid Pair
+/// Suppose a valid pair `(i, j)` exists. The invariant is
+/// `left <= i` and `j <= right`, which holds at the start. While it holds
+/// and the pair has not been found, one pointer has not reached its
+/// index yet, say `left < i` (the other case is symmetric). Then:
+/// - If the sum is too small, moving `left` keeps `left <= i`.
+/// - If the sum is too large, `right` cannot equal `j`: that would give
+///   `sorted[left] + sorted[j] <= sorted[i] + sorted[j] == target`. So
+///   `right > j`, and moving it keeps `j <= right`.
+///
+/// Each step shrinks the window, so the pointers reach `(i, j)` (or another
+/// valid pair first) before they can cross.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+pub fn pair_with_sum_sorted(sorted: &[i32], target: i32) -> Option<(usize, usize)> {
+    crate::vector::two_pointers::two_sum_sorted(sorted, target)
+}
+
+/// # Count Pairs With Sum in a Sorted Array
+///
+/// ## Problem Statement
+/// Count the index pairs `i < j` of an ascending array with
+/// `sorted[i] + sorted[j] == target`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::count_pairs_with_sum_sorted;
+/// assert_eq!(count_pairs_with_sum_sorted(&[1, 2, 3, 4, 5], 6), 2); // 1+5, 2+4
+/// assert_eq!(count_pairs_with_sum_sorted(&[1, 1, 2, 2, 2], 3), 6); // 2 × 3
+/// assert_eq!(count_pairs_with_sum_sorted(&[3, 3, 3, 3], 6), 6); // 4 choose 2
+/// ```
+///
+/// ## Approach
+/// The same walk as [`pair_with_sum_sorted`], but a match does not end it.
+/// On a match, the equal values at either end form runs of lengths `a` and
+/// `b`, which contribute `a · b` pairs at once; then both pointers skip past
+/// their runs. If the two runs meet (`sorted[left] == sorted[right]`), the
+/// `k` remaining elements are all equal and pair up in `k(k - 1) / 2` ways.
+///
+/// ## Complexity
+/// - Time: O(n), each element is passed over once
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Counting one pair per match and moving both pointers, which
+///    undercounts as soon as values repeat
+pub fn count_pairs_with_sum_sorted(sorted: &[i32], target: i32) -> usize {
+    let target = i64::from(target);
+    let mut count = 0;
+    let (mut left, mut right) = match sorted.len().checked_sub(1) {
+        Some(last) => (0, last),
+        None => return 0,
+    };
+    while left < right {
+        let sum = i64::from(sorted[left]) + i64::from(sorted[right]);
+        match sum.cmp(&target) {
+            std::cmp::Ordering::Less => left += 1,
+            std::cmp::Ordering::Greater => right -= 1,
+            std::cmp::Ordering::Equal if sorted[left] == sorted[right] => {
+                let k = right - left + 1;
+                count += k * (k - 1) / 2;
+                break;
+            }
+            std::cmp::Ordering::Equal => {
+                let low_run = sorted[left..]
+                    .iter()
+                    .take_while(|&&v| v == sorted[left])
+                    .count();
+                let high_run = sorted[..=right]
+                    .iter()
+                    .rev()
+                    .take_while(|&&v| v == sorted[right])
+                    .count();
+                count += low_run * high_run;
+                left += low_run;
+                right -= high_run;
+            }
+        }
+    }
+    count
+}
+
+/// # Equilibrium Index
+///
+/// ## Problem Statement
+/// Return the first index `i` where the elements before it and the elements
+/// after it have the same sum: `arr[..i].sum() == arr[i + 1..].sum()`. An
+/// empty side sums to 0.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::equilibrium_index;
+/// assert_eq!(equilibrium_index(&[-7, 1, 5, 2, -4, 3, 0]), Some(3)); // -7+1+5 = -4+3+0
+/// assert_eq!(equilibrium_index(&[42]), Some(0));
+/// assert_eq!(equilibrium_index(&[1, 2, 3]), None);
+/// ```
+///
+/// ## Approach
+/// Compute the total once. Then scan with a running sum of the elements to
+/// the left: the right side is `total - left - arr[i]`, so each candidate
+/// is checked in O(1) instead of re-summing both sides.
+///
+/// ## Complexity
+/// - Time: O(n), one pass for the total and one scan
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Sums are kept in `i128`: two `i64`s already overflow `i64`
+/// 2. The element at `i` belongs to neither side
+///
+/// ## Common Pitfalls
+/// 1. Adding `arr[i]` to the running sum before the comparison, which
+///    checks `arr[..=i]` against `arr[i + 1..]` instead
+pub fn equilibrium_index(arr: &[i64]) -> Option<usize> {
+    let total: i128 = arr.iter().map(|&value| i128::from(value)).sum();
+    let mut left = 0i128;
+    for (i, &value) in arr.iter().enumerate() {
+        let value = i128::from(value);
+        if left == total - left - value {
+            return Some(i);
+        }
+        left += value;
+    }
+    None
+}
+
+/// # Search Insert Position
+///
+/// ## Problem Statement
+/// Given an ascending array and a target, return the index of the target if
+/// present, or the index where it would be inserted to keep the array
+/// sorted. With duplicates, return the first of them.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::search_insert;
+/// let sorted = [1, 3, 5, 6];
+/// assert_eq!(search_insert(&sorted, 5), 2);
+/// assert_eq!(search_insert(&sorted, 2), 1);
+/// assert_eq!(search_insert(&sorted, 7), 4); // past the end is valid
+/// assert_eq!(search_insert(&sorted, 0), 0);
+/// ```
+///
+/// ## Approach
+/// This is exactly [`lower_bound`](crate::vector::lower_bound): the first
+/// index whose element is `>= target`. Its half-open window `lo..hi`
+/// starts as `0..len`, so the answer `len` is reachable without a special
+/// case.
+///
+/// ## Complexity
+/// - Time: O(log n)
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Starting with `hi = len - 1`, which can never return `len` for a
+///    target larger than everything (and underflows for an empty array)
+/// 2. Returning `mid` on the first match, which is *a* matching index but
+///    not necessarily the first when values repeat
+pub fn search_insert(sorted: &[i32], target: i32) -> usize {
+    crate::vector::lower_bound(sorted, &target)
+}
+
+/// # First Occurrence
+///
+/// ## Problem Statement
+/// Return the index of the first element equal to `target` in an ascending
+/// array with duplicates, or `None`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::{first_occurrence, last_occurrence};
+/// let sorted = [1, 2, 2, 2, 3];
+/// assert_eq!(first_occurrence(&sorted, 2), Some(1));
+/// assert_eq!(last_occurrence(&sorted, 2), Some(3));
+/// assert_eq!(first_occurrence(&sorted, 4), None);
+/// ```
+///
+/// ## Approach
+/// A binary search that does not stop at a match: it records `mid` as the
+/// best answer so far and keeps searching the left half, since an earlier
+/// match can only be there. [`last_occurrence`] keeps searching the right
+/// half instead.
+///
+/// ## Complexity
+/// - Time: O(log n), even if every element equals `target`
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Scanning left from a match to find the first one, which is O(n) for
+///    an array of equal values
+/// 2. A closed window `lo..=hi` with `usize` indices: `hi = mid - 1`
+///    underflows when `mid == 0`. The half-open window `lo..hi` with
+///    `hi = mid` avoids it
+/// 3. In [`last_occurrence`], moving `lo = mid` instead of `mid + 1`: with
+///    two elements left, `mid == lo` and the loop never ends
+pub fn first_occurrence(sorted: &[i32], target: i32) -> Option<usize> {
+    let (mut lo, mut hi) = (0, sorted.len());
+    let mut found = None;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match sorted[mid].cmp(&target) {
+            std::cmp::Ordering::Less => lo = mid + 1,
+            std::cmp::Ordering::Greater => hi = mid,
+            std::cmp::Ordering::Equal => {
+                found = Some(mid);
+                hi = mid;
+            }
+        }
+    }
+    found
+}
+
+/// Index of the last element equal to `target` in an ascending array, or
+/// `None`; the mirror image of [`first_occurrence`]. Time: O(log n);
+/// Space: O(1).
+pub fn last_occurrence(sorted: &[i32], target: i32) -> Option<usize> {
+    let (mut lo, mut hi) = (0, sorted.len());
+    let mut found = None;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match sorted[mid].cmp(&target) {
+            std::cmp::Ordering::Less => lo = mid + 1,
+            std::cmp::Ordering::Greater => hi = mid,
+            std::cmp::Ordering::Equal => {
+                found = Some(mid);
+                lo = mid + 1;
+            }
+        }
+    }
+    found
 }
@@ -0,0 +1,218 @@
+//! Const-Generic Array Problem Set
+//!
+//! The rest of the crate works on slices, `&[T]`, whose length is only
+//! known at run time. A Rust array `[T; N]` carries its length in its type,
+//! and const generics let one function handle every `N`:
+//!
+//! ```
+//! fn first_and_last<T: Copy, const N: usize>(arr: [T; N]) -> Option<(T, T)> {
+//!     Some((*arr.first()?, *arr.last()?))
+//! }
+//! assert_eq!(first_and_last([1, 2, 3]), Some((1, 3)));
+//! assert_eq!(first_and_last::<i32, 0>([]), None);
+//! ```
+//!
+//! Because `N` is a compile-time constant, size mismatches that a slice
+//! function would report as an `Err` (or a panic) become type errors, and
+//! the results live on the stack with no allocation.
+//!
+//! ## Length Arithmetic on Stable Rust
+//! A signature like `fn concat<const A: usize, const B: usize>(..) -> [T; A + B]`
+//! needs the unstable `generic_const_exprs` feature. The functions here use
+//! the stable workaround: the output length is one more const parameter,
+//! usually inferred from the caller's type annotation, and an inline
+//! `const { assert!(..) }` block checks the arithmetic when the function is
+//! instantiated. A wrong length is still a compile error, just reported at
+//! the call site's instantiation rather than in the signature.
+
+/// # Ring Rotation
+///
+/// ## Problem Statement
+/// Rotate a fixed-size array `shift` places to the right, wrapping around
+/// like a ring; a negative `shift` rotates to the left.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::fixed::ring_rotate;
+/// assert_eq!(ring_rotate([1, 2, 3, 4, 5], 2), [4, 5, 1, 2, 3]);
+/// assert_eq!(ring_rotate([1, 2, 3, 4, 5], -1), [2, 3, 4, 5, 1]);
+/// assert_eq!(ring_rotate([1, 2, 3], 7), [3, 1, 2]); // 7 ≡ 1 (mod 3)
+/// ```
+///
+/// ## Approach
+/// Reduce the shift into `0..N` with `rem_euclid`, which, unlike `%`, never
+/// returns a negative remainder, then rotate the owned array in place.
+///
+/// ## Complexity
+/// - Time: O(N)
+/// - Space: O(1) besides the array itself, which is moved in and out
+///
+/// ## Common Pitfalls
+/// 1. `shift % N` is negative for negative shifts
+/// 2. `N == 0` makes any remainder a division by zero, so it is handled
+///    first
+pub fn ring_rotate<T, const N: usize>(mut arr: [T; N], shift: isize) -> [T; N] {
+    if N == 0 {
+        return arr;
+    }
+    arr.rotate_right(shift.rem_euclid(N as isize) as usize);
+    arr
+}
+
+/// # Pairwise Swap
+///
+/// ## Problem Statement
+/// Swap elements `0` and `1`, `2` and `3`, and so on. For an odd `N` the
+/// last element has no partner and stays in place.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::fixed::pairwise_swap;
+/// assert_eq!(pairwise_swap([1, 2, 3, 4]), [2, 1, 4, 3]);
+/// assert_eq!(pairwise_swap(['a', 'b', 'c']), ['b', 'a', 'c']);
+/// ```
+///
+/// ## Approach
+/// `chunks_exact_mut(2)` yields the pairs and skips the odd element out.
+///
+/// ## Complexity
+/// - Time: O(N)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. `T` needs no `Clone` or `Copy` bound: elements are swapped, never
+///    duplicated
+pub fn pairwise_swap<T, const N: usize>(mut arr: [T; N]) -> [T; N] {
+    for pair in arr.chunks_exact_mut(2) {
+        pair.swap(0, 1);
+    }
+    arr
+}
+
+/// # Concatenate Arrays
+///
+/// ## Problem Statement
+/// Join `[T; A]` and `[T; B]` into a `[T; C]` with `C == A + B`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::fixed::concat;
+/// let joined: [i32; 5] = concat([1, 2], [3, 4, 5]);
+/// assert_eq!(joined, [1, 2, 3, 4, 5]);
+///
+/// // Works for types that are not `Copy`
+/// let words: [String; 3] = concat([String::from("a")], [String::from("b"), String::from("c")]);
+/// assert_eq!(words, ["a", "b", "c"]);
+/// ```
+///
+/// A wrong output length does not compile:
+/// ```compile_fail
+/// use rust_ds_learning::array::fixed::concat;
+/// let joined: [i32; 4] = concat([1, 2], [3, 4, 5]);
+/// ```
+///
+/// ## Approach
+/// Chain the two by-value iterators and fill the output with
+/// `std::array::from_fn`, taking one element per slot. The `const` block
+/// guarantees there are exactly `C` elements to take.
+///
+/// ## Complexity
+/// - Time: O(A + B)
+/// - Space: O(A + B) for the result, on the stack
+///
+/// ## Common Pitfalls
+/// 1. Copying through indices (`a[i]`) forces a `Copy` bound; consuming
+///    the arrays with `into_iter` moves the elements instead
+pub fn concat<T, const A: usize, const B: usize, const C: usize>(a: [T; A], b: [T; B]) -> [T; C] {
+    const { assert!(A + B == C, "output length must equal A + B") };
+    let mut elements = a.into_iter().chain(b);
+    std::array::from_fn(|_| elements.next().expect("A + B == C elements"))
+}
+
+/// # Split an Array
+///
+/// ## Problem Statement
+/// The inverse of [`concat`](fn@concat): split `[T; N]` into its first `A` and last
+/// `B` elements, with `A + B == N`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::fixed::split;
+/// let (head, tail): ([i32; 2], [i32; 3]) = split([1, 2, 3, 4, 5]);
+/// assert_eq!(head, [1, 2]);
+/// assert_eq!(tail, [3, 4, 5]);
+/// ```
+///
+/// A split that does not add up does not compile:
+/// ```compile_fail
+/// use rust_ds_learning::array::fixed::split;
+/// let (head, tail): ([i32; 2], [i32; 2]) = split([1, 2, 3, 4, 5]);
+/// ```
+///
+/// ## Complexity
+/// - Time: O(N)
+/// - Space: O(N) for the two halves, on the stack
+///
+/// ## Key Points
+/// 1. Compare with `slice::split_at`, which returns borrowed halves and
+///    panics at run time if the index is out of bounds
+pub fn split<T, const N: usize, const A: usize, const B: usize>(arr: [T; N]) -> ([T; A], [T; B]) {
+    const { assert!(A + B == N, "A + B must equal the input length") };
+    let mut elements = arr.into_iter();
+    let head = std::array::from_fn(|_| elements.next().expect("A <= N elements"));
+    let tail = std::array::from_fn(|_| elements.next().expect("A + B == N elements"));
+    (head, tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_rotate_matches_slice_rotation() {
+        let arr = [0, 1, 2, 3, 4, 5, 6];
+        for shift in -20..20isize {
+            let mut expected = arr;
+            expected.rotate_right(shift.rem_euclid(7) as usize);
+            assert_eq!(ring_rotate(arr, shift), expected, "shift {shift}");
+        }
+    }
+
+    #[test]
+    fn test_ring_rotate_edge_cases() {
+        assert_eq!(ring_rotate::<i32, 0>([], 3), [0i32; 0]);
+        assert_eq!(ring_rotate([9], -4), [9]);
+        assert_eq!(ring_rotate([1, 2], isize::MIN), [1, 2]);
+        assert_eq!(ring_rotate([1, 2, 3], isize::MAX), [3, 1, 2]);
+    }
+
+    #[test]
+    fn test_pairwise_swap() {
+        assert_eq!(pairwise_swap::<u8, 0>([]), [0u8; 0]);
+        assert_eq!(pairwise_swap([1]), [1]);
+        assert_eq!(pairwise_swap([1, 2, 3, 4, 5]), [2, 1, 4, 3, 5]);
+        // Swapping twice restores the original
+        let arr = [3, 1, 4, 1, 5, 9, 2, 6];
+        assert_eq!(pairwise_swap(pairwise_swap(arr)), arr);
+    }
+
+    #[test]
+    fn test_concat_with_empty_sides() {
+        let left: [u8; 3] = concat([], [1, 2, 3]);
+        let right: [u8; 3] = concat([1, 2, 3], []);
+        let neither: [u8; 0] = concat([], []);
+        assert_eq!(left, [1, 2, 3]);
+        assert_eq!(right, [1, 2, 3]);
+        assert_eq!(neither, [0u8; 0]);
+    }
+
+    #[test]
+    fn test_split_then_concat_round_trips() {
+        let arr = [String::from("x"), String::from("y"), String::from("z")];
+        let (head, tail): ([String; 1], [String; 2]) = split(arr.clone());
+        assert_eq!(head, ["x"]);
+        assert_eq!(tail, ["y", "z"]);
+        let joined: [String; 3] = concat(head, tail);
+        assert_eq!(joined, arr);
+    }
+}
@@ -0,0 +1,273 @@
+//! Hand-written versions of the slice iterators `chunks`, `windows` and
+//! `array_chunks`.
+//!
+//! Each iterator is a struct holding a borrowed slice `&'a [T]` plus a little
+//! state, and each call to `next` hands out a sub-slice and shrinks what is
+//! left. The lifetime `'a` ties every item to the original slice rather than
+//! to the iterator, so items may outlive the iterator itself:
+//!
+//! ```
+//! use rust_ds_learning::array::MyChunks;
+//!
+//! let data = [1, 2, 3, 4, 5];
+//! let first = {
+//!     let mut chunks = MyChunks::new(&data, 2).unwrap();
+//!     chunks.next()
+//! }; // `chunks` is dropped here, the chunk it returned lives on
+//! assert_eq!(first, Some(&[1, 2][..]));
+//! ```
+
+use crate::{ExerciseError, Result};
+
+/// # Chunks Iterator
+///
+/// ## Problem Statement
+/// Iterate over non-overlapping sub-slices of `size` elements, like
+/// `slice::chunks`. The last chunk is shorter if `size` does not divide the
+/// length.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::MyChunks;
+///
+/// let data = [1, 2, 3, 4, 5];
+/// let chunks: Vec<&[i32]> = MyChunks::new(&data, 2).unwrap().collect();
+/// assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+///
+/// let from_back: Vec<&[i32]> = MyChunks::new(&data, 2).unwrap().rev().collect();
+/// assert_eq!(from_back, [&[5][..], &[3, 4], &[1, 2]]);
+/// ```
+///
+/// ## Approach
+/// Keep the not-yet-returned part of the slice. `next` splits off the first
+/// `min(size, len)` elements; `next_back` splits off the *short* chunk
+/// first, so walking from the back yields the same chunks as walking from
+/// the front.
+///
+/// ## Complexity
+/// - Each step: O(1), sub-slicing copies no elements
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Taking `size` elements from the back in `next_back`, which yields
+///    different chunks than the forward walk when there is a remainder
+///
+/// ## Errors
+/// [`MyChunks::new`] returns `InvalidInput` for `size == 0`, where `std`
+/// panics instead.
+#[derive(Debug, Clone)]
+pub struct MyChunks<'a, T> {
+    rest: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> MyChunks<'a, T> {
+    pub fn new(slice: &'a [T], size: usize) -> Result<Self> {
+        Ok(MyChunks {
+            rest: slice,
+            size: check_size(size, "chunk")?,
+        })
+    }
+}
+
+impl<'a, T> Iterator for MyChunks<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.rest.split_at(self.size.min(self.rest.len()));
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for MyChunks<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let last_len = match self.rest.len() % self.size {
+            0 => self.size,
+            remainder => remainder,
+        };
+        let (rest, chunk) = self.rest.split_at(self.rest.len() - last_len);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for MyChunks<'_, T> {}
+
+/// # Windows Iterator
+///
+/// ## Problem Statement
+/// Iterate over every contiguous sub-slice of `size` elements, like
+/// `slice::windows`. Consecutive windows overlap in `size - 1` elements;
+/// a slice shorter than `size` has no windows.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::MyWindows;
+///
+/// let data = [1, 2, 3, 4];
+/// let sums: Vec<i32> = MyWindows::new(&data, 3)
+///     .unwrap()
+///     .map(|window| window.iter().sum())
+///     .collect();
+/// assert_eq!(sums, [6, 9]);
+/// assert_eq!(MyWindows::new(&data, 5).unwrap().count(), 0);
+/// ```
+///
+/// ## Approach
+/// Return the first `size` elements of the remaining slice, then drop only
+/// *one* element from its front. `next_back` mirrors this at the end.
+///
+/// ## Complexity
+/// - Each step: O(1)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. There are `len - size + 1` windows, computed as
+///    `(len + 1).saturating_sub(size)` so a short slice gives 0 instead of
+///    underflowing
+/// 2. Windows are borrowed, so they cannot be mutated: two overlapping
+///    `&mut` windows would alias, which is why `std` has no `windows_mut`
+///
+/// ## Errors
+/// [`MyWindows::new`] returns `InvalidInput` for `size == 0`.
+#[derive(Debug, Clone)]
+pub struct MyWindows<'a, T> {
+    rest: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> MyWindows<'a, T> {
+    pub fn new(slice: &'a [T], size: usize) -> Result<Self> {
+        Ok(MyWindows {
+            rest: slice,
+            size: check_size(size, "window")?,
+        })
+    }
+}
+
+impl<'a, T> Iterator for MyWindows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        let window = self.rest.get(..self.size)?;
+        self.rest = &self.rest[1..];
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.rest.len() + 1).saturating_sub(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for MyWindows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        let start = self.rest.len().checked_sub(self.size)?;
+        let window = &self.rest[start..];
+        self.rest = &self.rest[..self.rest.len() - 1];
+        Some(window)
+    }
+}
+
+impl<T> ExactSizeIterator for MyWindows<'_, T> {}
+
+/// # Array Chunks Iterator
+///
+/// ## Problem Statement
+/// Like [`MyChunks`], but the chunk size is a const generic `N` and every
+/// item is a fixed-size array reference `&[T; N]`. Elements that do not
+/// fill a whole chunk are not yielded; they are available from
+/// [`remainder`](MyArrayChunks::remainder).
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::MyArrayChunks;
+///
+/// let data = [1, 2, 3, 4, 5, 6, 7];
+/// let mut pairs = MyArrayChunks::<_, 2>::new(&data);
+/// assert_eq!(pairs.next(), Some(&[1, 2]));
+/// assert_eq!(pairs.remainder(), &[7]);
+///
+/// // Items are arrays, so they can be destructured
+/// let sums: Vec<i32> = MyArrayChunks::new(&data).map(|&[a, b]| a + b).collect();
+/// assert_eq!(sums, [3, 7, 11]);
+/// ```
+///
+/// A chunk size of zero does not compile:
+/// ```compile_fail
+/// use rust_ds_learning::array::MyArrayChunks;
+/// let chunks = MyArrayChunks::<i32, 0>::new(&[1, 2, 3]);
+/// ```
+///
+/// ## Approach
+/// Split the remainder off once, up front, and walk the evenly divisible
+/// part `N` elements at a time. Converting a sub-slice of length `N` into
+/// `&[T; N]` with `try_into` cannot fail, and it is free: both are pointers
+/// to the same memory, the array type just stops storing the length.
+///
+/// ## Complexity
+/// - Each step: O(1)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. `N == 0` is rejected by a `const` assertion at compile time, where
+///    [`MyChunks`] has to check its run-time size
+#[derive(Debug, Clone)]
+pub struct MyArrayChunks<'a, T, const N: usize> {
+    rest: &'a [T],
+    remainder: &'a [T],
+}
+
+impl<'a, T, const N: usize> MyArrayChunks<'a, T, N> {
+    pub fn new(slice: &'a [T]) -> Self {
+        const { assert!(N > 0, "chunk size must be positive") };
+        let (rest, remainder) = slice.split_at(slice.len() - slice.len() % N);
+        MyArrayChunks { rest, remainder }
+    }
+
+    /// The trailing elements that do not fill a whole chunk
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for MyArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<&'a [T; N]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.rest.split_at(N);
+        self.rest = rest;
+        Some(chunk.try_into().expect("chunk has exactly N elements"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for MyArrayChunks<'_, T, N> {}
+
+fn check_size(size: usize, what: &str) -> Result<usize> {
+    if size == 0 {
+        return Err(ExerciseError::InvalidInput(format!(
+            "{what} size must be positive"
+        )));
+    }
+    Ok(size)
+}
@@ -1,5 +1,64 @@
 mod exercises;
+pub mod fixed;
+mod iterators;
+mod ring_buffer;
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
 
 pub use exercises::*;
+pub use iterators::{MyArrayChunks, MyChunks, MyWindows};
+pub use ring_buffer::{OverflowPolicy, RingBuffer};
+
+crate::exercise! {
+    /// Registry entry for [`find_element`]
+    pub struct FindElementExercise {
+        name: "array::find_element",
+        difficulty: Easy,
+        topics: ["linear search"],
+        hints: ["Walk the array once and stop at the first match."],
+        solver: find_element::<i32> as fn(&[i32], &i32) -> Option<usize>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`find_peak`]
+    pub struct FindPeakExercise {
+        name: "array::find_peak",
+        difficulty: Medium,
+        topics: ["binary search"],
+        hints: [
+            "The largest element is always a peak, but you don't need the largest one.",
+            "If arr[mid] < arr[mid + 1], what do you know about the part right of mid?",
+        ],
+        solver: find_peak as fn(&[i32]) -> Option<usize>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`find_duplicate`]
+    pub struct FindDuplicateExercise {
+        name: "array::find_duplicate",
+        difficulty: Medium,
+        topics: ["cycle detection", "pigeonhole principle"],
+        hints: [
+            "Treat each value as a pointer to the index with that number.",
+            "Where does a walk from index 0 first enter a cycle?",
+        ],
+        solver: find_duplicate as fn(&[u32]) -> crate::Result<u32>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`count_pairs_with_sum_sorted`]
+    pub struct CountPairsWithSumSortedExercise {
+        name: "array::count_pairs_with_sum_sorted",
+        difficulty: Medium,
+        topics: ["two pointers"],
+        hints: [
+            "Start one pointer at each end and compare the sum with the target.",
+            "When the sum matches, how many pairs do the equal values at each end form?",
+        ],
+        solver: count_pairs_with_sum_sorted as fn(&[i32], i32) -> usize,
+    }
+}
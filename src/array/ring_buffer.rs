@@ -0,0 +1,172 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use crate::{ExerciseError, Result};
+
+/// What [`RingBuffer::push`] does when the buffer is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Evict the oldest element to make room, and return it
+    Overwrite,
+    /// Refuse the new element with an error
+    Reject,
+}
+
+/// # Fixed-Capacity Ring Buffer
+///
+/// A FIFO queue of at most `N` elements stored inline in a `[T; N]`-sized
+/// array, so it never allocates and can live entirely on the stack. It is
+/// the fixed-capacity counterpart of `VecDeque`, which grows its heap
+/// buffer instead of running out of room.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::array::{OverflowPolicy, RingBuffer};
+///
+/// // Keep the last three readings
+/// let mut recent = RingBuffer::<i32, 3>::new(OverflowPolicy::Overwrite);
+/// for reading in [10, 20, 30] {
+///     assert_eq!(recent.push(reading).unwrap(), None);
+/// }
+/// assert!(recent.is_full());
+/// assert_eq!(recent.push(40).unwrap(), Some(10)); // evicts the oldest
+/// assert_eq!(recent.iter().copied().collect::<Vec<_>>(), [20, 30, 40]);
+///
+/// // A bounded work queue refuses new jobs instead
+/// let mut jobs = RingBuffer::<&str, 2>::new(OverflowPolicy::Reject);
+/// jobs.push("build").unwrap();
+/// jobs.push("test").unwrap();
+/// assert!(jobs.push("deploy").is_err());
+/// assert_eq!(jobs.pop(), Some("build"));
+/// ```
+///
+/// ## Approach
+/// `head` is the slot of the oldest element and `len` the number of
+/// elements, which occupy slots `head, head + 1, ..` wrapping around modulo
+/// `N`. Pushing writes to slot `(head + len) % N`; popping reads slot
+/// `head` and advances it. Neither moves any other element.
+///
+/// ## Why `MaybeUninit`
+/// A plain `[T; N]` must hold `N` valid values at all times, which would
+/// force `T: Default` (or `Option<T>` slots, costing a tag per slot) just
+/// to have something in the empty slots. `[MaybeUninit<T>; N]` is allowed
+/// to hold garbage, and in exchange the compiler stops tracking which slots
+/// hold a value: the buffer has to do it. Everything `unsafe` here relies
+/// on one invariant, kept by every method:
+///
+/// > The slots `(head + i) % N` for `i < len` are initialized; all other
+/// > slots are not.
+///
+/// Reading a slot moves the value out (`assume_init_read`), after which the
+/// slot counts as uninitialized again, and `Drop` drops exactly the `len`
+/// live elements, since `MaybeUninit` never drops its contents itself.
+///
+/// ## Complexity
+/// - `push`, `pop`: O(1)
+/// - `iter`: O(1) per element
+/// - Space: `N` slots, always
+///
+/// ## Common Pitfalls
+/// 1. Overwriting a full slot with `slot.write(value)`, which forgets the
+///    old value instead of dropping it; it has to be read out first
+/// 2. Forgetting `Drop`, which leaks every element still in the buffer
+/// 3. Telling "full" from "empty" by `head == tail` alone: both look the
+///    same, which is why `len` is stored explicitly
+pub struct RingBuffer<T, const N: usize> {
+    slots: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub fn new(policy: OverflowPolicy) -> Self {
+        const { assert!(N > 0, "capacity must be positive") };
+        RingBuffer {
+            slots: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+            policy,
+        }
+    }
+
+    /// Appends `value` as the newest element.
+    ///
+    /// Returns the evicted oldest element if the buffer was full under
+    /// [`OverflowPolicy::Overwrite`], `None` otherwise.
+    ///
+    /// # Errors
+    /// `OperationFailed` if the buffer is full under
+    /// [`OverflowPolicy::Reject`]. `value` is dropped in that case; check
+    /// [`is_full`](Self::is_full) first to keep it.
+    pub fn push(&mut self, value: T) -> Result<Option<T>> {
+        let evicted = if self.is_full() {
+            match self.policy {
+                OverflowPolicy::Reject => {
+                    return Err(ExerciseError::OperationFailed(format!(
+                        "ring buffer is full ({N} elements)"
+                    )));
+                }
+                OverflowPolicy::Overwrite => self.pop(),
+            }
+        } else {
+            None
+        };
+        let tail = (self.head + self.len) % N;
+        // The slot after the last element is uninitialized (there was room
+        // or we just made room), so writing leaks nothing
+        self.slots[tail].write(value);
+        self.len += 1;
+        Ok(evicted)
+    }
+
+    /// Removes and returns the oldest element
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: `len > 0`, so the slot at `head` is initialized. Moving
+        // the value out and advancing `head` marks the slot as empty again.
+        let value = unsafe { self.slots[self.head].assume_init_read() };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// The elements from oldest to newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.len).map(move |i| {
+            // SAFETY: `i < len`, so this slot is initialized, and the shared
+            // borrow of `self` prevents it from being popped meanwhile
+            unsafe { self.slots[(self.head + i) % N].assume_init_ref() }
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for RingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
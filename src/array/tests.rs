@@ -1,25 +1,564 @@
 #[cfg(test)]
 mod tests {
-    use crate::array::find_element_arr;
+    use crate::array::{
+        count_pairs_with_sum_sorted, equilibrium_index, find_duplicate, find_element, find_missing,
+        find_missing_sum, find_peak, first_occurrence, last_occurrence, pair_with_sum_sorted,
+        search_insert, MyArrayChunks, MyChunks, MyWindows, OverflowPolicy, RingBuffer,
+    };
 
-    mod find_element_arr_tests {
+    mod find_element_tests {
         use super::*;
 
         #[test]
         fn test_empty_arr() {
-            assert_eq!(find_element_arr(&[], 0), -1);
+            assert_eq!(find_element(&[], &0), None);
         }
         #[test]
         fn test_no_element() {
-            assert_eq!(find_element_arr(&[1, 2, 3], 4), -1);
+            assert_eq!(find_element(&[1, 2, 3], &4), None);
         }
         #[test]
         fn test_typical_case() {
-            assert_eq!(find_element_arr(&[1, 2, 3], 2), 1);
+            assert_eq!(find_element(&[1, 2, 3], &2), Some(1));
         }
         #[test]
         fn test_one_element_arr() {
-            assert_eq!(find_element_arr(&[1], 1), 0);
+            assert_eq!(find_element(&[1], &1), Some(0));
+        }
+        #[test]
+        fn test_first_of_duplicates() {
+            assert_eq!(find_element(&[5, 3, 5, 3], &3), Some(1));
+        }
+        #[test]
+        fn test_generic_element_types() {
+            assert_eq!(find_element(&["x", "y"], &"y"), Some(1));
+            assert_eq!(find_element(&[0.5, f64::NAN], &f64::NAN), None);
+        }
+    }
+
+    #[allow(deprecated)]
+    mod find_element_arr_tests {
+        use crate::array::find_element_arr;
+
+        #[test]
+        fn test_forwards_with_sentinel() {
+            assert_eq!(find_element_arr(&[], 0), -1);
+            assert_eq!(find_element_arr(&[1, 2, 3], 4), -1);
+            assert_eq!(find_element_arr(&[1, 2, 3], 2), 1);
+            assert_eq!(find_element_arr(&[5, 3, 5, 3], 3), 1);
+        }
+    }
+
+    mod find_peak_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        fn is_peak(arr: &[i32], i: usize) -> bool {
+            (i == 0 || arr[i] >= arr[i - 1]) && (i + 1 == arr.len() || arr[i] >= arr[i + 1])
+        }
+
+        #[test]
+        fn test_empty_and_single() {
+            assert_eq!(find_peak(&[]), None);
+            assert_eq!(find_peak(&[42]), Some(0));
+        }
+        #[test]
+        fn test_strictly_increasing() {
+            let arr: Vec<i32> = (0..100).collect();
+            assert_eq!(find_peak(&arr), Some(99));
+        }
+        #[test]
+        fn test_strictly_decreasing() {
+            let arr: Vec<i32> = (0..100).rev().collect();
+            assert_eq!(find_peak(&arr), Some(0));
+        }
+        #[test]
+        fn test_plateaus() {
+            for arr in [
+                vec![1, 1, 1],
+                vec![1, 2, 2, 1],
+                vec![3, 3, 1, 2, 2],
+                vec![1, 2, 2, 2],
+            ] {
+                let peak = find_peak(&arr).unwrap();
+                assert!(is_peak(&arr, peak), "{arr:?}: {peak}");
+            }
+        }
+        #[test]
+        fn test_random_inputs() {
+            for seed in 0..300 {
+                let arr = random_vec(1 + seed as usize % 30, 0..5, seed);
+                let peak = find_peak(&arr).unwrap();
+                assert!(is_peak(&arr, peak), "{arr:?}: {peak}");
+            }
+        }
+    }
+
+    mod find_missing_tests {
+        use super::*;
+        use crate::vector::inputs::random_permutation;
+
+        #[test]
+        fn test_every_position_missing() {
+            for n in 0..40u32 {
+                for missing in 0..=n {
+                    let arr: Vec<u32> = (0..=n).filter(|&v| v != missing).rev().collect();
+                    assert_eq!(find_missing(&arr), missing, "{arr:?}");
+                    assert_eq!(find_missing_sum(&arr), missing, "{arr:?}");
+                }
+            }
+        }
+        #[test]
+        fn test_shuffled_inputs() {
+            for seed in 0..50 {
+                let mut arr: Vec<u32> = random_permutation(100 + seed as usize, seed)
+                    .into_iter()
+                    .map(|v| v as u32)
+                    .collect();
+                let missing = arr.swap_remove(seed as usize % arr.len());
+                assert_eq!(find_missing(&arr), missing);
+                assert_eq!(find_missing_sum(&arr), missing);
+            }
+        }
+        #[test]
+        fn test_sum_does_not_overflow_u32() {
+            // 0 + 1 + .. + 100_000 exceeds u32::MAX
+            let arr: Vec<u32> = (1..=100_000).collect();
+            assert_eq!(find_missing_sum(&arr), 0);
+            assert_eq!(find_missing(&arr), 0);
+        }
+        #[test]
+        fn test_broken_precondition_does_not_panic() {
+            // actual total 5 exceeds the expected total 1
+            let _ = find_missing_sum(&[5]);
+            let _ = find_missing(&[5]);
+        }
+    }
+
+    mod find_duplicate_tests {
+        use super::*;
+        use crate::vector::inputs::{random_permutation, random_vec};
+
+        fn is_repeated(arr: &[u32], value: u32) -> bool {
+            arr.iter().filter(|&&v| v == value).count() > 1
+        }
+
+        #[test]
+        fn test_duplicate_at_the_ends() {
+            for n in 1..30u32 {
+                for dup in 1..=n {
+                    let values: Vec<u32> = (1..=n).filter(|&v| v != dup).collect();
+                    // Both copies at the front, both at the back, one at each end
+                    let mut front = vec![dup, dup];
+                    front.extend(&values);
+                    let mut back = values.clone();
+                    back.extend([dup, dup]);
+                    let mut ends = vec![dup];
+                    ends.extend(&values);
+                    ends.push(dup);
+                    for arr in [front, back, ends] {
+                        assert_eq!(find_duplicate(&arr).unwrap(), dup, "{arr:?}");
+                    }
+                }
+            }
+        }
+        #[test]
+        fn test_extreme_values_duplicated() {
+            // The smallest and the largest value, n + 1 copies of one value
+            assert_eq!(find_duplicate(&[1, 1]).unwrap(), 1);
+            assert_eq!(find_duplicate(&[1, 2, 3, 4, 4]).unwrap(), 4);
+            assert_eq!(find_duplicate(&[4, 1, 2, 3, 4]).unwrap(), 4);
+            assert_eq!(find_duplicate(&[1, 4, 2, 3, 1]).unwrap(), 1);
+            assert_eq!(find_duplicate(&[3; 6]).unwrap(), 3);
+        }
+        #[test]
+        fn test_shuffled_with_repeats() {
+            for seed in 0..100 {
+                let n = 2 + seed as usize % 50;
+                let dup = 1 + seed as u32 % n as u32;
+                let mut arr: Vec<u32> = random_permutation(n, seed)
+                    .into_iter()
+                    .map(|v| v as u32 + 1)
+                    .collect();
+                arr.push(dup);
+                // Overwrite a few other values with the duplicate too
+                for i in random_vec(seed as usize % 4, 0..arr.len(), seed) {
+                    arr[i] = dup;
+                }
+                assert_eq!(find_duplicate(&arr).unwrap(), dup, "{arr:?}");
+            }
+        }
+        #[test]
+        fn test_several_repeated_values() {
+            for seed in 0..100 {
+                let n = 1 + seed as u32 % 20;
+                let arr = random_vec(n as usize + 1, 1..n + 1, seed);
+                let found = find_duplicate(&arr).unwrap();
+                assert!(is_repeated(&arr, found), "{found} in {arr:?}");
+            }
+        }
+        #[test]
+        fn test_invalid_input() {
+            assert!(find_duplicate(&[]).is_err());
+            assert!(find_duplicate(&[1]).is_err());
+            assert!(find_duplicate(&[0, 1]).is_err());
+            assert!(find_duplicate(&[1, 2, 3]).is_err());
+        }
+    }
+
+    mod pair_with_sum_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        fn count_brute_force(sorted: &[i32], target: i32) -> usize {
+            (0..sorted.len())
+                .map(|i| {
+                    (i + 1..sorted.len())
+                        .filter(|&j| sorted[i] + sorted[j] == target)
+                        .count()
+                })
+                .sum()
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            for seed in 0..200 {
+                let mut sorted = random_vec(seed as usize % 25, -10..10, seed);
+                sorted.sort_unstable();
+                for target in -22..22 {
+                    let expected = count_brute_force(&sorted, target);
+                    assert_eq!(count_pairs_with_sum_sorted(&sorted, target), expected);
+                    match pair_with_sum_sorted(&sorted, target) {
+                        Some((i, j)) => {
+                            assert!(i < j);
+                            assert_eq!(sorted[i] + sorted[j], target);
+                        }
+                        None => assert_eq!(expected, 0, "missed {target} in {sorted:?}"),
+                    }
+                }
+            }
+        }
+        #[test]
+        fn test_empty_and_single() {
+            assert_eq!(pair_with_sum_sorted(&[], 0), None);
+            assert_eq!(pair_with_sum_sorted(&[3], 6), None);
+            assert_eq!(count_pairs_with_sum_sorted(&[], 0), 0);
+            assert_eq!(count_pairs_with_sum_sorted(&[3], 6), 0);
+        }
+        #[test]
+        fn test_runs_of_equal_values() {
+            assert_eq!(count_pairs_with_sum_sorted(&[0; 100], 0), 4950);
+            assert_eq!(count_pairs_with_sum_sorted(&[1, 1, 1, 5, 5], 6), 6);
+            assert_eq!(count_pairs_with_sum_sorted(&[1, 1, 3, 3, 3, 5, 5], 6), 7);
+        }
+        #[test]
+        fn test_extreme_values() {
+            let sorted = [i32::MIN, i32::MIN, i32::MAX, i32::MAX];
+            assert_eq!(pair_with_sum_sorted(&sorted, -1), Some((0, 3)));
+            assert_eq!(count_pairs_with_sum_sorted(&sorted, -1), 4);
+            assert_eq!(count_pairs_with_sum_sorted(&sorted, i32::MIN), 0);
+        }
+    }
+
+    mod iterator_tests {
+        use super::*;
+
+        #[test]
+        fn test_chunks_match_std() {
+            let data: Vec<u32> = (0..23).collect();
+            for size in 1..30 {
+                let mine: Vec<&[u32]> = MyChunks::new(&data, size).unwrap().collect();
+                let std: Vec<&[u32]> = data.chunks(size).collect();
+                assert_eq!(mine, std, "size {size}");
+
+                let mine: Vec<&[u32]> = MyChunks::new(&data, size).unwrap().rev().collect();
+                let std: Vec<&[u32]> = data.chunks(size).rev().collect();
+                assert_eq!(mine, std, "size {size}, reversed");
+                assert_eq!(
+                    MyChunks::new(&data, size).unwrap().len(),
+                    data.chunks(size).len()
+                );
+            }
+        }
+        #[test]
+        fn test_chunks_from_both_ends() {
+            let data = [1, 2, 3, 4, 5, 6, 7];
+            let mut chunks = MyChunks::new(&data, 3).unwrap();
+            assert_eq!(chunks.next_back(), Some(&[7][..]));
+            assert_eq!(chunks.next(), Some(&[1, 2, 3][..]));
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(chunks.next_back(), Some(&[4, 5, 6][..]));
+            assert_eq!(chunks.next(), None);
+            assert_eq!(chunks.next_back(), None);
+        }
+        #[test]
+        fn test_windows_match_std() {
+            let data: Vec<u32> = (0..12).collect();
+            for size in 1..15 {
+                let mine: Vec<&[u32]> = MyWindows::new(&data, size).unwrap().collect();
+                let std: Vec<&[u32]> = data.windows(size).collect();
+                assert_eq!(mine, std, "size {size}");
+
+                let mine: Vec<&[u32]> = MyWindows::new(&data, size).unwrap().rev().collect();
+                let std: Vec<&[u32]> = data.windows(size).rev().collect();
+                assert_eq!(mine, std, "size {size}, reversed");
+                assert_eq!(
+                    MyWindows::new(&data, size).unwrap().len(),
+                    data.windows(size).len()
+                );
+            }
+        }
+        #[test]
+        fn test_array_chunks_match_std() {
+            let data: Vec<u32> = (0..23).collect();
+            let mine: Vec<&[u32; 4]> = MyArrayChunks::new(&data).collect();
+            let std: Vec<&[u32]> = data.chunks_exact(4).collect();
+            assert_eq!(mine.len(), std.len());
+            assert!(mine.iter().zip(&std).all(|(a, b)| a[..] == b[..]));
+            assert_eq!(
+                MyArrayChunks::<_, 4>::new(&data).remainder(),
+                data.chunks_exact(4).remainder()
+            );
+            assert_eq!(MyArrayChunks::<_, 4>::new(&data).len(), 5);
+        }
+        #[test]
+        fn test_empty_slices() {
+            let empty: [u8; 0] = [];
+            assert_eq!(MyChunks::new(&empty, 3).unwrap().next(), None);
+            assert_eq!(MyWindows::new(&empty, 1).unwrap().next_back(), None);
+            let mut chunks = MyArrayChunks::<_, 2>::new(&empty);
+            assert_eq!(chunks.next(), None);
+            assert!(chunks.remainder().is_empty());
+        }
+        #[test]
+        fn test_zero_size_is_rejected() {
+            assert!(MyChunks::new(&[1, 2], 0).is_err());
+            assert!(MyWindows::new(&[1, 2], 0).is_err());
+        }
+    }
+
+    mod equilibrium_index_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        fn brute_force(arr: &[i64]) -> Option<usize> {
+            (0..arr.len())
+                .find(|&i| arr[..i].iter().sum::<i64>() == arr[i + 1..].iter().sum::<i64>())
+        }
+
+        #[test]
+        fn test_empty_and_single() {
+            assert_eq!(equilibrium_index(&[]), None);
+            assert_eq!(equilibrium_index(&[0]), Some(0));
+            assert_eq!(equilibrium_index(&[-5]), Some(0));
+        }
+        #[test]
+        fn test_all_negative() {
+            assert_eq!(equilibrium_index(&[-1, -3, -5, -2, -2]), Some(2));
+            assert_eq!(equilibrium_index(&[-1, -2, -3]), None);
+            assert_eq!(equilibrium_index(&[-4, -4, -4]), Some(1));
+        }
+        #[test]
+        fn test_first_of_several() {
+            // Zeros keep both sides balanced everywhere
+            assert_eq!(equilibrium_index(&[0, 0, 0]), Some(0));
+            assert_eq!(equilibrium_index(&[1, -1, 0, 5]), Some(3));
+            assert_eq!(equilibrium_index(&[2, 0, 0, 2]), Some(1));
+        }
+        #[test]
+        fn test_matches_brute_force() {
+            for seed in 0..300 {
+                let arr = random_vec(seed as usize % 15, -3..4, seed);
+                assert_eq!(equilibrium_index(&arr), brute_force(&arr), "{arr:?}");
+            }
+        }
+        #[test]
+        fn test_no_overflow() {
+            assert_eq!(equilibrium_index(&[i64::MAX, 7, i64::MAX]), Some(1));
+            assert_eq!(
+                equilibrium_index(&[i64::MIN, i64::MIN, 1, i64::MIN, i64::MIN]),
+                Some(2)
+            );
+            assert_eq!(
+                equilibrium_index(&[i64::MAX, i64::MAX, 0, i64::MAX - 1]),
+                None
+            );
+        }
+    }
+
+    mod search_insert_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        #[test]
+        fn test_empty() {
+            assert_eq!(search_insert(&[], 5), 0);
+            assert_eq!(first_occurrence(&[], 5), None);
+            assert_eq!(last_occurrence(&[], 5), None);
+        }
+        #[test]
+        fn test_single_element() {
+            assert_eq!(search_insert(&[5], 4), 0);
+            assert_eq!(search_insert(&[5], 5), 0);
+            assert_eq!(search_insert(&[5], 6), 1);
+            assert_eq!(first_occurrence(&[5], 5), Some(0));
+            assert_eq!(last_occurrence(&[5], 5), Some(0));
+            assert_eq!(last_occurrence(&[5], 6), None);
+        }
+        #[test]
+        fn test_beyond_both_ends() {
+            let sorted = [i32::MIN, -1, 0, i32::MAX];
+            assert_eq!(search_insert(&sorted, i32::MIN), 0);
+            assert_eq!(search_insert(&sorted, i32::MAX), 3);
+            assert_eq!(search_insert(&[1, 2, 3], i32::MAX), 3);
+            assert_eq!(search_insert(&[1, 2, 3], i32::MIN), 0);
+            assert_eq!(first_occurrence(&sorted, i32::MIN), Some(0));
+            assert_eq!(last_occurrence(&sorted, i32::MAX), Some(3));
+        }
+        #[test]
+        fn test_two_elements() {
+            // The window of two is where `lo = mid` would loop forever
+            assert_eq!(last_occurrence(&[3, 3], 3), Some(1));
+            assert_eq!(first_occurrence(&[3, 3], 3), Some(0));
+            assert_eq!(last_occurrence(&[2, 3], 2), Some(0));
+            assert_eq!(first_occurrence(&[2, 3], 3), Some(1));
+        }
+        #[test]
+        fn test_all_equal() {
+            let sorted = [7; 1000];
+            assert_eq!(search_insert(&sorted, 7), 0);
+            assert_eq!(first_occurrence(&sorted, 7), Some(0));
+            assert_eq!(last_occurrence(&sorted, 7), Some(999));
+            assert_eq!(search_insert(&sorted, 8), 1000);
+        }
+        #[test]
+        fn test_matches_linear_scan() {
+            for seed in 0..200 {
+                let mut sorted = random_vec(seed as usize % 20, 0..8, seed);
+                sorted.sort_unstable();
+                for target in -1..10 {
+                    let first = sorted.iter().position(|&x| x == target);
+                    let last = sorted.iter().rposition(|&x| x == target);
+                    let insert = sorted.iter().take_while(|&&x| x < target).count();
+                    assert_eq!(
+                        first_occurrence(&sorted, target),
+                        first,
+                        "{sorted:?} {target}"
+                    );
+                    assert_eq!(
+                        last_occurrence(&sorted, target),
+                        last,
+                        "{sorted:?} {target}"
+                    );
+                    assert_eq!(
+                        search_insert(&sorted, target),
+                        insert,
+                        "{sorted:?} {target}"
+                    );
+                }
+            }
+        }
+    }
+
+    mod ring_buffer_tests {
+        use super::*;
+        use std::collections::VecDeque;
+        use std::rc::Rc;
+
+        fn contents<const N: usize>(buffer: &RingBuffer<i32, N>) -> Vec<i32> {
+            buffer.iter().copied().collect()
+        }
+
+        #[test]
+        fn test_fifo_order_across_wraparound() {
+            let mut buffer = RingBuffer::<i32, 3>::new(OverflowPolicy::Reject);
+            for round in 0..10 {
+                buffer.push(round).unwrap();
+                buffer.push(round + 100).unwrap();
+                assert_eq!(buffer.pop(), Some(round));
+                assert_eq!(buffer.pop(), Some(round + 100));
+                assert!(buffer.is_empty());
+            }
+            assert_eq!(buffer.pop(), None);
+        }
+        #[test]
+        fn test_overwrite_evicts_oldest() {
+            let mut buffer = RingBuffer::<i32, 3>::new(OverflowPolicy::Overwrite);
+            let evicted: Vec<Option<i32>> = (1..=7).map(|v| buffer.push(v).unwrap()).collect();
+            assert_eq!(
+                evicted,
+                [None, None, None, Some(1), Some(2), Some(3), Some(4)]
+            );
+            assert_eq!(contents(&buffer), [5, 6, 7]);
+            assert_eq!(buffer.iter().next_back(), Some(&7));
+            assert_eq!(buffer.len(), buffer.capacity());
+        }
+        #[test]
+        fn test_reject_keeps_contents() {
+            let mut buffer = RingBuffer::<i32, 2>::new(OverflowPolicy::Reject);
+            buffer.push(1).unwrap();
+            buffer.push(2).unwrap();
+            assert!(buffer.is_full());
+            assert!(buffer.push(3).is_err());
+            assert_eq!(contents(&buffer), [1, 2]);
+            buffer.pop();
+            buffer.push(3).unwrap();
+            assert_eq!(contents(&buffer), [2, 3]);
+        }
+        #[test]
+        fn test_capacity_one() {
+            let mut buffer = RingBuffer::<i32, 1>::new(OverflowPolicy::Overwrite);
+            assert_eq!(buffer.push(1).unwrap(), None);
+            assert_eq!(buffer.push(2).unwrap(), Some(1));
+            assert_eq!(buffer.pop(), Some(2));
+            assert_eq!(buffer.pop(), None);
+        }
+        #[test]
+        fn test_matches_vecdeque_model() {
+            let mut buffer = RingBuffer::<i32, 4>::new(OverflowPolicy::Overwrite);
+            let mut model = VecDeque::new();
+            for step in 0..200 {
+                if step % 3 == 2 {
+                    assert_eq!(buffer.pop(), model.pop_front());
+                } else {
+                    let evicted = if model.len() == 4 {
+                        model.pop_front()
+                    } else {
+                        None
+                    };
+                    model.push_back(step);
+                    assert_eq!(buffer.push(step).unwrap(), evicted);
+                }
+                assert!(buffer.iter().eq(model.iter()));
+                assert_eq!(buffer.len(), model.len());
+            }
+        }
+        #[test]
+        fn test_every_element_dropped_exactly_once() {
+            let tracker = Rc::new(());
+            {
+                let mut buffer = RingBuffer::<Rc<()>, 3>::new(OverflowPolicy::Overwrite);
+                for _ in 0..5 {
+                    // Evicted values are returned and dropped right here
+                    buffer.push(Rc::clone(&tracker)).unwrap();
+                }
+                drop(buffer.pop());
+                assert_eq!(Rc::strong_count(&tracker), 3);
+                let mut rejecting = RingBuffer::<Rc<()>, 1>::new(OverflowPolicy::Reject);
+                rejecting.push(Rc::clone(&tracker)).unwrap();
+                assert!(rejecting.push(Rc::clone(&tracker)).is_err());
+                assert_eq!(Rc::strong_count(&tracker), 4);
+            }
+            // Dropping the buffers dropped the elements still inside
+            assert_eq!(Rc::strong_count(&tracker), 1);
+        }
+        #[test]
+        fn test_debug_lists_oldest_first() {
+            let mut buffer = RingBuffer::<i32, 2>::new(OverflowPolicy::Overwrite);
+            for v in 1..=3 {
+                buffer.push(v).unwrap();
+            }
+            assert_eq!(format!("{buffer:?}"), "[2, 3]");
         }
     }
 }
@@ -0,0 +1,9 @@
+//! BinaryHeap exercises and examples module
+//!
+//! Exercises in other modules that are built around a `BinaryHeap`:
+//! - [`crate::vector::merge_k_sorted`]: a min-heap of list heads, using
+//!   `std::cmp::Reverse` to turn the max-heap around
+//! - [`crate::streaming::RunningMedian`] and [`crate::streaming::TopK`]:
+//!   heaps over a stream of items
+//! - [`crate::vector::min_meeting_rooms`]: a min-heap of room end times for
+//!   greedy room allocation
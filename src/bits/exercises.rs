@@ -0,0 +1,417 @@
+//! Bit Manipulation Exercises
+//!
+//! These exercises work directly on the binary representation of integers.
+//! Rust's bit operators behave differently on signed and unsigned types in a
+//! few places that matter here:
+//!
+//! - `>>` is a *logical* shift (fills with zeros) on unsigned types and an
+//!   *arithmetic* shift (fills with the sign bit) on signed types:
+//!   `-8_i32 >> 1 == -4`, but `(-8_i32 as u32) >> 1 == 0x7FFF_FFFC`.
+//! - `&`, `|`, `^` and `!` act on the two's complement bits and never
+//!   overflow, for both signed and unsigned types.
+//! - `-x` and `x - 1` *can* overflow (`i32::MIN`, `0_u32`) and panic in debug
+//!   builds; use `wrapping_neg`/`wrapping_sub` when the bits are all you need.
+//! - `as` between same-width signed and unsigned types reinterprets the bits
+//!   without changing them, which is the usual way to "view" a negative number
+//!   as a bit pattern.
+
+use crate::{ExerciseError, Result};
+
+/// # Count Set Bits (Kernighan)
+///
+/// ## Problem Statement
+/// Count the number of `1` bits in an unsigned integer.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::count_set_bits_kernighan;
+/// assert_eq!(count_set_bits_kernighan(0b1011_0000), 3);
+/// ```
+///
+/// ## Approach
+/// `x & (x - 1)` clears the lowest set bit, so the loop runs once per set
+/// bit rather than once per bit position.
+///
+/// ## Complexity
+/// - Time: O(k) where k is the number of set bits
+/// - Space: O(1)
+///
+/// ## Signed vs Unsigned
+/// On `i32`, `x - 1` overflows for `i32::MIN`. Convert with `x as u32` first:
+/// the bit pattern is unchanged and unsigned subtraction is safe once `x != 0`.
+///
+/// ## Common Pitfalls
+/// 1. Porting the loop to `i32` with `while x > 0`: a negative input has
+///    its sign bit set but fails the test at once, so the count comes out
+///    as 0. On `u32` the conditions `x > 0` and `x != 0` agree; `x != 0`
+///    is the one that stays correct for both
+pub fn count_set_bits_kernighan(mut x: u32) -> u32 {
+    let mut count = 0;
+    while x != 0 {
+        x &= x - 1;
+        count += 1;
+    }
+    count
+}
+
+/// # Count Set Bits (Parallel Popcount)
+///
+/// ## Problem Statement
+/// Count the number of `1` bits in constant time without a loop.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::count_set_bits_popcount;
+/// assert_eq!(count_set_bits_popcount(u32::MAX), 32);
+/// ```
+///
+/// ## Approach
+/// "SWAR" (SIMD within a register): first count bits in every 2-bit field,
+/// then add neighbouring fields into 4-bit and 8-bit counts, and finally sum
+/// the four byte counts with one multiplication.
+///
+/// ## Complexity
+/// - Time: O(1), about a dozen operations
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. This is what `u32::count_ones` compiles to when the CPU lacks a
+///    `popcnt` instruction
+/// 2. The final multiply must wrap: use `wrapping_mul`, since plain `*`
+///    panics on overflow in debug builds
+pub fn count_set_bits_popcount(x: u32) -> u32 {
+    let x = x - ((x >> 1) & 0x5555_5555);
+    let x = (x & 0x3333_3333) + ((x >> 2) & 0x3333_3333);
+    let x = (x + (x >> 4)) & 0x0F0F_0F0F;
+    x.wrapping_mul(0x0101_0101) >> 24
+}
+
+/// # Single Number
+///
+/// ## Problem Statement
+/// Every element appears exactly twice except one. Find that one in O(n)
+/// time and O(1) space. Returns `None` for an empty slice.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::single_number;
+/// assert_eq!(single_number(&[4, 1, 2, 1, 2]), Some(4));
+/// ```
+///
+/// ## Approach
+/// XOR every element: `a ^ a == 0` and XOR is commutative, so pairs cancel.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Signed vs Unsigned
+/// XOR works bit by bit, so negative numbers need no special handling.
+pub fn single_number(nums: &[i32]) -> Option<i32> {
+    if nums.is_empty() {
+        return None;
+    }
+    Some(nums.iter().fold(0, |acc, &x| acc ^ x))
+}
+
+/// # Single Number (Every Other Appears Three Times)
+///
+/// ## Problem Statement
+/// Every element appears exactly three times except one, which appears once.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::single_number_thrice;
+/// assert_eq!(single_number_thrice(&[2, 2, 3, 2]), Some(3));
+/// ```
+///
+/// ## Approach
+/// Count each bit position modulo 3 using two masks: `ones` holds bits seen
+/// once (mod 3), `twos` bits seen twice. A bit seen a third time is cleared
+/// from both, so at the end `ones` is exactly the single number.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Signed vs Unsigned
+/// The sign bit is counted like any other bit, so negative answers come out
+/// correctly without a cast.
+pub fn single_number_thrice(nums: &[i32]) -> Option<i32> {
+    if nums.is_empty() {
+        return None;
+    }
+    let (mut ones, mut twos) = (0, 0);
+    for &x in nums {
+        ones = (ones ^ x) & !twos;
+        twos = (twos ^ x) & !ones;
+    }
+    Some(ones)
+}
+
+/// # Two Single Numbers
+///
+/// ## Problem Statement
+/// Every element appears exactly twice except two distinct ones. Return them
+/// (smaller first), or `None` if the XOR of everything is zero.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::two_single_numbers;
+/// assert_eq!(two_single_numbers(&[1, 2, 1, 3, 2, 5]), Some((3, 5)));
+/// ```
+///
+/// ## Approach
+/// XOR of everything is `a ^ b`, which is non-zero because `a != b`. Any set
+/// bit of it differs between `a` and `b`; splitting the input by that bit
+/// puts `a` and `b` in different groups, each reducing to a single number.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Signed vs Unsigned
+/// The lowest set bit is `x & -x`, but `-x` overflows for `i32::MIN`, which is
+/// a perfectly valid XOR result. `x & x.wrapping_neg()` gives the same bits
+/// without the overflow check.
+pub fn two_single_numbers(nums: &[i32]) -> Option<(i32, i32)> {
+    let diff = nums.iter().fold(0, |acc, &x| acc ^ x);
+    if diff == 0 {
+        return None;
+    }
+    let lowest = diff & diff.wrapping_neg();
+    let a = nums
+        .iter()
+        .filter(|&&x| x & lowest != 0)
+        .fold(0, |acc, &x| acc ^ x);
+    let b = diff ^ a;
+    Some((a.min(b), a.max(b)))
+}
+
+/// # Swap Without a Temporary
+///
+/// ## Problem Statement
+/// Swap two integers using only XOR.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::swap_xor;
+/// let (mut a, mut b) = (3, -7);
+/// swap_xor(&mut a, &mut b);
+/// assert_eq!((a, b), (-7, 3));
+/// ```
+///
+/// ## Approach
+/// `a ^= b; b ^= a; a ^= b;` After the second step `b` holds the original
+/// `a`; the third step recovers the original `b` into `a`.
+///
+/// ## Key Points
+/// 1. In C the trick zeroes the value when both pointers alias the same
+///    variable. Rust cannot express that call: two `&mut` to one place are
+///    rejected by the borrow checker
+/// 2. In real code use `std::mem::swap`, which is clearer and just as fast
+#[allow(clippy::manual_swap)] // the manual version is the exercise
+pub fn swap_xor(a: &mut i32, b: &mut i32) {
+    *a ^= *b;
+    *b ^= *a;
+    *a ^= *b;
+}
+
+/// # Reverse Bits
+///
+/// ## Problem Statement
+/// Reverse the order of the 32 bits of an unsigned integer.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::reverse_bits;
+/// assert_eq!(reverse_bits(1), 0x8000_0000);
+/// ```
+///
+/// ## Approach
+/// Divide and conquer: swap the two 16-bit halves, then the bytes inside
+/// each half, then nibbles, bit pairs and finally single bits.
+///
+/// ## Complexity
+/// - Time: O(log w) for a w-bit word, i.e. five steps
+/// - Space: O(1)
+///
+/// ## Signed vs Unsigned
+/// Use an unsigned type: with `i32` the arithmetic `>>` would smear the sign
+/// bit into the upper half during the first swap.
+pub fn reverse_bits(x: u32) -> u32 {
+    let x = x.rotate_left(16);
+    let x = ((x & 0x00FF_00FF) << 8) | ((x >> 8) & 0x00FF_00FF);
+    let x = ((x & 0x0F0F_0F0F) << 4) | ((x >> 4) & 0x0F0F_0F0F);
+    let x = ((x & 0x3333_3333) << 2) | ((x >> 2) & 0x3333_3333);
+    ((x & 0x5555_5555) << 1) | ((x >> 1) & 0x5555_5555)
+}
+
+/// # Subsets via Bitmask
+///
+/// ## Problem Statement
+/// Generate all 2^n subsets of `items`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::subsets;
+/// assert_eq!(
+///     subsets(&['a', 'b']),
+///     vec![vec![], vec!['a'], vec!['b'], vec!['a', 'b']]
+/// );
+/// ```
+///
+/// ## Approach
+/// Every number `mask` in `0..2^n` encodes one subset: bit `i` set means
+/// `items[i]` is included.
+///
+/// ## Complexity
+/// - Time: O(n · 2^n)
+/// - Space: O(n · 2^n) for the output
+///
+/// ## Common Pitfalls
+/// 1. `1 << n` overflows once n reaches the word size; this function panics
+///    for more than 30 items (which would be over a billion subsets anyway)
+pub fn subsets<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    assert!(items.len() <= 30, "too many items for subset enumeration");
+    (0..1_usize << items.len())
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.clone())
+                .collect()
+        })
+        .collect()
+}
+
+/// # Gray Code
+///
+/// ## Problem Statement
+/// Produce the n-bit reflected Gray code: all 2^n values, each differing
+/// from the previous one in exactly one bit.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::bits::gray_code;
+/// assert_eq!(gray_code(2), vec![0b00, 0b01, 0b11, 0b10]);
+/// ```
+///
+/// ## Approach
+/// The i-th Gray code is `i ^ (i >> 1)`. Incrementing `i` flips a run of
+/// trailing bits, and XOR with the shifted value cancels all but one flip.
+///
+/// ## Complexity
+/// - Time: O(2^n)
+/// - Space: O(2^n)
+///
+/// ## Signed vs Unsigned
+/// The formula relies on a logical shift, hence `u32`.
+pub fn gray_code(n: u32) -> Vec<u32> {
+    assert!(n <= 30, "gray code longer than 2^30 entries");
+    (0..1_u32 << n).map(|i| i ^ (i >> 1)).collect()
+}
+
+/// Inverse of the Gray code mapping: prefix XOR of all higher bits.
+///
+/// ```
+/// use rust_ds_learning::bits::gray_to_binary;
+/// assert_eq!(gray_to_binary(0b10), 3);
+/// ```
+pub fn gray_to_binary(mut g: u32) -> u32 {
+    for shift in [1, 2, 4, 8, 16] {
+        g ^= g >> shift;
+    }
+    g
+}
+
+/// # Traveling Salesman via Bitmask DP (Held–Karp)
+///
+/// ## Problem Statement
+/// Given a square distance matrix, find the cost of the shortest tour that
+/// starts at city 0, visits every city exactly once and returns to city 0.
+///
+/// ## Example
+/// The classic five-city example:
+/// ```
+/// use rust_ds_learning::bits::tsp_min_tour;
+/// let dist = vec![
+///     vec![0, 2, 9, 10, 7],
+///     vec![2, 0, 6, 4, 3],
+///     vec![9, 6, 0, 8, 5],
+///     vec![10, 4, 8, 0, 6],
+///     vec![7, 3, 5, 6, 0],
+/// ];
+/// // 0 -> 1 -> 3 -> 2 -> 4 -> 0 costs 2 + 4 + 8 + 5 + 7
+/// assert_eq!(tsp_min_tour(&dist).unwrap(), 26);
+/// ```
+///
+/// ## Approach
+/// `dp[mask][last]` is the cheapest path that starts at 0, visits exactly
+/// the cities in `mask` and ends at `last`. Each state extends a state whose
+/// mask lacks `last`, so iterating masks in increasing order is a valid
+/// topological order. For small `n` this is no saving (5 cities give
+/// 32 · 5 = 160 states but only 4! = 24 tours); the bitmask wins as `n`
+/// grows: 10 cities is 1024 · 10 ≈ 10k states versus 9! ≈ 363k tours, and
+/// 16 cities is ~1M states versus 1.3 trillion tours.
+///
+/// ## Complexity
+/// - Time: O(2^n · n²)
+/// - Space: O(2^n · n)
+///
+/// ## Common Pitfalls
+/// 1. Adding to an "infinity" sentinel overflows; skip unreachable states
+/// 2. Forgetting the edge back to the start city
+///
+/// ## Errors
+/// `InvalidInput` for a non-square matrix or more than 16 cities.
+pub fn tsp_min_tour(dist: &[Vec<u32>]) -> Result<u64> {
+    const MAX_CITIES: usize = 16;
+    let n = dist.len();
+    if let Some(row) = dist.iter().position(|row| row.len() != n) {
+        return Err(ExerciseError::InvalidInput(format!(
+            "distance matrix must be square: row {row} has {} entries, expected {n}",
+            dist[row].len()
+        )));
+    }
+    if n > MAX_CITIES {
+        return Err(ExerciseError::InvalidInput(format!(
+            "{n} cities exceed the supported maximum of {MAX_CITIES}"
+        )));
+    }
+    if n <= 1 {
+        return Ok(0);
+    }
+
+    let full = (1_usize << n) - 1;
+    let mut dp = vec![vec![u64::MAX; n]; 1 << n];
+    dp[1][0] = 0;
+    for mask in 1..=full {
+        // Every path starts at city 0
+        if mask & 1 == 0 {
+            continue;
+        }
+        for last in 0..n {
+            let cost = dp[mask][last];
+            if cost == u64::MAX {
+                continue;
+            }
+            for next in 0..n {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                let next_mask = mask | (1 << next);
+                let candidate = cost + u64::from(dist[last][next]);
+                if candidate < dp[next_mask][next] {
+                    dp[next_mask][next] = candidate;
+                }
+            }
+        }
+    }
+
+    Ok((1..n)
+        .map(|last| dp[full][last] + u64::from(dist[last][0]))
+        .min()
+        .unwrap_or(0))
+}
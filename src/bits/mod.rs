@@ -0,0 +1,50 @@
+//! Bit manipulation exercises module
+
+mod exercises;
+#[cfg(test)]
+mod tests;
+
+pub use exercises::*;
+
+crate::exercise! {
+    /// Registry entry for [`count_set_bits_kernighan`]
+    pub struct CountSetBitsExercise {
+        name: "bits::count_set_bits_kernighan",
+        difficulty: Easy,
+        topics: ["bit manipulation"],
+        hints: [
+            "What does x & (x - 1) do to the lowest set bit?",
+            "Count how many times you can apply it before reaching zero.",
+        ],
+        solver: count_set_bits_kernighan as fn(u32) -> u32,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`single_number`]
+    pub struct SingleNumberExercise {
+        name: "bits::single_number",
+        difficulty: Easy,
+        topics: ["bit manipulation", "xor"],
+        hints: [
+            "x ^ x == 0 and x ^ 0 == x.",
+            "XOR is commutative, so pairs cancel regardless of order.",
+        ],
+        solver: single_number as fn(&[i32]) -> Option<i32>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`tsp_min_tour`]
+    pub struct TspBitmaskExercise {
+        name: "bits::tsp_min_tour",
+        difficulty: Hard,
+        topics: ["bit manipulation", "dynamic programming", "graphs"],
+        hints: [
+            "A set of visited cities fits in an integer: bit i means city i was visited.",
+            "The best way to finish only depends on the visited set and the current city.",
+            "dp[mask][last] = min over prev in mask of dp[mask without last][prev] + dist[prev][last].",
+        ],
+        solver: tsp_min_tour as fn(&[Vec<u32>]) -> crate::Result<u64>,
+    }
+}
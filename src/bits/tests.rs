@@ -0,0 +1,197 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::bits::*;
+use crate::ExerciseError;
+
+mod count_bits_tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_values() {
+        for x in [0, 1, 2, 3, 0x8000_0000, u32::MAX, 0xAAAA_AAAA] {
+            assert_eq!(count_set_bits_kernighan(x), x.count_ones());
+            assert_eq!(count_set_bits_popcount(x), x.count_ones());
+        }
+    }
+
+    #[test]
+    fn test_random_values_agree_with_count_ones() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..1000 {
+            let x: u32 = rng.gen();
+            assert_eq!(count_set_bits_kernighan(x), x.count_ones());
+            assert_eq!(count_set_bits_popcount(x), x.count_ones());
+        }
+    }
+
+    #[test]
+    fn test_negative_numbers_via_cast() {
+        assert_eq!(count_set_bits_kernighan(-1_i32 as u32), 32);
+        assert_eq!(count_set_bits_kernighan(i32::MIN as u32), 1);
+    }
+}
+
+mod single_number_tests {
+    use super::*;
+
+    #[test]
+    fn test_single_number() {
+        assert_eq!(single_number(&[]), None);
+        assert_eq!(single_number(&[7]), Some(7));
+        assert_eq!(single_number(&[2, 2, 1]), Some(1));
+        assert_eq!(single_number(&[-3, 5, 5]), Some(-3));
+        assert_eq!(single_number(&[i32::MIN, 0, 0]), Some(i32::MIN));
+    }
+
+    #[test]
+    fn test_single_number_thrice() {
+        assert_eq!(single_number_thrice(&[]), None);
+        assert_eq!(single_number_thrice(&[0, 1, 0, 1, 0, 1, 99]), Some(99));
+        assert_eq!(single_number_thrice(&[-2, -2, 1, 1, -3, 1, -2]), Some(-3));
+        assert_eq!(
+            single_number_thrice(&[i32::MAX, 4, i32::MAX, i32::MAX]),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_two_single_numbers() {
+        assert_eq!(two_single_numbers(&[]), None);
+        assert_eq!(two_single_numbers(&[1, 1]), None);
+        assert_eq!(two_single_numbers(&[-1, 0]), Some((-1, 0)));
+        assert_eq!(
+            two_single_numbers(&[i32::MIN, 0, 9, 9]),
+            Some((i32::MIN, 0))
+        );
+        assert_eq!(two_single_numbers(&[4, -4, 6, 6]), Some((-4, 4)));
+    }
+}
+
+mod swap_and_reverse_tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_xor() {
+        let (mut a, mut b) = (i32::MIN, i32::MAX);
+        swap_xor(&mut a, &mut b);
+        assert_eq!((a, b), (i32::MAX, i32::MIN));
+        let (mut c, mut d) = (5, 5);
+        swap_xor(&mut c, &mut d);
+        assert_eq!((c, d), (5, 5));
+    }
+
+    #[test]
+    fn test_reverse_bits() {
+        assert_eq!(reverse_bits(0), 0);
+        assert_eq!(reverse_bits(0b1011), 0xD000_0000);
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..1000 {
+            let x: u32 = rng.gen();
+            assert_eq!(reverse_bits(x), x.reverse_bits());
+            assert_eq!(reverse_bits(reverse_bits(x)), x);
+        }
+    }
+}
+
+mod subset_and_gray_tests {
+    use super::*;
+
+    #[test]
+    fn test_subsets() {
+        assert_eq!(subsets::<i32>(&[]), vec![Vec::<i32>::new()]);
+        let all = subsets(&[1, 2, 3]);
+        assert_eq!(all.len(), 8);
+        assert!(all.contains(&vec![1, 3]));
+        assert_eq!(all.last(), Some(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_gray_code_changes_one_bit() {
+        assert_eq!(gray_code(0), vec![0]);
+        for n in 1..=8 {
+            let codes = gray_code(n);
+            assert_eq!(codes.len(), 1 << n);
+            for pair in codes.windows(2) {
+                assert_eq!((pair[0] ^ pair[1]).count_ones(), 1);
+            }
+            // It is a permutation of 0..2^n
+            let mut sorted = codes.clone();
+            sorted.sort_unstable();
+            assert!(sorted.iter().copied().eq(0..1 << n));
+        }
+    }
+
+    #[test]
+    fn test_gray_to_binary_inverts() {
+        for (i, code) in gray_code(10).into_iter().enumerate() {
+            assert_eq!(gray_to_binary(code), i as u32);
+        }
+        assert_eq!(gray_to_binary(u32::MAX ^ (u32::MAX >> 1)), u32::MAX);
+    }
+}
+
+mod tsp_tests {
+    use super::*;
+
+    fn brute_force(dist: &[Vec<u32>]) -> u64 {
+        fn permute(rest: &mut Vec<usize>, k: usize, dist: &[Vec<u32>], best: &mut u64) {
+            if k == rest.len() {
+                let mut cost = 0;
+                let mut prev = 0;
+                for &city in rest.iter() {
+                    cost += u64::from(dist[prev][city]);
+                    prev = city;
+                }
+                *best = (*best).min(cost + u64::from(dist[prev][0]));
+                return;
+            }
+            for i in k..rest.len() {
+                rest.swap(k, i);
+                permute(rest, k + 1, dist, best);
+                rest.swap(k, i);
+            }
+        }
+        let mut rest: Vec<usize> = (1..dist.len()).collect();
+        let mut best = u64::MAX;
+        permute(&mut rest, 0, dist, &mut best);
+        best
+    }
+
+    #[test]
+    fn test_trivial_sizes() {
+        assert_eq!(tsp_min_tour(&[]).unwrap(), 0);
+        assert_eq!(tsp_min_tour(&[vec![0]]).unwrap(), 0);
+        assert_eq!(tsp_min_tour(&[vec![0, 3], vec![4, 0]]).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_asymmetric_distances_match_brute_force() {
+        let mut rng = StdRng::seed_from_u64(13);
+        for n in 2..=7 {
+            let dist: Vec<Vec<u32>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.gen_range(1..100)).collect())
+                .collect();
+            assert_eq!(tsp_min_tour(&dist).unwrap(), brute_force(&dist), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            tsp_min_tour(&[vec![0, 1], vec![1]]),
+            Err(ExerciseError::InvalidInput(_))
+        ));
+        let too_big = vec![vec![1; 17]; 17];
+        assert!(matches!(
+            tsp_min_tour(&too_big),
+            Err(ExerciseError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_large_weights_do_not_overflow() {
+        let dist = vec![vec![u32::MAX; 4]; 4];
+        assert_eq!(tsp_min_tour(&dist).unwrap(), 4 * u64::from(u32::MAX));
+    }
+}
@@ -0,0 +1,205 @@
+//! Ownership-aware API design exercises
+//!
+//! These exercises are about *signatures* as much as algorithms: when should
+//! a function take or return an owned value, a borrow, or a `Cow` that is
+//! either, decided at run time?
+
+use std::borrow::Cow;
+
+/// # Escape HTML Without Needless Allocation
+///
+/// ## Problem Statement
+/// Replace `&`, `<`, `>`, `"` and `'` with their HTML entities. Inputs that
+/// contain none of them must be returned without allocating.
+///
+/// ## Example
+/// ```
+/// use std::borrow::Cow;
+/// use rust_ds_learning::borrowing::escape_html;
+///
+/// assert!(matches!(escape_html("plain text"), Cow::Borrowed("plain text")));
+/// assert_eq!(escape_html("a < b"), "a &lt; b");
+/// ```
+///
+/// ## Approach
+/// Find the first special character. If there is none, hand back the input
+/// as `Cow::Borrowed`. Otherwise copy the clean prefix into a `String` once
+/// and escape the remainder into it.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1) when nothing needs escaping, O(n) otherwise
+///
+/// ## Key Points
+/// 1. `Cow<str>` derefs to `&str`, so callers rarely need to care which
+///    variant they got
+/// 2. Compare with [`escape_html_owned`], which always allocates: for
+///    mostly-clean input the difference is one allocation per call
+///
+/// ## Common Pitfalls
+/// 1. Calling `.to_string()` "just in case" before knowing whether anything
+///    changes, which throws the benefit away
+/// 2. Searching bytes and then slicing at a non-char boundary; all special
+///    characters here are ASCII, so byte positions are char boundaries
+pub fn escape_html(input: &str) -> Cow<'_, str> {
+    let Some(first) = input.find(['&', '<', '>', '"', '\'']) else {
+        return Cow::Borrowed(input);
+    };
+    let mut escaped = String::with_capacity(input.len() + 16);
+    escaped.push_str(&input[..first]);
+    push_escaped(&mut escaped, &input[first..]);
+    Cow::Owned(escaped)
+}
+
+/// The always-allocating baseline for [`escape_html`].
+pub fn escape_html_owned(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    push_escaped(&mut escaped, input);
+    escaped
+}
+
+fn push_escaped(out: &mut String, input: &str) {
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// # Clamp Values Copy-on-Write
+///
+/// ## Problem Statement
+/// Clamp every value into `lo..=hi`. Return the input slice itself when all
+/// values are already in range.
+///
+/// ## Example
+/// ```
+/// use std::borrow::Cow;
+/// use rust_ds_learning::borrowing::clamp_values;
+///
+/// let ok = [1, 2, 3];
+/// assert!(matches!(clamp_values(&ok, 0, 5), Cow::Borrowed(_)));
+/// assert_eq!(clamp_values(&[-4, 2, 9], 0, 5).as_ref(), &[0, 2, 5]);
+/// ```
+///
+/// ## Approach
+/// Start from `Cow::Borrowed` and call `Cow::to_mut` only for values that
+/// need changing. The first `to_mut` clones the slice into a `Vec`; later
+/// calls reuse it.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1) when nothing changes, O(n) otherwise
+///
+/// ## Common Pitfalls
+/// 1. Holding a reference into the borrowed data across `to_mut`: the borrow
+///    checker rejects it, because `to_mut` may replace the storage
+pub fn clamp_values(values: &[i32], lo: i32, hi: i32) -> Cow<'_, [i32]> {
+    let mut result = Cow::Borrowed(values);
+    for (i, &value) in values.iter().enumerate() {
+        let clamped = value.clamp(lo, hi);
+        if clamped != value {
+            result.to_mut()[i] = clamped;
+        }
+    }
+    result
+}
+
+/// # API Design: From Owning to Borrowing (before)
+///
+/// Splits `"key = value"` into a trimmed key and value. This version takes
+/// the line by value and returns owned strings, so every call costs up to
+/// three allocations: the caller's `String` plus two new ones. See
+/// [`split_key_value`] for the borrowing rewrite.
+pub fn split_key_value_owned(line: String) -> Option<(String, String)> {
+    let (key, value) = line.split_once('=')?;
+    Some((key.trim().to_string(), value.trim().to_string()))
+}
+
+/// # API Design: From Owning to Borrowing (after)
+///
+/// ## Problem Statement
+/// Rewrite [`split_key_value_owned`] so that it never allocates.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::borrowing::split_key_value;
+/// assert_eq!(split_key_value(" name = ferris "), Some(("name", "ferris")));
+/// assert_eq!(split_key_value("no separator"), None);
+/// ```
+///
+/// ## Approach
+/// Key and value are both sub-slices of the input, so return `&str`s that
+/// borrow from it. The results live as long as the line does, not a moment
+/// longer.
+///
+/// ## Key Points
+/// 1. Accepting `&str` instead of `String` lets callers pass literals,
+///    slices of larger buffers, or `&String` alike
+/// 2. With a single reference parameter, elision ties every output reference
+///    to it; written out the signature is
+///    `fn split_key_value<'a>(line: &'a str) -> Option<(&'a str, &'a str)>`
+///
+/// ## Common Pitfalls
+/// 1. Returning references to a temporary (e.g. a trimmed `String` built
+///    inside the function): there is nothing for them to borrow from
+pub fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once('=')?;
+    Some((key.trim(), value.trim()))
+}
+
+/// # Borrowing View Over a Config File
+///
+/// ## Problem Statement
+/// Parse `key = value` lines (ignoring blanks and `#` comments) into a
+/// structure that borrows from the source text instead of copying it.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::borrowing::ConfigView;
+///
+/// let text = String::from("# settings\nname = ferris\nlegs = 10\n");
+/// let config = ConfigView::parse(&text);
+/// assert_eq!(config.get("legs"), Some("10"));
+/// // `config` cannot outlive `text`: dropping `text` first is a compile error
+/// ```
+///
+/// ## Key Points
+/// 1. A struct holding references needs a lifetime parameter, and so does
+///    every `impl` block for it
+/// 2. `get` returns `&'a str` (tied to the text), not `&str` tied to `&self`,
+///    so results stay usable after the view itself is dropped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigView<'a> {
+    entries: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> ConfigView<'a> {
+    pub fn parse(text: &'a str) -> Self {
+        let entries = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(split_key_value)
+            .collect();
+        Self { entries }
+    }
+
+    /// Value of the last entry for `key`, as later lines override earlier ones
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map(|&(_, v)| v)
+    }
+
+    pub fn entries(&self) -> &[(&'a str, &'a str)] {
+        &self.entries
+    }
+}
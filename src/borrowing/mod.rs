@@ -0,0 +1,35 @@
+//! Borrowing, lifetimes and `Cow` exercises module
+
+mod exercises;
+#[cfg(test)]
+mod tests;
+
+pub use exercises::*;
+
+crate::exercise! {
+    /// Registry entry for [`escape_html`]
+    pub struct EscapeHtmlExercise {
+        name: "borrowing::escape_html",
+        difficulty: Easy,
+        topics: ["cow", "strings", "allocation"],
+        hints: [
+            "Most inputs contain nothing to escape. What can you return then without copying?",
+            "Scan for the first special character; only from there on do you need a String.",
+        ],
+        solver: escape_html as fn(&str) -> std::borrow::Cow<'_, str>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`split_key_value`]
+    pub struct SplitKeyValueExercise {
+        name: "borrowing::split_key_value",
+        difficulty: Medium,
+        topics: ["lifetimes", "api design"],
+        hints: [
+            "Both halves are substrings of the input line.",
+            "Tie the lifetime of the returned slices to the input with a named lifetime.",
+        ],
+        solver: split_key_value as fn(&str) -> Option<(&str, &str)>,
+    }
+}
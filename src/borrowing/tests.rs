@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+use crate::borrowing::*;
+use crate::compare::CountingAllocator;
+
+mod escape_html_tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_input_is_borrowed() {
+        for input in ["", "hello", "ünïcödé ✓", "a + b = c"] {
+            assert!(matches!(escape_html(input), Cow::Borrowed(s) if s == input));
+        }
+    }
+
+    #[test]
+    fn test_escapes_every_special_character() {
+        assert_eq!(
+            escape_html(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+        assert!(matches!(escape_html("&"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_matches_owned_version() {
+        for input in ["", "plain", "<>", "x & y", "ü<ü", "'quoted'"] {
+            assert_eq!(escape_html(input), escape_html_owned(input));
+        }
+    }
+
+    #[test]
+    fn test_clean_input_does_not_allocate() {
+        let inputs: Vec<String> = (0..100).map(|i| format!("line {i}")).collect();
+
+        let before = CountingAllocator::allocations();
+        let total: usize = inputs.iter().map(|s| escape_html(s).len()).sum();
+        let cow_allocations = CountingAllocator::allocations() - before;
+
+        let before = CountingAllocator::allocations();
+        let owned_total: usize = inputs.iter().map(|s| escape_html_owned(s).len()).sum();
+        let owned_allocations = CountingAllocator::allocations() - before;
+
+        assert_eq!(total, owned_total);
+        assert_eq!(cow_allocations, 0);
+        assert_eq!(owned_allocations, 100);
+    }
+}
+
+mod clamp_values_tests {
+    use super::*;
+
+    #[test]
+    fn test_in_range_is_borrowed() {
+        let values = [0, 5, 3];
+        assert!(matches!(clamp_values(&values, 0, 5), Cow::Borrowed(v) if v == values));
+        assert!(matches!(clamp_values(&[], 0, 0), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_out_of_range_is_clamped() {
+        let values = [-10, 0, 10, 20];
+        let clamped = clamp_values(&values, -5, 15);
+        assert!(matches!(clamped, Cow::Owned(_)));
+        assert_eq!(clamped.as_ref(), &[-5, 0, 10, 15]);
+        assert_eq!(values, [-10, 0, 10, 20], "input is untouched");
+    }
+
+    #[test]
+    fn test_clones_only_once() {
+        let values = vec![100; 50];
+        let before = CountingAllocator::allocations();
+        let clamped = clamp_values(&values, 0, 1);
+        assert_eq!(CountingAllocator::allocations() - before, 1);
+        assert!(clamped.iter().all(|&v| v == 1));
+    }
+}
+
+mod api_design_tests {
+    use super::*;
+
+    #[test]
+    fn test_owned_and_borrowed_agree() {
+        for line in ["a=b", " key = value ", "x=", "=y", "none", "a=b=c"] {
+            let owned = split_key_value_owned(line.to_string());
+            let borrowed = split_key_value(line).map(|(k, v)| (k.to_string(), v.to_string()));
+            assert_eq!(owned, borrowed, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_results_borrow_from_input() {
+        let line = String::from("lang = rust");
+        let (key, value) = split_key_value(&line).unwrap();
+        let range = line.as_bytes().as_ptr_range();
+        assert!(range.contains(&key.as_ptr()));
+        assert!(range.contains(&value.as_ptr()));
+    }
+
+    #[test]
+    fn test_config_view() {
+        let text = "\n# comment\nname = ferris\n  invalid line\nname = corro\nlegs=10";
+        let config = ConfigView::parse(text);
+        assert_eq!(
+            config.entries(),
+            &[("name", "ferris"), ("name", "corro"), ("legs", "10")]
+        );
+        assert_eq!(config.get("name"), Some("corro"));
+        assert_eq!(config.get("missing"), None);
+    }
+
+    #[test]
+    fn test_config_values_outlive_view() {
+        let text = String::from("k = v");
+        let value = {
+            let config = ConfigView::parse(&text);
+            config.get("k").unwrap()
+        };
+        assert_eq!(value, "v");
+    }
+}
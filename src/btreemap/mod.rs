@@ -0,0 +1,5 @@
+//! BTreeMap exercises and examples module
+//!
+//! Exercises in other modules that are built around a `BTreeMap`:
+//! - [`crate::vector::min_meeting_rooms_sweep`]: a sweep line over event
+//!   times, relying on the map iterating its keys in sorted order
@@ -0,0 +1,61 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// A global allocator that counts allocations per thread.
+///
+/// Install it in a binary (or test crate) to get allocation counts from the
+/// comparison driver:
+///
+/// ```text
+/// #[global_allocator]
+/// static ALLOC: CountingAllocator = CountingAllocator;
+/// ```
+///
+/// Counting per thread keeps measurements exact even while other threads
+/// (e.g. parallel tests) allocate.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Allocations (including reallocations) made by the current thread
+    pub fn allocations() -> u64 {
+        ALLOCATIONS.with(Cell::get)
+    }
+
+    /// Whether `CountingAllocator` is the active global allocator
+    pub fn is_installed() -> bool {
+        let before = Self::allocations();
+        drop(std::hint::black_box(Box::new(0_u8)));
+        Self::allocations() != before
+    }
+}
+
+fn record_allocation() {
+    // `try_with` because the allocator also runs while thread locals are torn down
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::alloc::CountingAllocator;
+use crate::arena::ArenaList;
+
+/// Uniform operations the driver can run against any collection.
+///
+/// Sequences treat `insert` as `push_back` and search linearly; maps store
+/// `key -> key` and ignore the front/back distinction. That asymmetry is the
+/// point: the same workload exposes each structure's strengths and costs.
+pub trait Collection {
+    const NAME: &'static str;
+
+    fn create() -> Self;
+    fn insert(&mut self, key: u64);
+    fn contains(&self, key: u64) -> bool;
+    /// Removes one occurrence of `key`, returning whether it was present
+    fn remove(&mut self, key: u64) -> bool;
+    fn push_front(&mut self, key: u64);
+    fn push_back(&mut self, key: u64);
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Collection for Vec<u64> {
+    const NAME: &'static str = "Vec";
+
+    fn create() -> Self {
+        Vec::new()
+    }
+    fn insert(&mut self, key: u64) {
+        self.push(key);
+    }
+    fn contains(&self, key: u64) -> bool {
+        self.as_slice().contains(&key)
+    }
+    fn remove(&mut self, key: u64) -> bool {
+        match self.iter().position(|&x| x == key) {
+            Some(i) => {
+                Vec::remove(self, i);
+                true
+            }
+            None => false,
+        }
+    }
+    fn push_front(&mut self, key: u64) {
+        Vec::insert(self, 0, key);
+    }
+    fn push_back(&mut self, key: u64) {
+        self.push(key);
+    }
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl Collection for VecDeque<u64> {
+    const NAME: &'static str = "VecDeque";
+
+    fn create() -> Self {
+        VecDeque::new()
+    }
+    fn insert(&mut self, key: u64) {
+        VecDeque::push_back(self, key);
+    }
+    fn contains(&self, key: u64) -> bool {
+        VecDeque::contains(self, &key)
+    }
+    fn remove(&mut self, key: u64) -> bool {
+        match self.iter().position(|&x| x == key) {
+            Some(i) => VecDeque::remove(self, i).is_some(),
+            None => false,
+        }
+    }
+    fn push_front(&mut self, key: u64) {
+        VecDeque::push_front(self, key);
+    }
+    fn push_back(&mut self, key: u64) {
+        VecDeque::push_back(self, key);
+    }
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+}
+
+impl Collection for LinkedList<u64> {
+    const NAME: &'static str = "LinkedList";
+
+    fn create() -> Self {
+        LinkedList::new()
+    }
+    fn insert(&mut self, key: u64) {
+        LinkedList::push_back(self, key);
+    }
+    fn contains(&self, key: u64) -> bool {
+        LinkedList::contains(self, &key)
+    }
+    fn remove(&mut self, key: u64) -> bool {
+        // Stable LinkedList has no O(1) remove-at-cursor, so split and rejoin
+        match self.iter().position(|&x| x == key) {
+            Some(i) => {
+                let mut tail = self.split_off(i);
+                tail.pop_front();
+                self.append(&mut tail);
+                true
+            }
+            None => false,
+        }
+    }
+    fn push_front(&mut self, key: u64) {
+        LinkedList::push_front(self, key);
+    }
+    fn push_back(&mut self, key: u64) {
+        LinkedList::push_back(self, key);
+    }
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+}
+
+impl Collection for HashMap<u64, u64> {
+    const NAME: &'static str = "HashMap";
+
+    fn create() -> Self {
+        HashMap::new()
+    }
+    fn insert(&mut self, key: u64) {
+        HashMap::insert(self, key, key);
+    }
+    fn contains(&self, key: u64) -> bool {
+        self.contains_key(&key)
+    }
+    fn remove(&mut self, key: u64) -> bool {
+        HashMap::remove(self, &key).is_some()
+    }
+    fn push_front(&mut self, key: u64) {
+        HashMap::insert(self, key, key);
+    }
+    fn push_back(&mut self, key: u64) {
+        HashMap::insert(self, key, key);
+    }
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+impl Collection for BTreeMap<u64, u64> {
+    const NAME: &'static str = "BTreeMap";
+
+    fn create() -> Self {
+        BTreeMap::new()
+    }
+    fn insert(&mut self, key: u64) {
+        BTreeMap::insert(self, key, key);
+    }
+    fn contains(&self, key: u64) -> bool {
+        self.contains_key(&key)
+    }
+    fn remove(&mut self, key: u64) -> bool {
+        BTreeMap::remove(self, &key).is_some()
+    }
+    fn push_front(&mut self, key: u64) {
+        BTreeMap::insert(self, key, key);
+    }
+    fn push_back(&mut self, key: u64) {
+        BTreeMap::insert(self, key, key);
+    }
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+}
+
+impl Collection for ArenaList<u64> {
+    const NAME: &'static str = "ArenaList";
+
+    fn create() -> Self {
+        ArenaList::new()
+    }
+    fn insert(&mut self, key: u64) {
+        ArenaList::push_back(self, key);
+    }
+    fn contains(&self, key: u64) -> bool {
+        self.find(&key).is_some()
+    }
+    fn remove(&mut self, key: u64) -> bool {
+        self.find(&key)
+            .is_some_and(|id| ArenaList::remove(self, id))
+    }
+    fn push_front(&mut self, key: u64) {
+        ArenaList::push_front(self, key);
+    }
+    fn push_back(&mut self, key: u64) {
+        ArenaList::push_back(self, key);
+    }
+    fn len(&self) -> usize {
+        ArenaList::len(self)
+    }
+}
+
+/// Operation counts for one comparison run.
+///
+/// Inserted keys are distinct, lookups hit about half the time and removals
+/// always target present keys, so every collection does the same logical work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Workload {
+    pub inserts: usize,
+    pub lookups: usize,
+    /// Capped at `inserts`
+    pub removals: usize,
+    pub front_pushes: usize,
+    pub back_pushes: usize,
+    pub seed: u64,
+}
+
+impl Default for Workload {
+    fn default() -> Self {
+        Self {
+            inserts: 10_000,
+            lookups: 1_000,
+            removals: 1_000,
+            front_pushes: 1_000,
+            back_pushes: 1_000,
+            seed: 42,
+        }
+    }
+}
+
+/// Timings and counters from running a [`Workload`] on one collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub collection: &'static str,
+    pub insert: Duration,
+    pub lookup: Duration,
+    pub remove: Duration,
+    pub push: Duration,
+    /// `None` unless [`CountingAllocator`] is the global allocator
+    pub allocations: Option<u64>,
+    /// Lookups that found their key; identical for every collection
+    pub hits: usize,
+    pub final_len: usize,
+}
+
+impl Measurement {
+    pub fn total(&self) -> Duration {
+        self.insert + self.lookup + self.remove + self.push
+    }
+}
+
+/// Runs `workload` against collection `C`
+pub fn run<C: Collection>(workload: &Workload) -> Measurement {
+    let mut rng = StdRng::seed_from_u64(workload.seed);
+    let n = workload.inserts as u64;
+    let mut keys: Vec<u64> = (0..n).collect();
+    keys.shuffle(&mut rng);
+    let probes: Vec<u64> = (0..workload.lookups)
+        .map(|_| rng.gen_range(0..n.max(1) * 2))
+        .collect();
+    let removals: Vec<u64> = keys
+        .choose_multiple(&mut rng, workload.removals.min(keys.len()))
+        .copied()
+        .collect();
+
+    let counting = CountingAllocator::is_installed();
+    let allocations_before = CountingAllocator::allocations();
+    let mut collection = C::create();
+
+    let insert = timed(|| keys.iter().for_each(|&key| collection.insert(key)));
+    let mut hits = 0;
+    let lookup = timed(|| {
+        hits = probes
+            .iter()
+            .filter(|&&key| collection.contains(key))
+            .count();
+    });
+    let remove = timed(|| {
+        for &key in &removals {
+            assert!(collection.remove(key), "{} lost key {key}", C::NAME);
+        }
+    });
+    let push = timed(|| {
+        // Fresh keys above every inserted one, so maps stay comparable
+        let mut next = n * 2;
+        for _ in 0..workload.front_pushes {
+            collection.push_front(next);
+            next += 1;
+        }
+        for _ in 0..workload.back_pushes {
+            collection.push_back(next);
+            next += 1;
+        }
+    });
+
+    let allocations = counting.then(|| CountingAllocator::allocations() - allocations_before);
+    Measurement {
+        collection: C::NAME,
+        insert,
+        lookup,
+        remove,
+        push,
+        allocations,
+        hits: black_box(hits),
+        final_len: collection.len(),
+    }
+}
+
+/// Runs `workload` against every supported collection
+pub fn run_all(workload: &Workload) -> Report {
+    Report {
+        workload: *workload,
+        measurements: vec![
+            run::<Vec<u64>>(workload),
+            run::<VecDeque<u64>>(workload),
+            run::<LinkedList<u64>>(workload),
+            run::<HashMap<u64, u64>>(workload),
+            run::<BTreeMap<u64, u64>>(workload),
+            run::<ArenaList<u64>>(workload),
+        ],
+    }
+}
+
+/// Results of [`run_all`], printable as a Markdown table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub workload: Workload,
+    pub measurements: Vec<Measurement>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let w = &self.workload;
+        writeln!(
+            f,
+            "{} inserts, {} lookups, {} removals, {} front / {} back pushes",
+            w.inserts, w.lookups, w.removals, w.front_pushes, w.back_pushes
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "| Collection | Insert | Lookup | Remove | Push | Total | Allocations |"
+        )?;
+        writeln!(f, "|---|---:|---:|---:|---:|---:|---:|")?;
+        for m in &self.measurements {
+            let allocations = m
+                .allocations
+                .map_or_else(|| "n/a".to_string(), |count| count.to_string());
+            writeln!(
+                f,
+                "| {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} | {:.2?} | {} |",
+                m.collection,
+                m.insert,
+                m.lookup,
+                m.remove,
+                m.push,
+                m.total(),
+                allocations
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn timed(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
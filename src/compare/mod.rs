@@ -0,0 +1,21 @@
+//! Collection comparison module
+//!
+//! Runs one uniform [`Workload`] (inserts, lookups, removals, pushes at both
+//! ends) against std collections and the crate's own structures and reports
+//! timings and allocation counts, so the trade-offs described in
+//! `docs/guide.md` can be measured instead of taken on faith.
+//!
+//! Allocation counts need [`CountingAllocator`] installed as the global
+//! allocator; see `examples/compare_collections.rs`:
+//!
+//! ```text
+//! cargo run --release --example compare_collections
+//! ```
+
+mod alloc;
+mod driver;
+#[cfg(test)]
+mod tests;
+
+pub use alloc::*;
+pub use driver::*;
@@ -0,0 +1,104 @@
+use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
+
+use crate::arena::ArenaList;
+use crate::compare::*;
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+fn small_workload() -> Workload {
+    Workload {
+        inserts: 200,
+        lookups: 100,
+        removals: 50,
+        front_pushes: 20,
+        back_pushes: 30,
+        seed: 9,
+    }
+}
+
+mod allocator_tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_allocator_is_active_in_tests() {
+        assert!(CountingAllocator::is_installed());
+    }
+
+    #[test]
+    fn test_counts_this_threads_allocations() {
+        let before = CountingAllocator::allocations();
+        let v: Vec<Box<u32>> = (0..10).map(Box::new).collect();
+        assert!(CountingAllocator::allocations() - before >= 10);
+        drop(v);
+    }
+}
+
+mod driver_tests {
+    use super::*;
+
+    #[test]
+    fn test_collections_do_the_same_logical_work() {
+        let report = run_all(&small_workload());
+        assert_eq!(report.measurements.len(), 6);
+        let first = &report.measurements[0];
+        assert!(first.hits > 0 && first.hits < 100);
+        for m in &report.measurements {
+            assert_eq!(m.hits, first.hits, "{}", m.collection);
+            assert_eq!(m.final_len, 200 - 50 + 20 + 30, "{}", m.collection);
+        }
+    }
+
+    #[test]
+    fn test_allocation_counts_reflect_structure() {
+        let workload = small_workload();
+        let vec = run::<Vec<u64>>(&workload).allocations.unwrap();
+        let list = run::<LinkedList<u64>>(&workload).allocations.unwrap();
+        let arena = run::<ArenaList<u64>>(&workload).allocations.unwrap();
+        // One heap node per element vs. amortized doubling
+        assert!(list >= 250);
+        assert!(vec < 50);
+        assert!(arena < 50);
+    }
+
+    #[test]
+    fn test_individual_runs() {
+        let workload = small_workload();
+        assert_eq!(run::<VecDeque<u64>>(&workload).collection, "VecDeque");
+        assert_eq!(run::<HashMap<u64, u64>>(&workload).collection, "HashMap");
+        assert_eq!(run::<BTreeMap<u64, u64>>(&workload).collection, "BTreeMap");
+    }
+
+    #[test]
+    fn test_removals_capped_at_inserts() {
+        let workload = Workload {
+            inserts: 5,
+            removals: 10,
+            ..small_workload()
+        };
+        assert_eq!(run::<Vec<u64>>(&workload).final_len, 50);
+    }
+
+    #[test]
+    fn test_empty_workload() {
+        let workload = Workload {
+            inserts: 0,
+            lookups: 3,
+            removals: 0,
+            front_pushes: 0,
+            back_pushes: 0,
+            seed: 0,
+        };
+        let m = run::<Vec<u64>>(&workload);
+        assert_eq!((m.hits, m.final_len), (0, 0));
+    }
+
+    #[test]
+    fn test_report_table() {
+        let text = run_all(&small_workload()).to_string();
+        assert!(text.starts_with("200 inserts, 100 lookups"));
+        assert!(text.contains("| Collection | Insert |"));
+        assert_eq!(text.lines().filter(|l| l.starts_with("| ")).count(), 7);
+        assert!(!text.contains("n/a"));
+    }
+}
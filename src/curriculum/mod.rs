@@ -0,0 +1,28 @@
+//! Curriculum and learning-path module
+//!
+//! A [`Curriculum`] is a set of ordered tracks (e.g. "arrays → vectors →
+//! matrices") plus explicit prerequisite edges between exercises. Given a
+//! learner's [`Progress`] it suggests what to work on next.
+//!
+//! The prerequisite graph is itself validated with a depth-first search that
+//! reports any cycle, which doubles as a graph exercise: see
+//! [`Curriculum::validate`].
+//!
+//! ```
+//! use rust_ds_learning::curriculum::{Curriculum, Progress};
+//!
+//! let curriculum = Curriculum::standard();
+//! let mut progress = Progress::new();
+//! assert_eq!(curriculum.next_exercise(&progress), Some("array::find_element"));
+//!
+//! progress.complete("array::find_element");
+//! assert_eq!(curriculum.next_exercise(&progress), Some("vector::merge_intervals"));
+//! ```
+
+mod progress;
+#[cfg(test)]
+mod tests;
+mod tracks;
+
+pub use progress::*;
+pub use tracks::*;
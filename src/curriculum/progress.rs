@@ -0,0 +1,46 @@
+use std::collections::BTreeSet;
+
+use crate::grading::GradeBook;
+
+/// The set of exercises a learner has completed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    completed: BTreeSet<String>,
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds progress from a grade book: every correctly solved exercise
+    /// counts as completed, regardless of speed or hints.
+    pub fn from_grade_book(book: &GradeBook) -> Self {
+        let completed = book
+            .grades()
+            .filter(|grade| grade.is_correct())
+            .map(|grade| grade.exercise.to_string())
+            .collect();
+        Self { completed }
+    }
+
+    pub fn complete(&mut self, exercise: &str) {
+        self.completed.insert(exercise.to_string());
+    }
+
+    pub fn is_completed(&self, exercise: &str) -> bool {
+        self.completed.contains(exercise)
+    }
+
+    pub fn completed(&self) -> impl Iterator<Item = &str> {
+        self.completed.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.completed.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.completed.is_empty()
+    }
+}
@@ -0,0 +1,162 @@
+use crate::curriculum::{Curriculum, CurriculumError, Progress};
+use crate::grading::{ExerciseGrade, GradeBook};
+
+fn grade(exercise: &'static str, cases_passed: usize) -> ExerciseGrade {
+    ExerciseGrade {
+        exercise,
+        cases_passed,
+        cases_total: 4,
+        first_failure: None,
+        speed_ratio: None,
+        hints_used: 0,
+    }
+}
+
+mod progress_tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_and_query() {
+        let mut progress = Progress::new();
+        assert!(progress.is_empty());
+        progress.complete("a");
+        progress.complete("a");
+        assert_eq!(progress.len(), 1);
+        assert!(progress.is_completed("a"));
+        assert!(!progress.is_completed("b"));
+    }
+
+    #[test]
+    fn test_from_grade_book_only_counts_correct_attempts() {
+        let mut book = GradeBook::new();
+        book.record(grade("vector::merge_intervals", 4));
+        book.record(grade("vector::max_product", 3));
+        let progress = Progress::from_grade_book(&book);
+        assert_eq!(
+            progress.completed().collect::<Vec<_>>(),
+            vec!["vector::merge_intervals"]
+        );
+    }
+}
+
+mod suggestion_tests {
+    use super::*;
+
+    #[test]
+    fn test_walks_track_in_order() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("basics", &["a", "b", "c"]);
+        let mut progress = Progress::new();
+
+        let mut visited = Vec::new();
+        while let Some(next) = curriculum.next_exercise(&progress) {
+            visited.push(next);
+            progress.complete(next);
+        }
+        assert_eq!(visited, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_cross_track_prerequisite_blocks() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("arrays", &["arr1", "arr2"]);
+        curriculum.add_track("heaps", &["heap1"]);
+        curriculum.add_prerequisite("heap1", "arr2");
+
+        let mut progress = Progress::new();
+        assert_eq!(curriculum.available(&progress), vec!["arr1"]);
+        progress.complete("arr1");
+        assert_eq!(curriculum.available(&progress), vec!["arr2"]);
+        progress.complete("arr2");
+        assert_eq!(curriculum.next_exercise(&progress), Some("heap1"));
+        progress.complete("heap1");
+        assert_eq!(curriculum.next_exercise(&progress), None);
+    }
+
+    #[test]
+    fn test_earlier_tracks_take_priority() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("first", &["x"]);
+        curriculum.add_track("second", &["y"]);
+        assert_eq!(curriculum.available(&Progress::new()), vec!["x", "y"]);
+        assert_eq!(curriculum.next_exercise(&Progress::new()), Some("x"));
+    }
+
+    #[test]
+    fn test_prerequisites_of_combines_tracks_and_edges() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("one", &["a", "c"]);
+        curriculum.add_track("two", &["b", "c"]);
+        curriculum.add_prerequisite("c", "d");
+        curriculum.add_track("three", &["d"]);
+        assert_eq!(
+            curriculum
+                .prerequisites_of("c")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "d"]
+        );
+        assert_eq!(curriculum.exercises(), vec!["a", "c", "b", "d"]);
+    }
+}
+
+mod validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_curriculum_is_valid() {
+        let curriculum = Curriculum::standard();
+        let order = curriculum.validate().unwrap();
+        assert_eq!(order.len(), curriculum.exercises().len());
+        for (i, exercise) in order.iter().enumerate() {
+            for required in curriculum.prerequisites_of(exercise) {
+                let position = order.iter().position(|&e| e == required).unwrap();
+                assert!(position < i, "{required} must come before {exercise}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_detects_cycle() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("loop", &["a", "b", "c"]);
+        curriculum.add_prerequisite("a", "c");
+        assert_eq!(
+            curriculum.validate(),
+            Err(CurriculumError::Cycle {
+                path: vec!["a", "c", "b", "a"]
+            })
+        );
+        // Nothing is reachable inside a cycle
+        assert_eq!(curriculum.next_exercise(&Progress::new()), None);
+    }
+
+    #[test]
+    fn test_detects_self_loop() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("solo", &["a"]);
+        curriculum.add_prerequisite("a", "a");
+        let err = curriculum.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Prerequisite cycle: a -> a");
+    }
+
+    #[test]
+    fn test_unknown_prerequisite() {
+        let mut curriculum = Curriculum::new();
+        curriculum.add_track("basics", &["a"]);
+        curriculum.add_prerequisite("a", "missing");
+        assert_eq!(
+            curriculum.validate(),
+            Err(CurriculumError::UnknownExercise {
+                exercise: "missing"
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_curriculum() {
+        let curriculum = Curriculum::new();
+        assert_eq!(curriculum.validate(), Ok(vec![]));
+        assert_eq!(curriculum.next_exercise(&Progress::new()), None);
+    }
+}
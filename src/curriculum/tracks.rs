@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use thiserror::Error;
+
+use super::progress::Progress;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CurriculumError {
+    #[error("Exercise {exercise} is referenced as a prerequisite but belongs to no track")]
+    UnknownExercise { exercise: &'static str },
+    #[error("Prerequisite cycle: {}", path.join(" -> "))]
+    Cycle { path: Vec<&'static str> },
+}
+
+/// An ordered sequence of exercises. Each exercise implicitly requires the
+/// one before it in the track.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Track {
+    pub name: &'static str,
+    pub exercises: Vec<&'static str>,
+}
+
+/// Tracks plus explicit cross-track prerequisite edges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Curriculum {
+    tracks: Vec<Track>,
+    prerequisites: BTreeMap<&'static str, BTreeSet<&'static str>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+impl Curriculum {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in learning path over the crate's exercises
+    pub fn standard() -> Self {
+        let mut curriculum = Self::new();
+        curriculum.add_track(
+            "fundamentals",
+            &[
+                "array::find_element",
+                "vector::merge_intervals",
+                "vector::sliding_window_maximum",
+                "vector::max_product",
+            ],
+        );
+        curriculum.add_track("matrices", &["matrix::find_postition_sorted_square_matrix"]);
+        curriculum.add_prerequisite(
+            "matrix::find_postition_sorted_square_matrix",
+            "array::find_element",
+        );
+        curriculum
+    }
+
+    /// Appends a track; earlier tracks take priority in suggestions
+    pub fn add_track(&mut self, name: &'static str, exercises: &[&'static str]) {
+        self.tracks.push(Track {
+            name,
+            exercises: exercises.to_vec(),
+        });
+    }
+
+    /// Declares that `exercise` can only be started once `requires` is done
+    pub fn add_prerequisite(&mut self, exercise: &'static str, requires: &'static str) {
+        self.prerequisites
+            .entry(exercise)
+            .or_default()
+            .insert(requires);
+    }
+
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// All exercises in track order, without duplicates
+    pub fn exercises(&self) -> Vec<&'static str> {
+        let mut seen = BTreeSet::new();
+        self.tracks
+            .iter()
+            .flat_map(|track| track.exercises.iter().copied())
+            .filter(|exercise| seen.insert(*exercise))
+            .collect()
+    }
+
+    /// Explicit prerequisites plus the preceding exercise in every track
+    /// that contains `exercise`
+    pub fn prerequisites_of(&self, exercise: &str) -> BTreeSet<&'static str> {
+        let mut result = self
+            .prerequisites
+            .get(exercise)
+            .cloned()
+            .unwrap_or_default();
+        for track in &self.tracks {
+            for pair in track.exercises.windows(2) {
+                if pair[1] == exercise {
+                    result.insert(pair[0]);
+                }
+            }
+        }
+        result
+    }
+
+    /// Exercises that are not completed yet but whose prerequisites all are
+    pub fn available(&self, progress: &Progress) -> Vec<&'static str> {
+        self.exercises()
+            .into_iter()
+            .filter(|exercise| !progress.is_completed(exercise))
+            .filter(|exercise| {
+                self.prerequisites_of(exercise)
+                    .iter()
+                    .all(|required| progress.is_completed(required))
+            })
+            .collect()
+    }
+
+    /// The first available exercise in track order, or `None` once the
+    /// learner is done (or blocked by a broken prerequisite graph)
+    pub fn next_exercise(&self, progress: &Progress) -> Option<&'static str> {
+        self.available(progress).into_iter().next()
+    }
+
+    /// Checks the prerequisite graph and returns a topological order in which
+    /// every exercise comes after all of its prerequisites.
+    ///
+    /// ## Approach
+    /// Depth-first search with two marks. A node is "in progress" while its
+    /// prerequisites are being explored and "done" afterwards. Reaching an
+    /// in-progress node again means we walked around a cycle; the DFS stack
+    /// from that node onwards is exactly the cycle.
+    ///
+    /// ## Complexity
+    /// - Time: O(V + E)
+    /// - Space: O(V) for the marks and the recursion stack
+    pub fn validate(&self) -> Result<Vec<&'static str>, CurriculumError> {
+        let exercises = self.exercises();
+        let known: BTreeSet<&str> = exercises.iter().copied().collect();
+        for (&exercise, required) in &self.prerequisites {
+            if let Some(&unknown) = std::iter::once(&exercise)
+                .chain(required)
+                .find(|name| !known.contains(*name))
+            {
+                return Err(CurriculumError::UnknownExercise { exercise: unknown });
+            }
+        }
+
+        let mut marks = HashMap::new();
+        let mut stack = Vec::new();
+        let mut order = Vec::with_capacity(exercises.len());
+        for exercise in exercises {
+            self.visit(exercise, &mut marks, &mut stack, &mut order)?;
+        }
+        Ok(order)
+    }
+
+    fn visit(
+        &self,
+        exercise: &'static str,
+        marks: &mut HashMap<&'static str, Visit>,
+        stack: &mut Vec<&'static str>,
+        order: &mut Vec<&'static str>,
+    ) -> Result<(), CurriculumError> {
+        match marks.get(exercise) {
+            Some(Visit::Done) => return Ok(()),
+            Some(Visit::InProgress) => {
+                let start = stack.iter().position(|&e| e == exercise).unwrap_or(0);
+                let mut path = stack[start..].to_vec();
+                path.push(exercise);
+                return Err(CurriculumError::Cycle { path });
+            }
+            None => {}
+        }
+
+        marks.insert(exercise, Visit::InProgress);
+        stack.push(exercise);
+        for required in self.prerequisites_of(exercise) {
+            self.visit(required, marks, stack, order)?;
+        }
+        stack.pop();
+        marks.insert(exercise, Visit::Done);
+        order.push(exercise);
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+/// Cursor that turns raw fuzzer bytes into typed values.
+///
+/// Every read succeeds: once the input is exhausted the reader keeps
+/// returning zeros, so decoders never have to handle short inputs.
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Number of bytes not yet consumed
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        let byte = self.data.get(self.pos).copied().unwrap_or(0);
+        self.pos = (self.pos + 1).min(self.data.len());
+        byte
+    }
+
+    pub fn i8(&mut self) -> i8 {
+        self.u8() as i8
+    }
+
+    pub fn i16(&mut self) -> i16 {
+        i16::from_le_bytes([self.u8(), self.u8()])
+    }
+
+    /// A value in `0..=max`
+    pub fn usize_up_to(&mut self, max: usize) -> usize {
+        let raw = u16::from_le_bytes([self.u8(), self.u8()]) as usize;
+        raw % (max + 1)
+    }
+
+    /// A finite `f64` with a quarter-step fractional part.
+    ///
+    /// NaN and infinities are deliberately excluded: they break `PartialEq`
+    /// based invariants without telling us anything about the exercises.
+    pub fn finite_f64(&mut self) -> f64 {
+        f64::from(self.i16()) / 4.0
+    }
+}
@@ -0,0 +1,28 @@
+//! Fuzzing entry points (enabled with the `fuzzing` feature)
+//!
+//! Each `fuzz_*` function takes an arbitrary byte slice, decodes it into a
+//! valid input for one exercise and asserts structural invariants of the
+//! result rather than comparing against a fixed expected output. A failing
+//! assertion or a panic inside the exercise is a finding.
+//!
+//! To point cargo-fuzz at the crate, create a target that forwards the data:
+//!
+//! ```text
+//! #![no_main]
+//! use libfuzzer_sys::fuzz_target;
+//!
+//! fuzz_target!(|data: &[u8]| {
+//!     rust_ds_learning::fuzz_targets::fuzz_merge_intervals(data);
+//! });
+//! ```
+//!
+//! and enable the feature in `fuzz/Cargo.toml`:
+//! `rust-ds-learning = { path = "..", features = ["fuzzing"] }`.
+
+mod decode;
+mod targets;
+#[cfg(test)]
+mod tests;
+
+pub use decode::ByteReader;
+pub use targets::*;
@@ -0,0 +1,274 @@
+//! Fuzz targets for matrix, vector and parser exercises.
+//!
+//! The parsers (`Matrix::from_str`, [`crate::string::parse_int`] and
+//! [`crate::string::decompress`]) take text straight from the fuzzer, mapped
+//! onto a small alphabet of the characters they care about so that most
+//! inputs get past the first token. Sizes are capped so that a single run
+//! stays fast and the integer exercises never overflow on valid input.
+
+use super::decode::ByteReader;
+use crate::matrix::{find_postition_sorted_square_matrix, Matrix, MatrixError, ParseMatrixError};
+
+const MAX_DIM: usize = 8;
+const MAX_LEN: usize = 64;
+
+/// Decodes `rows`, `cols` and a data buffer of arbitrary length, then checks
+/// that `Matrix::from_vec` accepts it exactly when the length matches and that
+/// `get`, `set` and `transpose` agree with the flat storage.
+pub fn fuzz_matrix_from_vec(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let rows = input.usize_up_to(MAX_DIM);
+    let cols = input.usize_up_to(MAX_DIM);
+    let len = input.usize_up_to(MAX_DIM * MAX_DIM);
+    let values: Vec<f64> = (0..len).map(|_| input.finite_f64()).collect();
+
+    let mut matrix = match Matrix::from_vec(rows, cols, values.clone()) {
+        Ok(matrix) => matrix,
+        Err(MatrixError::InvalidCreation { expected, actual }) => {
+            assert_eq!(expected, rows * cols);
+            assert_eq!(actual, len);
+            assert_ne!(expected, actual);
+            return;
+        }
+        Err(other) => panic!("unexpected error from from_vec: {other}"),
+    };
+    assert_eq!(len, rows * cols);
+
+    for i in 0..rows {
+        for j in 0..cols {
+            assert_eq!(matrix.get(i, j).unwrap(), values[i * cols + j]);
+        }
+    }
+    assert!(matches!(
+        matrix.get(rows, 0),
+        Err(MatrixError::IndexOutOfBounds { .. })
+    ));
+    assert!(matches!(
+        matrix.set(0, cols, 1.0),
+        Err(MatrixError::IndexOutOfBounds { .. })
+    ));
+
+    let transposed = matrix.transpose();
+    assert_eq!((transposed.rows(), transposed.cols()), (cols, rows));
+    assert_eq!(transposed.transpose(), matrix);
+}
+
+/// Decodes two matrices of arbitrary shapes and checks that `+` and `*`
+/// succeed exactly when the shapes are compatible, producing the right shape.
+pub fn fuzz_matrix_ops(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let left = decode_matrix(&mut input);
+    let right = decode_matrix(&mut input);
+
+    match &left + &right {
+        Ok(sum) => {
+            assert_eq!((left.rows(), left.cols()), (right.rows(), right.cols()));
+            assert_eq!(sum.data.len(), left.data.len());
+            // Quarter-step values are exact in f64, so addition is too
+            for (k, &value) in sum.data.iter().enumerate() {
+                assert_eq!(value, left.data[k] + right.data[k]);
+            }
+        }
+        Err(MatrixError::DimensionMismatch { .. }) => {
+            assert_ne!((left.rows(), left.cols()), (right.rows(), right.cols()));
+        }
+        Err(other) => panic!("unexpected error from add: {other}"),
+    }
+
+    match &left * &right {
+        Ok(product) => {
+            assert_eq!(left.cols(), right.rows());
+            assert_eq!(
+                (product.rows(), product.cols()),
+                (left.rows(), right.cols())
+            );
+        }
+        Err(MatrixError::DimensionMismatch { .. }) => assert_ne!(left.cols(), right.rows()),
+        Err(other) => panic!("unexpected error from mul: {other}"),
+    }
+}
+
+/// Decodes a row- and column-sorted square matrix plus a needle and checks
+/// that the reported position really holds the value, or that the value is
+/// absent when the search fails.
+pub fn fuzz_sorted_square_matrix_search(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let n = input.usize_up_to(MAX_DIM);
+    let mut matrix: Matrix = Matrix::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            let up = if i > 0 {
+                matrix.data[(i - 1) * n + j]
+            } else {
+                0.0
+            };
+            let left = if j > 0 {
+                matrix.data[i * n + j - 1]
+            } else {
+                0.0
+            };
+            // Steps of zero allow duplicates, which the search must tolerate
+            matrix.data[i * n + j] = up.max(left) + f64::from(input.u8() % 4);
+        }
+    }
+    let val = f64::from(input.u8() % 64);
+
+    match find_postition_sorted_square_matrix(&matrix, val) {
+        Ok((i, j)) => assert_eq!(matrix.get(i, j).unwrap(), val),
+        Err(_) => assert!(!matrix.data.contains(&val)),
+    }
+}
+
+/// Decodes a vector and a window size in `0..=len` and checks that every
+/// output is the maximum of its window.
+pub fn fuzz_sliding_window_maximum(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let len = input.usize_up_to(MAX_LEN);
+    let nums: Vec<i32> = (0..len).map(|_| i32::from(input.i16())).collect();
+    let window_size = input.usize_up_to(len);
+
+    let result = crate::vector::sliding_window_maximum(&nums, window_size);
+    if nums.is_empty() || window_size == 0 {
+        assert!(result.is_empty());
+        return;
+    }
+    assert_eq!(result.len(), nums.len() - window_size + 1);
+    for (window, &max) in nums.windows(window_size).zip(&result) {
+        assert!(window.contains(&max));
+        assert!(window.iter().all(|&x| x <= max));
+    }
+}
+
+/// Decodes a list of well-formed intervals and checks that the merged output
+/// is sorted, pairwise disjoint and covers every input interval.
+pub fn fuzz_merge_intervals(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let len = input.usize_up_to(MAX_LEN);
+    let intervals: Vec<(i32, i32)> = (0..len)
+        .map(|_| {
+            let start = i32::from(input.i8());
+            (start, start + i32::from(input.u8() % 16))
+        })
+        .collect();
+
+    let merged = crate::vector::merge_intervals(&intervals);
+    assert_eq!(merged.is_empty(), intervals.is_empty());
+    for &(start, end) in &merged {
+        assert!(start <= end);
+    }
+    for pair in merged.windows(2) {
+        assert!(
+            pair[0].1 < pair[1].0,
+            "intervals {pair:?} should have been merged"
+        );
+    }
+    for &(start, end) in &intervals {
+        assert!(merged.iter().any(|&(s, e)| s <= start && end <= e));
+    }
+}
+
+/// Decodes a short vector of small integers and checks that `max_product`
+/// (and its functional twin) returns the product of some subarray that is at
+/// least as large as every single element.
+pub fn fuzz_max_product(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    // |x| <= 10 and len <= 8 keeps every product within i32
+    let len = input.usize_up_to(8);
+    let nums: Vec<i32> = (0..len).map(|_| i32::from(input.i8() % 11)).collect();
+
+    let result = crate::vector::max_product(&nums);
+    assert_eq!(result, crate::vector::max_product_functional(&nums));
+    if nums.is_empty() {
+        assert_eq!(result, 0);
+        return;
+    }
+    assert!(nums.iter().all(|&x| x <= result));
+    let achievable = (0..nums.len())
+        .any(|i| (i..nums.len()).any(|j| nums[i..=j].iter().product::<i32>() == result));
+    assert!(achievable, "{result} is not the product of any subarray");
+}
+
+/// Decodes `;`/whitespace-separated integer text and checks that
+/// `Matrix::<i32>::from_str` either returns a matrix that prints back to an
+/// equal one, or an error that describes the input correctly.
+pub fn fuzz_matrix_from_str(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let text = decode_text(&mut input, &[' ', ';', '\n', '-', '1', '2', '9', 'x']);
+
+    match text.parse::<Matrix<i32>>() {
+        // Rows of separators alone, as in ";;", give an `n x 0` matrix,
+        // which has no text form of its own to round-trip through
+        Ok(matrix) if matrix.cols() == 0 => {
+            assert!(!text.contains(|c: char| c.is_ascii_digit()), "{text:?}");
+        }
+        Ok(matrix) => {
+            let printed: Vec<String> = (0..matrix.rows())
+                .map(|i| {
+                    let row: Vec<String> = (0..matrix.cols())
+                        .map(|j| matrix[(i, j)].to_string())
+                        .collect();
+                    row.join(" ")
+                })
+                .collect();
+            let reparsed: Matrix<i32> = printed.join("; ").parse().unwrap();
+            assert_eq!(reparsed, matrix, "{text:?}");
+        }
+        Err(ParseMatrixError::InvalidToken { token, .. }) => {
+            assert!(token.parse::<i32>().is_err(), "{token:?} is a valid i32");
+        }
+        Err(ParseMatrixError::RaggedRow {
+            expected, actual, ..
+        }) => assert_ne!(expected, actual),
+    }
+}
+
+/// Decodes short numeric-looking text and checks that `parse_int` agrees
+/// with `str::parse::<i32>` on the trimmed text, its documented behavior.
+pub fn fuzz_parse_int(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let text = decode_text(
+        &mut input,
+        &[' ', '\t', '+', '-', '0', '1', '2', '7', '8', '9', 'a'],
+    );
+
+    let parsed = crate::string::parse_int(&text);
+    assert_eq!(
+        parsed.as_ref().ok(),
+        text.trim().parse::<i32>().ok().as_ref(),
+        "{text:?}"
+    );
+    if let Ok(value) = parsed {
+        assert_eq!(crate::string::parse_int(&value.to_string()).unwrap(), value);
+    }
+}
+
+/// Decodes run-length text with arbitrarily large counts and checks that
+/// `decompress` stays within its output cap, and that compressing what it
+/// returns and decompressing again is lossless.
+pub fn fuzz_decompress(data: &[u8]) {
+    let mut input = ByteReader::new(data);
+    let text = decode_text(&mut input, &['a', 'b', 'é', '0', '1', '3', '9']);
+
+    let Ok(expanded) = crate::string::decompress(&text) else {
+        return;
+    };
+    assert!(expanded.len() <= crate::string::MAX_DECOMPRESSED_LEN);
+    assert!(!expanded.contains(|c: char| c.is_ascii_digit()));
+    let compressed = crate::string::compress(&expanded);
+    assert_eq!(crate::string::decompress(&compressed).unwrap(), expanded);
+}
+
+/// Up to `MAX_LEN` characters, each picked from `alphabet` by one byte
+fn decode_text(input: &mut ByteReader<'_>, alphabet: &[char]) -> String {
+    let len = input.usize_up_to(MAX_LEN);
+    (0..len)
+        .map(|_| alphabet[usize::from(input.u8()) % alphabet.len()])
+        .collect()
+}
+
+fn decode_matrix(input: &mut ByteReader<'_>) -> Matrix {
+    let rows = input.usize_up_to(MAX_DIM);
+    let cols = input.usize_up_to(MAX_DIM);
+    let data = (0..rows * cols).map(|_| input.finite_f64()).collect();
+    Matrix::from_vec(rows, cols, data).unwrap()
+}
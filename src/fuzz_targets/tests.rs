@@ -0,0 +1,74 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::fuzz_targets::*;
+
+type Target = fn(&[u8]);
+
+const TARGETS: [(&str, Target); 9] = [
+    ("matrix_from_vec", fuzz_matrix_from_vec),
+    ("matrix_ops", fuzz_matrix_ops),
+    (
+        "sorted_square_matrix_search",
+        fuzz_sorted_square_matrix_search,
+    ),
+    ("sliding_window_maximum", fuzz_sliding_window_maximum),
+    ("merge_intervals", fuzz_merge_intervals),
+    ("max_product", fuzz_max_product),
+    ("matrix_from_str", fuzz_matrix_from_str),
+    ("parse_int", fuzz_parse_int),
+    ("decompress", fuzz_decompress),
+];
+
+mod byte_reader_tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_past_end_return_zero() {
+        let mut input = ByteReader::new(&[0xFF]);
+        assert_eq!(input.u8(), 0xFF);
+        assert_eq!(input.remaining(), 0);
+        assert_eq!(input.u8(), 0);
+        assert_eq!(input.i16(), 0);
+    }
+
+    #[test]
+    fn test_usize_up_to_stays_in_range() {
+        let mut input = ByteReader::new(&[0xFF, 0xFF, 0x07, 0x00]);
+        assert!(input.usize_up_to(10) <= 10);
+        assert_eq!(input.usize_up_to(100), 7);
+    }
+
+    #[test]
+    fn test_finite_f64() {
+        let mut input = ByteReader::new(&[0xFF, 0x7F, 0x00, 0x80]);
+        assert_eq!(input.finite_f64(), f64::from(i16::MAX) / 4.0);
+        assert_eq!(input.finite_f64(), f64::from(i16::MIN) / 4.0);
+    }
+}
+
+mod target_tests {
+    use super::*;
+
+    #[test]
+    fn test_targets_accept_degenerate_inputs() {
+        for (_, target) in TARGETS {
+            target(&[]);
+            target(&[0; 64]);
+            target(&[0xFF; 512]);
+        }
+    }
+
+    #[test]
+    fn test_targets_hold_on_random_inputs() {
+        let mut rng = StdRng::seed_from_u64(0xF022);
+        for (name, target) in TARGETS {
+            for _ in 0..500 {
+                let len = rng.gen_range(0..256);
+                let data: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+                let result = std::panic::catch_unwind(|| target(&data));
+                assert!(result.is_ok(), "{name} failed on {data:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::verify::{CheckConfig, Verify};
+
+/// Points awarded when every verifier case matches the reference
+pub const CORRECTNESS_POINTS: u32 = 70;
+/// Points awarded for running at least as fast as the reference
+pub const PERFORMANCE_POINTS: u32 = 20;
+/// Points awarded for solving without hints
+pub const HINT_POINTS: u32 = 10;
+/// Points deducted per hint, down to zero
+pub const HINT_PENALTY: u32 = 5;
+
+/// Slowdowns up to this factor still earn full performance points
+const FULL_SPEED_RATIO: f64 = 1.5;
+/// Slowdowns from this factor on earn no performance points
+const ZERO_SPEED_RATIO: f64 = 10.0;
+
+/// Settings for a grading run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GradingConfig {
+    /// Cases used for both correctness and timing
+    pub check: CheckConfig,
+    /// Timing repetitions; the fastest round is kept to reduce noise
+    pub timing_rounds: usize,
+}
+
+impl Default for GradingConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckConfig::default(),
+            timing_rounds: 5,
+        }
+    }
+}
+
+/// The graded result of one exercise attempt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExerciseGrade {
+    /// Fully qualified exercise name, e.g. `vector::merge_intervals`
+    pub exercise: &'static str,
+    pub cases_passed: usize,
+    pub cases_total: usize,
+    /// Description of the first failing case, if any
+    pub first_failure: Option<String>,
+    /// User time divided by reference time; only measured for correct attempts
+    pub speed_ratio: Option<f64>,
+    pub hints_used: u32,
+}
+
+impl ExerciseGrade {
+    /// The crate module the exercise belongs to (the part before `::`)
+    pub fn module(&self) -> &'static str {
+        self.exercise
+            .split_once("::")
+            .map_or(self.exercise, |(module, _)| module)
+    }
+
+    pub fn is_correct(&self) -> bool {
+        self.cases_passed == self.cases_total
+    }
+
+    /// Correctness points, proportional to the share of passing cases
+    pub fn correctness_points(&self) -> u32 {
+        if self.cases_total == 0 {
+            return 0;
+        }
+        (CORRECTNESS_POINTS as usize * self.cases_passed / self.cases_total) as u32
+    }
+
+    /// Performance points; incorrect attempts earn none
+    pub fn performance_points(&self) -> u32 {
+        self.speed_ratio.map_or(0, performance_points_for)
+    }
+
+    pub fn hint_points(&self) -> u32 {
+        HINT_POINTS.saturating_sub(self.hints_used.saturating_mul(HINT_PENALTY))
+    }
+
+    /// Total score out of 100
+    pub fn score(&self) -> u32 {
+        self.correctness_points() + self.performance_points() + self.hint_points()
+    }
+}
+
+/// Maps a user/reference time ratio to performance points.
+///
+/// Anything up to 1.5x the reference time earns full points; the award then
+/// falls linearly to zero at 10x.
+pub fn performance_points_for(ratio: f64) -> u32 {
+    if ratio.is_nan() || ratio >= ZERO_SPEED_RATIO {
+        return 0;
+    }
+    if ratio <= FULL_SPEED_RATIO {
+        return PERFORMANCE_POINTS;
+    }
+    let share = (ZERO_SPEED_RATIO - ratio) / (ZERO_SPEED_RATIO - FULL_SPEED_RATIO);
+    (PERFORMANCE_POINTS as f64 * share).round() as u32
+}
+
+/// Grades a user implementation of exercise `E`.
+///
+/// Every case is run (not just up to the first failure) so that partially
+/// correct attempts earn partial credit. Timing only happens when all cases
+/// pass, since a wrong answer computed quickly is not worth anything.
+pub fn grade<E, F>(config: &GradingConfig, hints_used: u32, mut f: F) -> ExerciseGrade
+where
+    E: Verify,
+    F: FnMut(&E::Input) -> E::Output,
+{
+    let cases = E::cases(&config.check);
+    let mut cases_passed = 0;
+    let mut first_failure = None;
+
+    for (case, input) in cases.iter().enumerate() {
+        let expected = E::reference(input);
+        let actual = f(input);
+        if actual == expected {
+            cases_passed += 1;
+        } else if first_failure.is_none() {
+            first_failure = Some(format!(
+                "case #{case}: input {input:?}, expected {expected:?}, got {actual:?}"
+            ));
+        }
+    }
+
+    let speed_ratio = (cases_passed == cases.len()).then(|| {
+        let user = fastest_round(config.timing_rounds, || {
+            cases.iter().for_each(|input| drop(black_box(f(input))))
+        });
+        let reference = fastest_round(config.timing_rounds, || {
+            cases
+                .iter()
+                .for_each(|input| drop(black_box(E::reference(input))))
+        });
+        user.as_nanos().max(1) as f64 / reference.as_nanos().max(1) as f64
+    });
+
+    ExerciseGrade {
+        exercise: E::NAME,
+        cases_passed,
+        cases_total: cases.len(),
+        first_failure,
+        speed_ratio,
+        hints_used,
+    }
+}
+
+fn fastest_round(rounds: usize, mut run: impl FnMut()) -> Duration {
+    (0..rounds.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
@@ -0,0 +1,30 @@
+//! Grading and scoring module
+//!
+//! Scores learner implementations on three axes:
+//! 1. Correctness: share of verifier cases that match the reference
+//! 2. Performance: run time relative to the reference implementation
+//! 3. Hints: how many hints the learner needed
+//!
+//! Grades are collected in a [`GradeBook`], which groups them into one
+//! [`ModuleReport`] per crate module.
+//!
+//! ```
+//! use rust_ds_learning::grading::{grade, GradeBook, GradingConfig};
+//! use rust_ds_learning::verify::MaxProduct;
+//!
+//! let mut book = GradeBook::new();
+//! let attempt = grade::<MaxProduct, _>(&GradingConfig::default(), 1, |nums| {
+//!     rust_ds_learning::vector::max_product(nums)
+//! });
+//! assert!(attempt.is_correct());
+//! book.record(attempt);
+//! println!("{}", book.reports()[0]);
+//! ```
+
+mod grader;
+mod report;
+#[cfg(test)]
+mod tests;
+
+pub use grader::*;
+pub use report::*;
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::grader::ExerciseGrade;
+
+/// All grades recorded for one crate module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleReport {
+    pub module: &'static str,
+    pub grades: Vec<ExerciseGrade>,
+}
+
+impl ModuleReport {
+    /// Average score over the module's exercises (0 for an empty report)
+    pub fn average_score(&self) -> f64 {
+        if self.grades.is_empty() {
+            return 0.0;
+        }
+        let total: u32 = self.grades.iter().map(ExerciseGrade::score).sum();
+        f64::from(total) / self.grades.len() as f64
+    }
+
+    /// Number of exercises solved correctly
+    pub fn solved(&self) -> usize {
+        self.grades.iter().filter(|g| g.is_correct()).count()
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.grades.iter().map(|g| g.hints_used).sum()
+    }
+}
+
+impl fmt::Display for ModuleReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: {}/{} solved, average score {:.1}",
+            self.module,
+            self.solved(),
+            self.grades.len(),
+            self.average_score()
+        )?;
+        for grade in &self.grades {
+            let speed = grade
+                .speed_ratio
+                .map_or_else(|| "-".to_string(), |ratio| format!("{ratio:.2}x"));
+            writeln!(
+                f,
+                "  {:<45} {:>3}/100  cases {}/{}  speed {}  hints {}",
+                grade.exercise,
+                grade.score(),
+                grade.cases_passed,
+                grade.cases_total,
+                speed,
+                grade.hints_used
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects grades across a session and groups them by module.
+///
+/// Re-grading an exercise replaces its earlier grade, so a grade book always
+/// reflects the latest attempt.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GradeBook {
+    grades: BTreeMap<&'static str, ExerciseGrade>,
+}
+
+impl GradeBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, grade: ExerciseGrade) {
+        self.grades.insert(grade.exercise, grade);
+    }
+
+    pub fn get(&self, exercise: &str) -> Option<&ExerciseGrade> {
+        self.grades.get(exercise)
+    }
+
+    pub fn grades(&self) -> impl Iterator<Item = &ExerciseGrade> {
+        self.grades.values()
+    }
+
+    /// One report per module, ordered by module name
+    pub fn reports(&self) -> Vec<ModuleReport> {
+        let mut by_module: BTreeMap<&'static str, Vec<ExerciseGrade>> = BTreeMap::new();
+        for grade in self.grades.values() {
+            by_module
+                .entry(grade.module())
+                .or_default()
+                .push(grade.clone());
+        }
+        by_module
+            .into_iter()
+            .map(|(module, grades)| ModuleReport { module, grades })
+            .collect()
+    }
+}
@@ -0,0 +1,157 @@
+use crate::grading::*;
+use crate::verify::{CheckConfig, FindElement, MergeIntervals, SlidingWindowMaximum};
+
+fn quick_config() -> GradingConfig {
+    GradingConfig {
+        check: CheckConfig { cases: 32, seed: 3 },
+        timing_rounds: 1,
+    }
+}
+
+fn sample_grade(exercise: &'static str, passed: usize, hints_used: u32) -> ExerciseGrade {
+    ExerciseGrade {
+        exercise,
+        cases_passed: passed,
+        cases_total: 10,
+        first_failure: None,
+        speed_ratio: (passed == 10).then_some(1.0),
+        hints_used,
+    }
+}
+
+mod scoring_tests {
+    use super::*;
+
+    #[test]
+    fn test_perfect_score() {
+        let grade = sample_grade("vector::merge_intervals", 10, 0);
+        assert_eq!(grade.correctness_points(), CORRECTNESS_POINTS);
+        assert_eq!(grade.performance_points(), PERFORMANCE_POINTS);
+        assert_eq!(grade.hint_points(), HINT_POINTS);
+        assert_eq!(grade.score(), 100);
+    }
+
+    #[test]
+    fn test_partial_correctness() {
+        let grade = sample_grade("vector::merge_intervals", 5, 0);
+        assert!(!grade.is_correct());
+        assert_eq!(grade.correctness_points(), 35);
+        assert_eq!(grade.performance_points(), 0);
+        assert_eq!(grade.score(), 45);
+    }
+
+    #[test]
+    fn test_hint_penalty_saturates() {
+        assert_eq!(sample_grade("array::x", 10, 1).hint_points(), 5);
+        assert_eq!(sample_grade("array::x", 10, 2).hint_points(), 0);
+        assert_eq!(sample_grade("array::x", 10, u32::MAX).hint_points(), 0);
+    }
+
+    #[test]
+    fn test_performance_points_curve() {
+        assert_eq!(performance_points_for(0.5), PERFORMANCE_POINTS);
+        assert_eq!(performance_points_for(1.5), PERFORMANCE_POINTS);
+        assert_eq!(performance_points_for(5.75), 10);
+        assert_eq!(performance_points_for(10.0), 0);
+        assert_eq!(performance_points_for(f64::INFINITY), 0);
+        assert_eq!(performance_points_for(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_empty_case_set_scores_no_correctness() {
+        let mut grade = sample_grade("array::x", 0, 0);
+        grade.cases_total = 0;
+        assert_eq!(grade.correctness_points(), 0);
+    }
+
+    #[test]
+    fn test_module_name() {
+        assert_eq!(
+            sample_grade("vector::merge_intervals", 0, 0).module(),
+            "vector"
+        );
+        assert_eq!(sample_grade("standalone", 0, 0).module(), "standalone");
+    }
+}
+
+mod grade_tests {
+    use super::*;
+
+    #[test]
+    fn test_grade_correct_impl() {
+        let grade = grade::<SlidingWindowMaximum, _>(&quick_config(), 0, |(nums, k)| {
+            nums.windows(*k).map(|w| *w.iter().max().unwrap()).collect()
+        });
+        assert!(grade.is_correct());
+        assert!(grade.first_failure.is_none());
+        assert!(grade.speed_ratio.is_some());
+        assert_eq!(grade.correctness_points(), CORRECTNESS_POINTS);
+    }
+
+    #[test]
+    fn test_grade_wrong_impl() {
+        // Always reports "not found", which is right only for absent elements
+        let grade = grade::<FindElement, _>(&quick_config(), 2, |_| None);
+        assert!(!grade.is_correct());
+        assert!(grade.cases_passed > 0);
+        assert!(grade.speed_ratio.is_none());
+        assert_eq!(
+            grade.first_failure.as_deref(),
+            Some("case #1: input ([1], 1), expected Some(0), got None")
+        );
+        assert_eq!(grade.score(), grade.correctness_points());
+    }
+}
+
+mod grade_book_tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_group_by_module() {
+        let mut book = GradeBook::new();
+        book.record(sample_grade("vector::merge_intervals", 10, 0));
+        book.record(sample_grade("array::find_element", 5, 1));
+        book.record(sample_grade("vector::max_product", 10, 2));
+
+        let reports = book.reports();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].module, "array");
+        assert_eq!(reports[1].module, "vector");
+        assert_eq!(reports[1].solved(), 2);
+        assert_eq!(reports[1].hints_used(), 2);
+        assert_eq!(reports[1].average_score(), 95.0);
+    }
+
+    #[test]
+    fn test_regrade_replaces_previous_attempt() {
+        let mut book = GradeBook::new();
+        book.record(sample_grade("vector::merge_intervals", 3, 0));
+        book.record(sample_grade("vector::merge_intervals", 10, 0));
+        assert_eq!(book.grades().count(), 1);
+        assert!(book.get("vector::merge_intervals").unwrap().is_correct());
+    }
+
+    #[test]
+    fn test_report_display() {
+        let mut book = GradeBook::new();
+        // Returning the input unchanged is only right for already-merged lists
+        book.record(grade::<MergeIntervals, _>(&quick_config(), 0, |v| {
+            v.clone()
+        }));
+
+        let text = book.reports()[0].to_string();
+        assert!(text.starts_with("vector: 0/1 solved"));
+        assert!(text.contains("vector::merge_intervals"));
+        assert!(text.contains("speed -"));
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = ModuleReport {
+            module: "vector",
+            grades: vec![],
+        };
+        assert_eq!(report.average_score(), 0.0);
+        assert_eq!(report.solved(), 0);
+    }
+}
@@ -0,0 +1,10 @@
+//! HashMap exercises and examples module
+//!
+//! Exercises in other modules that are built around a `HashMap`:
+//! - [`crate::vector::two_sum`]: value-to-index lookup of complements
+//! - [`crate::vector::subarray_sum_count`]: frequency counts of prefix sums
+//! - [`crate::string::group_anagrams`]: grouping words under a shared
+//!   signature, and [`crate::string::is_anagram`]: character counts when an
+//!   array of counters is too small
+//! - [`crate::string::Interner`]: a string-to-symbol map, paired with a
+//!   `Vec` for the reverse direction, sharing each string between them
@@ -0,0 +1,6 @@
+//! HashSet exercises and examples module
+//!
+//! Exercises in other modules that are built around a `HashSet`:
+//! - [`crate::string::word_break`] and [`crate::string::word_break_all`]:
+//!   dictionary lookups of candidate words, borrowing `&str` slices to
+//!   query a `HashSet<String>`
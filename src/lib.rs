@@ -4,6 +4,8 @@
 //! data structures, including exercises, examples, and best practices.
 
 pub mod binary_heap;
+pub mod bits;
+pub mod borrowing;
 pub mod btreemap;
 pub mod hashmap;
 pub mod hashset;
@@ -13,6 +15,17 @@ pub mod vector;
 pub mod array;
 pub mod matrix;
 
+pub mod arena;
+pub mod compare;
+pub mod curriculum;
+pub mod grading;
+pub mod registry;
+pub mod streaming;
+pub mod verify;
+
+#[cfg(feature = "fuzzing")]
+pub mod fuzz_targets;
+
 // We don't need to re-export VectorExercises here since it's already
 // public through the vector module
 
@@ -0,0 +1,339 @@
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// # Matrix Power by Repeated Squaring
+    ///
+    /// ## Problem Statement
+    /// Compute `Aⁿ` for a square matrix `A` with O(log n) multiplications.
+    ///
+    /// ## Example
+    /// The classic application: `[[1, 1], [1, 0]]ⁿ = [[F(n+1), F(n)], [F(n), F(n-1)]]`,
+    /// so the n-th Fibonacci number takes O(log n) 2x2 products.
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// fn fibonacci(n: u64) -> i64 {
+    ///     let q = Matrix::from_vec(2, 2, vec![1_i64, 1, 1, 0]).unwrap();
+    ///     q.pow(n).unwrap()[(0, 1)]
+    /// }
+    ///
+    /// assert_eq!((0..10).map(fibonacci).collect::<Vec<_>>(), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    /// assert_eq!(fibonacci(90), 2_880_067_194_370_816_120);
+    /// ```
+    ///
+    /// ## Approach
+    /// Walk the bits of `n` from least significant: square the base at every
+    /// step and multiply it into the result when the bit is set, because
+    /// `A^13 = A^8 · A^4 · A^1`.
+    ///
+    /// ## Complexity
+    /// - Time: O(k³ log n) for a `k x k` matrix
+    /// - Space: O(k²)
+    ///
+    /// ## Key Points
+    /// 1. `A⁰` is the identity, the neutral element of multiplication
+    /// 2. Any linear recurrence (Fibonacci, tribonacci, path counting in a
+    ///    graph) becomes a matrix power
+    ///
+    /// ## Common Pitfalls
+    /// 1. Multiplying `n` times in a loop: O(n) instead of O(log n)
+    /// 2. Integer overflow; entries of Fibonacci powers grow exponentially
+    ///
+    /// Returns `MatrixError::NotSquare` for non-square matrices.
+    pub fn pow(&self, mut n: u64) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = (&result * &base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = (&base * &base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Sum of the main diagonal. Returns `MatrixError::NotSquare` for
+    /// non-square matrices; the empty matrix has trace zero.
+    pub fn trace(&self) -> Result<T, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let mut sum = T::ZERO;
+        for i in 0..self.rows {
+            sum += self[(i, i)];
+        }
+        Ok(sum)
+    }
+
+    /// [`Matrix::rank_with_tolerance`] with a tolerance of
+    /// [`RANK_EPSILON`] times the largest absolute entry, so the threshold
+    /// scales with the data.
+    pub fn rank(&self) -> usize {
+        let largest = self.iter().map(|x| x.to_f64().abs()).fold(0.0, f64::max);
+        self.rank_with_tolerance(RANK_EPSILON * largest)
+    }
+
+    /// # Rank by Row Reduction
+    ///
+    /// ## Problem Statement
+    /// Count the linearly independent rows (equivalently, columns).
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// // The third row is the sum of the first two
+    /// let m: Matrix<i32> = "1 2 3; 4 5 6; 5 7 9".parse().unwrap();
+    /// assert_eq!(m.rank(), 2);
+    /// ```
+    ///
+    /// ## Approach
+    /// Reduce a floating point copy to row echelon form. For each column,
+    /// pick the remaining row with the largest absolute entry; if even that
+    /// is within `epsilon` of zero the column has no pivot. The rank is the
+    /// number of pivots found.
+    ///
+    /// ## Complexity
+    /// - Time: O(rows · cols · min(rows, cols))
+    /// - Space: O(rows · cols) for the working copy
+    ///
+    /// ## Key Points
+    /// 1. In floating point, "zero" after elimination is usually `1e-16`,
+    ///    not `0.0`; the tolerance decides what counts as dependent
+    /// 2. Choosing the largest pivot keeps the round-off small enough that
+    ///    the tolerance can be tight
+    ///
+    /// ## Common Pitfalls
+    /// 1. Comparing pivots with `== 0.0`, which overestimates the rank of
+    ///    nearly every computed matrix
+    /// 2. Stopping at the first zero column instead of moving on to the next
+    pub fn rank_with_tolerance(&self, epsilon: f64) -> usize {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut a: Vec<f64> = self.iter().map(|x| x.to_f64()).collect();
+        let mut rank = 0;
+
+        for col in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let pivot_row = (rank..rows)
+                .max_by(|&p, &q| a[p * cols + col].abs().total_cmp(&a[q * cols + col].abs()))
+                .expect("rank < rows");
+            if a[pivot_row * cols + col].abs() <= epsilon {
+                continue;
+            }
+            for j in 0..cols {
+                a.swap(rank * cols + j, pivot_row * cols + j);
+            }
+            let pivot = a[rank * cols + col];
+            for i in rank + 1..rows {
+                let factor = a[i * cols + col] / pivot;
+                for j in col..cols {
+                    a[i * cols + j] -= factor * a[rank * cols + j];
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+}
+
+/// Matrix norms, for measuring how far apart two matrices are
+/// (`(&a - &b)?.norm_frobenius()`) or whether an iteration has converged.
+///
+/// `norm_l1` and `norm_inf` are the *induced* (operator) norms: the largest
+/// absolute column sum and row sum respectively. They are cheap upper bounds
+/// on how much the matrix can stretch a vector in the matching vector norm.
+impl<T: Scalar> Matrix<T> {
+    /// `sqrt(sum of squared entries)`, the matrix analogue of vector length
+    pub fn norm_frobenius(&self) -> f64 {
+        self.iter()
+            .map(|x| {
+                let x = x.to_f64();
+                x * x
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Largest absolute column sum
+    pub fn norm_l1(&self) -> f64 {
+        self.iter_cols()
+            .map(|col| col.map(|x| x.to_f64().abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Largest absolute row sum
+    pub fn norm_inf(&self) -> f64 {
+        self.iter_rows()
+            .map(|row| row.iter().map(|x| x.to_f64().abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Matrix {
+    /// Scales the matrix to unit Frobenius norm, or `None` for an all-zero
+    /// matrix, which has no direction to keep.
+    ///
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let m: Matrix = "3 0; 0 4".parse().unwrap();
+    /// let unit = m.normalize().unwrap();
+    /// assert_eq!(unit.data, vec![0.6, 0.0, 0.0, 0.8]);
+    /// assert!(Matrix::zeros(2, 2).normalize().is_none());
+    /// ```
+    pub fn normalize(&self) -> Option<Matrix> {
+        let norm = self.norm_frobenius();
+        (norm > 0.0).then(|| self / norm)
+    }
+}
+
+/// Relative tolerance used by [`Matrix::rank`]
+pub const RANK_EPSILON: f64 = 1e-10;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow_small_exponents() {
+        let m = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(m.pow(0).unwrap(), Matrix::identity(2));
+        assert_eq!(m.pow(1).unwrap(), m);
+        assert_eq!(m.pow(2).unwrap().data, vec![7, 10, 15, 22]);
+
+        let mut repeated = Matrix::identity(2);
+        for n in 0..12 {
+            assert_eq!(m.pow(n).unwrap(), repeated, "n = {n}");
+            repeated = (&repeated * &m).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_pow_counts_paths() {
+        // Adjacency of a directed 3-cycle: A^3 maps every node back to itself
+        let cycle = Matrix::from_vec(3, 3, vec![0, 1, 0, 0, 0, 1, 1, 0, 0]).unwrap();
+        assert_eq!(cycle.pow(3).unwrap(), Matrix::identity(3));
+        assert_eq!(cycle.pow(3_000_000_000_000).unwrap(), Matrix::identity(3));
+        assert_eq!(cycle.pow(4).unwrap(), cycle);
+    }
+
+    #[test]
+    fn test_pow_edge_cases() {
+        assert_eq!(
+            Matrix::<f64>::zeros(0, 0).pow(5).unwrap(),
+            Matrix::zeros(0, 0)
+        );
+        let half: Matrix = Matrix::from_vec(1, 1, vec![0.5]).unwrap();
+        assert_eq!(half.pow(3).unwrap().data, vec![0.125]);
+        assert!(matches!(
+            Matrix::<i32>::zeros(2, 3).pow(2),
+            Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_trace() {
+        let m = Matrix::from_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+        assert_eq!(m.trace().unwrap(), 15);
+        assert_eq!(Matrix::<f64>::identity(4).trace().unwrap(), 4.0);
+        assert_eq!(Matrix::<i64>::zeros(0, 0).trace().unwrap(), 0);
+        assert!(matches!(
+            Matrix::<i32>::zeros(3, 2).trace(),
+            Err(MatrixError::NotSquare { rows: 3, cols: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_rank_exact() {
+        assert_eq!(Matrix::<f64>::identity(4).rank(), 4);
+        assert_eq!(Matrix::<f64>::zeros(3, 5).rank(), 0);
+        assert_eq!(Matrix::<f64>::zeros(0, 0).rank(), 0);
+
+        let m: Matrix<i32> = "1 2 3; 2 4 6; 3 6 9".parse().unwrap();
+        assert_eq!(m.rank(), 1);
+        // Rank is bounded by the smaller dimension
+        let wide: Matrix<i32> = "1 0 0 1; 0 1 0 1".parse().unwrap();
+        assert_eq!(wide.rank(), 2);
+        assert_eq!(wide.transpose().rank(), 2);
+        // A zero first column must not stop the reduction
+        let shifted: Matrix<i32> = "0 1 2; 0 2 5; 0 0 0".parse().unwrap();
+        assert_eq!(shifted.rank(), 2);
+    }
+
+    #[test]
+    fn test_rank_floating_point_tolerance() {
+        // Row 3 = 0.1 * row 1 + 0.2 * row 2, which is not exact in binary
+        let r1 = [0.3, 0.7, 1.1];
+        let r2 = [1.3, 0.9, 0.2];
+        let r3: Vec<f64> = (0..3).map(|j| 0.1 * r1[j] + 0.2 * r2[j]).collect();
+        let m = Matrix::from_rows(&[r1.to_vec(), r2.to_vec(), r3]).unwrap();
+        assert_eq!(m.rank(), 2);
+        assert_eq!(
+            m.rank_with_tolerance(0.0),
+            3,
+            "without a tolerance round-off counts as rank"
+        );
+
+        // Scaling the data must not change the rank
+        assert_eq!((&m * 1e12).rank(), 2);
+        assert_eq!((&m * 1e-12).rank(), 2);
+    }
+
+    #[test]
+    fn test_norms() {
+        // [ 1 -2]
+        // [-3  4]
+        let m: Matrix<i32> = "1 -2; -3 4".parse().unwrap();
+        assert!((m.norm_frobenius() - 30f64.sqrt()).abs() < 1e-12);
+        assert_eq!(m.norm_l1(), 6.0, "column sums are 4 and 6");
+        assert_eq!(m.norm_inf(), 7.0, "row sums are 3 and 7");
+
+        // Transposing swaps the roles of the two induced norms
+        assert_eq!(m.transpose().norm_l1(), m.norm_inf());
+        assert_eq!(m.transpose().norm_inf(), m.norm_l1());
+
+        let empty = Matrix::<f64>::zeros(0, 3);
+        assert_eq!(
+            (empty.norm_frobenius(), empty.norm_l1(), empty.norm_inf()),
+            (0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_norm_measures_distance() {
+        let a: Matrix = Matrix::random(4, 4, -1.0..1.0, 1);
+        let b = &a + 1e-9;
+        let distance = (&a - &b).unwrap().norm_frobenius();
+        assert!((distance - 4e-9).abs() < 1e-15);
+        assert_eq!((&a - &a).unwrap().norm_inf(), 0.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let m: Matrix = Matrix::random(3, 5, -10.0..10.0, 2);
+        let unit = m.normalize().unwrap();
+        assert!((unit.norm_frobenius() - 1.0).abs() < 1e-12);
+        // Same direction: every entry scaled by the same positive factor
+        let factor = m.norm_frobenius();
+        for (x, y) in unit.iter().zip(m.iter()) {
+            assert!((x * factor - y).abs() < 1e-9);
+        }
+        assert!(Matrix::zeros(0, 0).normalize().is_none());
+    }
+}
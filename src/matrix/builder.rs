@@ -0,0 +1,237 @@
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// # Row-by-Row Matrix Builder
+///
+/// Assembles a [`Matrix`] from rows that arrive one at a time, checking
+/// each row's width as it is added so the error names the offending row.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{MatrixBuilder, MatrixError};
+///
+/// # fn main() -> Result<(), MatrixError> {
+/// let m = MatrixBuilder::new().row([1, 2, 3])?.row([4, 5, 6])?.build()?;
+/// assert_eq!(m, "1 2 3; 4 5 6".parse().unwrap());
+///
+/// let ragged = MatrixBuilder::new().row([1, 2, 3])?.row([4, 5]);
+/// assert!(matches!(
+///     ragged,
+///     Err(MatrixError::RaggedRows { row: 1, expected: 3, actual: 2 })
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Without a declared shape, the first row fixes the width.
+/// [`MatrixBuilder::with_shape`] fixes both dimensions up front, so extra
+/// rows are rejected as they are added and missing rows when building.
+#[derive(Debug, Clone)]
+pub struct MatrixBuilder<T = f64> {
+    rows: usize,
+    cols: Option<usize>,
+    expected_rows: Option<usize>,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> Default for MatrixBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar> MatrixBuilder<T> {
+    pub fn new() -> Self {
+        Self {
+            rows: 0,
+            cols: None,
+            expected_rows: None,
+            data: Vec::new(),
+        }
+    }
+
+    /// A builder that expects exactly `rows` rows of `cols` elements
+    pub fn with_shape(rows: usize, cols: usize) -> Self {
+        Self {
+            rows: 0,
+            cols: Some(cols),
+            expected_rows: Some(rows),
+            data: Vec::with_capacity(rows * cols),
+        }
+    }
+
+    /// Appends a row.
+    ///
+    /// Returns `MatrixError::RaggedRows` if its width differs from earlier
+    /// rows (or the declared shape), and `MatrixError::IndexOutOfBounds` if
+    /// it is one row more than [`MatrixBuilder::with_shape`] allows.
+    pub fn row(mut self, values: impl IntoIterator<Item = T>) -> Result<Self, MatrixError> {
+        if let Some(expected_rows) = self.expected_rows {
+            if self.rows == expected_rows {
+                return Err(MatrixError::IndexOutOfBounds {
+                    row: self.rows,
+                    col: 0,
+                    rows: expected_rows,
+                    cols: self.cols.unwrap_or(0),
+                });
+            }
+        }
+
+        let start = self.data.len();
+        self.data.extend(values);
+        let actual = self.data.len() - start;
+        let expected = *self.cols.get_or_insert(actual);
+        if actual != expected {
+            return Err(MatrixError::RaggedRows {
+                row: self.rows,
+                expected,
+                actual,
+            });
+        }
+        self.rows += 1;
+        Ok(self)
+    }
+
+    /// Number of rows added so far
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Finishes the matrix. A builder with no rows builds the `0 x 0`
+    /// matrix (or `0 x cols` after [`MatrixBuilder::with_shape`]).
+    ///
+    /// Returns `MatrixError::InvalidCreation` if fewer rows were added than
+    /// declared with [`MatrixBuilder::with_shape`].
+    pub fn build(self) -> Result<Matrix<T>, MatrixError> {
+        let cols = self.cols.unwrap_or(0);
+        if let Some(expected_rows) = self.expected_rows {
+            if self.rows != expected_rows {
+                return Err(MatrixError::InvalidCreation {
+                    expected: expected_rows * cols,
+                    actual: self.data.len(),
+                });
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols,
+            data: self.data,
+        })
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Shorthand for [`MatrixBuilder::new`]
+    pub fn builder() -> MatrixBuilder<T> {
+        MatrixBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_rows() {
+        let m = MatrixBuilder::new()
+            .row([1.0, 2.0])
+            .and_then(|b| b.row(vec![3.0, 4.0]))
+            .and_then(|b| b.row((5..=6).map(f64::from)))
+            .and_then(MatrixBuilder::build)
+            .unwrap();
+        assert_eq!(
+            m,
+            Matrix::from_vec(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ragged_row_reports_index() {
+        let builder = Matrix::<i32>::builder()
+            .row([1, 2])
+            .unwrap()
+            .row([3, 4])
+            .unwrap();
+        assert_eq!(builder.len(), 2);
+        assert!(matches!(
+            builder.row([5, 6, 7]),
+            Err(MatrixError::RaggedRows {
+                row: 2,
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_empty_builders() {
+        let empty = MatrixBuilder::<i32>::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.build().unwrap(), Matrix::zeros(0, 0));
+
+        let no_cols = MatrixBuilder::<i32>::new()
+            .row([])
+            .unwrap()
+            .row([])
+            .unwrap();
+        let m = no_cols.build().unwrap();
+        assert_eq!((m.rows(), m.cols()), (2, 0));
+
+        let m = MatrixBuilder::<i32>::with_shape(0, 3).build().unwrap();
+        assert_eq!((m.rows(), m.cols()), (0, 3));
+    }
+
+    #[test]
+    fn test_with_shape() {
+        let m = MatrixBuilder::with_shape(2, 2)
+            .row([1, 0])
+            .unwrap()
+            .row([0, 1])
+            .unwrap();
+        assert!(matches!(
+            m.clone().row([1, 1]),
+            Err(MatrixError::IndexOutOfBounds {
+                row: 2,
+                rows: 2,
+                ..
+            })
+        ));
+        assert_eq!(m.build().unwrap(), Matrix::identity(2));
+
+        // The declared width applies to the first row too
+        assert!(matches!(
+            MatrixBuilder::with_shape(2, 2).row([1, 2, 3]),
+            Err(MatrixError::RaggedRows {
+                row: 0,
+                expected: 2,
+                actual: 3
+            })
+        ));
+
+        let short = MatrixBuilder::with_shape(3, 2).row([1, 2]).unwrap();
+        assert!(matches!(
+            short.build(),
+            Err(MatrixError::InvalidCreation {
+                expected: 6,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_build_from_parsed_lines() {
+        let csv = "1,2,3\n4,5,6\n7,8,9";
+        let m = csv
+            .lines()
+            .try_fold(MatrixBuilder::new(), |builder, line| {
+                builder.row(line.split(',').map(|t| t.parse::<i32>().unwrap()))
+            })
+            .and_then(MatrixBuilder::build)
+            .unwrap();
+        assert_eq!(m.trace().unwrap(), 15);
+    }
+}
@@ -0,0 +1,105 @@
+//! Conversions between [`Matrix`] and nested `Vec`s, for interop with data
+//! that arrives as rows (CSV readers, JSON arrays, other crates).
+
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// Takes ownership of the rows, which must all have the same length.
+///
+/// ```
+/// use rust_ds_learning::matrix::{Matrix, MatrixError};
+///
+/// let m = Matrix::try_from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+/// assert_eq!(m[(1, 0)], 3.0);
+///
+/// let ragged = Matrix::try_from(vec![vec![1.0, 2.0], vec![3.0]]);
+/// assert!(matches!(ragged, Err(MatrixError::RaggedRows { row: 1, .. })));
+/// ```
+impl<T: Scalar> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if let Some((row, values)) = rows.iter().enumerate().find(|(_, r)| r.len() != cols) {
+            return Err(MatrixError::RaggedRows {
+                row,
+                expected: cols,
+                actual: values.len(),
+            });
+        }
+        Ok(Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// Splits the matrix back into one `Vec` per row.
+///
+/// ```
+/// use rust_ds_learning::matrix::Matrix;
+///
+/// let m: Matrix<i32> = "1 2; 3 4".parse().unwrap();
+/// let rows: Vec<Vec<i32>> = m.into();
+/// assert_eq!(rows, vec![vec![1, 2], vec![3, 4]]);
+/// ```
+impl<T: Scalar> From<Matrix<T>> for Vec<Vec<T>> {
+    fn from(matrix: Matrix<T>) -> Self {
+        matrix.to_rows()
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Copies each row into its own `Vec`
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.iter_rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let rows = vec![vec![1.5, -2.0, 0.0], vec![4.0, 5.0, 6.25]];
+        let m = Matrix::try_from(rows.clone()).unwrap();
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m, Matrix::from_rows(&rows).unwrap());
+        let back: Vec<Vec<f64>> = m.into();
+        assert_eq!(back, rows);
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        assert!(matches!(
+            Matrix::try_from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]),
+            Err(MatrixError::RaggedRows {
+                row: 2,
+                expected: 3,
+                actual: 2
+            })
+        ));
+        assert!(matches!(
+            Matrix::try_from(vec![vec![], vec![1]]),
+            Err(MatrixError::RaggedRows {
+                row: 1,
+                expected: 0,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_degenerate_shapes() {
+        let empty = Matrix::<f64>::try_from(Vec::new()).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+        assert!(Vec::<Vec<f64>>::from(empty).is_empty());
+
+        // Rows without columns survive the round trip
+        let no_cols = Matrix::<i32>::try_from(vec![vec![], vec![]]).unwrap();
+        assert_eq!((no_cols.rows(), no_cols.cols()), (2, 0));
+        assert_eq!(no_cols.to_rows(), vec![Vec::<i32>::new(), Vec::new()]);
+    }
+}
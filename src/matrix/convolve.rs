@@ -0,0 +1,249 @@
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// How [`Matrix::convolve`] treats positions outside the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingMode {
+    /// No padding: the kernel only visits positions where it fits entirely,
+    /// so the output shrinks by `kernel - 1` in each dimension
+    Valid,
+    /// Pad with zeros so the output (at stride 1) has the input's shape
+    Zero,
+    /// Pad by repeating the nearest edge value, which avoids the dark
+    /// border zero padding produces when blurring an image
+    Replicate,
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// [`Matrix::convolve_strided`] with stride 1
+    pub fn convolve(
+        &self,
+        kernel: &Matrix<T>,
+        padding: PaddingMode,
+    ) -> Result<Matrix<T>, MatrixError> {
+        self.convolve_strided(kernel, padding, 1)
+    }
+
+    /// # 2D Convolution
+    ///
+    /// ## Problem Statement
+    /// Slide `kernel` over the matrix and, at each position, sum the
+    /// element-wise products of the kernel and the patch beneath it. This
+    /// is how blurs, edge detectors and CNN layers process images.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::{Matrix, PaddingMode};
+    ///
+    /// let image: Matrix<i32> = "1 2 3; 4 5 6; 7 8 9".parse().unwrap();
+    /// let box_sum: Matrix<i32> = "1 1; 1 1".parse().unwrap();
+    /// let out = image.convolve(&box_sum, PaddingMode::Valid).unwrap();
+    /// assert_eq!(out, "12 16; 24 28".parse().unwrap());
+    /// ```
+    ///
+    /// ## Approach
+    /// For every output cell, map it back to the top-left input position
+    /// `(out_row * stride, out_col * stride)` (shifted by the padding) and
+    /// accumulate over the kernel. Reads that fall outside the input are
+    /// resolved by the [`PaddingMode`] instead of materializing a padded
+    /// copy.
+    ///
+    /// With padding, the kernel is anchored at its centre (`kernel / 2`),
+    /// so an odd-sized kernel at stride 1 keeps the input's shape. A stride
+    /// of `s` keeps every `s`-th output in each dimension.
+    ///
+    /// ## Complexity
+    /// - Time: O(out_rows · out_cols · k_rows · k_cols)
+    /// - Space: O(out_rows · out_cols)
+    ///
+    /// ## Key Points
+    /// 1. Like most image-processing and ML libraries this computes
+    ///    cross-correlation: the kernel is not flipped. For symmetric
+    ///    kernels (blurs) the two coincide
+    ///
+    /// ## Common Pitfalls
+    /// 1. Off-by-one output sizes: `Valid` gives
+    ///    `(rows - k_rows) / stride + 1`, padded modes give
+    ///    `(rows - 1) / stride + 1`
+    /// 2. Clamping indices for zero padding, or zeroing them for replicate
+    ///
+    /// Returns `MatrixError::InvalidStride` for a stride of zero and
+    /// `MatrixError::DimensionMismatch` for an empty kernel or, with
+    /// `Valid` padding, a kernel larger than the input.
+    pub fn convolve_strided(
+        &self,
+        kernel: &Matrix<T>,
+        padding: PaddingMode,
+        stride: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if stride == 0 {
+            return Err(MatrixError::InvalidStride { stride });
+        }
+        let (k_rows, k_cols) = (kernel.rows, kernel.cols);
+        let too_large = padding == PaddingMode::Valid && (k_rows > self.rows || k_cols > self.cols);
+        if k_rows == 0 || k_cols == 0 || too_large {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "convolve",
+                left_dims: (self.rows, self.cols),
+                right_dims: (k_rows, k_cols),
+            });
+        }
+
+        let (out_rows, out_cols, anchor_row, anchor_col) = match padding {
+            PaddingMode::Valid => (
+                (self.rows - k_rows) / stride + 1,
+                (self.cols - k_cols) / stride + 1,
+                0,
+                0,
+            ),
+            PaddingMode::Zero | PaddingMode::Replicate => (
+                self.rows.div_ceil(stride),
+                self.cols.div_ceil(stride),
+                k_rows / 2,
+                k_cols / 2,
+            ),
+        };
+
+        Ok(Matrix::from_fn(out_rows, out_cols, |out_row, out_col| {
+            let mut sum = T::ZERO;
+            for ki in 0..k_rows {
+                for kj in 0..k_cols {
+                    let i = (out_row * stride + ki) as isize - anchor_row as isize;
+                    let j = (out_col * stride + kj) as isize - anchor_col as isize;
+                    if let Some(value) = self.padded(i, j, padding) {
+                        sum += value * kernel[(ki, kj)];
+                    }
+                }
+            }
+            sum
+        }))
+    }
+
+    /// Element at a possibly out-of-range position; `None` means zero
+    fn padded(&self, i: isize, j: isize, padding: PaddingMode) -> Option<T> {
+        let inside = (0..self.rows as isize).contains(&i) && (0..self.cols as isize).contains(&j);
+        match padding {
+            _ if inside => Some(self[(i as usize, j as usize)]),
+            PaddingMode::Valid | PaddingMode::Zero => None,
+            PaddingMode::Replicate => {
+                let i = i.clamp(0, self.rows as isize - 1) as usize;
+                let j = j.clamp(0, self.cols as isize - 1) as usize;
+                Some(self[(i, j)])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Matrix<i32> {
+        "1 2 3; 4 5 6; 7 8 9".parse().unwrap()
+    }
+
+    fn box_kernel() -> Matrix<i32> {
+        "1 1 1; 1 1 1; 1 1 1".parse().unwrap()
+    }
+
+    #[test]
+    fn test_valid_padding() {
+        assert_eq!(
+            image()
+                .convolve(&box_kernel(), PaddingMode::Valid)
+                .unwrap()
+                .data,
+            vec![45]
+        );
+
+        // Horizontal edge detector on a 4x4 ramp
+        let ramp = Matrix::from_fn(4, 4, |_, j| j as i32);
+        let sobel_x: Matrix<i32> = "-1 0 1; -2 0 2; -1 0 1".parse().unwrap();
+        let edges = ramp.convolve(&sobel_x, PaddingMode::Valid).unwrap();
+        assert_eq!(edges, "8 8; 8 8".parse().unwrap());
+    }
+
+    #[test]
+    fn test_zero_padding() {
+        // Each output is the sum of the 3x3 neighbourhood, missing cells count as 0
+        let out = image().convolve(&box_kernel(), PaddingMode::Zero).unwrap();
+        assert_eq!(out, "12 21 16; 27 45 33; 24 39 28".parse().unwrap());
+    }
+
+    #[test]
+    fn test_replicate_padding() {
+        // Top-left: rows [1 1 2], [1 1 2], [4 4 5] => 21
+        let out = image()
+            .convolve(&box_kernel(), PaddingMode::Replicate)
+            .unwrap();
+        assert_eq!(out, "21 27 33; 39 45 51; 57 63 69".parse().unwrap());
+
+        // A constant image stays constant under a normalized blur
+        let flat: Matrix = Matrix::from_fn(5, 4, |_, _| 2.0);
+        let blur = &Matrix::from_fn(3, 3, |_, _| 1.0) / 9.0;
+        let blurred = flat.convolve(&blur, PaddingMode::Replicate).unwrap();
+        assert!(blurred.iter().all(|x| (x - 2.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_identity_kernel_and_cross_correlation() {
+        let identity: Matrix<i32> = "0 0 0; 0 1 0; 0 0 0".parse().unwrap();
+        assert_eq!(
+            image().convolve(&identity, PaddingMode::Zero).unwrap(),
+            image()
+        );
+
+        // The kernel is not flipped: "1 0" picks the element under its left cell
+        let pick_first: Matrix<i32> = "1 0".parse().unwrap();
+        let out = image().convolve(&pick_first, PaddingMode::Valid).unwrap();
+        assert_eq!(out, "1 2; 4 5; 7 8".parse().unwrap());
+    }
+
+    #[test]
+    fn test_stride() {
+        let m = Matrix::from_fn(5, 5, |i, j| (5 * i + j) as i32);
+        let single: Matrix<i32> = "1".parse().unwrap();
+        let out = m.convolve_strided(&single, PaddingMode::Valid, 2).unwrap();
+        assert_eq!(out, "0 2 4; 10 12 14; 20 22 24".parse().unwrap());
+
+        let padded = m
+            .convolve_strided(&box_kernel(), PaddingMode::Zero, 2)
+            .unwrap();
+        let full = m.convolve(&box_kernel(), PaddingMode::Zero).unwrap();
+        assert_eq!((padded.rows(), padded.cols()), (3, 3));
+        for (i, j, &value) in padded.indexed_iter() {
+            assert_eq!(value, full[(2 * i, 2 * j)]);
+        }
+
+        let valid = m
+            .convolve_strided(&box_kernel(), PaddingMode::Valid, 2)
+            .unwrap();
+        assert_eq!((valid.rows(), valid.cols()), (2, 2));
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(matches!(
+            image().convolve_strided(&box_kernel(), PaddingMode::Zero, 0),
+            Err(MatrixError::InvalidStride { stride: 0 })
+        ));
+        let big = Matrix::<i32>::zeros(4, 1);
+        assert!(matches!(
+            image().convolve(&big, PaddingMode::Valid),
+            Err(MatrixError::DimensionMismatch {
+                operation: "convolve",
+                ..
+            })
+        ));
+        // Padded modes accept kernels larger than the input
+        assert!(image().convolve(&big, PaddingMode::Zero).is_ok());
+        assert!(image()
+            .convolve(&Matrix::zeros(0, 0), PaddingMode::Zero)
+            .is_err());
+
+        let empty = Matrix::<i32>::zeros(0, 0)
+            .convolve(&box_kernel(), PaddingMode::Zero)
+            .unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+    }
+}
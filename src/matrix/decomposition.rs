@@ -0,0 +1,387 @@
+use super::matrix::{Matrix, MatrixError};
+
+/// Pivots smaller than this, relative to the size of the matrix's entries,
+/// are treated as zero
+pub(crate) const PIVOT_EPSILON: f64 = 1e-12;
+
+/// Absolute pivot threshold for elimination on `m`: [`PIVOT_EPSILON`]
+/// scaled by the largest absolute entry, so that scaling a matrix does not
+/// change whether it counts as singular
+pub(crate) fn pivot_tolerance(m: &Matrix) -> f64 {
+    let scale = m.data.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+    PIVOT_EPSILON * scale.max(f64::MIN_POSITIVE)
+}
+
+/// Result of [`Matrix::lu`]: `P * A = L * U`.
+///
+/// `L` is unit lower triangular, `U` is upper triangular and the row
+/// permutation `P` is stored compactly: row `i` of `P * A` is row
+/// `permutation[i]` of `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuDecomposition {
+    pub l: Matrix,
+    pub u: Matrix,
+    pub permutation: Vec<usize>,
+    /// Number of row swaps performed, which fixes the sign of the determinant
+    pub swaps: usize,
+}
+
+impl LuDecomposition {
+    /// The permutation as an explicit matrix `P`
+    pub fn permutation_matrix(&self) -> Matrix {
+        let n = self.permutation.len();
+        let mut p = Matrix::zeros(n, n);
+        for (i, &row) in self.permutation.iter().enumerate() {
+            p.data[i * n + row] = 1.0;
+        }
+        p
+    }
+
+    /// `det(A)`: the product of the diagonal of `U`, negated for an odd
+    /// number of row swaps.
+    pub fn determinant(&self) -> f64 {
+        let n = self.u.rows;
+        let diagonal: f64 = (0..n).map(|i| self.u.data[i * n + i]).product();
+        if self.swaps.is_multiple_of(2) {
+            diagonal
+        } else {
+            -diagonal
+        }
+    }
+}
+
+impl Matrix {
+    /// # LU Decomposition with Partial Pivoting
+    ///
+    /// ## Problem Statement
+    /// Factor a square matrix `A` into `P * A = L * U` with `L` unit lower
+    /// triangular and `U` upper triangular.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    /// let lu = a.lu().unwrap();
+    /// assert_eq!(lu.permutation, vec![1, 0]); // 3.0 is the larger pivot
+    /// assert!((lu.determinant() - -2.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// ## Approach
+    /// Gaussian elimination that remembers its multipliers. For each column,
+    /// swap up the row with the largest absolute value in that column, then
+    /// subtract multiples of it from the rows below; the multipliers form `L`
+    /// and what remains is `U`.
+    ///
+    /// ## Complexity
+    /// - Time: O(n³)
+    /// - Space: O(n²) for the two factors
+    ///
+    /// ## Key Points
+    /// 1. Once you have `L`, `U` and `P`, solving `A x = b` for any `b` is two
+    ///    O(n²) triangular solves, and the determinant is a diagonal product
+    /// 2. Partial pivoting keeps the multipliers at most 1 in magnitude,
+    ///    which is what keeps round-off under control
+    ///
+    /// ## Common Pitfalls
+    /// 1. Pivoting on the first non-zero entry instead of the largest: it
+    ///    works in exact arithmetic but amplifies floating point error
+    /// 2. Swapping rows of `U` but forgetting the already computed part of `L`
+    ///
+    /// Returns `MatrixError::NotSquare` for non-square input and
+    /// `MatrixError::Singular` when a column has no usable pivot.
+    pub fn lu(&self) -> Result<LuDecomposition, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let mut u = self.clone();
+        let mut l = Matrix::zeros(n, n);
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+        let tolerance = pivot_tolerance(self);
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| u.data[a * n + k].abs().total_cmp(&u.data[b * n + k].abs()))
+                .expect("k < n");
+            if u.data[pivot_row * n + k].abs() < tolerance {
+                return Err(MatrixError::Singular);
+            }
+            if pivot_row != k {
+                for j in 0..n {
+                    u.data.swap(k * n + j, pivot_row * n + j);
+                }
+                // Only the multipliers computed so far (columns < k) move
+                for j in 0..k {
+                    l.data.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                swaps += 1;
+            }
+
+            let pivot = u.data[k * n + k];
+            for i in k + 1..n {
+                let factor = u.data[i * n + k] / pivot;
+                l.data[i * n + k] = factor;
+                for j in k..n {
+                    u.data[i * n + j] -= factor * u.data[k * n + j];
+                }
+            }
+        }
+
+        for i in 0..n {
+            l.data[i * n + i] = 1.0;
+        }
+        Ok(LuDecomposition {
+            l,
+            u,
+            permutation,
+            swaps,
+        })
+    }
+}
+
+/// Result of [`Matrix::qr`]: `A = Q * R`.
+///
+/// For an `m x n` input (`m >= n`) this is the *thin* factorization: `Q`
+/// is `m x n` with orthonormal columns and `R` is `n x n` upper triangular
+/// with a positive diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrDecomposition {
+    pub q: Matrix,
+    pub r: Matrix,
+}
+
+impl Matrix {
+    /// # QR Decomposition by Modified Gram–Schmidt
+    ///
+    /// ## Problem Statement
+    /// Factor `A` into an orthonormal basis `Q` for its column space and
+    /// an upper triangular `R` holding the coordinates of `A`'s columns in
+    /// that basis.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let a: Matrix = "3 1; 4 2".parse().unwrap();
+    /// let qr = a.qr().unwrap();
+    /// assert!((qr.r[(0, 0)] - 5.0).abs() < 1e-12); // length of column (3, 4)
+    /// let back = (&qr.q * &qr.r).unwrap();
+    /// assert!((&back - &a).unwrap().norm_frobenius() < 1e-12);
+    /// ```
+    ///
+    /// ## Approach
+    /// Process columns left to right. Normalize column `i` to get `q_i`
+    /// (its length is `R[i][i]`), then immediately remove the `q_i`
+    /// component from every later column, recording the projections as
+    /// `R[i][j]`.
+    ///
+    /// ## Complexity
+    /// - Time: O(m·n²)
+    /// - Space: O(m·n + n²)
+    ///
+    /// ## Key Points
+    /// 1. Classical Gram–Schmidt projects each column against the original
+    ///    columns; the modified version projects against the *updated* ones.
+    ///    Same maths, but round-off errors no longer pile up in the same
+    ///    direction, so `Q` stays far closer to orthogonal
+    /// 2. Orthogonality still degrades in proportion to the condition number
+    ///    of `A`; Householder reflections are the robust alternative
+    ///
+    /// ## Common Pitfalls
+    /// 1. Dividing by a near-zero norm when columns are dependent
+    ///
+    /// Returns `MatrixError::Singular` when the columns are linearly
+    /// dependent (which includes every input with more columns than rows).
+    pub fn qr(&self) -> Result<QrDecomposition, MatrixError> {
+        let (m, n) = (self.rows, self.cols);
+        // Work on columns as contiguous vectors
+        let mut columns: Vec<Vec<f64>> =
+            self.iter_cols().map(|col| col.copied().collect()).collect();
+        let mut r = Matrix::zeros(n, n);
+
+        let scale = self.norm_frobenius().max(f64::MIN_POSITIVE);
+        for i in 0..n {
+            let norm = columns[i].iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm <= PIVOT_EPSILON * scale {
+                return Err(MatrixError::Singular);
+            }
+            r[(i, i)] = norm;
+            columns[i].iter_mut().for_each(|x| *x /= norm);
+
+            let (done, rest) = columns.split_at_mut(i + 1);
+            let q_i = &done[i];
+            for (offset, column) in rest.iter_mut().enumerate() {
+                let projection: f64 = q_i.iter().zip(column.iter()).map(|(a, b)| a * b).sum();
+                r[(i, i + 1 + offset)] = projection;
+                for (x, q) in column.iter_mut().zip(q_i) {
+                    *x -= projection * q;
+                }
+            }
+        }
+
+        let q = Matrix::from_fn(m, n, |row, col| columns[col][row]);
+        Ok(QrDecomposition { q, r })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_matrix_approx_eq(a: &Matrix, b: &Matrix) {
+        assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+        for (x, y) in a.data.iter().zip(&b.data) {
+            assert!((x - y).abs() < EPSILON, "{:?} != {:?}", a.data, b.data);
+        }
+    }
+
+    fn check_factorization(a: &Matrix) -> LuDecomposition {
+        let lu = a.lu().unwrap();
+        let n = a.rows;
+        for i in 0..n {
+            assert_eq!(lu.l.data[i * n + i], 1.0);
+            for j in 0..n {
+                if j > i {
+                    assert_eq!(lu.l.data[i * n + j], 0.0, "L must be lower triangular");
+                }
+                if j < i {
+                    assert!(
+                        lu.u.data[i * n + j].abs() < EPSILON,
+                        "U must be upper triangular"
+                    );
+                }
+            }
+        }
+        let pa = (&lu.permutation_matrix() * a).unwrap();
+        let product = (&lu.l * &lu.u).unwrap();
+        assert_matrix_approx_eq(&pa, &product);
+        lu
+    }
+
+    #[test]
+    fn test_lu_reconstructs_input() {
+        let a =
+            Matrix::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]).unwrap();
+        let lu = check_factorization(&a);
+        assert_eq!(lu.permutation[0], 1, "largest first-column entry is 4.0");
+        assert!((lu.determinant() - -16.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_lu_needs_pivoting() {
+        // A zero in the top-left corner breaks elimination without pivoting
+        let a = Matrix::from_vec(3, 3, vec![0.0, 2.0, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 0.0]).unwrap();
+        let lu = check_factorization(&a);
+        assert!(lu.swaps > 0);
+        assert!((lu.determinant() - 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_lu_identity_and_empty() {
+        let identity = Matrix::identity(4);
+        let lu = check_factorization(&identity);
+        assert_eq!(lu.permutation, vec![0, 1, 2, 3]);
+        assert_eq!(lu.l, identity);
+        assert_eq!(lu.u, identity);
+
+        let lu = Matrix::zeros(0, 0).lu().unwrap();
+        assert_eq!(lu.determinant(), 1.0);
+    }
+
+    #[test]
+    fn test_lu_tiny_magnitude_is_not_singular() {
+        // Well conditioned, just small: the pivot threshold scales with it
+        let a: Matrix = "2 1; 1 3".parse().unwrap();
+        let tiny = &a * 1e-13;
+        let lu = check_factorization(&tiny);
+        assert!((lu.determinant() - 5e-26).abs() < 1e-36);
+
+        let lu = (&Matrix::identity(3) * 1e-13).lu().unwrap();
+        assert_eq!(lu.u, &Matrix::identity(3) * 1e-13);
+    }
+
+    #[test]
+    fn test_lu_errors() {
+        let singular = Matrix::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        assert!(matches!(singular.lu(), Err(MatrixError::Singular)));
+        assert!(matches!(
+            Matrix::zeros(2, 2).lu(),
+            Err(MatrixError::Singular)
+        ));
+
+        assert!(matches!(
+            Matrix::zeros(2, 3).lu(),
+            Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+        ));
+    }
+
+    fn check_qr(a: &Matrix) -> QrDecomposition {
+        let qr = a.qr().unwrap();
+        let n = a.cols;
+        assert_eq!((qr.q.rows, qr.q.cols), (a.rows, n));
+        assert_eq!((qr.r.rows, qr.r.cols), (n, n));
+        for i in 0..n {
+            assert!(qr.r[(i, i)] > 0.0);
+            for j in 0..i {
+                assert_eq!(qr.r[(i, j)], 0.0, "R must be upper triangular");
+            }
+        }
+        let qtq = (&qr.q.transpose() * &qr.q).unwrap();
+        assert_matrix_approx_eq(&qtq, &Matrix::identity(n));
+        assert_matrix_approx_eq(&(&qr.q * &qr.r).unwrap(), a);
+        qr
+    }
+
+    #[test]
+    fn test_qr_square_and_tall() {
+        let square: Matrix = "12 -51 4; 6 167 -68; -4 24 -41".parse().unwrap();
+        let qr = check_qr(&square);
+        assert!((qr.r[(0, 0)] - 14.0).abs() < EPSILON);
+        assert!((qr.r[(1, 1)] - 175.0).abs() < EPSILON);
+        assert!((qr.r[(2, 2)] - 35.0).abs() < EPSILON);
+
+        check_qr(&Matrix::random(8, 3, -1.0..1.0, 11));
+        check_qr(&Matrix::identity(4));
+    }
+
+    #[test]
+    fn test_qr_orthogonality_loss_grows_with_conditioning() {
+        // Hilbert matrices are notoriously ill-conditioned
+        let hilbert = |n: usize| Matrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let loss = |n: usize| {
+            let q = hilbert(n).qr().unwrap().q;
+            let qtq = (&q.transpose() * &q).unwrap();
+            (&qtq - &Matrix::identity(n)).unwrap().norm_frobenius()
+        };
+        assert!(loss(3) < 1e-12);
+        assert!(
+            loss(8) > loss(3),
+            "error accumulates as conditioning worsens"
+        );
+        assert!(loss(8) < 1e-2);
+    }
+
+    #[test]
+    fn test_qr_dependent_columns() {
+        let dependent: Matrix = "1 2; 2 4; 3 6".parse().unwrap();
+        assert!(matches!(dependent.qr(), Err(MatrixError::Singular)));
+        let wide: Matrix = "1 0 1; 0 1 1".parse().unwrap();
+        assert!(matches!(wide.qr(), Err(MatrixError::Singular)));
+        assert!(matches!(
+            Matrix::zeros(2, 2).qr(),
+            Err(MatrixError::Singular)
+        ));
+
+        let empty = Matrix::zeros(3, 0).qr().unwrap();
+        assert_eq!((empty.q.cols, empty.r.rows), (0, 0));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+/// Prints one bracketed row per line with right-aligned columns:
+///
+/// ```
+/// use rust_ds_learning::matrix::Matrix;
+///
+/// let m = Matrix::from_vec(2, 2, vec![1.5, -20.0, 3.0, 4.25]).unwrap();
+/// assert_eq!(m.to_string(), "[1.5  -20]\n[  3 4.25]");
+/// assert_eq!(format!("{m:.1}"), "[1.5 -20.0]\n[3.0   4.2]");
+/// ```
+///
+/// A precision in the format string (`{:.3}`) is applied to every element;
+/// [`Matrix::display_with_precision`] does the same without a format string.
+impl<T: Scalar> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_matrix(self, f.precision(), f)
+    }
+}
+
+/// Display adapter returned by [`Matrix::display_with_precision`]
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixDisplay<'a, T> {
+    matrix: &'a Matrix<T>,
+    precision: usize,
+}
+
+impl<T: Scalar> fmt::Display for MatrixDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_matrix(self.matrix, Some(self.precision), f)
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Formats every element with `precision` digits after the decimal
+    /// point (ignored for integer matrices).
+    ///
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_vec(1, 2, vec![1.0 / 3.0, 2.0]).unwrap();
+    /// assert_eq!(m.display_with_precision(3).to_string(), "[0.333 2.000]");
+    /// ```
+    pub fn display_with_precision(&self, precision: usize) -> MatrixDisplay<'_, T> {
+        MatrixDisplay {
+            matrix: self,
+            precision,
+        }
+    }
+}
+
+fn write_matrix<T: Scalar>(
+    matrix: &Matrix<T>,
+    precision: Option<usize>,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if matrix.rows == 0 {
+        return write!(f, "[]");
+    }
+
+    let cells: Vec<String> = matrix
+        .data
+        .iter()
+        .map(|value| match precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        })
+        .collect();
+    let widths: Vec<usize> = (0..matrix.cols)
+        .map(|col| {
+            (0..matrix.rows)
+                .map(|row| cells[row * matrix.cols + col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in 0..matrix.rows {
+        if row > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "[")?;
+        for (col, width) in widths.iter().enumerate() {
+            if col > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:>width$}", cells[row * matrix.cols + col])?;
+        }
+        write!(f, "]")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_aligns_columns() {
+        let m = Matrix::from_vec(3, 2, vec![1, 200, -30, 4, 5, 60]).unwrap();
+        assert_eq!(m.to_string(), "[  1 200]\n[-30   4]\n[  5  60]");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let m = Matrix::from_vec(2, 2, vec![0.5, 10.0, -1.0 / 3.0, 2.0]).unwrap();
+        assert_eq!(
+            m.display_with_precision(2).to_string(),
+            "[ 0.50 10.00]\n[-0.33  2.00]"
+        );
+        assert_eq!(format!("{m:.0}"), "[ 0 10]\n[-0  2]");
+        assert_eq!(
+            format!("{}", m.display_with_precision(1)),
+            format!("{m:.1}")
+        );
+    }
+
+    #[test]
+    fn test_display_integers_ignore_precision() {
+        let m = Matrix::from_vec(1, 2, vec![7_i64, -8]).unwrap();
+        assert_eq!(m.display_with_precision(3).to_string(), "[7 -8]");
+    }
+
+    #[test]
+    fn test_display_degenerate_shapes() {
+        assert_eq!(Matrix::<f64>::zeros(0, 0).to_string(), "[]");
+        assert_eq!(Matrix::<f64>::zeros(0, 3).to_string(), "[]");
+        assert_eq!(Matrix::<f64>::zeros(2, 0).to_string(), "[]\n[]");
+    }
+}
@@ -12,6 +12,8 @@ pub enum SearchError {
     ElementNotFound {
         el: f64,
     },
+    #[error("Matrix rows and columns must be sorted in ascending order")]
+    NotSorted,
     #[error(transparent)]
     Matrix(#[from] MatrixError),
 }
@@ -27,6 +29,13 @@ pub enum SearchError {
 /// [4.0, 5.0, 6.0]
 /// [7.0, 8.0, 9.0]
 /// ```
+///
+/// Staircase search: start at the top-right corner. A larger value there
+/// rules out the whole column (everything below is larger still), a smaller
+/// one rules out the whole row, so each step discards a row or a column.
+///
+/// Time: O(rows + cols) comparisons, versus O(rows * cols) for
+/// [`find_postition_sorted_square_matrix_naive`].
 pub fn find_postition_sorted_square_matrix(m: &Matrix, val: f64) -> Result<(usize, usize), SearchError> {
     if m.rows != m.cols {
         return Err(SearchError::NotSquareMatrix {
@@ -61,6 +70,210 @@ pub fn find_postition_sorted_square_matrix(m: &Matrix, val: f64) -> Result<(usiz
     Err(SearchError::ElementNotFound { el: val })
 }
 
+/// [`find_postition_sorted_square_matrix`] that first validates its input
+/// with [`Matrix::is_sorted_square`], returning `SearchError::NotSorted`
+/// instead of a meaningless answer for unsorted matrices.
+///
+/// The check is O(n²), which dominates the O(n) search: use it when the
+/// input is untrusted, and the unchecked search when sortedness is known.
+pub fn find_postition_sorted_square_matrix_checked(
+    m: &Matrix,
+    val: f64,
+) -> Result<(usize, usize), SearchError> {
+    if !m.is_square() {
+        return Err(SearchError::NotSquareMatrix {
+            rows: m.rows,
+            cols: m.cols,
+        });
+    }
+    if !m.is_sorted_square() {
+        return Err(SearchError::NotSorted);
+    }
+    find_postition_sorted_square_matrix(m, val)
+}
+
+/// Brute-force baseline for [`find_postition_sorted_square_matrix`]: scans
+/// every element in row-major order and ignores the sorted structure.
+///
+/// Time: O(rows * cols). Kept for comparison (see `benches/matrix.rs`).
+pub fn find_postition_sorted_square_matrix_naive(
+    m: &Matrix,
+    val: f64,
+) -> Result<(usize, usize), SearchError> {
+    if m.rows != m.cols {
+        return Err(SearchError::NotSquareMatrix {
+            rows: m.rows,
+            cols: m.cols,
+        });
+    }
+
+    m.indexed_iter()
+        .find(|&(_, _, &x)| x == val)
+        .map(|(i, j, _)| (i, j))
+        .ok_or(SearchError::ElementNotFound { el: val })
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TableauError {
+    #[error("Young tableau is full ({rows}x{cols})")]
+    Full { rows: usize, cols: usize },
+    #[error("Cannot insert {val} into a Young tableau, values must be finite")]
+    InvalidValue { val: f64 },
+}
+
+/// A `rows x cols` Young tableau with no elements: every cell holds
+/// `f64::INFINITY`, which marks it as empty.
+pub fn empty_tableau(rows: usize, cols: usize) -> Matrix {
+    Matrix::from_fn(rows, cols, |_, _| f64::INFINITY)
+}
+
+/// Checks the Young tableau invariant: every row and every column is sorted
+/// in ascending order. Unlike [`Matrix::is_sorted_square`] the matrix may be
+/// rectangular, and empty cells (`f64::INFINITY`) sort after every element.
+pub fn is_young_tableau(m: &Matrix) -> bool {
+    m.indexed_iter()
+        .all(|(i, j, &x)| (i == 0 || m[(i - 1, j)] <= x) && (j == 0 || m[(i, j - 1)] <= x))
+}
+
+/// # Young Tableau Insert
+///
+/// ## Problem Statement
+/// Insert `val` into a Young tableau (rows and columns sorted ascending,
+/// empty cells holding `f64::INFINITY`) so that it stays a Young tableau.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{empty_tableau, tableau_insert, is_young_tableau};
+///
+/// let mut t = empty_tableau(2, 2);
+/// for val in [5.0, 2.0, 8.0] {
+///     tableau_insert(&mut t, val).unwrap();
+/// }
+/// assert!(is_young_tableau(&t));
+/// assert_eq!(t[(0, 0)], 2.0);
+/// ```
+///
+/// ## Approach
+/// The bottom-right cell is the largest, so it is empty whenever the
+/// tableau has room. Put `val` there and sift it up: while the larger of
+/// its upper and left neighbours exceeds it, swap with that neighbour.
+/// This is a heap's sift-up with two parents per cell.
+///
+/// ## Complexity
+/// - Time: O(rows + cols), each swap moves one step up or left
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Swapping with the *larger* parent keeps the other parent's row or
+///    column sorted
+/// 2. Returns `TableauError::Full` when the bottom-right cell is occupied
+///
+/// ## Common Pitfalls
+/// 1. Swapping with the smaller parent, which breaks the invariant with
+///    the other one
+/// 2. Allowing `NaN` or infinite values: `NaN` compares false with
+///    everything and infinity is indistinguishable from an empty cell
+pub fn tableau_insert(m: &mut Matrix, val: f64) -> Result<(), TableauError> {
+    if !val.is_finite() {
+        return Err(TableauError::InvalidValue { val });
+    }
+    if m.data.last().is_none_or(|last| last.is_finite()) {
+        return Err(TableauError::Full {
+            rows: m.rows,
+            cols: m.cols,
+        });
+    }
+
+    let (mut i, mut j) = (m.rows - 1, m.cols - 1);
+    m[(i, j)] = val;
+    loop {
+        let up = (i > 0).then(|| (i - 1, j));
+        let left = (j > 0).then(|| (i, j - 1));
+        let largest = match (up, left) {
+            (Some(u), Some(l)) => {
+                if m[u] >= m[l] {
+                    u
+                } else {
+                    l
+                }
+            }
+            (Some(p), None) | (None, Some(p)) => p,
+            (None, None) => break,
+        };
+        if m[largest] <= val {
+            break;
+        }
+        m[(i, j)] = m[largest];
+        m[largest] = val;
+        (i, j) = largest;
+    }
+    Ok(())
+}
+
+/// # Young Tableau Extract-Min
+///
+/// ## Problem Statement
+/// Remove and return the smallest element of a Young tableau, leaving a
+/// valid Young tableau behind. Returns `None` if the tableau is empty.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{tableau_extract_min, Matrix};
+///
+/// let mut t = Matrix::from_vec(2, 2, vec![1.0, 3.0, 2.0, f64::INFINITY]).unwrap();
+/// assert_eq!(tableau_extract_min(&mut t), Some(1.0));
+/// assert_eq!(tableau_extract_min(&mut t), Some(2.0));
+/// assert_eq!(tableau_extract_min(&mut t), Some(3.0));
+/// assert_eq!(tableau_extract_min(&mut t), None);
+/// ```
+///
+/// ## Approach
+/// The minimum sits in the top-left cell. Replace it with an empty cell
+/// (`f64::INFINITY`) and sift that hole down: swap it with the smaller of
+/// its lower and right neighbours until both are empty or out of bounds.
+///
+/// ## Complexity
+/// - Time: O(rows + cols)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Extracting every element yields them in ascending order, giving an
+///    O(n³) sort of n² elements in an n x n tableau
+///
+/// ## Common Pitfalls
+/// 1. Swapping with the larger child, which puts it above a smaller value
+pub fn tableau_extract_min(m: &mut Matrix) -> Option<f64> {
+    let min = *m.data.first()?;
+    if min == f64::INFINITY {
+        return None;
+    }
+
+    let (mut i, mut j) = (0, 0);
+    m[(i, j)] = f64::INFINITY;
+    loop {
+        let down = (i + 1 < m.rows).then(|| (i + 1, j));
+        let right = (j + 1 < m.cols).then(|| (i, j + 1));
+        let smallest = match (down, right) {
+            (Some(d), Some(r)) => {
+                if m[d] <= m[r] {
+                    d
+                } else {
+                    r
+                }
+            }
+            (Some(c), None) | (None, Some(c)) => c,
+            (None, None) => break,
+        };
+        if m[smallest] == f64::INFINITY {
+            break;
+        }
+        m[(i, j)] = m[smallest];
+        m[smallest] = f64::INFINITY;
+        (i, j) = smallest;
+    }
+    Some(min)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -152,4 +365,148 @@ mod tests {
             Err(SearchError::ElementNotFound { el: 1.25 })
         ));
     }
+
+    #[test]
+    fn test_naive_agrees_with_staircase() {
+        let matrix = Matrix::from_fn(6, 6, |i, j| (i + 2 * j) as f64);
+        for val in (-1..20).map(f64::from) {
+            let fast = find_postition_sorted_square_matrix(&matrix, val);
+            let naive = find_postition_sorted_square_matrix_naive(&matrix, val);
+            match (fast, naive) {
+                // Duplicates may be found at different positions
+                (Ok(a), Ok(b)) => assert_eq!(matrix[a], matrix[b]),
+                (
+                    Err(SearchError::ElementNotFound { .. }),
+                    Err(SearchError::ElementNotFound { .. }),
+                ) => {}
+                other => panic!("searches disagree for {val}: {other:?}"),
+            }
+        }
+
+        let matrix = Matrix::zeros(2, 3);
+        assert!(matches!(
+            find_postition_sorted_square_matrix_naive(&matrix, 0.0),
+            Err(SearchError::NotSquareMatrix { rows: 2, cols: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_checked_search_rejects_unsorted_input() {
+        let sorted = Matrix::from_fn(4, 4, |i, j| (i + j) as f64);
+        assert_eq!(
+            find_postition_sorted_square_matrix_checked(&sorted, 0.0).unwrap(),
+            (0, 0)
+        );
+
+        // The unchecked search silently misses 1.0 here
+        let unsorted = Matrix::from_vec(2, 2, vec![5.0, 6.0, 1.0, 7.0]).unwrap();
+        assert!(find_postition_sorted_square_matrix(&unsorted, 1.0).is_err());
+        assert!(matches!(
+            find_postition_sorted_square_matrix_checked(&unsorted, 1.0),
+            Err(SearchError::NotSorted)
+        ));
+        assert!(matches!(
+            find_postition_sorted_square_matrix_checked(&Matrix::zeros(2, 3), 0.0),
+            Err(SearchError::NotSquareMatrix { rows: 2, cols: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_tableau_insert_keeps_invariant() {
+        let mut t = empty_tableau(4, 3);
+        assert!(is_young_tableau(&t));
+        for (n, val) in [9.0, 3.0, 7.0, 1.0, 8.0, 2.0, 6.0, 4.0, 5.0, 0.0, 3.0, -1.0]
+            .into_iter()
+            .enumerate()
+        {
+            tableau_insert(&mut t, val).unwrap();
+            assert!(
+                is_young_tableau(&t),
+                "invariant broken after insert #{n}: {t:?}"
+            );
+        }
+        assert_eq!(t[(0, 0)], -1.0);
+        assert_eq!(
+            tableau_insert(&mut t, 10.0),
+            Err(TableauError::Full { rows: 4, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_tableau_insert_rejects_invalid() {
+        let mut t = empty_tableau(2, 2);
+        assert_eq!(
+            tableau_insert(&mut t, f64::INFINITY),
+            Err(TableauError::InvalidValue { val: f64::INFINITY })
+        );
+        assert!(matches!(
+            tableau_insert(&mut t, f64::NAN),
+            Err(TableauError::InvalidValue { .. })
+        ));
+        assert_eq!(
+            tableau_insert(&mut Matrix::zeros(0, 0), 1.0),
+            Err(TableauError::Full { rows: 0, cols: 0 })
+        );
+    }
+
+    #[test]
+    fn test_tableau_extract_min_sorts() {
+        let values: Vec<f64> = (0..20).map(|k| ((k * 7) % 20) as f64 - 5.0).collect();
+        let mut t = empty_tableau(5, 4);
+        for &val in &values {
+            tableau_insert(&mut t, val).unwrap();
+        }
+
+        let mut extracted = Vec::new();
+        while let Some(min) = tableau_extract_min(&mut t) {
+            assert!(
+                is_young_tableau(&t),
+                "invariant broken after extracting {min}: {t:?}"
+            );
+            extracted.push(min);
+        }
+        let mut expected = values;
+        expected.sort_by(f64::total_cmp);
+        assert_eq!(extracted, expected);
+        assert_eq!(t, empty_tableau(5, 4));
+    }
+
+    #[test]
+    fn test_tableau_extract_min_edge_cases() {
+        assert_eq!(tableau_extract_min(&mut Matrix::zeros(0, 0)), None);
+        assert_eq!(tableau_extract_min(&mut empty_tableau(3, 3)), None);
+
+        // A full sorted square matrix is a Young tableau too
+        let mut full = Matrix::from_fn(3, 3, |i, j| (3 * i + j) as f64);
+        assert_eq!(tableau_extract_min(&mut full), Some(0.0));
+        assert!(is_young_tableau(&full));
+        assert_eq!(full[(2, 2)], f64::INFINITY);
+        tableau_insert(&mut full, 4.5).unwrap();
+        assert!(is_young_tableau(&full));
+
+        // Single row and single column degenerate to sorted lists
+        let mut row = empty_tableau(1, 4);
+        let mut col = empty_tableau(4, 1);
+        for val in [3.0, 1.0, 2.0] {
+            tableau_insert(&mut row, val).unwrap();
+            tableau_insert(&mut col, val).unwrap();
+        }
+        assert_eq!(row.data, vec![1.0, 2.0, 3.0, f64::INFINITY]);
+        assert_eq!(col.data, row.data);
+        assert_eq!(tableau_extract_min(&mut row), Some(1.0));
+        assert_eq!(row.data, vec![2.0, 3.0, f64::INFINITY, f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_is_young_tableau() {
+        assert!(is_young_tableau(
+            &Matrix::from_fn(2, 3, |i, j| (i + j) as f64)
+        ));
+        assert!(!is_young_tableau(
+            &Matrix::from_vec(2, 2, vec![1.0, 2.0, 0.5, 3.0]).unwrap()
+        ));
+        assert!(!is_young_tableau(
+            &Matrix::from_vec(1, 2, vec![f64::NAN, 1.0]).unwrap()
+        ));
+    }
 }
@@ -0,0 +1,280 @@
+//! Grid Algorithm Exercises
+//!
+//! Interview problems over a grid stored in a [`Matrix`]. The flat
+//! row-major storage means a cell `(row, col)` lives at
+//! `row * cols + col`, so visited sets are a plain `Vec<bool>` of the same
+//! length instead of a hash set of coordinates.
+
+use std::collections::VecDeque;
+
+use super::matrix::{Matrix, MatrixError};
+
+/// The up-to-four in-bounds orthogonal neighbours of `(row, col)`
+fn neighbours(
+    rows: usize,
+    cols: usize,
+    row: usize,
+    col: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    let up = row.checked_sub(1).map(|r| (r, col));
+    let down = (row + 1 < rows).then_some((row + 1, col));
+    let left = col.checked_sub(1).map(|c| (row, c));
+    let right = (col + 1 < cols).then_some((row, col + 1));
+    [up, down, left, right].into_iter().flatten()
+}
+
+/// # Number of Islands
+///
+/// ## Problem Statement
+/// Given a grid where non-zero cells are land and zero cells are water,
+/// count the islands: maximal groups of land cells connected horizontally
+/// or vertically (diagonal contact does not join islands).
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{grid_exercises::count_islands, Matrix};
+///
+/// let grid: Matrix<i32> = "1 1 0 0; 1 0 0 1; 0 0 1 1; 1 0 0 0".parse().unwrap();
+/// assert_eq!(count_islands(&grid), 3);
+/// ```
+///
+/// ## Approach
+/// Scan every cell; each unvisited land cell starts a new island, which is
+/// then explored with a breadth-first search that marks all of its cells
+/// visited so they are not counted again.
+///
+/// ## Complexity
+/// - Time: O(rows · cols), every cell is enqueued at most once
+/// - Space: O(rows · cols) for the visited flags and the queue
+///
+/// ## Key Points
+/// 1. Mark cells visited when they are enqueued, not when dequeued
+/// 2. A separate visited buffer leaves the input untouched
+///
+/// ## Common Pitfalls
+/// 1. Counting diagonal neighbours as connected
+/// 2. Underflowing `row - 1` / `col - 1` on the top and left edges
+/// 3. Recursive DFS overflowing the stack on large all-land grids
+pub fn count_islands(grid: &Matrix<i32>) -> usize {
+    let (rows, cols) = (grid.rows, grid.cols);
+    let mut visited = vec![false; grid.data.len()];
+    let mut queue = VecDeque::new();
+    let mut islands = 0;
+
+    for start in 0..grid.data.len() {
+        if grid.data[start] == 0 || visited[start] {
+            continue;
+        }
+        islands += 1;
+        visited[start] = true;
+        queue.push_back((start / cols, start % cols));
+
+        while let Some((row, col)) = queue.pop_front() {
+            for (r, c) in neighbours(rows, cols, row, col) {
+                let idx = r * cols + c;
+                if grid.data[idx] != 0 && !visited[idx] {
+                    visited[idx] = true;
+                    queue.push_back((r, c));
+                }
+            }
+        }
+    }
+
+    islands
+}
+
+/// # Minimum Path Sum
+///
+/// ## Problem Statement
+/// Given a grid of costs, find the cheapest path from the top-left to the
+/// bottom-right cell moving only right or down. The cost of a path is the
+/// sum of every cell on it, both endpoints included.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{grid_exercises::min_path_sum, Matrix};
+///
+/// let grid: Matrix<i32> = "1 3 1; 1 5 1; 4 2 1".parse().unwrap();
+/// assert_eq!(min_path_sum(&grid), Some(7)); // 1 → 3 → 1 → 1 → 1
+/// ```
+///
+/// ## Approach
+/// Dynamic programming: the best cost to reach `(r, c)` is its own cost
+/// plus the cheaper of the cells above and to the left. Processing rows
+/// top to bottom, only the previous row's costs are needed, so a single
+/// row buffer updated in place suffices.
+///
+/// ## Complexity
+/// - Time: O(rows · cols)
+/// - Space: O(cols)
+///
+/// ## Key Points
+/// 1. The first row and first column each have only one predecessor
+/// 2. Sums are accumulated in `i64` so long paths of large `i32` costs
+///    cannot overflow
+///
+/// ## Common Pitfalls
+/// 1. Greedily taking the cheaper next step, which misses cheaper paths
+///    behind an expensive cell
+/// 2. Forgetting to include the starting cell's cost
+///
+/// Returns `None` for an empty grid, which has no start cell.
+pub fn min_path_sum(grid: &Matrix<i32>) -> Option<i64> {
+    if grid.data.is_empty() {
+        return None;
+    }
+
+    let mut best = vec![0i64; grid.cols];
+    for (row, values) in grid.data.chunks(grid.cols).enumerate() {
+        for (col, &cost) in values.iter().enumerate() {
+            let from_above = (row > 0).then(|| best[col]);
+            let from_left = (col > 0).then(|| best[col - 1]);
+            let previous = match (from_above, from_left) {
+                (Some(a), Some(b)) => a.min(b),
+                (Some(a), None) | (None, Some(a)) => a,
+                (None, None) => 0,
+            };
+            best[col] = previous + i64::from(cost);
+        }
+    }
+
+    best.last().copied()
+}
+
+/// # Flood Fill
+///
+/// ## Problem Statement
+/// Starting from `(row, col)`, repaint the connected region of cells that
+/// share the start cell's colour with `new_color`, like the paint bucket
+/// tool in an image editor. Cells connect horizontally and vertically.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{grid_exercises::flood_fill, Matrix};
+///
+/// let mut image: Matrix<i32> = "1 1 1; 1 1 0; 1 0 1".parse().unwrap();
+/// let painted = flood_fill(&mut image, 1, 1, 2).unwrap();
+/// assert_eq!(painted, 6);
+/// assert_eq!(image, "2 2 2; 2 2 0; 2 0 1".parse().unwrap());
+/// ```
+///
+/// ## Approach
+/// Depth-first search with an explicit stack. Repainting a cell doubles as
+/// marking it visited, so no extra buffer is needed.
+///
+/// ## Complexity
+/// - Time: O(rows · cols)
+/// - Space: O(rows · cols) for the stack in the worst case
+///
+/// ## Key Points
+/// 1. Returns the number of cells repainted
+/// 2. Paint cells as they are pushed so each is pushed at most once
+///
+/// ## Common Pitfalls
+/// 1. Looping forever when `new_color` equals the original colour, since
+///    repainted cells still match; this is handled as a no-op
+/// 2. Recursing instead of using a stack, which overflows on large regions
+///
+/// Returns `MatrixError::IndexOutOfBounds` if the start cell is outside
+/// the grid.
+pub fn flood_fill(
+    grid: &mut Matrix<i32>,
+    row: usize,
+    col: usize,
+    new_color: i32,
+) -> Result<usize, MatrixError> {
+    let original = grid.get(row, col)?;
+    if original == new_color {
+        return Ok(0);
+    }
+
+    let (rows, cols) = (grid.rows, grid.cols);
+    grid[(row, col)] = new_color;
+    let mut stack = vec![(row, col)];
+    let mut painted = 1;
+
+    while let Some((r, c)) = stack.pop() {
+        for (nr, nc) in neighbours(rows, cols, r, c) {
+            if grid[(nr, nc)] == original {
+                grid[(nr, nc)] = new_color;
+                painted += 1;
+                stack.push((nr, nc));
+            }
+        }
+    }
+
+    Ok(painted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Matrix<i32> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_count_islands() {
+        assert_eq!(
+            count_islands(&grid("1 1 0 0 0; 1 1 0 0 0; 0 0 1 0 0; 0 0 0 1 1")),
+            3
+        );
+        assert_eq!(count_islands(&grid("1 1 1; 1 1 1")), 1);
+        assert_eq!(count_islands(&grid("0 0; 0 0")), 0);
+        // Diagonal contact does not connect
+        assert_eq!(count_islands(&grid("1 0 1; 0 1 0; 1 0 1")), 5);
+        // A ring around a lake is a single island
+        assert_eq!(count_islands(&grid("1 1 1; 1 0 1; 1 1 1")), 1);
+        assert_eq!(count_islands(&Matrix::zeros(0, 0)), 0);
+        assert_eq!(count_islands(&grid("1 0 1 0 1")), 3);
+    }
+
+    #[test]
+    fn test_count_islands_large_single_island() {
+        let land = Matrix::from_fn(300, 300, |_, _| 1);
+        assert_eq!(count_islands(&land), 1);
+    }
+
+    #[test]
+    fn test_min_path_sum() {
+        assert_eq!(min_path_sum(&grid("1 3 1; 1 5 1; 4 2 1")), Some(7));
+        assert_eq!(min_path_sum(&grid("1 2 3; 4 5 6")), Some(12));
+        assert_eq!(min_path_sum(&grid("5")), Some(5));
+        assert_eq!(min_path_sum(&grid("1 2 3 4")), Some(10));
+        assert_eq!(min_path_sum(&grid("1; 2; 3")), Some(6));
+        assert_eq!(min_path_sum(&Matrix::zeros(0, 0)), None);
+        // The greedy choice (right to 1) leads into a wall of 100s
+        assert_eq!(min_path_sum(&grid("0 1 100; 2 100 100; 3 4 5")), Some(14));
+    }
+
+    #[test]
+    fn test_min_path_sum_does_not_overflow() {
+        let expensive = Matrix::from_fn(3, 3, |_, _| i32::MAX);
+        assert_eq!(min_path_sum(&expensive), Some(5 * i64::from(i32::MAX)));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let mut image = grid("1 1 1; 1 1 0; 1 0 1");
+        assert_eq!(flood_fill(&mut image, 1, 1, 2).unwrap(), 6);
+        assert_eq!(image, grid("2 2 2; 2 2 0; 2 0 1"));
+
+        // Only the connected region changes, not every cell of that colour
+        let mut image = grid("0 1 0; 1 1 1; 0 1 0");
+        assert_eq!(flood_fill(&mut image, 0, 0, 7).unwrap(), 1);
+        assert_eq!(image, grid("7 1 0; 1 1 1; 0 1 0"));
+    }
+
+    #[test]
+    fn test_flood_fill_same_color_and_bounds() {
+        let mut image = grid("1 1; 1 1");
+        assert_eq!(flood_fill(&mut image, 0, 0, 1).unwrap(), 0);
+        assert_eq!(image, grid("1 1; 1 1"));
+
+        assert!(matches!(
+            flood_fill(&mut image, 2, 0, 5),
+            Err(MatrixError::IndexOutOfBounds { row: 2, col: 0, .. })
+        ));
+    }
+}
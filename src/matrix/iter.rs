@@ -0,0 +1,218 @@
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+/// Iterator APIs, so exercises can use combinators instead of index loops.
+///
+/// `rows()` and `cols()` are the dimension accessors, so the iterators over
+/// whole rows and columns are named `iter_rows()` and `iter_cols()`.
+impl<T: Scalar> Matrix<T> {
+    /// All elements in row-major order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Elements of row `row`, left to right. Panics if `row` is out of bounds.
+    pub fn row_iter(&self, row: usize) -> std::slice::Iter<'_, T> {
+        self.row_slice(row).iter()
+    }
+
+    pub fn row_iter_mut(&mut self, row: usize) -> std::slice::IterMut<'_, T> {
+        let range = self.row_range(row);
+        self.data[range].iter_mut()
+    }
+
+    /// Elements of column `col`, top to bottom. Panics if `col` is out of bounds.
+    pub fn col_iter(
+        &self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.check_col(col);
+        self.data.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn col_iter_mut(
+        &mut self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator + '_ {
+        self.check_col(col);
+        let cols = self.cols;
+        self.data.iter_mut().skip(col).step_by(cols)
+    }
+
+    /// Each row as a slice
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.rows).map(move |row| self.row_slice(row))
+    }
+
+    /// Each row as a mutable slice
+    pub fn iter_rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let (rows, cols) = (self.rows, self.cols);
+        // `chunks_mut(0)` panics, and a matrix with zero columns still has rows
+        let mut rest = self.data.as_mut_slice();
+        (0..rows).map(move |_| {
+            let (row, tail) = std::mem::take(&mut rest).split_at_mut(cols);
+            rest = tail;
+            row
+        })
+    }
+
+    /// Each column as an iterator over its elements
+    pub fn iter_cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_>
+           + ExactSizeIterator
+           + '_ {
+        (0..self.cols).map(move |col| self.col_iter(col))
+    }
+
+    /// `(row, col, &value)` for every element in row-major order
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(k, value)| (k / cols, k % cols, value))
+    }
+
+    /// `(row, col, &mut value)` for every element in row-major order
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> + '_ {
+        let cols = self.cols;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(k, value)| (k / cols, k % cols, value))
+    }
+
+    fn row_range(&self, row: usize) -> std::ops::Range<usize> {
+        assert!(
+            row < self.rows,
+            "row {row} out of bounds for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        row * self.cols..(row + 1) * self.cols
+    }
+
+    fn row_slice(&self, row: usize) -> &[T] {
+        &self.data[self.row_range(row)]
+    }
+
+    fn check_col(&self, col: usize) {
+        assert!(
+            col < self.cols,
+            "column {col} out of bounds for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Matrix<i32> {
+        Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    #[test]
+    fn test_element_iterators() {
+        let mut m = sample();
+        assert_eq!(m.iter().sum::<i32>(), 21);
+        for value in &mut m {
+            *value *= 2;
+        }
+        assert_eq!(m.data, vec![2, 4, 6, 8, 10, 12]);
+        assert_eq!((&m).into_iter().max(), Some(&12));
+    }
+
+    #[test]
+    fn test_row_and_col_iterators() {
+        let mut m = sample();
+        assert_eq!(m.row_iter(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(m.col_iter(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(m.col_iter(2).rev().copied().collect::<Vec<_>>(), vec![6, 3]);
+        assert_eq!(m.col_iter(0).len(), 2);
+
+        m.row_iter_mut(0).for_each(|x| *x = 0);
+        m.col_iter_mut(2).for_each(|x| *x = -1);
+        assert_eq!(m.data, vec![0, 0, -1, 4, 5, -1]);
+    }
+
+    #[test]
+    fn test_whole_row_and_col_iterators() {
+        let mut m = sample();
+        let rows: Vec<&[i32]> = m.iter_rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        let col_sums: Vec<i32> = m.iter_cols().map(|col| col.sum()).collect();
+        assert_eq!(col_sums, vec![5, 7, 9]);
+
+        for row in m.iter_rows_mut() {
+            row.reverse();
+        }
+        assert_eq!(m.data, vec![3, 2, 1, 6, 5, 4]);
+    }
+
+    #[test]
+    fn test_indexed_iterators() {
+        let mut m = sample();
+        let positions: Vec<(usize, usize, i32)> =
+            m.indexed_iter().map(|(i, j, &v)| (i, j, v)).collect();
+        assert_eq!(positions[0], (0, 0, 1));
+        assert_eq!(positions[4], (1, 1, 5));
+        assert!(m.indexed_iter().all(|(i, j, &v)| m[(i, j)] == v));
+
+        for (i, j, value) in m.indexed_iter_mut() {
+            *value = (10 * i + j) as i32;
+        }
+        assert_eq!(m.data, vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_degenerate_shapes() {
+        let mut no_cols = Matrix::<f64>::zeros(3, 0);
+        assert_eq!(no_cols.iter_rows().count(), 3);
+        assert!(no_cols.iter_rows().all(|row| row.is_empty()));
+        assert_eq!(no_cols.iter_rows_mut().count(), 3);
+        assert_eq!(no_cols.iter_cols().count(), 0);
+
+        let no_rows = Matrix::<f64>::zeros(0, 2);
+        assert_eq!(no_rows.iter_cols().count(), 2);
+        assert!(no_rows.iter_cols().all(|mut col| col.next().is_none()));
+        assert_eq!(no_rows.indexed_iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of bounds for 2x3 matrix")]
+    fn test_col_iter_out_of_bounds() {
+        let _ = sample().col_iter(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 out of bounds")]
+    fn test_row_iter_out_of_bounds() {
+        let _ = sample().row_iter(2);
+    }
+}
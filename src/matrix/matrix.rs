@@ -1,5 +1,12 @@
+use std::ops::Range;
+
+use rand::distributions::uniform::SampleUniform;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use thiserror::Error;
 
+use super::scalar::Scalar;
+
 #[derive(Error, Debug)]
 pub enum MatrixError {
     #[error("Invalid dimensions: expected {expected} elements, got {actual}")]
@@ -23,26 +30,39 @@ pub enum MatrixError {
     #[error("Element ({el}) not found")]
     ElementNotFound {
         el: f64,
-    }
+    },
+    #[error("Matrix must be square, got dimensions {rows}x{cols}")]
+    NotSquare { rows: usize, cols: usize },
+    #[error("Matrix is singular")]
+    Singular,
+    #[error("Invalid stride {stride}: must be at least 1")]
+    InvalidStride { stride: usize },
+    #[error("Ragged rows: row {row} has {actual} elements, expected {expected}")]
+    RaggedRows {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
 }
 
+/// Row-major dense matrix over a [`Scalar`] element type (`f64` by default)
 #[derive(Debug, Clone, PartialEq)]
-pub struct Matrix {
+pub struct Matrix<T = f64> {
     pub rows: usize,
     pub cols: usize,
-    pub data: Vec<f64>,
+    pub data: Vec<T>,
 }
 
-impl Matrix {
+impl<T: Scalar> Matrix<T> {
     pub fn zeros(rows: usize, cols: usize) -> Self {
         Self {
             rows,
             cols,
-            data: vec![0.0; rows * cols],
+            data: vec![T::ZERO; rows * cols],
         }
     }
 
-    pub fn from_vec(rows: usize, cols: usize, data: Vec<f64>) -> Result<Self, MatrixError> {
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, MatrixError> {
         let expected = rows * cols;
         if data.len() != expected {
             return Err(MatrixError::InvalidCreation {
@@ -53,6 +73,56 @@ impl Matrix {
         Ok(Self { rows, cols, data })
     }
 
+    /// The `n x n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::ONE } else { T::ZERO })
+    }
+
+    /// Builds a matrix from a slice of rows, which must all have the same
+    /// length. An empty slice gives a `0 x 0` matrix.
+    pub fn from_rows(rows: &[Vec<T>]) -> Result<Self, MatrixError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for (row, values) in rows.iter().enumerate() {
+            if values.len() != cols {
+                return Err(MatrixError::RaggedRows {
+                    row,
+                    expected: cols,
+                    actual: values.len(),
+                });
+            }
+            data.extend_from_slice(values);
+        }
+        Ok(Self {
+            rows: rows.len(),
+            cols,
+            data,
+        })
+    }
+
+    /// Builds a matrix whose element `(i, j)` is `f(i, j)`
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Self { rows, cols, data }
+    }
+
+    /// Uniformly random elements from `range`; the same `seed` always gives
+    /// the same matrix, which keeps benchmarks and tests reproducible.
+    ///
+    /// Panics if `range` is empty.
+    pub fn random(rows: usize, cols: usize, range: Range<T>, seed: u64) -> Self
+    where
+        T: SampleUniform,
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self::from_fn(rows, cols, |_, _| rng.gen_range(range.clone()))
+    }
+
     #[inline]
     pub fn rows(&self) -> usize {
         self.rows
@@ -63,7 +133,7 @@ impl Matrix {
         self.cols
     }
 
-    pub fn get(&self, row: usize, col: usize) -> Result<f64, MatrixError> {
+    pub fn get(&self, row: usize, col: usize) -> Result<T, MatrixError> {
         if row >= self.rows || col >= self.cols {
             return Err(MatrixError::IndexOutOfBounds {
                 row,
@@ -75,7 +145,7 @@ impl Matrix {
         Ok(self.data[row * self.cols + col])
     }
 
-    pub fn set(&mut self, row: usize, col: usize, value: f64) -> Result<(), MatrixError> {
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<(), MatrixError> {
         if row >= self.rows || col >= self.cols {
             return Err(MatrixError::IndexOutOfBounds {
                 row,
@@ -98,7 +168,7 @@ impl Matrix {
         result
     }
 
-    pub fn find_position(&self, val: f64) -> Result<(usize, usize), MatrixError> {
+    pub fn find_position(&self, val: T) -> Result<(usize, usize), MatrixError> {
         for i in 0..self.rows {
             for j in 0..self.cols {
                 if self.data[i*self.cols + j] == val {
@@ -106,17 +176,63 @@ impl Matrix {
                 }
             }
         }
-        return Err(MatrixError::ElementNotFound { 
-            el: val
-        });
+        Err(MatrixError::ElementNotFound { 
+            el: val.to_f64()
+        })
+    }
+
+    /// Like [`Matrix::find_position`], but treats any element within
+    /// `epsilon` of `val` as a match, so `0.1 + 0.2` is found as `0.3`.
+    pub fn find_position_approx(
+        &self,
+        val: T,
+        epsilon: f64,
+    ) -> Result<(usize, usize), MatrixError> {
+        self.find_position_by(val, Comparison::Approx { epsilon })
+    }
+
+    /// First position (row-major) whose element matches `val` under `comparison`
+    pub fn find_position_by(
+        &self,
+        val: T,
+        comparison: Comparison,
+    ) -> Result<(usize, usize), MatrixError> {
+        self.data
+            .iter()
+            .position(|&x| comparison.matches(x, val))
+            .map(|k| (k / self.cols, k % self.cols))
+            .ok_or(MatrixError::ElementNotFound { el: val.to_f64() })
     }
+}
 
+/// How searches compare matrix elements with the value they look for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    /// `==`, right for integers and for floats that were never computed
+    Exact,
+    /// `|a - b| <= epsilon`. An absolute tolerance: pick `epsilon` relative
+    /// to the magnitude of the data, since `1e-9` is meaningless next to `1e12`
+    Approx { epsilon: f64 },
 }
 
-impl std::ops::Add for &Matrix {
-    type Output = Result<Matrix, MatrixError>;
+impl Comparison {
+    pub fn matches<T: Scalar>(self, a: T, b: T) -> bool {
+        match self {
+            Comparison::Exact => a == b,
+            Comparison::Approx { epsilon } => approx_eq(a.to_f64(), b.to_f64(), epsilon),
+        }
+    }
+}
 
-    fn add(self, rhs: &Matrix) -> Self::Output {
+/// `|a - b| <= epsilon`; NaN never compares equal, even to itself
+pub fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    (a - b).abs() <= epsilon
+}
+
+impl<T: Scalar> std::ops::Add for &Matrix<T> {
+    type Output = Result<Matrix<T>, MatrixError>;
+
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
         if self.rows != rhs.rows || self.cols != rhs.cols {
             return Err(MatrixError::DimensionMismatch {
                 operation: "addition",
@@ -133,10 +249,42 @@ impl std::ops::Add for &Matrix {
     }
 }
 
-impl std::ops::Mul for &Matrix {
-    type Output = Result<Matrix, MatrixError>;
+impl<T: Scalar> std::ops::Sub for &Matrix<T> {
+    type Output = Result<Matrix<T>, MatrixError>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "subtraction",
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.data.len() {
+            result.data[i] = self.data[i] - rhs.data[i];
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Scalar> std::ops::Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&x| -x).collect(),
+        }
+    }
+}
+
+impl<T: Scalar> std::ops::Mul for &Matrix<T> {
+    type Output = Result<Matrix<T>, MatrixError>;
 
-    fn mul(self, rhs: &Matrix) -> Self::Output {
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
         if self.cols != rhs.rows {
             return Err(MatrixError::DimensionMismatch {
                 operation: "multiplication",
@@ -148,7 +296,7 @@ impl std::ops::Mul for &Matrix {
         let mut result = Matrix::zeros(self.rows, rhs.cols);
         for i in 0..self.rows {
             for j in 0..rhs.cols {
-                let mut sum = 0.0;
+                let mut sum = T::ZERO;
                 for k in 0..self.cols {
                     sum += self.data[i * self.cols + k] * rhs.data[k * rhs.cols + j];
                 }
@@ -159,6 +307,89 @@ impl std::ops::Mul for &Matrix {
     }
 }
 
+/// Element-wise `matrix op scalar`, e.g. `&m * 2.0` or `&m - 1`.
+/// Unlike the matrix-matrix operators these cannot fail, so they return the
+/// matrix directly. Integer division by zero panics, as it does for scalars.
+macro_rules! impl_scalar_op {
+    ($($trait:ident :: $method:ident => $op:tt;)*) => {
+        $(
+            impl<T: Scalar> std::ops::$trait<T> for &Matrix<T> {
+                type Output = Matrix<T>;
+
+                fn $method(self, rhs: T) -> Matrix<T> {
+                    Matrix {
+                        rows: self.rows,
+                        cols: self.cols,
+                        data: self.data.iter().map(|&x| x $op rhs).collect(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_op! {
+    Add::add => +;
+    Sub::sub => -;
+    Mul::mul => *;
+    Div::div => /;
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Element-wise (Hadamard) product: `result[(i, j)] = self[(i, j)] * rhs[(i, j)]`.
+    /// Not to be confused with `*`, which is the matrix product.
+    pub fn hadamard(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "hadamard product",
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.data.len() {
+            result.data[i] = self.data[i] * rhs.data[i];
+        }
+        Ok(result)
+    }
+
+    /// Multiplies every element by `factor` in place, without allocating
+    pub fn scale_mut(&mut self, factor: T) {
+        for x in &mut self.data {
+            *x = *x * factor;
+        }
+    }
+}
+
+/// Panicking `matrix[(row, col)]` access, mirroring slice indexing.
+/// Use [`Matrix::get`] / [`Matrix::set`] when out-of-bounds is a recoverable error.
+impl<T: Scalar> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Scalar> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +455,56 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_sub() {
+        let m1 = Matrix::from_vec(2, 2, vec![5.0, 6.0, 7.0, 8.0]).unwrap();
+        let m2 = Matrix::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let difference = (&m1 - &m2).unwrap();
+        assert!(vec_approx_eq(&difference.data, &[4.0, 4.0, 4.0, 4.0]));
+
+        let m3 = Matrix::zeros(2, 3);
+        assert!(matches!(
+            &m1 - &m3,
+            Err(MatrixError::DimensionMismatch {
+                operation: "subtraction",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_neg() {
+        let m = Matrix::from_vec(2, 2, vec![1.0, -2.0, 0.0, 4.0]).unwrap();
+        let negated = -&m;
+        assert!(vec_approx_eq(&negated.data, &[-1.0, 2.0, 0.0, -4.0]));
+        assert_eq!(-&negated, m);
+        assert!((&m + &negated)
+            .unwrap()
+            .data
+            .iter()
+            .all(|&x| approx_eq(x, 0.0, EPSILON)));
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let m1 = Matrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let m2 = Matrix::from_vec(2, 3, vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0]).unwrap();
+        let product = m1.hadamard(&m2).unwrap();
+        assert!(vec_approx_eq(
+            &product.data,
+            &[7.0, 16.0, 27.0, 40.0, 55.0, 72.0]
+        ));
+
+        let m3 = Matrix::zeros(3, 2);
+        assert!(matches!(
+            m1.hadamard(&m3),
+            Err(MatrixError::DimensionMismatch {
+                operation: "hadamard product",
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_mul() {
         let m1 = Matrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
@@ -293,13 +574,196 @@ mod tests {
             0.5,                 0.6
         ]).unwrap();
 
-        // 0.1 + 0.2 is not exactly equal to 0.3 in floating point arithmetic
-        // This test will fail with direct comparison
-        // You might want to modify find_position to use approx_eq if this is important
-        // for your use case
+        // 0.1 + 0.2 is not exactly equal to 0.3 in floating point arithmetic,
+        // so the exact search misses it; find_position_approx is the fix
         assert!(matches!(
             matrix.find_position(0.3),
             Err(MatrixError::ElementNotFound { el: 0.3 })
         ));
+        assert_eq!(matrix.find_position_approx(0.3, EPSILON).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_find_position_approx() {
+        let matrix = Matrix::from_vec(2, 2, vec![1.0, 2.0, 3.0 + 1e-12, 4.0]).unwrap();
+        assert_eq!(matrix.find_position_approx(3.0, 1e-9).unwrap(), (1, 0));
+        // Too tight a tolerance behaves like the exact search
+        assert!(matrix.find_position_approx(3.0, 0.0).is_err());
+        // Too loose a tolerance finds the first element that is close enough
+        assert_eq!(matrix.find_position_approx(2.9, 1.5).unwrap(), (0, 1));
+        assert!(matches!(
+            matrix.find_position_approx(10.0, 1e-9),
+            Err(MatrixError::ElementNotFound { el: 10.0 })
+        ));
+        assert!(Matrix::zeros(0, 0).find_position_approx(0.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_find_position_by() {
+        let ints = Matrix::from_vec(1, 3, vec![10, 20, 30]).unwrap();
+        assert_eq!(
+            ints.find_position_by(20, Comparison::Exact).unwrap(),
+            (0, 1)
+        );
+        assert_eq!(
+            ints.find_position_by(29, Comparison::Approx { epsilon: 1.0 })
+                .unwrap(),
+            (0, 2)
+        );
+
+        assert!(Comparison::Approx { epsilon: 1e-9 }.matches(0.1 + 0.2, 0.3));
+        assert!(!Comparison::Exact.matches(0.1 + 0.2, 0.3));
+        assert!(!Comparison::Approx {
+            epsilon: f64::INFINITY
+        }
+        .matches(f64::NAN, f64::NAN));
+    }
+
+    #[test]
+    fn test_generic_i32() {
+        let mut m: Matrix<i32> = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.get(1, 2).unwrap(), 6);
+        m.set(0, 0, -1).unwrap();
+        assert_eq!(m.transpose().data, vec![-1, 4, 2, 5, 3, 6]);
+
+        let other = Matrix::from_vec(2, 3, vec![1; 6]).unwrap();
+        assert_eq!((&m + &other).unwrap().data, vec![0, 3, 4, 5, 6, 7]);
+        let product = (&m * &m.transpose()).unwrap();
+        assert_eq!(product.data, vec![14, 24, 24, 77]);
+        assert_eq!(m.find_position(5).unwrap(), (1, 1));
+        assert!(matches!(
+            m.find_position(9),
+            Err(MatrixError::ElementNotFound { el }) if el == 9.0
+        ));
+    }
+
+    #[test]
+    fn test_generic_i64() {
+        let big = 3_000_000_000_i64;
+        let m: Matrix<i64> = Matrix::from_vec(2, 2, vec![big, 0, 0, 2]).unwrap();
+        let zeros = Matrix::<i64>::zeros(2, 2);
+        assert_eq!((&m + &zeros).unwrap(), m);
+        let squared = (&m * &m).unwrap();
+        assert_eq!(squared.get(0, 0).unwrap(), big * big);
+        assert_eq!(squared.get(1, 1).unwrap(), 4);
+        assert!(matches!(
+            &m * &Matrix::<i64>::zeros(3, 1),
+            Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_generic_f64_is_default() {
+        // `Matrix` without a type argument is `Matrix<f64>`
+        let m: Matrix = Matrix::from_vec(1, 2, vec![0.5, 1.5]).unwrap();
+        let product: Matrix<f64> = (&m * &m.transpose()).unwrap();
+        assert!(approx_eq(product.get(0, 0).unwrap(), 2.5, EPSILON));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut m = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 0)], 4);
+        m[(1, 0)] = 40;
+        m[(0, 0)] += 10;
+        assert_eq!(m.data, vec![11, 2, 3, 40, 5, 6]);
+        assert_eq!(m[(1, 0)], m.get(1, 0).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 3) out of bounds for 2x3 matrix")]
+    fn test_index_column_out_of_bounds() {
+        // (0, 3) maps to flat index 3, which exists; the column check must still fire
+        let m = Matrix::<i32>::zeros(2, 3);
+        let _ = m[(0, 3)];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_mut_out_of_bounds() {
+        let mut m = Matrix::<f64>::zeros(2, 2);
+        m[(2, 0)] = 1.0;
+    }
+
+    #[test]
+    fn test_identity() {
+        let id = Matrix::<i32>::identity(3);
+        assert_eq!(id.data, vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+        let m = Matrix::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!((&id * &m).unwrap(), m);
+        assert_eq!(Matrix::<f64>::identity(0).data, Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let m = Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        assert_eq!((m.rows(), m.cols()), (3, 2));
+        assert_eq!(m.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let empty = Matrix::<f64>::from_rows(&[]).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+
+        assert!(matches!(
+            Matrix::from_rows(&[vec![1, 2], vec![3, 4], vec![5]]),
+            Err(MatrixError::RaggedRows {
+                row: 2,
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let m = Matrix::from_fn(2, 3, |i, j| (10 * i + j) as i64);
+        assert_eq!(m.data, vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_random_is_seeded_and_in_range() {
+        let a = Matrix::random(4, 5, -1.0..1.0, 7);
+        let b = Matrix::random(4, 5, -1.0..1.0, 7);
+        assert_eq!(a, b);
+        assert_ne!(a, Matrix::random(4, 5, -1.0..1.0, 8));
+        assert!(a.data.iter().all(|x| (-1.0..1.0).contains(x)));
+
+        let ints = Matrix::random(10, 10, 0..3, 1);
+        assert!(ints.data.iter().all(|&x| (0..3).contains(&x)));
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let m = Matrix::from_vec(2, 2, vec![1.0, -2.0, 3.0, 4.0]).unwrap();
+        assert!(vec_approx_eq(&(&m * 2.0).data, &[2.0, -4.0, 6.0, 8.0]));
+        assert!(vec_approx_eq(&(&m / 4.0).data, &[0.25, -0.5, 0.75, 1.0]));
+        assert!(vec_approx_eq(&(&m + 1.0).data, &[2.0, -1.0, 4.0, 5.0]));
+        assert!(vec_approx_eq(&(&m - 1.0).data, &[0.0, -3.0, 2.0, 3.0]));
+
+        let ints = Matrix::from_vec(1, 3, vec![7, -7, 9]).unwrap();
+        assert_eq!(
+            (&ints / 2).data,
+            vec![3, -3, 4],
+            "integer division truncates"
+        );
+        assert_eq!((&ints * 3).data, vec![21, -21, 27]);
+    }
+
+    #[test]
+    fn test_scale_mut() {
+        let mut m = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let expected = &m * -3;
+        m.scale_mut(-3);
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_integer_scalar_division_by_zero_panics() {
+        let m = Matrix::from_vec(1, 1, vec![1]).unwrap();
+        let _ = &m / 0;
     }
 }
@@ -1,4 +1,118 @@
+#[allow(clippy::module_inception)]
 mod matrix;
+mod algebra;
+mod builder;
+mod convert;
+mod convolve;
+mod decomposition;
+mod display;
 mod exercises;
+pub mod grid_exercises;
+mod iter;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod parse;
+mod predicates;
+mod resize;
+mod rotate;
+mod row_ops;
+mod scalar;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod simd;
+mod smatrix;
+mod solve;
+mod sparse;
+mod strassen;
+mod transposed;
+mod traversal;
+mod view;
 
+pub use algebra::RANK_EPSILON;
+pub use builder::MatrixBuilder;
+pub use convolve::PaddingMode;
+pub use decomposition::{LuDecomposition, QrDecomposition};
+pub use display::MatrixDisplay;
 pub use exercises::*;
+pub use matrix::{approx_eq, Comparison, Matrix, MatrixError};
+pub use parse::ParseMatrixError;
+pub use scalar::Scalar;
+pub use simd::dot_simd;
+pub use smatrix::SMatrix;
+pub use solve::solve;
+pub use sparse::{SparseMatrix, SparseMatrixError};
+pub use strassen::STRASSEN_CUTOFF;
+pub use transposed::TransposedView;
+pub use traversal::{DiagonalIter, SpiralIter};
+pub use view::MatrixView;
+
+crate::exercise! {
+    /// Registry entry for [`find_postition_sorted_square_matrix`]
+    pub struct SortedSquareMatrixSearchExercise {
+        name: "matrix::find_postition_sorted_square_matrix",
+        difficulty: Medium,
+        topics: ["search", "young tableau"],
+        hints: [
+            "From which corner does every comparison rule out a whole row or column?",
+            "Start at the top-right: moving left decreases the value, moving down increases it.",
+        ],
+        solver: find_postition_sorted_square_matrix
+            as fn(&Matrix, f64) -> Result<(usize, usize), SearchError>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`Matrix::rotate_cw_in_place`]
+    pub struct RotateInPlaceExercise {
+        name: "matrix::rotate_cw_in_place",
+        difficulty: Medium,
+        topics: ["in-place", "index arithmetic"],
+        hints: [
+            "Each element moves along a cycle of four positions, one per side of its ring.",
+            "Rotate ring by ring from the outside in; a ring of side length k has k - 1 cycles.",
+        ],
+        solver: Matrix::rotate_cw_in_place as fn(&mut Matrix) -> Result<(), MatrixError>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`grid_exercises::count_islands`]
+    pub struct CountIslandsExercise {
+        name: "matrix::count_islands",
+        difficulty: Medium,
+        topics: ["graph traversal", "bfs", "grid"],
+        hints: [
+            "Every unvisited land cell you meet while scanning starts a new island.",
+            "Explore the whole island from that cell and mark it visited so it isn't counted twice.",
+        ],
+        solver: grid_exercises::count_islands as fn(&Matrix<i32>) -> usize,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`grid_exercises::min_path_sum`]
+    pub struct MinPathSumExercise {
+        name: "matrix::min_path_sum",
+        difficulty: Medium,
+        topics: ["dynamic programming", "grid"],
+        hints: [
+            "A cell can only be reached from the cell above it or the cell to its left.",
+            "Row by row, you only ever need the best costs of the previous row.",
+        ],
+        solver: grid_exercises::min_path_sum as fn(&Matrix<i32>) -> Option<i64>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`tableau_extract_min`]
+    pub struct TableauExtractMinExercise {
+        name: "matrix::tableau_extract_min",
+        difficulty: Medium,
+        topics: ["young tableau", "heap"],
+        hints: [
+            "Where in a Young tableau must the minimum be?",
+            "After removing it, sift the hole towards the bottom-right like a heap's sift-down.",
+        ],
+        solver: tableau_extract_min as fn(&mut Matrix) -> Option<f64>,
+    }
+}
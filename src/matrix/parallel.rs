@@ -0,0 +1,120 @@
+//! Rayon-based matrix multiplication (enabled with the `parallel` feature).
+
+use rayon::prelude::*;
+
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+impl<T: Scalar + Send + Sync> Matrix<T> {
+    /// # Parallel Matrix Multiplication
+    ///
+    /// ## Problem Statement
+    /// Compute `self * rhs` using every core, with the same result as the
+    /// sequential `*` operator.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let a = Matrix::random(64, 32, -5..5, 1);
+    /// let b = Matrix::random(32, 48, -5..5, 2);
+    /// assert_eq!(a.par_mul(&b).unwrap(), (&a * &b).unwrap());
+    /// ```
+    ///
+    /// ## Approach
+    /// Each output row depends only on one row of `self` and all of `rhs`.
+    /// `par_chunks_mut` splits the output buffer into disjoint row slices
+    /// and hands them to rayon's worker threads; every worker reads the
+    /// inputs through shared references.
+    ///
+    /// ## Complexity
+    /// - Time: O(n·m·p / threads) plus scheduling overhead
+    /// - Space: O(n·p) for the result only
+    ///
+    /// ## Key Points
+    /// 1. Writing needs `&mut` access, and the borrow checker only allows it
+    ///    because the row slices provably do not overlap: no locks needed
+    /// 2. Sharing `&self` and `&rhs` across threads requires `T: Sync`;
+    ///    sending the `&mut [T]` output chunks to workers requires `T: Send`
+    ///
+    /// ## Common Pitfalls
+    /// 1. Splitting too finely (one task per element): the scheduling cost
+    ///    outweighs the work, so small matrices are faster sequentially
+    /// 2. Wrapping the output in a `Mutex` to write from threads, which
+    ///    serializes the very work you meant to parallelize
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::zeros(self.rows, rhs.cols);
+        if rhs.cols == 0 {
+            // `par_chunks_mut(0)` panics, and there is nothing to compute
+            return Ok(result);
+        }
+        result
+            .data
+            .par_chunks_mut(rhs.cols)
+            .enumerate()
+            .for_each(|(i, out_row)| {
+                let left_row = &self.data[i * self.cols..(i + 1) * self.cols];
+                for (k, &left) in left_row.iter().enumerate() {
+                    let right_row = &rhs.data[k * rhs.cols..(k + 1) * rhs.cols];
+                    for (out, &right) in out_row.iter_mut().zip(right_row) {
+                        *out += left * right;
+                    }
+                }
+            });
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_mul_matches_sequential() {
+        for (rows, inner, cols) in [(1, 1, 1), (3, 4, 5), (64, 33, 17), (200, 50, 120)] {
+            let a = Matrix::random(rows, inner, -100_i64..100, 1);
+            let b = Matrix::random(inner, cols, -100_i64..100, 2);
+            assert_eq!(a.par_mul(&b).unwrap(), (&a * &b).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_par_mul_floats() {
+        // Each element is summed in the same order as the sequential kernel
+        let a: Matrix = Matrix::random(40, 30, -1.0..1.0, 3);
+        let b = Matrix::random(30, 20, -1.0..1.0, 4);
+        assert_eq!(a.par_mul(&b).unwrap(), (&a * &b).unwrap());
+    }
+
+    #[test]
+    fn test_par_mul_degenerate_shapes() {
+        let no_cols = Matrix::<i32>::zeros(3, 2)
+            .par_mul(&Matrix::zeros(2, 0))
+            .unwrap();
+        assert_eq!((no_cols.rows(), no_cols.cols()), (3, 0));
+        let no_inner = Matrix::<i32>::zeros(2, 0)
+            .par_mul(&Matrix::zeros(0, 2))
+            .unwrap();
+        assert_eq!(no_inner, Matrix::zeros(2, 2));
+    }
+
+    #[test]
+    fn test_par_mul_dimension_mismatch() {
+        let a = Matrix::<f64>::zeros(2, 3);
+        assert!(matches!(
+            a.par_mul(&a),
+            Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                ..
+            })
+        ));
+    }
+}
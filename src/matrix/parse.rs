@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+/// Error returned when parsing a [`Matrix`] from text. Rows and columns are
+/// zero-based.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    #[error("Invalid token {token:?} at row {row}, column {col}")]
+    InvalidToken {
+        row: usize,
+        col: usize,
+        token: String,
+    },
+    #[error("Row {row} has {actual} elements, expected {expected}")]
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// Parses rows separated by `;` and elements separated by whitespace:
+///
+/// ```
+/// use rust_ds_learning::matrix::Matrix;
+///
+/// let m: Matrix = "1 2 3; 4 5 6".parse().unwrap();
+/// assert_eq!((m.rows(), m.cols()), (2, 3));
+/// assert_eq!(m[(1, 0)], 4.0);
+///
+/// let ints: Matrix<i32> = "1 0; 0 1;".parse().unwrap(); // trailing `;` is fine
+/// assert_eq!(ints, Matrix::identity(2));
+/// ```
+///
+/// Blank input is the `0 x 0` matrix. Newlines count as whitespace, so a
+/// matrix literal can be laid out over several lines.
+impl<T: Scalar + FromStr> FromStr for Matrix<T> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_suffix(';').unwrap_or(s);
+        if s.trim().is_empty() {
+            return Ok(Matrix::zeros(0, 0));
+        }
+
+        let mut data = Vec::new();
+        let mut cols = None;
+        let mut rows = 0;
+        for (row, line) in s.split(';').enumerate() {
+            let before = data.len();
+            for (col, token) in line.split_whitespace().enumerate() {
+                let value = token.parse().map_err(|_| ParseMatrixError::InvalidToken {
+                    row,
+                    col,
+                    token: token.to_string(),
+                })?;
+                data.push(value);
+            }
+            let actual = data.len() - before;
+            let expected = *cols.get_or_insert(actual);
+            if actual != expected {
+                return Err(ParseMatrixError::RaggedRow {
+                    row,
+                    expected,
+                    actual,
+                });
+            }
+            rows += 1;
+        }
+
+        Ok(Matrix {
+            rows,
+            cols: cols.unwrap_or(0),
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shapes() {
+        let m: Matrix = "1 2 3; 4 5 6".parse().unwrap();
+        assert_eq!(
+            m,
+            Matrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+        );
+
+        let column: Matrix<i64> = "1;2;3".parse().unwrap();
+        assert_eq!((column.rows(), column.cols()), (3, 1));
+
+        let single: Matrix<i32> = "  -7 ".parse().unwrap();
+        assert_eq!(single.data, vec![-7]);
+
+        for blank in ["", "   ", ";", " ; "] {
+            let empty: Matrix = blank.parse().unwrap();
+            assert_eq!((empty.rows(), empty.cols()), (0, 0), "{blank:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_whitespace_and_layout() {
+        let m: Matrix<i32> = "
+            1   2
+            3\t4;
+            5 6 7 8;
+        "
+        .parse()
+        .unwrap();
+        assert_eq!((m.rows(), m.cols()), (2, 4));
+        assert_eq!(m.data, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let floats: Matrix = "1.5 -2e3; .25 inf".parse().unwrap();
+        assert_eq!(floats.data, vec![1.5, -2000.0, 0.25, f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_parse_invalid_token() {
+        assert_eq!(
+            "1 2; 3 x".parse::<Matrix>(),
+            Err(ParseMatrixError::InvalidToken {
+                row: 1,
+                col: 1,
+                token: "x".to_string()
+            })
+        );
+        // A float is not a valid integer element
+        assert_eq!(
+            "1 2.5".parse::<Matrix<i32>>(),
+            Err(ParseMatrixError::InvalidToken {
+                row: 0,
+                col: 1,
+                token: "2.5".to_string()
+            })
+        );
+        // Commas are not separators
+        assert!(matches!(
+            "1, 2".parse::<Matrix>(),
+            Err(ParseMatrixError::InvalidToken { row: 0, col: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        assert_eq!(
+            "1 2; 3".parse::<Matrix>(),
+            Err(ParseMatrixError::RaggedRow {
+                row: 1,
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            "1 2;; 3 4".parse::<Matrix>(),
+            Err(ParseMatrixError::RaggedRow {
+                row: 1,
+                expected: 2,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            "1; 2 3".parse::<Matrix>().unwrap_err().to_string(),
+            "Row 1 has 2 elements, expected 1"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Structural predicates.
+//!
+//! The element-wise checks take an `epsilon`: entries within `epsilon` of
+//! the value they should have (zero, or their mirror image) count as
+//! matching, because a computed "zero" is rarely exactly `0.0`. Pass `0.0`
+//! for exact checks on integer matrices.
+
+use super::matrix::approx_eq;
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Square and `A[i][j] ≈ A[j][i]` for all `i, j`
+    pub fn is_symmetric(&self, epsilon: f64) -> bool {
+        self.is_square()
+            && (0..self.rows).all(|i| {
+                (0..i).all(|j| approx_eq(self[(i, j)].to_f64(), self[(j, i)].to_f64(), epsilon))
+            })
+    }
+
+    /// Square with every off-diagonal entry ≈ 0
+    pub fn is_diagonal(&self, epsilon: f64) -> bool {
+        self.is_square() && self.all_near_zero(epsilon, |i, j| i != j)
+    }
+
+    /// Square with every entry below the diagonal ≈ 0
+    pub fn is_upper_triangular(&self, epsilon: f64) -> bool {
+        self.is_square() && self.all_near_zero(epsilon, |i, j| i > j)
+    }
+
+    /// Square with every entry above the diagonal ≈ 0
+    pub fn is_lower_triangular(&self, epsilon: f64) -> bool {
+        self.is_square() && self.all_near_zero(epsilon, |i, j| i < j)
+    }
+
+    /// Whether the matrix is a valid input for the sorted-matrix search:
+    /// square, with every row and every column non-decreasing.
+    ///
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let sorted: Matrix<i32> = "1 4; 2 5".parse().unwrap();
+    /// assert!(sorted.is_sorted_square());
+    /// let unsorted: Matrix<i32> = "1 4; 5 2".parse().unwrap();
+    /// assert!(!unsorted.is_sorted_square());
+    /// ```
+    pub fn is_sorted_square(&self) -> bool {
+        self.is_square()
+            && self.indexed_iter().all(|(i, j, &x)| {
+                (i == 0 || self[(i - 1, j)] <= x) && (j == 0 || self[(i, j - 1)] <= x)
+            })
+    }
+
+    fn all_near_zero(&self, epsilon: f64, selected: impl Fn(usize, usize) -> bool) -> bool {
+        self.indexed_iter()
+            .filter(|&(i, j, _)| selected(i, j))
+            .all(|(_, _, x)| approx_eq(x.to_f64(), 0.0, epsilon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric() {
+        let m: Matrix<i32> = "1 2 3; 2 5 6; 3 6 9".parse().unwrap();
+        assert!(m.is_symmetric(0.0));
+        assert!(!Matrix::<i32>::from_fn(2, 2, |i, j| (i * 2 + j) as i32).is_symmetric(0.0));
+        assert!(!Matrix::<i32>::zeros(2, 3).is_symmetric(0.0));
+
+        // A^T A is symmetric up to round-off
+        let a: Matrix = Matrix::random(5, 3, -1.0..1.0, 1);
+        let ata = (&a.transpose() * &a).unwrap();
+        assert!(ata.is_symmetric(1e-12));
+    }
+
+    #[test]
+    fn test_diagonal_and_triangular() {
+        let identity = Matrix::<f64>::identity(3);
+        assert!(identity.is_diagonal(0.0));
+        assert!(identity.is_upper_triangular(0.0) && identity.is_lower_triangular(0.0));
+
+        let upper: Matrix<i32> = "1 2 3; 0 4 5; 0 0 6".parse().unwrap();
+        assert!(upper.is_upper_triangular(0.0));
+        assert!(!upper.is_lower_triangular(0.0));
+        assert!(!upper.is_diagonal(0.0));
+        assert!(upper.transpose().is_lower_triangular(0.0));
+
+        assert!(!Matrix::<f64>::zeros(2, 3).is_upper_triangular(0.0));
+        assert!(Matrix::<f64>::zeros(0, 0).is_diagonal(0.0));
+    }
+
+    #[test]
+    fn test_epsilon_tolerance() {
+        let almost: Matrix = "1 1e-13; -1e-13 2".parse().unwrap();
+        assert!(!almost.is_diagonal(0.0));
+        assert!(almost.is_diagonal(1e-12));
+        assert!(almost.is_symmetric(1e-12));
+
+        // The U factor of LU is upper triangular up to round-off
+        let a: Matrix = Matrix::random(6, 6, -1.0..1.0, 2);
+        let lu = a.lu().unwrap();
+        assert!(lu.u.is_upper_triangular(1e-12));
+        assert!(lu.l.is_lower_triangular(0.0));
+    }
+
+    #[test]
+    fn test_sorted_square() {
+        let m: Matrix = "1 2 3; 4 5 6; 7 8 9".parse().unwrap();
+        assert!(m.is_sorted_square());
+        // Duplicates are allowed
+        assert!("1 1; 1 1"
+            .parse::<Matrix<i32>>()
+            .unwrap()
+            .is_sorted_square());
+        assert!(Matrix::<f64>::zeros(0, 0).is_sorted_square());
+
+        // Sorted rows but an unsorted column
+        assert!(!"1 2; 0 3"
+            .parse::<Matrix<i32>>()
+            .unwrap()
+            .is_sorted_square());
+        // Sorted columns but an unsorted row
+        assert!(!"2 1; 3 4"
+            .parse::<Matrix<i32>>()
+            .unwrap()
+            .is_sorted_square());
+        assert!(!"1 2 3; 4 5 6"
+            .parse::<Matrix<i32>>()
+            .unwrap()
+            .is_sorted_square());
+    }
+}
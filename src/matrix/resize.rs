@@ -0,0 +1,194 @@
+//! Growing and shrinking a matrix one row or column at a time.
+//!
+//! Rows are contiguous in the row-major buffer, so row operations are a
+//! cheap append or `drain`; column operations touch every row and cost
+//! O(rows · cols).
+
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// Appends a row. It must have `cols` elements, except that an empty
+    /// `0 x 0` matrix takes its width from the first row.
+    pub fn push_row(&mut self, row: &[T]) -> Result<(), MatrixError> {
+        if self.rows == 0 && self.cols == 0 {
+            self.cols = row.len();
+        } else if row.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "concatenate",
+                left_dims: (self.rows, self.cols),
+                right_dims: (1, row.len()),
+            });
+        }
+        self.data.extend_from_slice(row);
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Appends a column. It must have `rows` elements, except that an empty
+    /// `0 x 0` matrix takes its height from the first column.
+    ///
+    /// Building the augmented matrix `[A | b]` for elimination:
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let mut augmented: Matrix = "2 1; 1 -1".parse().unwrap();
+    /// augmented.push_col(&[5.0, 1.0]).unwrap();
+    /// assert_eq!(augmented, "2 1 5; 1 -1 1".parse().unwrap());
+    /// ```
+    pub fn push_col(&mut self, col: &[T]) -> Result<(), MatrixError> {
+        if self.rows == 0 && self.cols == 0 {
+            self.rows = col.len();
+        } else if col.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "concatenate",
+                left_dims: (self.rows, self.cols),
+                right_dims: (col.len(), 1),
+            });
+        }
+        let old_cols = self.cols;
+        let mut data = Vec::with_capacity(self.rows * (old_cols + 1));
+        for (i, &value) in col.iter().enumerate() {
+            data.extend_from_slice(&self.data[i * old_cols..(i + 1) * old_cols]);
+            data.push(value);
+        }
+        self.data = data;
+        self.cols += 1;
+        Ok(())
+    }
+
+    /// Removes row `row` and returns its elements
+    pub fn remove_row(&mut self, row: usize) -> Result<Vec<T>, MatrixError> {
+        if row >= self.rows {
+            return Err(MatrixError::IndexOutOfBounds {
+                row,
+                col: 0,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let removed = self
+            .data
+            .drain(row * self.cols..(row + 1) * self.cols)
+            .collect();
+        self.rows -= 1;
+        Ok(removed)
+    }
+
+    /// Removes column `col` and returns its elements, top to bottom
+    pub fn remove_col(&mut self, col: usize) -> Result<Vec<T>, MatrixError> {
+        if col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds {
+                row: 0,
+                col,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let cols = self.cols;
+        let removed = self.col_iter(col).copied().collect();
+        let mut k = 0;
+        self.data.retain(|_| {
+            let keep = k % cols != col;
+            k += 1;
+            keep
+        });
+        self.cols -= 1;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_row_and_col() {
+        let mut m: Matrix<i32> = "1 2; 3 4".parse().unwrap();
+        m.push_row(&[5, 6]).unwrap();
+        assert_eq!(m, "1 2; 3 4; 5 6".parse().unwrap());
+        m.push_col(&[7, 8, 9]).unwrap();
+        assert_eq!(m, "1 2 7; 3 4 8; 5 6 9".parse().unwrap());
+    }
+
+    #[test]
+    fn test_build_from_empty() {
+        let mut by_rows = Matrix::<i32>::zeros(0, 0);
+        by_rows.push_row(&[1, 2, 3]).unwrap();
+        by_rows.push_row(&[4, 5, 6]).unwrap();
+        assert_eq!(by_rows, "1 2 3; 4 5 6".parse().unwrap());
+
+        let mut by_cols = Matrix::<i32>::zeros(0, 0);
+        by_cols.push_col(&[1, 4]).unwrap();
+        by_cols.push_col(&[2, 5]).unwrap();
+        by_cols.push_col(&[3, 6]).unwrap();
+        assert_eq!(by_cols, by_rows);
+    }
+
+    #[test]
+    fn test_push_dimension_errors() {
+        let mut m: Matrix<i32> = "1 2; 3 4".parse().unwrap();
+        assert!(matches!(
+            m.push_row(&[1, 2, 3]),
+            Err(MatrixError::DimensionMismatch {
+                left_dims: (2, 2),
+                right_dims: (1, 3),
+                ..
+            })
+        ));
+        assert!(matches!(
+            m.push_col(&[1]),
+            Err(MatrixError::DimensionMismatch {
+                left_dims: (2, 2),
+                right_dims: (1, 1),
+                ..
+            })
+        ));
+        // A 0 x 3 matrix has a fixed width even without rows
+        let mut no_rows = Matrix::<i32>::zeros(0, 3);
+        assert!(no_rows.push_row(&[1, 2]).is_err());
+        no_rows.push_row(&[1, 2, 3]).unwrap();
+        assert_eq!(m, "1 2; 3 4".parse().unwrap());
+    }
+
+    #[test]
+    fn test_remove_row_and_col() {
+        let mut m: Matrix<i32> = "1 2 3; 4 5 6; 7 8 9".parse().unwrap();
+        assert_eq!(m.remove_row(1).unwrap(), vec![4, 5, 6]);
+        assert_eq!(m, "1 2 3; 7 8 9".parse().unwrap());
+        assert_eq!(m.remove_col(0).unwrap(), vec![1, 7]);
+        assert_eq!(m, "2 3; 8 9".parse().unwrap());
+        assert_eq!(m.remove_col(1).unwrap(), vec![3, 9]);
+        assert_eq!(m, "2; 8".parse().unwrap());
+
+        assert!(matches!(
+            m.remove_row(2),
+            Err(MatrixError::IndexOutOfBounds {
+                row: 2,
+                rows: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            m.remove_col(1),
+            Err(MatrixError::IndexOutOfBounds {
+                col: 1,
+                cols: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_remove_everything() {
+        let mut m: Matrix<i32> = "1 2; 3 4".parse().unwrap();
+        m.remove_row(0).unwrap();
+        m.remove_row(0).unwrap();
+        assert_eq!((m.rows(), m.cols()), (0, 2));
+
+        let mut m: Matrix<i32> = "1 2; 3 4".parse().unwrap();
+        m.remove_col(1).unwrap();
+        m.remove_col(0).unwrap();
+        assert_eq!((m.rows(), m.cols(), m.data.len()), (2, 0, 0));
+    }
+}
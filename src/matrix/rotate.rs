@@ -0,0 +1,182 @@
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// A new matrix rotated 90° clockwise; an `r x c` input becomes `c x r`
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(self.rows - 1 - j, i)])
+    }
+
+    /// A new matrix rotated 90° counter-clockwise (270° clockwise)
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(j, self.cols - 1 - i)])
+    }
+
+    /// A new matrix rotated 180°: the row-major data reversed
+    pub fn rotate_180(&self) -> Self {
+        let mut data = self.data.clone();
+        data.reverse();
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// # Rotate a Square Matrix In Place
+    ///
+    /// ## Problem Statement
+    /// Rotate an `n x n` matrix 90° clockwise using O(1) extra space.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let mut m = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// m.rotate_cw_in_place().unwrap();
+    /// assert_eq!(m.data, vec![3, 1, 4, 2]);
+    /// ```
+    ///
+    /// ## Approach
+    /// Work from the outer ring inwards. Within a ring, each element belongs
+    /// to a cycle of four positions (top → right → bottom → left); rotating
+    /// that cycle by one step needs a single temporary.
+    ///
+    /// ## Complexity
+    /// - Time: O(n²), every element moves once
+    /// - Space: O(1)
+    ///
+    /// ## Key Points
+    /// 1. There are `n / 2` rings; the centre of an odd-sized matrix stays put
+    /// 2. Equivalent two-pass solution: transpose, then reverse every row
+    ///
+    /// ## Common Pitfalls
+    /// 1. Iterating a ring's full length instead of `len - 1`, which rotates
+    ///    the corners twice
+    /// 2. Rotating a non-square matrix in place: the shape changes, so there
+    ///    is no in-place answer. Returns `MatrixError::NotSquare`.
+    pub fn rotate_cw_in_place(&mut self) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        for layer in 0..n / 2 {
+            let last = n - 1 - layer;
+            for offset in 0..last - layer {
+                let top = (layer, layer + offset);
+                let right = (layer + offset, last);
+                let bottom = (last, last - offset);
+                let left = (last - offset, layer);
+
+                let saved = self[top];
+                self[top] = self[left];
+                self[left] = self[bottom];
+                self[bottom] = self[right];
+                self[right] = saved;
+            }
+        }
+        Ok(())
+    }
+
+    /// Counter-clockwise counterpart of [`Matrix::rotate_cw_in_place`]: the
+    /// same four-position cycles, moved the other way round
+    pub fn rotate_ccw_in_place(&mut self) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        for layer in 0..n / 2 {
+            let last = n - 1 - layer;
+            for offset in 0..last - layer {
+                let top = (layer, layer + offset);
+                let right = (layer + offset, last);
+                let bottom = (last, last - offset);
+                let left = (last - offset, layer);
+
+                let saved = self[top];
+                self[top] = self[right];
+                self[right] = self[bottom];
+                self[bottom] = self[left];
+                self[left] = saved;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting(rows: usize, cols: usize) -> Matrix<i32> {
+        Matrix::from_fn(rows, cols, |i, j| (i * cols + j) as i32 + 1)
+    }
+
+    #[test]
+    fn test_rotate_rectangular() {
+        // [1 2 3]    cw    [4 1]    ccw   [3 6]
+        // [4 5 6]   --->   [5 2]   ---->  [2 5]
+        //                  [6 3]          [1 4]
+        let m = counting(2, 3);
+        let cw = m.rotate_cw();
+        assert_eq!((cw.rows(), cw.cols()), (3, 2));
+        assert_eq!(cw.data, vec![4, 1, 5, 2, 6, 3]);
+        assert_eq!(m.rotate_ccw().data, vec![3, 6, 2, 5, 1, 4]);
+        assert_eq!(m.rotate_180().data, vec![6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_rotations_compose() {
+        let m = counting(3, 4);
+        assert_eq!(m.rotate_cw().rotate_ccw(), m);
+        assert_eq!(m.rotate_cw().rotate_cw(), m.rotate_180());
+        assert_eq!(m.rotate_180().rotate_cw(), m.rotate_ccw());
+        assert_eq!(m.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), m);
+    }
+
+    #[test]
+    fn test_in_place_matches_copying_rotation() {
+        // Odd sizes have a fixed centre, even sizes do not
+        for n in 0..=6 {
+            let original = counting(n, n);
+            let mut m = original.clone();
+            m.rotate_cw_in_place().unwrap();
+            assert_eq!(m, original.rotate_cw(), "n = {n}");
+            m.rotate_ccw_in_place().unwrap();
+            assert_eq!(m, original, "n = {n}");
+            m.rotate_ccw_in_place().unwrap();
+            assert_eq!(m, original.rotate_ccw(), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_in_place_odd_dimension() {
+        let mut m = counting(3, 3);
+        m.rotate_cw_in_place().unwrap();
+        assert_eq!(m.data, vec![7, 4, 1, 8, 5, 2, 9, 6, 3]);
+        assert_eq!(m[(1, 1)], 5);
+    }
+
+    #[test]
+    fn test_in_place_rejects_non_square() {
+        let mut m = counting(2, 3);
+        assert!(matches!(
+            m.rotate_cw_in_place(),
+            Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+        ));
+        assert!(m.rotate_ccw_in_place().is_err());
+        assert_eq!(
+            m,
+            counting(2, 3),
+            "failed rotation leaves the matrix untouched"
+        );
+    }
+}
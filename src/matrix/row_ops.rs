@@ -0,0 +1,158 @@
+//! Elementary row and column operations.
+//!
+//! Gaussian elimination, LU and rank are all built from three row moves:
+//! swapping two rows, scaling a row, and adding a multiple of one row to
+//! another. Each keeps the solution set of `A x = b` unchanged when applied
+//! to the augmented matrix.
+
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// Exchanges rows `a` and `b`
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), MatrixError> {
+        self.check_row(a)?;
+        self.check_row(b)?;
+        if a != b {
+            let cols = self.cols;
+            let (low, high) = (a.min(b), a.max(b));
+            let (head, tail) = self.data.split_at_mut(high * cols);
+            head[low * cols..(low + 1) * cols].swap_with_slice(&mut tail[..cols]);
+        }
+        Ok(())
+    }
+
+    /// Exchanges columns `a` and `b`
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), MatrixError> {
+        self.check_col_index(a)?;
+        self.check_col_index(b)?;
+        for row in 0..self.rows {
+            self.data.swap(row * self.cols + a, row * self.cols + b);
+        }
+        Ok(())
+    }
+
+    /// Multiplies every element of `row` by `factor`
+    pub fn scale_row(&mut self, row: usize, factor: T) -> Result<(), MatrixError> {
+        self.check_row(row)?;
+        for x in self.row_iter_mut(row) {
+            *x = *x * factor;
+        }
+        Ok(())
+    }
+
+    /// `row[dst] += factor * row[src]`, the workhorse of elimination
+    pub fn add_scaled_row(&mut self, src: usize, dst: usize, factor: T) -> Result<(), MatrixError> {
+        self.check_row(src)?;
+        self.check_row(dst)?;
+        let cols = self.cols;
+        for j in 0..cols {
+            let add = factor * self.data[src * cols + j];
+            self.data[dst * cols + j] += add;
+        }
+        Ok(())
+    }
+
+    fn check_row(&self, row: usize) -> Result<(), MatrixError> {
+        if row >= self.rows {
+            return Err(MatrixError::IndexOutOfBounds {
+                row,
+                col: 0,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_col_index(&self, col: usize) -> Result<(), MatrixError> {
+        if col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds {
+                row: 0,
+                col,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Matrix<i32> {
+        "1 2 3; 4 5 6; 7 8 9".parse().unwrap()
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut m = sample();
+        m.swap_rows(0, 2).unwrap();
+        assert_eq!(m, "7 8 9; 4 5 6; 1 2 3".parse().unwrap());
+        m.swap_rows(2, 0).unwrap();
+        m.swap_rows(1, 1).unwrap();
+        assert_eq!(m, sample());
+    }
+
+    #[test]
+    fn test_swap_cols() {
+        let mut m = sample();
+        m.swap_cols(0, 1).unwrap();
+        assert_eq!(m, "2 1 3; 5 4 6; 8 7 9".parse().unwrap());
+    }
+
+    #[test]
+    fn test_scale_and_add_scaled_row() {
+        let mut m = sample();
+        m.scale_row(1, -1).unwrap();
+        assert_eq!(m, "1 2 3; -4 -5 -6; 7 8 9".parse().unwrap());
+        // Eliminate the first column below the pivot
+        m.add_scaled_row(0, 1, 4).unwrap();
+        m.add_scaled_row(0, 2, -7).unwrap();
+        assert_eq!(m, "1 2 3; 0 3 6; 0 -6 -12".parse().unwrap());
+        // A row can be added to itself
+        m.add_scaled_row(2, 2, 1).unwrap();
+        assert_eq!(
+            m.row_iter(2).copied().collect::<Vec<_>>(),
+            vec![0, -12, -24]
+        );
+    }
+
+    #[test]
+    fn test_row_ops_preserve_solution() {
+        // [A | b] for x = (1, 2): x + y = 3, x - y = -1
+        let mut augmented: Matrix = "1 1 3; 1 -1 -1".parse().unwrap();
+        augmented.swap_rows(0, 1).unwrap();
+        augmented.add_scaled_row(0, 1, -1.0).unwrap();
+        augmented.scale_row(1, 0.5).unwrap();
+        augmented.add_scaled_row(1, 0, 1.0).unwrap();
+        assert_eq!(augmented, "1 0 1; 0 1 2".parse().unwrap());
+    }
+
+    #[test]
+    fn test_bounds_errors_leave_matrix_untouched() {
+        let mut m = sample();
+        assert!(matches!(
+            m.swap_rows(0, 3),
+            Err(MatrixError::IndexOutOfBounds {
+                row: 3,
+                rows: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            m.swap_cols(5, 0),
+            Err(MatrixError::IndexOutOfBounds {
+                col: 5,
+                cols: 3,
+                ..
+            })
+        ));
+        assert!(m.scale_row(3, 2).is_err());
+        assert!(m.add_scaled_row(3, 0, 1).is_err());
+        assert!(m.add_scaled_row(0, 3, 1).is_err());
+        assert_eq!(m, sample());
+    }
+}
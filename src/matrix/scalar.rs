@@ -0,0 +1,49 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// Numeric element type a [`Matrix`](super::Matrix) can hold.
+///
+/// Implemented for `i32`, `i64`, `f32` and `f64`. Integer matrices compare
+/// exactly, so exercises on them need no epsilon.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Lossy conversion used for error reporting and floating point results
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar {
+    ($($ty:ty => $zero:literal, $one:literal;)*) => {
+        $(
+            impl Scalar for $ty {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar! {
+    i32 => 0, 1;
+    i64 => 0, 1;
+    f32 => 0.0, 1.0;
+    f64 => 0.0, 1.0;
+}
@@ -0,0 +1,95 @@
+//! `serde` support for [`Matrix`] (enabled with the `serde` feature).
+//!
+//! The wire format mirrors the struct: `{"rows": 2, "cols": 2, "data": [..]}`.
+//! Deserializing goes through [`Matrix::from_vec`], so a `data` array whose
+//! length is not `rows * cols` is rejected with `MatrixError::InvalidCreation`
+//! instead of producing a matrix that panics on first access.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+#[derive(Serialize)]
+struct MatrixRef<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T],
+}
+
+#[derive(Deserialize)]
+struct RawMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar + Serialize> Serialize for Matrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRef {
+            rows: self.rows,
+            cols: self.cols,
+            data: &self.data,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawMatrix::deserialize(deserializer)?;
+        Matrix::from_vec(raw.rows, raw.cols, raw.data).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::find_postition_sorted_square_matrix;
+
+    #[test]
+    fn test_round_trip() {
+        let m = Matrix::from_vec(2, 3, vec![1.5, -2.0, 3.0, 0.0, 5.25, 6.0]).unwrap();
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rows":2,"cols":3,"data":[1.5,-2.0,3.0,0.0,5.25,6.0]}"#
+        );
+        assert_eq!(serde_json::from_str::<Matrix>(&json).unwrap(), m);
+
+        let ints = Matrix::from_vec(1, 2, vec![7_i64, -8]).unwrap();
+        let json = serde_json::to_string(&ints).unwrap();
+        assert_eq!(serde_json::from_str::<Matrix<i64>>(&json).unwrap(), ints);
+    }
+
+    #[test]
+    fn test_rejects_wrong_length() {
+        let err = serde_json::from_str::<Matrix>(r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid dimensions: expected 4 elements, got 3"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_rejects_missing_fields_and_bad_elements() {
+        assert!(serde_json::from_str::<Matrix>(r#"{"rows":1,"data":[1.0]}"#).is_err());
+        assert!(
+            serde_json::from_str::<Matrix<i32>>(r#"{"rows":1,"cols":1,"data":[1.5]}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_load_fixture() {
+        let fixture = include_str!("../../tests/fixtures/sorted_square_matrix.json");
+        let m: Matrix = serde_json::from_str(fixture).unwrap();
+        assert_eq!((m.rows(), m.cols()), (3, 3));
+        assert_eq!(
+            find_postition_sorted_square_matrix(&m, 6.0).unwrap(),
+            (1, 2)
+        );
+    }
+}
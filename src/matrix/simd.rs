@@ -0,0 +1,136 @@
+//! Explicitly vectorizable matrix kernel.
+//!
+//! `std::simd` is still nightly-only, so this emulates an `f64x4` register
+//! with plain arrays: four independent accumulators processed in lockstep.
+//! The compiler turns the fixed-width inner loop into SIMD instructions on
+//! stable Rust; what matters for the lesson is the data layout that makes
+//! that possible.
+
+use super::matrix::{Matrix, MatrixError};
+
+const LANES: usize = 4;
+
+/// # Chunked Dot Product
+///
+/// ## Problem Statement
+/// Compute `sum(a[i] * b[i])` in a form the CPU can vectorize.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::dot_simd;
+///
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let b = [1.0, 1.0, 1.0, 1.0, 2.0];
+/// assert_eq!(dot_simd(&a, &b), 20.0);
+/// ```
+///
+/// ## Approach
+/// Walk both slices in chunks of four and keep four partial sums, one per
+/// lane. Combine the lanes at the end and add the leftover tail.
+///
+/// ## Key Points
+/// 1. A single accumulator forms a dependency chain (each add waits for the
+///    previous one); four independent lanes break it, even without SIMD
+/// 2. Floating point addition is not associative, so the result may differ
+///    from a left-to-right sum in the last few bits
+///
+/// ## Common Pitfalls
+/// 1. Forgetting the tail when the length is not a multiple of the width
+///
+/// Panics if the slices have different lengths.
+pub fn dot_simd(a: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "dot product of slices with different lengths"
+    );
+
+    let mut lanes = [0.0; LANES];
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail: f64 = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for lane in 0..LANES {
+            lanes[lane] += x[lane] * y[lane];
+        }
+    }
+    (lanes[0] + lanes[1]) + (lanes[2] + lanes[3]) + tail
+}
+
+impl Matrix {
+    /// Matrix product using [`dot_simd`] for every output element.
+    ///
+    /// `rhs` is transposed first so that each dot product reads two
+    /// contiguous rows; striding down a column would defeat vectorization.
+    pub fn mul_simd(&self, rhs: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let rhs_t = rhs.transpose();
+        Ok(Matrix::from_fn(self.rows, rhs.cols, |i, j| {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            let col = &rhs_t.data[j * rhs_t.cols..(j + 1) * rhs_t.cols];
+            dot_simd(row, col)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_simd_lengths() {
+        for len in 0..13 {
+            let a: Vec<f64> = (0..len).map(|x| x as f64).collect();
+            let b: Vec<f64> = (0..len).map(|x| (2 * x + 1) as f64).collect();
+            let expected: f64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+            assert_eq!(dot_simd(&a, &b), expected, "len {len}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn test_dot_simd_length_mismatch() {
+        dot_simd(&[1.0, 2.0], &[1.0]);
+    }
+
+    #[test]
+    fn test_mul_simd_matches_scalar_path() {
+        for (rows, inner, cols) in [(1, 1, 1), (3, 5, 2), (17, 33, 9), (64, 64, 64)] {
+            let a: Matrix = Matrix::random(rows, inner, -1.0..1.0, 1);
+            let b = Matrix::random(inner, cols, -1.0..1.0, 2);
+            let expected = (&a * &b).unwrap();
+            let actual = a.mul_simd(&b).unwrap();
+            assert_eq!((actual.rows(), actual.cols()), (rows, cols));
+            for (x, y) in actual.iter().zip(expected.iter()) {
+                assert!((x - y).abs() < 1e-12, "{x} vs {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_simd_errors_and_empty() {
+        let a = Matrix::zeros(2, 3);
+        assert!(matches!(
+            a.mul_simd(&a),
+            Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                ..
+            })
+        ));
+        let empty = Matrix::zeros(2, 0).mul_simd(&Matrix::zeros(0, 2)).unwrap();
+        assert_eq!(empty, Matrix::zeros(2, 2));
+    }
+}
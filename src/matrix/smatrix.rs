@@ -0,0 +1,288 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// # Fixed-Size Matrix
+///
+/// A companion to [`Matrix`] whose shape is part of its type: `R` rows and
+/// `C` columns are const generic parameters, and the elements live inline
+/// in a `[[T; C]; R]`, so small matrices need no heap allocation.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::SMatrix;
+///
+/// let a = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let b = SMatrix::new([[1, 0], [0, 1], [1, 1]]);
+/// let product: SMatrix<2, 2, i32> = a * b;
+/// assert_eq!(product, SMatrix::new([[4, 5], [10, 11]]));
+/// assert_eq!(a.transpose()[(2, 1)], 6);
+/// ```
+///
+/// Shape mismatches are compile-time errors rather than a
+/// `MatrixError::DimensionMismatch` at runtime:
+/// ```compile_fail
+/// use rust_ds_learning::matrix::SMatrix;
+///
+/// let a = SMatrix::new([[1, 2], [3, 4]]);
+/// let b = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let _ = a + b; // expected `SMatrix<2, 2, _>`, found `SMatrix<2, 3, _>`
+/// ```
+/// ```compile_fail
+/// use rust_ds_learning::matrix::SMatrix;
+///
+/// let a = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let _ = a * a; // a 2x3 matrix cannot multiply a 2x3 matrix
+/// ```
+///
+/// ## Compared to [`Matrix`]
+/// - Operators return the result directly instead of a `Result`, because
+///   an invalid shape cannot be expressed
+/// - `SMatrix` is `Copy` and allocation-free, but its size must be known
+///   when compiling and large ones bloat the stack
+///
+/// ## Key Points
+/// 1. `Mul<SMatrix<C, K, T>> for SMatrix<R, C, T>` encodes "inner
+///    dimensions must agree" in the signature, and the output type
+///    `SMatrix<R, K, T>` is computed by the compiler
+/// 2. Square-only operations such as [`SMatrix::identity`] live in an
+///    `impl<const N: usize> SMatrix<N, N, T>` block, so calling them on a
+///    non-square type does not compile
+/// 3. Converting from a dynamic [`Matrix`] is where the runtime check
+///    moves: `SMatrix::try_from(&matrix)` verifies the shape once
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<const R: usize, const C: usize, T = f64> {
+    data: [[T; C]; R],
+}
+
+impl<const R: usize, const C: usize, T: Scalar> SMatrix<R, C, T> {
+    /// Builds a matrix from its rows
+    pub fn new(data: [[T; C]; R]) -> Self {
+        Self { data }
+    }
+
+    pub fn zeros() -> Self {
+        Self::from_fn(|_, _| T::ZERO)
+    }
+
+    /// Fills each cell with `f(row, col)`
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| f(i, j))),
+        }
+    }
+
+    #[inline]
+    pub const fn rows(&self) -> usize {
+        R
+    }
+
+    #[inline]
+    pub const fn cols(&self) -> usize {
+        C
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        self.data.get(row)?.get(col).copied()
+    }
+
+    /// The rows as nested arrays
+    pub fn as_rows(&self) -> &[[T; C]; R] {
+        &self.data
+    }
+
+    pub fn transpose(&self) -> SMatrix<C, R, T> {
+        SMatrix::from_fn(|i, j| self.data[j][i])
+    }
+}
+
+impl<const N: usize, T: Scalar> SMatrix<N, N, T> {
+    pub fn identity() -> Self {
+        Self::from_fn(|i, j| if i == j { T::ONE } else { T::ZERO })
+    }
+
+    pub fn trace(&self) -> T {
+        let mut sum = T::ZERO;
+        for (i, row) in self.data.iter().enumerate() {
+            sum += row[i];
+        }
+        sum
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Default for SMatrix<R, C, T> {
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Index<(usize, usize)> for SMatrix<R, C, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < R && col < C,
+            "index ({row}, {col}) out of bounds for {R}x{C} matrix"
+        );
+        &self.data[row][col]
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> IndexMut<(usize, usize)> for SMatrix<R, C, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < R && col < C,
+            "index ({row}, {col}) out of bounds for {R}x{C} matrix"
+        );
+        &mut self.data[row][col]
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Add for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(|i, j| self.data[i][j] + rhs.data[i][j])
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Sub for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(|i, j| self.data[i][j] - rhs.data[i][j])
+    }
+}
+
+impl<const R: usize, const C: usize, const K: usize, T: Scalar> Mul<SMatrix<C, K, T>>
+    for SMatrix<R, C, T>
+{
+    type Output = SMatrix<R, K, T>;
+
+    fn mul(self, rhs: SMatrix<C, K, T>) -> SMatrix<R, K, T> {
+        SMatrix::from_fn(|i, j| dot(&self.data[i], rhs.data.iter().map(|row| row[j])))
+    }
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Mul<T> for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::from_fn(|i, j| self.data[i][j] * rhs)
+    }
+}
+
+fn dot<T: Scalar>(left: &[T], right: impl Iterator<Item = T>) -> T {
+    let mut sum = T::ZERO;
+    for (&a, b) in left.iter().zip(right) {
+        sum += a * b;
+    }
+    sum
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<SMatrix<R, C, T>> for Matrix<T> {
+    fn from(m: SMatrix<R, C, T>) -> Self {
+        Matrix {
+            rows: R,
+            cols: C,
+            data: m.data.iter().flatten().copied().collect(),
+        }
+    }
+}
+
+/// Checks the dynamic shape against `R x C`, returning
+/// `MatrixError::DimensionMismatch` with operation "convert" otherwise.
+impl<const R: usize, const C: usize, T: Scalar> TryFrom<&Matrix<T>> for SMatrix<R, C, T> {
+    type Error = MatrixError;
+
+    fn try_from(m: &Matrix<T>) -> Result<Self, MatrixError> {
+        if m.rows != R || m.cols != C {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "convert",
+                left_dims: (m.rows, m.cols),
+                right_dims: (R, C),
+            });
+        }
+        Ok(Self::from_fn(|i, j| m.data[i * C + j]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construction_and_access() {
+        let m = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m[(1, 2)], 6);
+        assert_eq!(m.get(1, 0), Some(4));
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.get(0, 3), None);
+        assert_eq!(m.as_rows(), &[[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(SMatrix::<2, 2, i32>::default(), SMatrix::zeros());
+        assert_eq!(
+            SMatrix::<2, 2, i32>::identity(),
+            SMatrix::new([[1, 0], [0, 1]])
+        );
+        assert_eq!(SMatrix::<3, 3, i32>::identity().trace(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 3) out of bounds for 2x3 matrix")]
+    fn test_index_out_of_bounds_panics() {
+        let m = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let _ = m[(0, 3)];
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = SMatrix::new([[1.0, 2.0], [3.0, 4.0]]);
+        let b = SMatrix::new([[0.5, 0.5], [1.0, -1.0]]);
+        assert_eq!(a + b, SMatrix::new([[1.5, 2.5], [4.0, 3.0]]));
+        assert_eq!(a - b, SMatrix::new([[0.5, 1.5], [2.0, 5.0]]));
+        assert_eq!(a * 2.0, SMatrix::new([[2.0, 4.0], [6.0, 8.0]]));
+        assert_eq!(a * SMatrix::identity(), a);
+    }
+
+    #[test]
+    fn test_mul_and_transpose_agree_with_matrix() {
+        let a = SMatrix::<3, 4, i32>::from_fn(|i, j| (i * 4 + j) as i32 - 5);
+        let b = SMatrix::<4, 2, i32>::from_fn(|i, j| (i + 3 * j) as i32);
+
+        let product: SMatrix<3, 2, i32> = a * b;
+        let expected = (&Matrix::from(a) * &Matrix::from(b)).unwrap();
+        assert_eq!(Matrix::from(product), expected);
+
+        let t: SMatrix<4, 3, i32> = a.transpose();
+        assert_eq!(Matrix::from(t), Matrix::from(a).transpose());
+        assert_eq!(t.transpose(), a);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let dynamic: Matrix<i32> = "1 2; 3 4; 5 6".parse().unwrap();
+        let fixed = SMatrix::<3, 2, i32>::try_from(&dynamic).unwrap();
+        assert_eq!(fixed, SMatrix::new([[1, 2], [3, 4], [5, 6]]));
+        assert_eq!(Matrix::from(fixed), dynamic);
+
+        assert!(matches!(
+            SMatrix::<2, 3, i32>::try_from(&dynamic),
+            Err(MatrixError::DimensionMismatch {
+                operation: "convert",
+                left_dims: (3, 2),
+                right_dims: (2, 3)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_lives_on_the_stack() {
+        assert_eq!(
+            std::mem::size_of::<SMatrix<3, 3>>(),
+            9 * std::mem::size_of::<f64>()
+        );
+    }
+}
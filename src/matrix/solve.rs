@@ -0,0 +1,172 @@
+use super::decomposition::pivot_tolerance;
+use super::matrix::{Matrix, MatrixError};
+
+/// # Solve a Linear System by Gaussian Elimination
+///
+/// ## Problem Statement
+/// Given a square `n x n` matrix `A` and an `n x m` right-hand side `B`,
+/// find `X` with `A * X = B`. Each column of `B` is an independent system.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{solve, Matrix};
+///
+/// // 2x + y = 5, x - y = 1  =>  x = 2, y = 1
+/// let a = Matrix::from_vec(2, 2, vec![2.0, 1.0, 1.0, -1.0]).unwrap();
+/// let b = Matrix::from_vec(2, 1, vec![5.0, 1.0]).unwrap();
+/// let x = solve(&a, &b).unwrap();
+/// assert!((x.data[0] - 2.0).abs() < 1e-12 && (x.data[1] - 1.0).abs() < 1e-12);
+/// ```
+///
+/// ## Approach
+/// 1. Forward elimination with partial pivoting on the augmented system
+///    `[A | B]` turns `A` upper triangular
+/// 2. Back-substitution solves for the last unknown first and works upward
+///
+/// ## Complexity
+/// - Time: O(n³ + n²m)
+/// - Space: O(n² + nm) for the working copies
+///
+/// ## Key Points
+/// 1. Row operations must be applied to `B` as well, or the system changes
+/// 2. Solving many right-hand sides against the same `A` is cheaper through
+///    [`Matrix::lu`], which does the O(n³) work once
+///
+/// ## Common Pitfalls
+/// 1. Computing `A⁻¹ * B` instead: slower and less accurate
+/// 2. Comparing pivots to exactly `0.0`; nearly singular systems need a
+///    tolerance
+///
+/// Returns `MatrixError::NotSquare` when `A` is not square (over- or
+/// under-determined systems), `MatrixError::DimensionMismatch` when `B` has
+/// a different number of rows, and `MatrixError::Singular` when there is no
+/// unique solution.
+pub fn solve(a: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+    if a.rows != a.cols {
+        return Err(MatrixError::NotSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    if b.rows != a.rows {
+        return Err(MatrixError::DimensionMismatch {
+            operation: "solve",
+            left_dims: (a.rows, a.cols),
+            right_dims: (b.rows, b.cols),
+        });
+    }
+
+    let n = a.rows;
+    let m = b.cols;
+    let tolerance = pivot_tolerance(a);
+    let mut a = a.clone();
+    let mut x = b.clone();
+
+    for k in 0..n {
+        let pivot_row = (k..n)
+            .max_by(|&p, &q| a.data[p * n + k].abs().total_cmp(&a.data[q * n + k].abs()))
+            .expect("k < n");
+        if a.data[pivot_row * n + k].abs() < tolerance {
+            return Err(MatrixError::Singular);
+        }
+        a.swap_rows(k, pivot_row)?;
+        x.swap_rows(k, pivot_row)?;
+
+        let pivot = a.data[k * n + k];
+        for i in k + 1..n {
+            let factor = a.data[i * n + k] / pivot;
+            for j in k..n {
+                a.data[i * n + j] -= factor * a.data[k * n + j];
+            }
+            for j in 0..m {
+                x.data[i * m + j] -= factor * x.data[k * m + j];
+            }
+        }
+    }
+
+    for i in (0..n).rev() {
+        for j in 0..m {
+            let known: f64 = (i + 1..n)
+                .map(|k| a.data[i * n + k] * x.data[k * m + j])
+                .sum();
+            x.data[i * m + j] = (x.data[i * m + j] - known) / a.data[i * n + i];
+        }
+    }
+    Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_approx_eq(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (x, y) in actual.iter().zip(expected) {
+            assert!((x - y).abs() < EPSILON, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_solve_3x3() {
+        //  2x +  y -  z =   8
+        // -3x -  y + 2z = -11
+        // -2x +  y + 2z =  -3
+        let a =
+            Matrix::from_vec(3, 3, vec![2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0]).unwrap();
+        let b = Matrix::from_vec(3, 1, vec![8.0, -11.0, -3.0]).unwrap();
+        let x = solve(&a, &b).unwrap();
+        assert_eq!((x.rows, x.cols), (3, 1));
+        assert_approx_eq(&x.data, &[2.0, 3.0, -1.0]);
+    }
+
+    #[test]
+    fn test_solve_needs_pivoting() {
+        // y = 3, x = 2
+        let a = Matrix::from_vec(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+        let b = Matrix::from_vec(2, 1, vec![3.0, 2.0]).unwrap();
+        assert_approx_eq(&solve(&a, &b).unwrap().data, &[2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_solve_multiple_right_hand_sides() {
+        // Solving against the identity yields the inverse
+        let a = Matrix::from_vec(2, 2, vec![4.0, 7.0, 2.0, 6.0]).unwrap();
+        let identity = Matrix::identity(2);
+        let inverse = solve(&a, &identity).unwrap();
+        assert_approx_eq(&inverse.data, &[0.6, -0.7, -0.2, 0.4]);
+        assert_approx_eq(&(&a * &inverse).unwrap().data, &identity.data);
+    }
+
+    #[test]
+    fn test_solve_tiny_magnitude() {
+        // Same system as test_solve_needs_pivoting, scaled down
+        let a = Matrix::from_vec(2, 2, vec![0.0, 1e-13, 1e-13, 0.0]).unwrap();
+        let b = Matrix::from_vec(2, 1, vec![3e-13, 2e-13]).unwrap();
+        assert_approx_eq(&solve(&a, &b).unwrap().data, &[2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let over_determined = Matrix::zeros(3, 2);
+        let b = Matrix::zeros(3, 1);
+        assert!(matches!(
+            solve(&over_determined, &b),
+            Err(MatrixError::NotSquare { rows: 3, cols: 2 })
+        ));
+
+        let a = Matrix::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert!(matches!(
+            solve(&a, &b),
+            Err(MatrixError::DimensionMismatch {
+                operation: "solve",
+                ..
+            })
+        ));
+
+        let singular = Matrix::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        let b = Matrix::from_vec(2, 1, vec![1.0, 2.0]).unwrap();
+        assert!(matches!(solve(&singular, &b), Err(MatrixError::Singular)));
+    }
+}
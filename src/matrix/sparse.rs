@@ -0,0 +1,345 @@
+use thiserror::Error;
+
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SparseMatrixError {
+    #[error("Triplet #{index} at ({row}, {col}) lies outside a {rows}x{cols} matrix")]
+    TripletOutOfBounds {
+        index: usize,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    },
+    #[error("Index out of bounds: tried to access ({row}, {col}) in a {rows}x{cols} matrix")]
+    IndexOutOfBounds {
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    },
+    #[error("Cannot multiply a {left_dims:?} sparse matrix by a {right_dims:?} matrix")]
+    DimensionMismatch {
+        left_dims: (usize, usize),
+        right_dims: (usize, usize),
+    },
+}
+
+/// # Sparse Matrix in Compressed Sparse Row (CSR) Form
+///
+/// Only non-zero entries are stored, in three arrays:
+/// - `values`: the non-zeros, row by row, left to right
+/// - `col_indices`: the column of each value
+/// - `row_ptr`: `rows + 1` offsets; row `i` owns `values[row_ptr[i]..row_ptr[i + 1]]`
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::{Matrix, SparseMatrix};
+///
+/// // [0 5 0]
+/// // [0 0 0]
+/// // [7 0 8]
+/// let s = SparseMatrix::from_triplets(3, 3, &[(0, 1, 5), (2, 0, 7), (2, 2, 8)]).unwrap();
+/// assert_eq!(s.nnz(), 3);
+/// assert_eq!(s.get(2, 2).unwrap(), 8);
+/// assert_eq!(s.get(1, 1).unwrap(), 0);
+///
+/// let x = Matrix::from_vec(3, 1, vec![1, 1, 1]).unwrap();
+/// assert_eq!(s.mul_dense(&x).unwrap().data, vec![5, 0, 15]);
+/// ```
+///
+/// ## Trade-offs Against the Dense [`Matrix`]
+/// | Operation        | Dense         | CSR                            |
+/// |------------------|---------------|--------------------------------|
+/// | Memory           | O(rows·cols)  | O(nnz + rows)                  |
+/// | `get(i, j)`      | O(1)          | O(log nnz in row i)            |
+/// | `A * x`          | O(rows·cols)  | O(nnz · x.cols)                |
+/// | Insert a value   | O(1)          | O(nnz), arrays must shift      |
+///
+/// CSR pays off when most entries are zero (graphs, finite-element meshes)
+/// and for workloads dominated by multiplication; it is a poor fit for
+/// matrices that are built up one entry at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<T = f64> {
+    rows: usize,
+    cols: usize,
+    row_ptr: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    /// Builds a CSR matrix from `(row, col, value)` triplets in any order.
+    ///
+    /// Duplicate positions are summed (the usual COO convention) and
+    /// entries that end up zero are not stored.
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self, SparseMatrixError> {
+        if let Some((index, &(row, col, _))) = triplets
+            .iter()
+            .enumerate()
+            .find(|(_, &(row, col, _))| row >= rows || col >= cols)
+        {
+            return Err(SparseMatrixError::TripletOutOfBounds {
+                index,
+                row,
+                col,
+                rows,
+                cols,
+            });
+        }
+
+        let mut sorted = triplets.to_vec();
+        sorted.sort_by_key(|&(row, col, _)| (row, col));
+
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(sorted.len());
+        for (row, col, value) in sorted {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (row, col) => last.2 += value,
+                _ => merged.push((row, col, value)),
+            }
+        }
+        merged.retain(|&(_, _, value)| value != T::ZERO);
+
+        let mut row_ptr = vec![0; rows + 1];
+        for &(row, _, _) in &merged {
+            row_ptr[row + 1] += 1;
+        }
+        for i in 0..rows {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            row_ptr,
+            col_indices: merged.iter().map(|&(_, col, _)| col).collect(),
+            values: merged.iter().map(|&(_, _, value)| value).collect(),
+        })
+    }
+
+    /// Compresses a dense matrix, keeping only its non-zero entries
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        let mut row_ptr = Vec::with_capacity(dense.rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for row in dense.iter_rows() {
+            for (col, &value) in row.iter().enumerate() {
+                if value != T::ZERO {
+                    col_indices.push(col);
+                    values.push(value);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Self {
+            rows: dense.rows,
+            cols: dense.cols,
+            row_ptr,
+            col_indices,
+            values,
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut dense = Matrix::zeros(self.rows, self.cols);
+        for (row, col, value) in self.iter() {
+            dense[(row, col)] = value;
+        }
+        dense
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Number of stored (non-zero) entries
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Element `(row, col)`, zero when nothing is stored there
+    pub fn get(&self, row: usize, col: usize) -> Result<T, SparseMatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(SparseMatrixError::IndexOutOfBounds {
+                row,
+                col,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let range = self.row_ptr[row]..self.row_ptr[row + 1];
+        // Columns within a row are sorted, so binary search applies
+        Ok(match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(offset) => self.values[range.start + offset],
+            Err(_) => T::ZERO,
+        })
+    }
+
+    /// `(row, col, value)` for every stored entry in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.rows).flat_map(move |row| {
+            let range = self.row_ptr[row]..self.row_ptr[row + 1];
+            self.col_indices[range.clone()]
+                .iter()
+                .zip(&self.values[range])
+                .map(move |(&col, &value)| (row, col, value))
+        })
+    }
+
+    /// Sparse × dense product, touching only the stored entries
+    pub fn mul_dense(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, SparseMatrixError> {
+        if self.cols != rhs.rows {
+            return Err(SparseMatrixError::DimensionMismatch {
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::zeros(self.rows, rhs.cols);
+        for (row, k, value) in self.iter() {
+            // result row `row` += value * rhs row `k`
+            for j in 0..rhs.cols {
+                result.data[row * rhs.cols + j] += value * rhs.data[k * rhs.cols + j];
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Scalar> From<&Matrix<T>> for SparseMatrix<T> {
+    fn from(dense: &Matrix<T>) -> Self {
+        Self::from_dense(dense)
+    }
+}
+
+impl<T: Scalar> From<&SparseMatrix<T>> for Matrix<T> {
+    fn from(sparse: &SparseMatrix<T>) -> Self {
+        sparse.to_dense()
+    }
+}
+
+impl<T: Scalar> std::ops::Mul<&Matrix<T>> for &SparseMatrix<T> {
+    type Output = Result<Matrix<T>, SparseMatrixError>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.mul_dense(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SparseMatrix<i32> {
+        // [1 0 2 0]
+        // [0 0 0 0]
+        // [0 3 0 4]
+        SparseMatrix::from_triplets(3, 4, &[(2, 3, 4), (0, 0, 1), (2, 1, 3), (0, 2, 2)]).unwrap()
+    }
+
+    #[test]
+    fn test_from_triplets_layout() {
+        let s = sample();
+        assert_eq!(s.row_ptr, vec![0, 2, 2, 4]);
+        assert_eq!(s.col_indices, vec![0, 2, 1, 3]);
+        assert_eq!(s.values, vec![1, 2, 3, 4]);
+        assert_eq!((s.rows(), s.cols(), s.nnz()), (3, 4, 4));
+    }
+
+    #[test]
+    fn test_duplicates_are_summed_and_zeros_dropped() {
+        let s = SparseMatrix::from_triplets(2, 2, &[(0, 0, 1), (0, 0, 2), (1, 1, 5), (1, 1, -5)])
+            .unwrap();
+        assert_eq!(s.nnz(), 1);
+        assert_eq!(s.get(0, 0).unwrap(), 3);
+        assert_eq!(s.get(1, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_get() {
+        let s = sample();
+        let dense = s.to_dense();
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(s.get(i, j).unwrap(), dense[(i, j)]);
+            }
+        }
+        assert_eq!(
+            s.get(3, 0),
+            Err(SparseMatrixError::IndexOutOfBounds {
+                row: 3,
+                col: 0,
+                rows: 3,
+                cols: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_triplet_out_of_bounds() {
+        assert_eq!(
+            SparseMatrix::from_triplets(2, 2, &[(0, 0, 1.0), (1, 2, 1.0)]),
+            Err(SparseMatrixError::TripletOutOfBounds {
+                index: 1,
+                row: 1,
+                col: 2,
+                rows: 2,
+                cols: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Matrix::from_vec(3, 4, vec![1, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 4]).unwrap();
+        let sparse = SparseMatrix::from(&dense);
+        assert_eq!(sparse, sample());
+        assert_eq!(Matrix::from(&sparse), dense);
+
+        let empty = SparseMatrix::<f64>::from_triplets(0, 0, &[]).unwrap();
+        assert_eq!(empty.to_dense(), Matrix::zeros(0, 0));
+        let all_zero = SparseMatrix::from_dense(&Matrix::<i32>::zeros(3, 3));
+        assert_eq!((all_zero.nnz(), all_zero.row_ptr.len()), (0, 4));
+    }
+
+    #[test]
+    fn test_mul_dense_matches_dense_product() {
+        let s = sample();
+        let rhs = Matrix::random(4, 3, -9..9, 7);
+        let expected = (&s.to_dense() * &rhs).unwrap();
+        assert_eq!(s.mul_dense(&rhs).unwrap(), expected);
+        assert_eq!((&s * &rhs).unwrap(), expected);
+
+        assert_eq!(
+            s.mul_dense(&Matrix::zeros(3, 1)),
+            Err(SparseMatrixError::DimensionMismatch {
+                left_dims: (3, 4),
+                right_dims: (3, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_large_sparse_identity() {
+        let n = 1000;
+        let triplets: Vec<_> = (0..n).map(|i| (i, i, 1.0)).collect();
+        let identity = SparseMatrix::from_triplets(n, n, &triplets).unwrap();
+        assert_eq!(identity.nnz(), n);
+        let x = Matrix::random(n, 2, -1.0..1.0, 1);
+        assert_eq!(identity.mul_dense(&x).unwrap(), x);
+    }
+}
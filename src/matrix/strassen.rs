@@ -0,0 +1,219 @@
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// Blocks of this size or smaller are multiplied with the naive kernel
+pub const STRASSEN_CUTOFF: usize = 64;
+
+impl<T: Scalar> Matrix<T> {
+    /// # Strassen Matrix Multiplication
+    ///
+    /// ## Problem Statement
+    /// Multiply two matrices with fewer than the n³ scalar multiplications
+    /// of the schoolbook algorithm.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// let a = Matrix::random(100, 80, -10..10, 1);
+    /// let b = Matrix::random(80, 120, -10..10, 2);
+    /// assert_eq!(a.mul_strassen(&b).unwrap(), (&a * &b).unwrap());
+    /// ```
+    ///
+    /// ## Approach
+    /// Pad both operands with zeros to a square power-of-two size, split
+    /// each into four quadrants, and combine seven (instead of eight)
+    /// recursive block products:
+    /// ```text
+    /// M1 = (A11 + A22)(B11 + B22)    C11 = M1 + M4 - M5 + M7
+    /// M2 = (A21 + A22) B11           C12 = M3 + M5
+    /// M3 = A11 (B12 - B22)           C21 = M2 + M4
+    /// M4 = A22 (B21 - B11)           C22 = M1 - M2 + M3 + M6
+    /// M5 = (A11 + A12) B22
+    /// M6 = (A21 - A11)(B11 + B12)
+    /// M7 = (A12 - A22)(B21 + B22)
+    /// ```
+    /// Blocks at or below [`STRASSEN_CUTOFF`] use the naive kernel, where
+    /// its lower constant factor wins.
+    ///
+    /// ## Complexity
+    /// - Time: O(n^log2(7)) ≈ O(n^2.81)
+    /// - Space: O(n²) for padded copies and temporaries
+    ///
+    /// ## Key Points
+    /// 1. Saving one multiplication per level pays for the extra O(n²)
+    ///    additions only once blocks are large, hence the cutoff
+    /// 2. Padding to the next power of two can nearly quadruple the work
+    ///    for sizes just above one (e.g. 513)
+    ///
+    /// ## Common Pitfalls
+    /// 1. Recursing down to 1x1 blocks, which is far slower than naive
+    /// 2. Expecting bit-identical floating point results: the different
+    ///    order of operations changes rounding (integers match exactly)
+    pub fn mul_strassen(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.mul_strassen_with_cutoff(rhs, STRASSEN_CUTOFF)
+    }
+
+    /// [`Matrix::mul_strassen`] with an explicit cutoff (at least 1), for
+    /// experimenting with where the crossover lies
+    pub fn mul_strassen_with_cutoff(
+        &self,
+        rhs: &Matrix<T>,
+        cutoff: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let n = self.rows.max(self.cols).max(rhs.cols).next_power_of_two();
+        let a = pad(self, n);
+        let b = pad(rhs, n);
+        let c = strassen(&a, &b, n, cutoff.max(1));
+
+        Ok(Matrix::from_fn(self.rows, rhs.cols, |i, j| c[i * n + j]))
+    }
+}
+
+/// Copies `m` into the top-left corner of an `n x n` zero matrix
+fn pad<T: Scalar>(m: &Matrix<T>, n: usize) -> Vec<T> {
+    let mut padded = vec![T::ZERO; n * n];
+    for (i, row) in m.iter_rows().enumerate() {
+        padded[i * n..i * n + m.cols].copy_from_slice(row);
+    }
+    padded
+}
+
+fn strassen<T: Scalar>(a: &[T], b: &[T], n: usize, cutoff: usize) -> Vec<T> {
+    if n <= cutoff {
+        return naive(a, b, n);
+    }
+
+    let h = n / 2;
+    let [a11, a12, a21, a22] = quadrants(a, n);
+    let [b11, b12, b21, b22] = quadrants(b, n);
+
+    let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22), h, cutoff);
+    let m2 = strassen(&add(&a21, &a22), &b11, h, cutoff);
+    let m3 = strassen(&a11, &sub(&b12, &b22), h, cutoff);
+    let m4 = strassen(&a22, &sub(&b21, &b11), h, cutoff);
+    let m5 = strassen(&add(&a11, &a12), &b22, h, cutoff);
+    let m6 = strassen(&sub(&a21, &a11), &add(&b11, &b12), h, cutoff);
+    let m7 = strassen(&sub(&a12, &a22), &add(&b21, &b22), h, cutoff);
+
+    let mut c = vec![T::ZERO; n * n];
+    for i in 0..h {
+        for j in 0..h {
+            let k = i * h + j;
+            c[i * n + j] = m1[k] + m4[k] - m5[k] + m7[k];
+            c[i * n + j + h] = m3[k] + m5[k];
+            c[(i + h) * n + j] = m2[k] + m4[k];
+            c[(i + h) * n + j + h] = m1[k] - m2[k] + m3[k] + m6[k];
+        }
+    }
+    c
+}
+
+/// The four `n/2 x n/2` quadrants of an `n x n` block, in row-major order
+fn quadrants<T: Scalar>(m: &[T], n: usize) -> [Vec<T>; 4] {
+    let h = n / 2;
+    let block = |row0: usize, col0: usize| {
+        (0..h)
+            .flat_map(|i| {
+                m[(row0 + i) * n + col0..(row0 + i) * n + col0 + h]
+                    .iter()
+                    .copied()
+            })
+            .collect()
+    };
+    [block(0, 0), block(0, h), block(h, 0), block(h, h)]
+}
+
+fn naive<T: Scalar>(a: &[T], b: &[T], n: usize) -> Vec<T> {
+    let mut c = vec![T::ZERO; n * n];
+    // i-k-j order walks both `b` and `c` row-wise, which is cache friendly
+    for i in 0..n {
+        for k in 0..n {
+            let aik = a[i * n + k];
+            for j in 0..n {
+                c[i * n + j] += aik * b[k * n + j];
+            }
+        }
+    }
+    c
+}
+
+fn add<T: Scalar>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter().zip(b).map(|(&x, &y)| x + y).collect()
+}
+
+fn sub<T: Scalar>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter().zip(b).map(|(&x, &y)| x - y).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_naive_on_integers() {
+        // Cutoff 1 forces recursion all the way down
+        for (rows, inner, cols) in [(1, 1, 1), (2, 2, 2), (3, 5, 2), (7, 7, 7), (8, 3, 9)] {
+            let a = Matrix::random(rows, inner, -20_i64..20, 1);
+            let b = Matrix::random(inner, cols, -20_i64..20, 2);
+            let expected = (&a * &b).unwrap();
+            for cutoff in [1, 2, 4, STRASSEN_CUTOFF] {
+                assert_eq!(
+                    a.mul_strassen_with_cutoff(&b, cutoff).unwrap(),
+                    expected,
+                    "{rows}x{inner} * {inner}x{cols}, cutoff {cutoff}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_naive_on_floats() {
+        let a: Matrix = Matrix::random(70, 65, -1.0..1.0, 3);
+        let b = Matrix::random(65, 90, -1.0..1.0, 4);
+        let expected = (&a * &b).unwrap();
+        let actual = a.mul_strassen_with_cutoff(&b, 8).unwrap();
+        assert_eq!((actual.rows(), actual.cols()), (70, 90));
+        for (x, y) in actual.iter().zip(expected.iter()) {
+            assert!((x - y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_identity_and_empty() {
+        let m = Matrix::random(5, 5, -9_i32..9, 5);
+        assert_eq!(
+            m.mul_strassen_with_cutoff(&Matrix::identity(5), 1).unwrap(),
+            m
+        );
+
+        let empty = Matrix::<f64>::zeros(0, 3)
+            .mul_strassen(&Matrix::zeros(3, 0))
+            .unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+        let zero_inner = Matrix::<i32>::zeros(2, 0)
+            .mul_strassen(&Matrix::zeros(0, 2))
+            .unwrap();
+        assert_eq!(zero_inner, Matrix::zeros(2, 2));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let a = Matrix::<f64>::zeros(2, 3);
+        assert!(matches!(
+            a.mul_strassen(&a),
+            Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,278 @@
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// # Zero-Copy Transpose
+///
+/// The transpose of a [`Matrix`] read straight from the original buffer:
+/// element `(i, j)` of the view is element `(j, i)` of the matrix, so the
+/// view walks the storage with the row and column strides swapped.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::Matrix;
+///
+/// let a: Matrix<i32> = "1 2 3; 4 5 6".parse().unwrap();
+/// let at = a.t();
+/// assert_eq!((at.rows(), at.cols()), (3, 2));
+/// assert_eq!(at[(2, 1)], 6);
+///
+/// // A · Aᵀ without materializing Aᵀ
+/// let gram = (&a * &a.t()).unwrap();
+/// assert_eq!(gram, "14 32; 32 77".parse().unwrap());
+/// ```
+///
+/// ## Compared to [`Matrix::transpose`]
+/// - `transpose` costs O(rows · cols) time and a new allocation up front,
+///   then every read is contiguous
+/// - `t` is O(1) and allocation-free, but walking a row of the view
+///   strides through the parent by `parent.cols` elements per step
+///
+/// ## Key Points
+/// 1. The lifetime `'a` ties the view to the matrix it borrows: the parent
+///    cannot be mutated or dropped while the view is alive
+/// 2. `A · Bᵀ` through the view is cache-friendly: output `(i, j)` is the
+///    dot product of row `i` of `A` and row `j` of `B`, both contiguous
+/// 3. Transposing the view again gives back the original matrix
+///
+/// ## Common Pitfalls
+/// 1. Computing the offset as `row * cols + col` with the view's `cols`;
+///    the stride between view rows is 1 and between view columns is
+///    `parent.cols`
+#[derive(Debug)]
+pub struct TransposedView<'a, T = f64> {
+    parent: &'a Matrix<T>,
+}
+
+impl<T> Clone for TransposedView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TransposedView<'_, T> {}
+
+impl<T: Scalar> Matrix<T> {
+    /// The transpose as a borrowed view, without copying
+    pub fn t(&self) -> TransposedView<'_, T> {
+        TransposedView { parent: self }
+    }
+}
+
+impl<'a, T: Scalar> TransposedView<'a, T> {
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.parent.cols()
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.parent.rows()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        if row >= self.rows() || col >= self.cols() {
+            return Err(MatrixError::IndexOutOfBounds {
+                row,
+                col,
+                rows: self.rows(),
+                cols: self.cols(),
+            });
+        }
+        Ok(self.parent.data[self.offset(row, col)])
+    }
+
+    /// Transposes back: the borrowed original matrix
+    pub fn t(&self) -> &'a Matrix<T> {
+        self.parent
+    }
+
+    /// All elements of the view in row-major order, i.e. the original
+    /// matrix in column-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let parent = self.parent;
+        (0..parent.cols).flat_map(move |col| {
+            (0..parent.rows).map(move |row| &parent.data[row * parent.cols + col])
+        })
+    }
+
+    /// Copies the view into a new matrix, equal to [`Matrix::transpose`]
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows(),
+            cols: self.cols(),
+            data: self.iter().copied().collect(),
+        }
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, col: usize) -> usize {
+        col * self.parent.cols + row
+    }
+}
+
+impl<T: Scalar> std::ops::Index<(usize, usize)> for TransposedView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows() && col < self.cols(),
+            "index ({row}, {col}) out of bounds for {}x{} transposed view",
+            self.rows(),
+            self.cols()
+        );
+        &self.parent.data[self.offset(row, col)]
+    }
+}
+
+fn dimension_mismatch(left_dims: (usize, usize), right_dims: (usize, usize)) -> MatrixError {
+    MatrixError::DimensionMismatch {
+        operation: "multiplication",
+        left_dims,
+        right_dims,
+    }
+}
+
+/// `Aᵀ · B`: output `(i, j)` is column `i` of `A` dotted with column `j` of `B`
+impl<T: Scalar> std::ops::Mul<&Matrix<T>> for &TransposedView<'_, T> {
+    type Output = Result<Matrix<T>, MatrixError>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        if self.cols() != rhs.rows {
+            return Err(dimension_mismatch(
+                (self.rows(), self.cols()),
+                (rhs.rows, rhs.cols),
+            ));
+        }
+
+        // Accumulate outer products of row k of A and row k of B so both
+        // inputs are read row by row
+        let mut result = Matrix::zeros(self.rows(), rhs.cols);
+        for (a_row, b_row) in self.parent.iter_rows().zip(rhs.iter_rows()) {
+            for (out_row, &left) in result.iter_rows_mut().zip(a_row) {
+                for (out, &right) in out_row.iter_mut().zip(b_row) {
+                    *out += left * right;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// `A · Bᵀ`: output `(i, j)` is row `i` of `A` dotted with row `j` of `B`
+impl<T: Scalar> std::ops::Mul<&TransposedView<'_, T>> for &Matrix<T> {
+    type Output = Result<Matrix<T>, MatrixError>;
+
+    fn mul(self, rhs: &TransposedView<'_, T>) -> Self::Output {
+        if self.cols != rhs.rows() {
+            return Err(dimension_mismatch(
+                (self.rows, self.cols),
+                (rhs.rows(), rhs.cols()),
+            ));
+        }
+
+        let mut result = Matrix::zeros(self.rows, rhs.cols());
+        for (a_row, out_row) in self.iter_rows().zip(result.iter_rows_mut()) {
+            for (out, b_row) in out_row.iter_mut().zip(rhs.parent.iter_rows()) {
+                for (&left, &right) in a_row.iter().zip(b_row) {
+                    *out += left * right;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Scalar> PartialEq for TransposedView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows() == other.rows() && self.cols() == other.cols() && self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect() -> Matrix<i32> {
+        "1 2 3; 4 5 6".parse().unwrap()
+    }
+
+    #[test]
+    fn test_view_matches_transpose() {
+        let m = rect();
+        let t = m.t();
+        assert_eq!((t.rows(), t.cols()), (3, 2));
+        assert_eq!(t.to_matrix(), m.transpose());
+        assert_eq!(
+            t.iter().copied().collect::<Vec<_>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        for (i, j, &value) in m.transpose().indexed_iter() {
+            assert_eq!(t.get(i, j).unwrap(), value);
+            assert_eq!(t[(i, j)], value);
+        }
+        assert!(std::ptr::eq(t.t(), &m));
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let m = rect();
+        assert!(matches!(
+            m.t().get(0, 2),
+            Err(MatrixError::IndexOutOfBounds {
+                row: 0,
+                col: 2,
+                rows: 3,
+                cols: 2
+            })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for 3x2 transposed view")]
+    fn test_index_out_of_bounds_panics() {
+        let m = rect();
+        let _ = m.t()[(3, 0)];
+    }
+
+    #[test]
+    fn test_multiplication_matches_copies() {
+        let a = rect();
+        let b: Matrix<i32> = "1 0 2; 0 1 -1".parse().unwrap();
+
+        let expected = (&a.transpose() * &b).unwrap();
+        assert_eq!((&a.t() * &b).unwrap(), expected);
+
+        let expected = (&a * &b.transpose()).unwrap();
+        assert_eq!((&a * &b.t()).unwrap(), expected);
+        assert_eq!((&a * &a.t()).unwrap(), "14 32; 32 77".parse().unwrap());
+    }
+
+    #[test]
+    fn test_multiplication_dimension_mismatch() {
+        let a = rect();
+        let square: Matrix<i32> = Matrix::identity(3);
+        assert!(matches!(
+            &a.t() * &square,
+            Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                left_dims: (3, 2),
+                right_dims: (3, 3)
+            })
+        ));
+        assert!(matches!(
+            &Matrix::identity(2) * &a.t(),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_empty_and_vector_shapes() {
+        let column: Matrix<i32> = "1; 2; 3".parse().unwrap();
+        assert_eq!(column.t().to_matrix(), "1 2 3".parse().unwrap());
+        assert_eq!((&column.t() * &column).unwrap().data, vec![14]);
+
+        let empty: Matrix<i32> = Matrix::zeros(0, 3);
+        assert_eq!((empty.t().rows(), empty.t().cols()), (3, 0));
+        assert_eq!(empty.t().iter().count(), 0);
+    }
+}
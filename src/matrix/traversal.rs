@@ -0,0 +1,287 @@
+//! Custom iterators for the classic grid traversal orders.
+
+use std::iter::FusedIterator;
+
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// # Spiral Order Traversal
+    ///
+    /// ## Problem Statement
+    /// Visit every element clockwise from the top-left corner, spiralling
+    /// inwards.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// // [1 2 3]
+    /// // [4 5 6]
+    /// // [7 8 9]
+    /// let m = Matrix::from_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    /// let order: Vec<i32> = m.spiral_iter().copied().collect();
+    /// assert_eq!(order, vec![1, 2, 3, 6, 9, 8, 7, 4, 5]);
+    /// ```
+    ///
+    /// ## Approach
+    /// Keep four shrinking bounds. Walk in the current direction until the
+    /// next step would cross a bound, then pull in the bound just finished
+    /// and turn clockwise.
+    ///
+    /// ## Complexity
+    /// - Time: O(1) per element
+    /// - Space: O(1), no visited set
+    ///
+    /// ## Common Pitfalls
+    /// 1. Visiting the last row or column twice in non-square matrices; the
+    ///    element count, not the bounds, decides when to stop
+    pub fn spiral_iter(&self) -> SpiralIter<'_, T> {
+        SpiralIter {
+            matrix: self,
+            top: 0,
+            bottom: self.rows,
+            left: 0,
+            right: self.cols,
+            row: 0,
+            col: 0,
+            direction: Direction::Right,
+            remaining: self.data.len(),
+        }
+    }
+
+    /// # Anti-Diagonal Traversal
+    ///
+    /// ## Problem Statement
+    /// Visit elements one anti-diagonal (`row + col == d`) at a time, for
+    /// `d = 0, 1, ...`, each from its top-right end to its bottom-left end.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::matrix::Matrix;
+    ///
+    /// // [1 2 3]
+    /// // [4 5 6]
+    /// let m = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let order: Vec<i32> = m.diagonal_iter().copied().collect();
+    /// assert_eq!(order, vec![1, 2, 4, 3, 5, 6]);
+    /// ```
+    ///
+    /// ## Approach
+    /// Anti-diagonal `d` holds rows `max(0, d - (cols - 1))..=min(d, rows - 1)`,
+    /// with `col = d - row`. Walk the rows, then move to `d + 1`.
+    ///
+    /// ## Complexity
+    /// - Time: O(1) per element
+    /// - Space: O(1)
+    pub fn diagonal_iter(&self) -> DiagonalIter<'_, T> {
+        DiagonalIter {
+            matrix: self,
+            diagonal: 0,
+            row: 0,
+            remaining: self.data.len(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+/// Iterator returned by [`Matrix::spiral_iter`]
+#[derive(Debug, Clone)]
+pub struct SpiralIter<'a, T> {
+    matrix: &'a Matrix<T>,
+    // Unvisited region is rows top..bottom, cols left..right
+    top: usize,
+    bottom: usize,
+    left: usize,
+    right: usize,
+    row: usize,
+    col: usize,
+    direction: Direction,
+    remaining: usize,
+}
+
+impl<'a, T: Scalar> Iterator for SpiralIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = &self.matrix.data[self.row * self.matrix.cols + self.col];
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.advance();
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Scalar> SpiralIter<'_, T> {
+    // Only called while elements remain, so the turns never step outside
+    // the unvisited region
+    fn advance(&mut self) {
+        match self.direction {
+            Direction::Right if self.col + 1 < self.right => self.col += 1,
+            Direction::Right => {
+                self.top += 1;
+                self.direction = Direction::Down;
+                self.row += 1;
+            }
+            Direction::Down if self.row + 1 < self.bottom => self.row += 1,
+            Direction::Down => {
+                self.right -= 1;
+                self.direction = Direction::Left;
+                self.col -= 1;
+            }
+            Direction::Left if self.col > self.left => self.col -= 1,
+            Direction::Left => {
+                self.bottom -= 1;
+                self.direction = Direction::Up;
+                self.row -= 1;
+            }
+            Direction::Up if self.row > self.top => self.row -= 1,
+            Direction::Up => {
+                self.left += 1;
+                self.direction = Direction::Right;
+                self.col += 1;
+            }
+        }
+    }
+}
+
+impl<T: Scalar> ExactSizeIterator for SpiralIter<'_, T> {}
+impl<T: Scalar> FusedIterator for SpiralIter<'_, T> {}
+
+/// Iterator returned by [`Matrix::diagonal_iter`]
+#[derive(Debug, Clone)]
+pub struct DiagonalIter<'a, T> {
+    matrix: &'a Matrix<T>,
+    diagonal: usize,
+    row: usize,
+    remaining: usize,
+}
+
+impl<'a, T: Scalar> Iterator for DiagonalIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (rows, cols) = (self.matrix.rows, self.matrix.cols);
+        let item = &self.matrix.data[self.row * cols + (self.diagonal - self.row)];
+        self.remaining -= 1;
+
+        if self.row + 1 < rows && self.row < self.diagonal {
+            self.row += 1;
+        } else {
+            self.diagonal += 1;
+            self.row = self.diagonal.saturating_sub(cols - 1);
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Scalar> ExactSizeIterator for DiagonalIter<'_, T> {}
+impl<T: Scalar> FusedIterator for DiagonalIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting(rows: usize, cols: usize) -> Matrix<i32> {
+        Matrix::from_fn(rows, cols, |i, j| (i * cols + j) as i32 + 1)
+    }
+
+    fn spiral(rows: usize, cols: usize) -> Vec<i32> {
+        counting(rows, cols).spiral_iter().copied().collect()
+    }
+
+    fn diagonal(rows: usize, cols: usize) -> Vec<i32> {
+        counting(rows, cols).diagonal_iter().copied().collect()
+    }
+
+    #[test]
+    fn test_spiral_shapes() {
+        assert_eq!(spiral(1, 1), vec![1]);
+        assert_eq!(spiral(1, 4), vec![1, 2, 3, 4]);
+        assert_eq!(spiral(4, 1), vec![1, 2, 3, 4]);
+        assert_eq!(spiral(2, 2), vec![1, 2, 4, 3]);
+        assert_eq!(spiral(3, 4), vec![1, 2, 3, 4, 8, 12, 11, 10, 9, 5, 6, 7]);
+        assert_eq!(spiral(4, 3), vec![1, 2, 3, 6, 9, 12, 11, 10, 7, 4, 5, 8]);
+        assert_eq!(
+            spiral(4, 4),
+            vec![1, 2, 3, 4, 8, 12, 16, 15, 14, 13, 9, 5, 6, 7, 11, 10]
+        );
+    }
+
+    #[test]
+    fn test_spiral_visits_every_element_once() {
+        for rows in 0..7 {
+            for cols in 0..7 {
+                let mut order = spiral(rows, cols);
+                assert_eq!(order.len(), rows * cols);
+                order.sort_unstable();
+                assert_eq!(order, counting(rows, cols).data, "{rows}x{cols}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_diagonal_shapes() {
+        assert_eq!(diagonal(1, 1), vec![1]);
+        assert_eq!(diagonal(1, 3), vec![1, 2, 3]);
+        assert_eq!(diagonal(3, 1), vec![1, 2, 3]);
+        assert_eq!(diagonal(3, 3), vec![1, 2, 4, 3, 5, 7, 6, 8, 9]);
+        assert_eq!(diagonal(3, 2), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_diagonal_groups_by_index_sum() {
+        for rows in 0..6 {
+            for cols in 0..6 {
+                let m = counting(rows, cols);
+                let sums: Vec<usize> = m
+                    .diagonal_iter()
+                    .map(|&v| {
+                        let k = (v - 1) as usize;
+                        k / cols + k % cols
+                    })
+                    .collect();
+                assert_eq!(sums.len(), rows * cols);
+                assert!(sums.windows(2).all(|w| w[0] <= w[1]), "{rows}x{cols}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_size_and_fused() {
+        let m = counting(3, 4);
+        let mut it = m.spiral_iter();
+        assert_eq!(it.len(), 12);
+        it.nth(10);
+        assert_eq!(it.len(), 1);
+        assert!(it.next().is_some());
+        assert_eq!((it.next(), it.next()), (None, None));
+
+        let mut it = m.diagonal_iter();
+        assert_eq!(it.len(), 12);
+        assert_eq!(it.by_ref().count(), 12);
+        assert_eq!(it.next(), None);
+    }
+}
@@ -0,0 +1,301 @@
+use std::ops::Range;
+
+use super::matrix::{Matrix, MatrixError};
+use super::scalar::Scalar;
+
+/// # Borrowed Submatrix View
+///
+/// A rectangular window into a [`Matrix`] that reads the parent's storage
+/// directly instead of copying it.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::Matrix;
+///
+/// let m = Matrix::from_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+/// let block = m.view(1..3, 1..3).unwrap();
+/// assert_eq!(block[(0, 0)], 5);
+/// assert_eq!(block.iter().copied().collect::<Vec<_>>(), vec![5, 6, 8, 9]);
+/// ```
+///
+/// ## Key Points
+/// 1. The lifetime `'a` ties the view to the matrix it borrows: the parent
+///    cannot be mutated or dropped while a view is alive
+/// 2. A view's row is still a contiguous slice of the parent, but moving to
+///    the next row skips `parent.cols` elements, not `view.cols`
+/// 3. Views are `Copy`, so blocked algorithms can pass them around freely
+///
+/// ## Common Pitfalls
+/// 1. Indexing the parent's `data` with view coordinates and forgetting the
+///    row/column offsets
+#[derive(Debug)]
+pub struct MatrixView<'a, T = f64> {
+    matrix: &'a Matrix<T>,
+    row_offset: usize,
+    col_offset: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<T: Scalar> Matrix<T> {
+    /// Borrows the block `row_range x col_range` without copying.
+    ///
+    /// Returns `MatrixError::IndexOutOfBounds` if either range is reversed or
+    /// extends past the matrix.
+    pub fn view(
+        &self,
+        row_range: Range<usize>,
+        col_range: Range<usize>,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        self.as_view().view(row_range, col_range)
+    }
+
+    /// The whole matrix as a view
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            matrix: self,
+            row_offset: 0,
+            col_offset: 0,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// A view of a block of this view; ranges are relative to the view
+    pub fn view(
+        &self,
+        row_range: Range<usize>,
+        col_range: Range<usize>,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        if row_range.start > row_range.end
+            || col_range.start > col_range.end
+            || row_range.end > self.rows
+            || col_range.end > self.cols
+        {
+            return Err(MatrixError::IndexOutOfBounds {
+                row: row_range.end,
+                col: col_range.end,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(MatrixView {
+            matrix: self.matrix,
+            row_offset: self.row_offset + row_range.start,
+            col_offset: self.col_offset + col_range.start,
+            rows: row_range.len(),
+            cols: col_range.len(),
+        })
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds {
+                row,
+                col,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(self.matrix.data[self.offset(row, col)])
+    }
+
+    /// Row `row` of the view as a slice of the parent's storage.
+    /// Panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(
+            row < self.rows,
+            "row {row} out of bounds for {}x{} view",
+            self.rows,
+            self.cols
+        );
+        let start = self.offset(row, 0);
+        &self.matrix.data[start..start + self.cols]
+    }
+
+    /// All elements of the view in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        (0..view.rows).flat_map(move |row| view.row(row).iter())
+    }
+
+    /// Copies the viewed block into a new matrix
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.iter().copied().collect(),
+        }
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, col: usize) -> usize {
+        (self.row_offset + row) * self.matrix.cols + self.col_offset + col
+    }
+}
+
+impl<T: Scalar> std::ops::Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds for {}x{} view",
+            self.rows,
+            self.cols
+        );
+        &self.matrix.data[self.offset(row, col)]
+    }
+}
+
+impl<T: Scalar> std::ops::Mul for &MatrixView<'_, T> {
+    type Output = Result<Matrix<T>, MatrixError>;
+
+    fn mul(self, rhs: &MatrixView<'_, T>) -> Self::Output {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                left_dims: (self.rows, self.cols),
+                right_dims: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            let left_row = self.row(i);
+            for j in 0..rhs.cols {
+                let mut sum = T::ZERO;
+                for (k, &left) in left_row.iter().enumerate() {
+                    sum += left * rhs[(k, j)];
+                }
+                result.data[i * rhs.cols + j] = sum;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Scalar> PartialEq for MatrixView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.cols == other.cols && self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Matrix<i32> {
+        // 4x4 with value 10 * row + col
+        let data = (0..4)
+            .flat_map(|i| (0..4).map(move |j| 10 * i + j))
+            .collect();
+        Matrix::from_vec(4, 4, data).unwrap()
+    }
+
+    #[test]
+    fn test_view_reads_parent() {
+        let m = grid();
+        let v = m.view(1..3, 2..4).unwrap();
+        assert_eq!((v.rows(), v.cols()), (2, 2));
+        assert_eq!(v.get(0, 0).unwrap(), 12);
+        assert_eq!(v[(1, 1)], 23);
+        assert_eq!(v.row(1), &[22, 23]);
+        assert_eq!(v.to_matrix().data, vec![12, 13, 22, 23]);
+        assert!(matches!(
+            v.get(2, 0),
+            Err(MatrixError::IndexOutOfBounds {
+                row: 2,
+                col: 0,
+                rows: 2,
+                cols: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_nested_views() {
+        let m = grid();
+        let outer = m.view(1..4, 1..4).unwrap();
+        let inner = outer.view(1..3, 0..2).unwrap();
+        assert_eq!(
+            inner.iter().copied().collect::<Vec<_>>(),
+            vec![21, 22, 31, 32]
+        );
+        assert_eq!(inner, m.view(2..4, 1..3).unwrap());
+        assert!(outer.view(0..4, 0..1).is_err());
+    }
+
+    #[test]
+    fn test_empty_and_invalid_ranges() {
+        let m = grid();
+        let empty = m.view(2..2, 0..4).unwrap();
+        assert_eq!(empty.rows(), 0);
+        assert_eq!(empty.iter().count(), 0);
+
+        assert!(m.view(0..5, 0..1).is_err());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = m.view(3..1, 0..1);
+        assert!(matches!(
+            reversed,
+            Err(MatrixError::IndexOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn test_view_multiplication_matches_copies() {
+        let m = grid();
+        let a = m.view(0..2, 1..4).unwrap();
+        let b = m.view(1..4, 0..2).unwrap();
+        let expected = (&a.to_matrix() * &b.to_matrix()).unwrap();
+        assert_eq!((&a * &b).unwrap(), expected);
+        assert!(matches!(
+            &a * &a,
+            Err(MatrixError::DimensionMismatch {
+                operation: "multiplication",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_blocked_multiplication() {
+        // Multiply 4x4 matrices block by block and compare with the direct product
+        let m: Matrix = Matrix::from_vec(4, 4, (0..16).map(f64::from).collect()).unwrap();
+        let direct = (&m * &m).unwrap();
+        let mut blocked = Matrix::zeros(4, 4);
+        for bi in [0..2, 2..4] {
+            for bj in [0..2, 2..4] {
+                for bk in [0..2, 2..4] {
+                    let a = m.view(bi.clone(), bk.clone()).unwrap();
+                    let b = m.view(bk, bj.clone()).unwrap();
+                    let partial = (&a * &b).unwrap();
+                    for (i, j, &value) in partial.indexed_iter() {
+                        blocked[(bi.start + i, bj.start + j)] += value;
+                    }
+                }
+            }
+        }
+        assert_eq!(blocked, direct);
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+
+/// Rough difficulty rating, in interview terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Metadata describing one exercise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExerciseInfo {
+    /// Fully qualified name, e.g. `vector::merge_intervals`; matches the
+    /// names used by the verifier, grader and curriculum
+    pub name: &'static str,
+    pub difficulty: Difficulty,
+    pub topics: &'static [&'static str],
+    /// Code-free hints, ordered from gentlest to most revealing
+    pub hints: &'static [&'static str],
+}
+
+impl ExerciseInfo {
+    /// The crate module the exercise belongs to (the part before `::`)
+    pub fn module(&self) -> &'static str {
+        self.name
+            .split_once("::")
+            .map_or(self.name, |(module, _)| module)
+    }
+
+    /// The `n`-th hint (0-based), if there are that many
+    pub fn hint(&self, n: usize) -> Option<&'static str> {
+        self.hints.get(n).copied()
+    }
+}
+
+inventory::collect!(ExerciseInfo);
+
+/// Implemented by the marker types that [`exercise!`](crate::exercise) generates.
+pub trait Exercise {
+    const INFO: ExerciseInfo;
+
+    /// Function pointer type of the solution
+    type Solver: Copy;
+
+    const SOLVER: Self::Solver;
+}
+
+/// Every registered exercise, sorted by name
+pub fn all() -> Vec<&'static ExerciseInfo> {
+    let mut infos: Vec<_> = inventory::iter::<ExerciseInfo>.into_iter().collect();
+    infos.sort_unstable_by_key(|info| info.name);
+    infos
+}
+
+pub fn find(name: &str) -> Option<&'static ExerciseInfo> {
+    inventory::iter::<ExerciseInfo>
+        .into_iter()
+        .find(|info| info.name == name)
+}
+
+/// Registered exercises of one module, sorted by name
+pub fn by_module(module: &str) -> Vec<&'static ExerciseInfo> {
+    all()
+        .into_iter()
+        .filter(|info| info.module() == module)
+        .collect()
+}
+
+/// Registered exercises tagged with `topic`, sorted by name
+pub fn by_topic(topic: &str) -> Vec<&'static ExerciseInfo> {
+    all()
+        .into_iter()
+        .filter(|info| info.topics.contains(&topic))
+        .collect()
+}
+
+/// Declares an exercise and registers it with the [`registry`](crate::registry).
+///
+/// Generates a unit struct implementing [`Exercise`], whose `SOLVER` is the
+/// given function coerced to the given function pointer type.
+///
+/// ```
+/// use rust_ds_learning::exercise;
+/// use rust_ds_learning::registry::{self, Exercise};
+///
+/// fn double(x: i32) -> i32 {
+///     x * 2
+/// }
+///
+/// exercise! {
+///     /// Multiply by two
+///     pub struct DoubleExercise {
+///         name: "demo::double",
+///         difficulty: Easy,
+///         topics: ["arithmetic"],
+///         hints: ["What is x + x?"],
+///         solver: double as fn(i32) -> i32,
+///     }
+/// }
+///
+/// assert_eq!((DoubleExercise::SOLVER)(21), 42);
+/// assert_eq!(registry::find("demo::double"), Some(&DoubleExercise::INFO));
+/// ```
+#[macro_export]
+macro_rules! exercise {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $ty:ident {
+            name: $name:literal,
+            difficulty: $difficulty:ident,
+            topics: [$($topic:literal),* $(,)?],
+            hints: [$($hint:literal),* $(,)?],
+            solver: $solver:path as $solver_ty:ty $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $vis struct $ty;
+
+        impl $crate::registry::Exercise for $ty {
+            const INFO: $crate::registry::ExerciseInfo = $crate::registry::ExerciseInfo {
+                name: $name,
+                difficulty: $crate::registry::Difficulty::$difficulty,
+                topics: &[$($topic),*],
+                hints: &[$($hint),*],
+            };
+            type Solver = $solver_ty;
+            const SOLVER: Self::Solver = $solver;
+        }
+
+        $crate::registry::inventory::submit! {
+            <$ty as $crate::registry::Exercise>::INFO
+        }
+    };
+}
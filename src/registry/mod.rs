@@ -0,0 +1,23 @@
+//! Exercise registry
+//!
+//! Modules declare their exercises with the [`exercise!`](crate::exercise)
+//! macro, which both implements [`Exercise`] for a marker type and registers
+//! its [`ExerciseInfo`] here. No central list has to be kept in sync:
+//!
+//! ```
+//! use rust_ds_learning::registry;
+//!
+//! let info = registry::find("vector::merge_intervals").unwrap();
+//! assert_eq!(info.module(), "vector");
+//! assert!(!info.hints.is_empty());
+//! ```
+
+mod exercise;
+#[cfg(test)]
+mod tests;
+
+pub use exercise::*;
+
+// Used by `exercise!` so that callers don't need their own dependency
+#[doc(hidden)]
+pub use inventory;
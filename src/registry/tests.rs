@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+
+use crate::curriculum::Curriculum;
+use crate::registry::{self, Difficulty, Exercise};
+use crate::vector::{MaxProductExercise, MergeIntervalsExercise};
+use crate::verify::{self, Verify};
+
+mod registration_tests {
+    use super::*;
+
+    #[test]
+    fn test_names_are_unique() {
+        let all = registry::all();
+        let names: BTreeSet<_> = all.iter().map(|info| info.name).collect();
+        assert_eq!(names.len(), all.len());
+    }
+
+    #[test]
+    fn test_all_is_sorted() {
+        let names: Vec<_> = registry::all().iter().map(|info| info.name).collect();
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn test_every_exercise_has_topics_and_hints() {
+        for info in registry::all() {
+            assert!(!info.topics.is_empty(), "{} has no topics", info.name);
+            assert!(!info.hints.is_empty(), "{} has no hints", info.name);
+        }
+    }
+
+    #[test]
+    fn test_curriculum_only_uses_registered_exercises() {
+        for exercise in Curriculum::standard().exercises() {
+            assert!(
+                registry::find(exercise).is_some(),
+                "{exercise} is not registered"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verifiers_match_registered_names() {
+        for name in [
+            verify::SlidingWindowMaximum::NAME,
+            verify::MergeIntervals::NAME,
+            verify::MaxProduct::NAME,
+            verify::FindElement::NAME,
+            verify::SortedSquareMatrixSearch::NAME,
+        ] {
+            assert!(registry::find(name).is_some(), "{name} is not registered");
+        }
+    }
+}
+
+mod lookup_tests {
+    use super::*;
+
+    #[test]
+    fn test_find_returns_declared_info() {
+        assert_eq!(
+            registry::find("vector::merge_intervals"),
+            Some(&MergeIntervalsExercise::INFO)
+        );
+        assert_eq!(registry::find("vector::does_not_exist"), None);
+    }
+
+    #[test]
+    fn test_by_module() {
+        let names: Vec<_> = registry::by_module("vector")
+            .iter()
+            .map(|info| info.name)
+            .collect();
+        assert!(names.contains(&"vector::max_product"));
+        assert!(names.iter().all(|name| name.starts_with("vector::")));
+    }
+
+    #[test]
+    fn test_by_topic() {
+        let infos = registry::by_topic("intervals");
+        assert!(infos.contains(&&MergeIntervalsExercise::INFO));
+        assert!(registry::by_topic("no such topic").is_empty());
+    }
+
+    #[test]
+    fn test_hint() {
+        let info = MaxProductExercise::INFO;
+        assert!(info.hint(0).is_some());
+        assert_eq!(info.hint(info.hints.len()), None);
+    }
+}
+
+mod exercise_tests {
+    use super::*;
+
+    #[test]
+    fn test_solver_is_callable() {
+        assert_eq!((MaxProductExercise::SOLVER)(&[-2, 3, -4]), 24);
+        assert_eq!(
+            (MergeIntervalsExercise::SOLVER)(&[(1, 3), (2, 4)]),
+            vec![(1, 4)]
+        );
+    }
+
+    #[test]
+    fn test_difficulty() {
+        assert_eq!(MergeIntervalsExercise::INFO.difficulty, Difficulty::Medium);
+        assert!(Difficulty::Easy < Difficulty::Hard);
+        assert_eq!(Difficulty::Hard.to_string(), "hard");
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use super::stream::Streaming;
+
+/// Merges intervals as they arrive, keeping the merged set at all times.
+///
+/// Like the batch `merge_intervals`, touching intervals such as `(1, 2)` and
+/// `(2, 3)` are merged. Each interval is assumed to satisfy `start <= end`.
+///
+/// ## Approach
+/// The merged intervals live in a `BTreeMap` from start to end. A new
+/// interval repeatedly absorbs the stored interval with the greatest start
+/// not after its end, as long as that interval reaches back into it.
+///
+/// ## Complexity
+/// - Time: O(log m) per item plus O(log m) per interval absorbed
+/// - Space: O(m) where m is the number of merged intervals
+#[derive(Debug, Clone, Default)]
+pub struct IntervalMerger {
+    merged: BTreeMap<i32, i32>,
+}
+
+impl IntervalMerger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of disjoint intervals currently stored
+    pub fn len(&self) -> usize {
+        self.merged.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.merged.is_empty()
+    }
+
+    /// Whether `point` lies inside one of the merged intervals
+    pub fn contains(&self, point: i32) -> bool {
+        self.merged
+            .range(..=point)
+            .next_back()
+            .is_some_and(|(_, &end)| end >= point)
+    }
+}
+
+impl Streaming for IntervalMerger {
+    type Item = (i32, i32);
+    type Output = Vec<(i32, i32)>;
+
+    fn push(&mut self, (mut start, mut end): (i32, i32)) {
+        while let Some((&s, &e)) = self.merged.range(..=end).next_back() {
+            if e < start {
+                break;
+            }
+            self.merged.remove(&s);
+            start = start.min(s);
+            end = end.max(e);
+        }
+        self.merged.insert(start, end);
+    }
+
+    fn current(&self) -> Vec<(i32, i32)> {
+        self.merged.iter().map(|(&s, &e)| (s, e)).collect()
+    }
+}
+
+/// Counts occurrences incrementally and tracks the most frequent item.
+///
+/// `current()` returns the mode so far; on ties the item that reached the
+/// count first wins.
+///
+/// ## Complexity
+/// - Time: O(1) average per item
+/// - Space: O(d) where d is the number of distinct items
+#[derive(Debug, Clone)]
+pub struct FrequencyCounter<T> {
+    counts: HashMap<T, usize>,
+    total: usize,
+    mode: Option<(T, usize)>,
+}
+
+impl<T: Hash + Eq + Clone> Default for FrequencyCounter<T> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+            total: 0,
+            mode: None,
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> FrequencyCounter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Number of items pushed
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of distinct items pushed
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn counts(&self) -> &HashMap<T, usize> {
+        &self.counts
+    }
+}
+
+impl<T: Hash + Eq + Clone + Ord> FrequencyCounter<T> {
+    /// The `n` most frequent items, ties broken by the smaller item first
+    pub fn most_common(&self, n: usize) -> Vec<(T, usize)> {
+        let mut entries: Vec<(T, usize)> = self
+            .counts
+            .iter()
+            .map(|(item, &count)| (item.clone(), count))
+            .collect();
+        entries.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        entries.truncate(n);
+        entries
+    }
+}
+
+impl<T: Hash + Eq + Clone> Streaming for FrequencyCounter<T> {
+    type Item = T;
+    type Output = Option<(T, usize)>;
+
+    fn push(&mut self, item: T) {
+        self.total += 1;
+        let count = self.counts.entry(item.clone()).or_insert(0);
+        *count += 1;
+        let count = *count;
+        if self.mode.as_ref().is_none_or(|&(_, best)| count > best) {
+            self.mode = Some((item, count));
+        }
+    }
+
+    fn current(&self) -> Option<(T, usize)> {
+        self.mode.clone()
+    }
+}
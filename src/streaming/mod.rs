@@ -0,0 +1,37 @@
+//! Streaming (iterator-consuming) variants of the batch exercises
+//!
+//! The batch exercises take a slice, which means the whole input has to fit
+//! in memory. The types here consume one item at a time through the
+//! [`Streaming`] trait and keep only the state they need:
+//!
+//! | Type                 | State kept             | Batch counterpart                |
+//! |----------------------|------------------------|----------------------------------|
+//! | [`WindowMax`]        | O(k) monotonic deque   | `vector::sliding_window_maximum` |
+//! | [`WindowMin`]        | O(k) monotonic deque   | `vector::sliding_window_minimum` |
+//! | [`RunningMedian`]    | O(n) two heaps         | sort + middle element            |
+//! | [`TopK`]             | O(k) min-heap          | sort + take k                    |
+//! | [`IntervalMerger`]   | O(m) merged intervals  | `vector::merge_intervals`        |
+//! | [`FrequencyCounter`] | O(d) distinct counts   | `HashMap` counting pass          |
+//! | [`SlidingWindow`]    | O(k) two stacks        | `vector::sliding_window_max`     |
+//!
+//! ```
+//! use rust_ds_learning::streaming::{Streaming, WindowMax};
+//!
+//! let maxima: Vec<i32> = WindowMax::new(3)
+//!     .scan([1, 3, -1, -3, 5, 3, 6, 7])
+//!     .flatten()
+//!     .collect();
+//! assert_eq!(maxima, vec![3, 3, 5, 5, 6, 7]);
+//! ```
+
+mod aggregates;
+mod order_stats;
+mod stream;
+#[cfg(test)]
+mod tests;
+mod window;
+
+pub use aggregates::*;
+pub use order_stats::*;
+pub use stream::*;
+pub use window::*;
@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::stream::Streaming;
+use crate::vector::MonotonicQueue;
+
+/// Fixed-size window over a [`MonotonicQueue`], shared by [`WindowMax`]
+/// and [`WindowMin`].
+#[derive(Debug, Clone)]
+struct MonotonicWindow<T> {
+    window_size: usize,
+    queue: MonotonicQueue<T>,
+}
+
+impl<T: Ord + Clone> MonotonicWindow<T> {
+    fn new(window_size: usize, queue: MonotonicQueue<T>) -> Self {
+        Self { window_size, queue }
+    }
+
+    fn push(&mut self, item: T) {
+        self.queue.push(item);
+        self.queue
+            .pop_expired(self.queue.pushed().saturating_sub(self.window_size));
+    }
+
+    fn current(&self) -> Option<T> {
+        if self.window_size == 0 || self.queue.pushed() < self.window_size {
+            return None;
+        }
+        self.queue.current_extreme().cloned()
+    }
+}
+
+/// Maximum of the last `window_size` items.
+///
+/// `current()` is `None` until the first full window has been seen, so
+/// flattening a [`scan`](Streaming::scan) reproduces the batch
+/// `sliding_window_maximum` output.
+///
+/// ## Complexity
+/// - Time: amortized O(1) per item
+/// - Space: O(k)
+#[derive(Debug, Clone)]
+pub struct WindowMax<T> {
+    window: MonotonicWindow<T>,
+}
+
+impl<T: Ord + Clone> WindowMax<T> {
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window: MonotonicWindow::new(window_size, MonotonicQueue::max()),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Streaming for WindowMax<T> {
+    type Item = T;
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        self.window.push(item);
+    }
+
+    fn current(&self) -> Option<T> {
+        self.window.current()
+    }
+}
+
+/// Minimum of the last `window_size` items; the mirror image of [`WindowMax`].
+#[derive(Debug, Clone)]
+pub struct WindowMin<T> {
+    window: MonotonicWindow<T>,
+}
+
+impl<T: Ord + Clone> WindowMin<T> {
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window: MonotonicWindow::new(window_size, MonotonicQueue::min()),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Streaming for WindowMin<T> {
+    type Item = T;
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        self.window.push(item);
+    }
+
+    fn current(&self) -> Option<T> {
+        self.window.current()
+    }
+}
+
+/// Median of every item seen so far.
+///
+/// ## Approach
+/// The lower half lives in a max-heap and the upper half in a min-heap, with
+/// the lower half allowed to hold one extra element. The median is then the
+/// top of the lower heap, or the mean of both tops for an even count.
+///
+/// ## Complexity
+/// - Time: O(log n) per item, O(1) per query
+/// - Space: O(n): an exact median needs every item
+#[derive(Debug, Clone, Default)]
+pub struct RunningMedian {
+    lower: BinaryHeap<i32>,
+    upper: BinaryHeap<Reverse<i32>>,
+}
+
+impl RunningMedian {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+}
+
+impl Streaming for RunningMedian {
+    type Item = i32;
+    type Output = Option<f64>;
+
+    fn push(&mut self, item: i32) {
+        match self.lower.peek() {
+            Some(&top) if item > top => self.upper.push(Reverse(item)),
+            _ => self.lower.push(item),
+        }
+        // Rebalance so that lower.len() is upper.len() or upper.len() + 1
+        if self.lower.len() > self.upper.len() + 1 {
+            let moved = self.lower.pop().unwrap();
+            self.upper.push(Reverse(moved));
+        } else if self.upper.len() > self.lower.len() {
+            let Reverse(moved) = self.upper.pop().unwrap();
+            self.lower.push(moved);
+        }
+    }
+
+    fn current(&self) -> Option<f64> {
+        let &low = self.lower.peek()?;
+        if self.lower.len() > self.upper.len() {
+            return Some(f64::from(low));
+        }
+        let &Reverse(high) = self.upper.peek()?;
+        Some((f64::from(low) + f64::from(high)) / 2.0)
+    }
+}
+
+/// The `k` largest items seen so far, largest first.
+///
+/// ## Approach
+/// A min-heap of size `k` holds the current winners; its root is the
+/// weakest of them, so a new item only has to beat the root to get in.
+///
+/// ## Complexity
+/// - Time: O(log k) per item, O(k log k) per query
+/// - Space: O(k)
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord + Clone> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Streaming for TopK<T> {
+    type Item = T;
+    type Output = Vec<T>;
+
+    fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if let Some(mut weakest) = self.heap.peek_mut() {
+            if item > weakest.0 {
+                *weakest = Reverse(item);
+            }
+        }
+    }
+
+    fn current(&self) -> Vec<T> {
+        let mut result: Vec<T> = self.heap.iter().map(|Reverse(x)| x.clone()).collect();
+        result.sort_unstable_by(|a, b| b.cmp(a));
+        result
+    }
+}
@@ -0,0 +1,76 @@
+/// An online algorithm that consumes items one at a time.
+pub trait Streaming {
+    type Item;
+    type Output;
+
+    /// Consumes one item
+    fn push(&mut self, item: Self::Item);
+
+    /// The answer for everything pushed so far
+    fn current(&self) -> Self::Output;
+
+    /// Pushes every item of `items`
+    fn extend_from<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = Self::Item>,
+        Self: Sized,
+    {
+        for item in items {
+            self.push(item);
+        }
+    }
+
+    /// Pushes every item of `items` and returns the final answer
+    fn run<I>(mut self, items: I) -> Self::Output
+    where
+        I: IntoIterator<Item = Self::Item>,
+        Self: Sized,
+    {
+        self.extend_from(items);
+        self.current()
+    }
+
+    /// Lazily pushes `items`, yielding the answer after each one
+    fn scan<I>(self, items: I) -> Scan<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = Self::Item>,
+        Self: Sized,
+    {
+        Scan {
+            state: self,
+            items: items.into_iter(),
+        }
+    }
+}
+
+/// Iterator returned by [`Streaming::scan`].
+#[derive(Debug, Clone)]
+pub struct Scan<S, I> {
+    state: S,
+    items: I,
+}
+
+impl<S, I> Scan<S, I> {
+    /// Gives back the streaming state, e.g. to keep feeding it later
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S, I> Iterator for Scan<S, I>
+where
+    S: Streaming,
+    I: Iterator<Item = S::Item>,
+{
+    type Item = S::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.items.next()?;
+        self.state.push(item);
+        Some(self.state.current())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::streaming::*;
+use crate::vector::{merge_intervals, sliding_window_maximum};
+
+fn random_vec(rng: &mut StdRng, max_len: usize) -> Vec<i32> {
+    let len = rng.gen_range(0..=max_len);
+    (0..len).map(|_| rng.gen_range(-30..=30)).collect()
+}
+
+mod stream_tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_yields_after_every_item() {
+        let medians: Vec<_> = RunningMedian::new().scan([5, 1, 3]).collect();
+        assert_eq!(medians, vec![Some(5.0), Some(3.0), Some(3.0)]);
+    }
+
+    #[test]
+    fn test_scan_into_inner_keeps_state() {
+        let mut scan = TopK::new(2).scan([4, 9, 1]);
+        assert_eq!(scan.by_ref().count(), 3);
+        let mut top = scan.into_inner();
+        top.push(7);
+        assert_eq!(top.current(), vec![9, 7]);
+    }
+
+    #[test]
+    fn test_run_accepts_any_iterator() {
+        // The input never exists as a Vec
+        let top = TopK::new(3).run((0..1_000_000_i64).map(|x| (x * 7919) % 1000));
+        assert_eq!(top, vec![999, 999, 999]);
+    }
+}
+
+mod window_tests {
+    use super::*;
+
+    #[test]
+    fn test_window_max_matches_batch() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..200 {
+            let nums = random_vec(&mut rng, 30);
+            let k = rng.gen_range(0..=nums.len().max(1)).min(nums.len());
+            let streamed: Vec<i32> = WindowMax::new(k).scan(nums.clone()).flatten().collect();
+            assert_eq!(
+                streamed,
+                sliding_window_maximum(&nums, k),
+                "{nums:?}, k={k}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_window_min_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..200 {
+            let nums = random_vec(&mut rng, 30);
+            let k = rng.gen_range(1..=nums.len().max(1));
+            let streamed: Vec<i32> = WindowMin::new(k).scan(nums.clone()).flatten().collect();
+            let expected: Vec<i32> = nums.windows(k).map(|w| *w.iter().min().unwrap()).collect();
+            assert_eq!(streamed, expected);
+        }
+    }
+
+    #[test]
+    fn test_window_not_full_yet() {
+        let mut max = WindowMax::new(3);
+        max.push(1);
+        max.push(2);
+        assert_eq!(max.current(), None);
+        max.push(0);
+        assert_eq!(max.current(), Some(2));
+    }
+
+    #[test]
+    fn test_zero_window() {
+        assert_eq!(WindowMax::new(0).run([1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_generic_items() {
+        let words = ["pear", "apple", "fig", "kiwi"];
+        let maxima: Vec<&str> = WindowMax::new(2).scan(words).flatten().collect();
+        assert_eq!(maxima, vec!["pear", "fig", "kiwi"]);
+    }
+}
+
+mod median_tests {
+    use super::*;
+
+    fn batch_median(nums: &[i32]) -> Option<f64> {
+        let mut sorted = nums.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(f64::from(sorted[n / 2])),
+            _ => Some((f64::from(sorted[n / 2 - 1]) + f64::from(sorted[n / 2])) / 2.0),
+        }
+    }
+
+    #[test]
+    fn test_running_median_matches_batch() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..100 {
+            let nums = random_vec(&mut rng, 40);
+            let streamed: Vec<_> = RunningMedian::new().scan(nums.clone()).collect();
+            for (i, median) in streamed.into_iter().enumerate() {
+                assert_eq!(median, batch_median(&nums[..=i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_and_extremes() {
+        assert_eq!(RunningMedian::new().current(), None);
+        let median = RunningMedian::new().run([i32::MAX, i32::MAX]);
+        assert_eq!(median, Some(f64::from(i32::MAX)));
+    }
+}
+
+mod top_k_tests {
+    use super::*;
+
+    #[test]
+    fn test_top_k_matches_batch() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..200 {
+            let nums = random_vec(&mut rng, 30);
+            let k = rng.gen_range(0..=10);
+            let mut expected = nums.clone();
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            expected.truncate(k);
+            assert_eq!(TopK::new(k).run(nums), expected);
+        }
+    }
+}
+
+mod interval_tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_merger_matches_batch() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..200 {
+            let len = rng.gen_range(0..=15);
+            let intervals: Vec<(i32, i32)> = (0..len)
+                .map(|_| {
+                    let start = rng.gen_range(-20..=20);
+                    (start, start + rng.gen_range(0..=6))
+                })
+                .collect();
+            let streamed = IntervalMerger::new().run(intervals.clone());
+            assert_eq!(streamed, merge_intervals(&intervals), "{intervals:?}");
+        }
+    }
+
+    #[test]
+    fn test_bridging_interval() {
+        let mut merger = IntervalMerger::new();
+        merger.extend_from([(1, 2), (5, 6), (9, 10)]);
+        assert_eq!(merger.len(), 3);
+        merger.push((2, 9));
+        assert_eq!(merger.current(), vec![(1, 10)]);
+        assert!(merger.contains(7));
+        assert!(!merger.contains(11));
+    }
+
+    #[test]
+    fn test_touching_intervals_merge() {
+        assert_eq!(IntervalMerger::new().run([(4, 5), (1, 4)]), vec![(1, 5)]);
+    }
+}
+
+mod frequency_tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_match_batch() {
+        let mut rng = StdRng::seed_from_u64(6);
+        let nums = random_vec(&mut rng, 500);
+        let mut expected: HashMap<i32, usize> = HashMap::new();
+        for &x in &nums {
+            *expected.entry(x).or_default() += 1;
+        }
+
+        let mut counter = FrequencyCounter::new();
+        counter.extend_from(nums.iter().copied());
+        assert_eq!(counter.counts(), &expected);
+        assert_eq!(counter.total(), nums.len());
+        assert_eq!(counter.distinct(), expected.len());
+        let (_, best) = counter.current().unwrap();
+        assert_eq!(best, *expected.values().max().unwrap());
+    }
+
+    #[test]
+    fn test_mode_ties_keep_first() {
+        let modes: Vec<_> = FrequencyCounter::new().scan("abba".chars()).collect();
+        assert_eq!(
+            modes,
+            vec![
+                Some(('a', 1)),
+                Some(('a', 1)),
+                Some(('b', 2)),
+                Some(('b', 2))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_most_common() {
+        let mut counter = FrequencyCounter::new();
+        counter.extend_from("mississippi".chars());
+        assert_eq!(counter.most_common(2), vec![('i', 4), ('s', 4)]);
+        assert_eq!(counter.count(&'p'), 2);
+        assert_eq!(counter.count(&'z'), 0);
+    }
+}
+
+mod sliding_window_tests {
+    use super::*;
+
+    fn gcd(a: &u64, b: &u64) -> u64 {
+        let (mut a, mut b) = (*a, *b);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    #[test]
+    fn test_max_matches_batch() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..200 {
+            let nums = random_vec(&mut rng, 30);
+            let k = rng.gen_range(0..=nums.len());
+            let streamed: Vec<i32> = SlidingWindow::new(k, |a: &i32, b: &i32| *a.max(b))
+                .scan(nums.clone())
+                .flatten()
+                .collect();
+            assert_eq!(
+                streamed,
+                sliding_window_maximum(&nums, k),
+                "{nums:?}, k={k}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum_and_gcd_match_brute_force() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..100 {
+            let nums: Vec<u64> = (0..rng.gen_range(0..40))
+                .map(|_| rng.gen_range(1..=12) * 6)
+                .collect();
+            let k = rng.gen_range(1..=8);
+
+            let sums: Vec<u64> = SlidingWindow::new(k, |a: &u64, b: &u64| a + b)
+                .scan(nums.clone())
+                .flatten()
+                .collect();
+            let expected: Vec<u64> = nums.windows(k).map(|w| w.iter().sum()).collect();
+            assert_eq!(sums, expected);
+
+            let gcds: Vec<u64> = SlidingWindow::new(k, gcd)
+                .scan(nums.clone())
+                .flatten()
+                .collect();
+            let expected: Vec<u64> = nums
+                .windows(k)
+                .map(|w| w.iter().fold(0, |acc, x| gcd(&acc, x)))
+                .collect();
+            assert_eq!(gcds, expected);
+        }
+    }
+
+    #[test]
+    fn test_non_commutative_combine_keeps_order() {
+        let concat = |a: &String, b: &String| format!("{a}{b}");
+        let windows: Vec<String> = SlidingWindow::new(3, concat)
+            .scan("abcdefg".chars().map(String::from))
+            .flatten()
+            .collect();
+        assert_eq!(windows, vec!["abc", "bcd", "cde", "def", "efg"]);
+    }
+
+    #[test]
+    fn test_partial_windows_and_len() {
+        let mut window = SlidingWindow::new(3, |a: &i32, b: &i32| a + b);
+        assert!(window.is_empty());
+        assert_eq!(window.aggregate(), None);
+
+        window.push(5);
+        window.push(1);
+        assert_eq!(window.len(), 2);
+        assert_eq!(window.current(), None);
+        assert_eq!(window.aggregate(), Some(6));
+
+        window.extend_from([2, 10]);
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.current(), Some(13));
+    }
+
+    #[test]
+    fn test_zero_sized_window_stays_empty() {
+        let mut window = SlidingWindow::new(0, |a: &i32, b: &i32| a + b);
+        window.extend_from([1, 2, 3]);
+        assert!(window.is_empty());
+        assert_eq!(window.current(), None);
+    }
+
+    #[test]
+    fn test_combine_calls_are_amortized_constant() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let n = 10_000;
+        let window = SlidingWindow::new(100, |a: &i64, b: &i64| {
+            calls.set(calls.get() + 1);
+            a + b
+        });
+        let total: i64 = window.scan(0..n).flatten().sum();
+        assert!(total > 0);
+        // One call per push, one per flipped item, one per query
+        assert!(calls.get() <= 3 * n, "{} combine calls", calls.get());
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+
+use super::stream::Streaming;
+
+/// Any associative aggregate (sum, max, gcd, matrix product, ...) of the
+/// last `window_size` items, maintained in amortized O(1) per item.
+///
+/// Generalizes [`WindowMax`](super::WindowMax): the monotonic deque only
+/// works for max/min, because it relies on an element being *dominated*.
+/// Here `combine` only has to be associative, not commutative or
+/// invertible (there is no way to "subtract" an item from a max or a gcd).
+///
+/// `current()` is `None` until the first full window has been seen, like
+/// `WindowMax`; [`SlidingWindow::aggregate`] also covers partial windows.
+///
+/// ```
+/// use rust_ds_learning::streaming::{SlidingWindow, Streaming};
+///
+/// let sums: Vec<i32> = SlidingWindow::new(3, |a: &i32, b: &i32| a + b)
+///     .scan([1, 2, 3, 4, 5])
+///     .flatten()
+///     .collect();
+/// assert_eq!(sums, vec![6, 9, 12]);
+///
+/// // Order is preserved, so non-commutative operations work too
+/// let concat = |a: &String, b: &String| format!("{a}{b}");
+/// let last_two = SlidingWindow::new(2, concat).run(["a", "b", "c"].map(String::from));
+/// assert_eq!(last_two.as_deref(), Some("bc"));
+/// ```
+///
+/// ## Approach
+/// A queue built from two stacks, where each stack also knows its
+/// aggregate:
+/// - `back` receives new items; its aggregate is updated on every push
+/// - `front` holds older items as *suffix aggregates*: each entry is the
+///   combination of that item and every newer item in `front`, so the top
+///   (oldest) entry covers all of `front`
+///
+/// Evicting the oldest item pops `front`. When `front` is empty, every
+/// item in `back` is moved over, newest first, building the suffix
+/// aggregates as they go. The window's aggregate is then
+/// `combine(front.top, back_aggregate)`.
+///
+/// ## Complexity
+/// - Time: amortized O(1) `combine` calls per item, since each item is
+///   moved from `back` to `front` at most once
+/// - Space: O(k)
+///
+/// ## Common Pitfalls
+/// 1. Combining in the wrong order while flipping the stacks, which only
+///    shows up with non-commutative operations
+/// 2. Keeping a single running aggregate: removing an item from it needs
+///    an inverse, which max, min and gcd do not have
+#[derive(Clone)]
+pub struct SlidingWindow<T, F> {
+    window_size: usize,
+    combine: F,
+    front: Vec<T>,
+    back: Vec<T>,
+    back_aggregate: Option<T>,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> SlidingWindow<T, F> {
+    /// `combine(older, newer)` must be associative
+    pub fn new(window_size: usize, combine: F) -> Self {
+        Self {
+            window_size,
+            combine,
+            front: Vec::with_capacity(window_size),
+            back: Vec::with_capacity(window_size),
+            back_aggregate: None,
+        }
+    }
+
+    /// Number of items currently in the window
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Aggregate of the items in the window, even before it is full.
+    /// `None` only while the window is empty.
+    pub fn aggregate(&self) -> Option<T> {
+        match (self.front.last(), &self.back_aggregate) {
+            (Some(front), Some(back)) => Some((self.combine)(front, back)),
+            (Some(only), None) | (None, Some(only)) => Some(only.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Removes the oldest item
+    fn evict(&mut self) {
+        if self.front.is_empty() {
+            // Newest first, so each entry combines an item with the newer ones
+            let mut suffix: Option<T> = None;
+            while let Some(item) = self.back.pop() {
+                let aggregate = match &suffix {
+                    Some(newer) => (self.combine)(&item, newer),
+                    None => item,
+                };
+                suffix = Some(aggregate.clone());
+                self.front.push(aggregate);
+            }
+            self.back_aggregate = None;
+        }
+        self.front.pop();
+    }
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> Streaming for SlidingWindow<T, F> {
+    type Item = T;
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        if self.window_size == 0 {
+            return;
+        }
+        if self.len() == self.window_size {
+            self.evict();
+        }
+        self.back_aggregate = Some(match &self.back_aggregate {
+            Some(older) => (self.combine)(older, &item),
+            None => item.clone(),
+        });
+        self.back.push(item);
+    }
+
+    fn current(&self) -> Option<T> {
+        if self.window_size == 0 || self.len() < self.window_size {
+            return None;
+        }
+        self.aggregate()
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for SlidingWindow<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlidingWindow")
+            .field("window_size", &self.window_size)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("back_aggregate", &self.back_aggregate)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,391 @@
+//! Classical Cipher Problem Set
+//!
+//! Caesar, Vigenère and repeating-key XOR are all broken, and have been for
+//! a long time; none of them should protect anything. They are here as
+//! practice in working on bytes: each one walks the input with an
+//! iterator, transforms every byte with a little modular arithmetic, and
+//! collects the result. [`crack_caesar`] shows why they are broken.
+//!
+//! The letter ciphers only touch ASCII letters. Everything else, spaces,
+//! punctuation and non-ASCII characters included, passes through
+//! unchanged, so their output is always valid UTF-8.
+
+use std::fmt::Write;
+
+use crate::{ExerciseError, Result};
+
+/// # Caesar Cipher
+///
+/// ## Problem Statement
+/// Shift every ASCII letter `shift` places forward in the alphabet,
+/// wrapping from `z` to `a` and keeping its case.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::ciphers::{caesar_decode, caesar_encode};
+/// assert_eq!(caesar_encode("Hello, World!", 3), "Khoor, Zruog!");
+/// assert_eq!(caesar_decode("Khoor, Zruog!", 3), "Hello, World!");
+/// assert_eq!(caesar_encode("xyz", 29), "abc"); // 29 ≡ 3 (mod 26)
+/// ```
+///
+/// ## Approach
+/// For a letter `b` in the alphabet starting at `base` (`b'a'` or
+/// `b'A'`), `base + (b - base + shift) % 26`. Decoding shifts by
+/// `26 - shift`, the inverse modulo 26.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(n) for the output
+///
+/// ## Common Pitfalls
+/// 1. `b + shift` in `u8` overflows for `shift` near 255; reducing `shift`
+///    modulo 26 first keeps every intermediate value below 256
+pub fn caesar_encode(text: &str, shift: u8) -> String {
+    shift_letters(text, || shift % 26)
+}
+
+/// Inverse of [`caesar_encode`] with the same `shift`
+pub fn caesar_decode(text: &str, shift: u8) -> String {
+    caesar_encode(text, 26 - shift % 26)
+}
+
+/// ROT13, the Caesar cipher with shift 13, which is its own inverse
+///
+/// ```
+/// use rust_ds_learning::string::ciphers::rot13;
+/// assert_eq!(rot13("Why did the chicken cross the road?"), "Jul qvq gur puvpxra pebff gur ebnq?");
+/// assert_eq!(rot13(&rot13("spoiler")), "spoiler");
+/// ```
+pub fn rot13(text: &str) -> String {
+    caesar_encode(text, 13)
+}
+
+/// # Vigenère Cipher
+///
+/// ## Problem Statement
+/// Encrypt with a Caesar shift that changes from letter to letter: the
+/// `i`-th letter of the text is shifted by the `i`-th letter of `key`
+/// (`a`/`A` = 0, `b`/`B` = 1, ...), repeating the key as needed.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::ciphers::{vigenere_decode, vigenere_encode};
+/// let secret = vigenere_encode("ATTACK AT DAWN", "Lemon").unwrap();
+/// assert_eq!(secret, "LXFOPV EF RNHR");
+/// assert_eq!(vigenere_decode(&secret, "Lemon").unwrap(), "ATTACK AT DAWN");
+/// ```
+///
+/// ## Approach
+/// Turn the key into shifts once, then cycle through them with
+/// `iter().cycle()`, drawing the next shift only when the text has a
+/// letter to encrypt.
+///
+/// ## Complexity
+/// - Time: O(n + k) for a key of length `k`
+/// - Space: O(n + k)
+///
+/// ## Key Points
+/// 1. A key of length `k` makes it `k` interleaved Caesar ciphers, which
+///    is also how it is broken: guess `k`, then crack each one by
+///    frequency analysis like [`crack_caesar`]
+///
+/// ## Common Pitfalls
+/// 1. Advancing the key on spaces and punctuation too, which changes the
+///    output whenever the text's spacing changes
+///
+/// ## Errors
+/// `InvalidInput` unless `key` is a non-empty string of ASCII letters.
+pub fn vigenere_encode(text: &str, key: &str) -> Result<String> {
+    let shifts = key_shifts(key)?;
+    let mut shifts = shifts.iter().cycle();
+    Ok(shift_letters(text, || {
+        *shifts.next().expect("cycling a non-empty key")
+    }))
+}
+
+/// Inverse of [`vigenere_encode`] with the same `key`
+///
+/// # Errors
+/// `InvalidInput` unless `key` is a non-empty string of ASCII letters.
+pub fn vigenere_decode(text: &str, key: &str) -> Result<String> {
+    let shifts = key_shifts(key)?;
+    let mut shifts = shifts.iter().cycle();
+    Ok(shift_letters(text, || {
+        (26 - shifts.next().expect("cycling a non-empty key")) % 26
+    }))
+}
+
+/// # Repeating-Key XOR
+///
+/// ## Problem Statement
+/// XOR the `i`-th byte of `data` with byte `i % key.len()` of `key`, and
+/// return the result as lowercase hex, two digits per byte.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::ciphers::{xor_decode, xor_encode};
+/// let hex = xor_encode(b"hi!", b"key").unwrap();
+/// assert_eq!(hex, "030c58");
+/// assert_eq!(xor_decode(&hex, b"key").unwrap(), b"hi!");
+/// ```
+///
+/// ## Approach
+/// `zip` the data with `key.iter().cycle()` and XOR the pairs. XOR is its
+/// own inverse (`x ^ k ^ k == x`), so decoding XORs with the same key
+/// again, after turning the hex back into bytes.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(n) for the output
+///
+/// ## Key Points
+/// 1. The output is hex because XOR produces arbitrary bytes, which are
+///    usually not valid UTF-8 and could not be returned as a `String`
+/// 2. With a key as long as the data, used once, this is the one-time
+///    pad and unbreakable; a short repeating key is as weak as Vigenère
+///
+/// ## Errors
+/// `InvalidInput` if `key` is empty.
+pub fn xor_encode(data: &[u8], key: &[u8]) -> Result<String> {
+    check_xor_key(key)?;
+    Ok(data.iter().zip(key.iter().cycle()).fold(
+        String::with_capacity(2 * data.len()),
+        |mut hex, (byte, k)| {
+            write!(hex, "{:02x}", byte ^ k).expect("writing to a String cannot fail");
+            hex
+        },
+    ))
+}
+
+/// Inverse of [`xor_encode`] with the same `key`. Upper- and lowercase hex
+/// digits are both accepted.
+///
+/// # Errors
+/// `InvalidInput` if `key` is empty, or `hex` has an odd number of digits
+/// or contains anything but hex digits.
+pub fn xor_decode(hex: &str, key: &[u8]) -> Result<Vec<u8>> {
+    check_xor_key(key)?;
+    if !hex.len().is_multiple_of(2) {
+        return Err(ExerciseError::InvalidInput(format!(
+            "hex input has an odd number of digits ({})",
+            hex.len()
+        )));
+    }
+    hex.as_bytes()
+        .chunks_exact(2)
+        .zip(key.iter().cycle())
+        .enumerate()
+        .map(|(i, (pair, k))| {
+            // from_str_radix alone would accept a sign, as in "+f"
+            if !pair.iter().all(u8::is_ascii_hexdigit) {
+                return Err(ExerciseError::InvalidInput(format!(
+                    "invalid hex digit at byte {}",
+                    2 * i
+                )));
+            }
+            let pair = std::str::from_utf8(pair).expect("hex digits are ASCII");
+            Ok(u8::from_str_radix(pair, 16).expect("two hex digits fit in a u8") ^ k)
+        })
+        .collect()
+}
+
+/// Relative frequency of `a` to `z` in English text, in percent
+pub const ENGLISH_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+
+/// # Cracking Caesar by Frequency Analysis
+///
+/// ## Problem Statement
+/// Given English text encrypted with [`caesar_encode`] under an unknown
+/// shift, recover the shift and the plaintext.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::ciphers::{caesar_encode, crack_caesar};
+/// let plain = "It was the best of times, it was the worst of times";
+/// let secret = caesar_encode(plain, 11);
+/// assert_eq!(crack_caesar(&secret).unwrap(), (11, plain.to_string()));
+/// ```
+///
+/// ## Approach
+/// There are only 26 keys, so try them all and pick the one whose
+/// decryption looks most like English. "Looks like English" is measured
+/// by the chi-squared statistic between the decryption's letter counts
+/// and the counts expected from [`ENGLISH_FREQUENCIES`]:
+///
+/// `χ² = Σ (observed - expected)² / expected`
+///
+/// which is small when the two distributions agree. Instead of decrypting
+/// 26 times, count the ciphertext letters once: under shift `s`, plaintext
+/// letter `p` was encrypted as `(p + s) % 26`.
+///
+/// ## Complexity
+/// - Time: O(n + 26²)
+/// - Space: O(1) besides the returned plaintext
+///
+/// ## Key Points
+/// 1. The key space, not the cipher's arithmetic, is the weakness:
+///    26 keys can be searched exhaustively
+/// 2. Frequency analysis needs enough text: on a handful of letters an
+///    unlucky shift can look more English than the right one
+///
+/// ## Errors
+/// `InvalidInput` if `ciphertext` contains no ASCII letters to analyze.
+pub fn crack_caesar(ciphertext: &str) -> Result<(u8, String)> {
+    let mut counts = [0usize; 26];
+    for byte in ciphertext.bytes().filter(u8::is_ascii_alphabetic) {
+        counts[usize::from(byte.to_ascii_lowercase() - b'a')] += 1;
+    }
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return Err(ExerciseError::InvalidInput(
+            "ciphertext has no letters to analyze".to_string(),
+        ));
+    }
+    let chi_squared = |shift: usize| -> f64 {
+        ENGLISH_FREQUENCIES
+            .iter()
+            .enumerate()
+            .map(|(plain, frequency)| {
+                let expected = frequency / 100.0 * total as f64;
+                let observed = counts[(plain + shift) % 26] as f64;
+                (observed - expected).powi(2) / expected
+            })
+            .sum()
+    };
+    let shift = (0..26)
+        .min_by(|&a, &b| chi_squared(a).total_cmp(&chi_squared(b)))
+        .expect("26 candidate shifts") as u8;
+    Ok((shift, caesar_decode(ciphertext, shift)))
+}
+
+/// Shifts every ASCII letter of `text` by the next `next_shift()`, which
+/// must be below 26; other characters are copied unchanged
+fn shift_letters(text: &str, mut next_shift: impl FnMut() -> u8) -> String {
+    text.chars()
+        .map(|c| {
+            if !c.is_ascii_alphabetic() {
+                return c;
+            }
+            let byte = c as u8;
+            let base = if byte.is_ascii_lowercase() {
+                b'a'
+            } else {
+                b'A'
+            };
+            char::from(base + (byte - base + next_shift()) % 26)
+        })
+        .collect()
+}
+
+fn key_shifts(key: &str) -> Result<Vec<u8>> {
+    if key.is_empty() || !key.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+        return Err(ExerciseError::InvalidInput(format!(
+            "key must be a non-empty string of ASCII letters, got {key:?}"
+        )));
+    }
+    Ok(key
+        .bytes()
+        .map(|byte| byte.to_ascii_lowercase() - b'a')
+        .collect())
+}
+
+fn check_xor_key(key: &[u8]) -> Result<()> {
+    if key.is_empty() {
+        return Err(ExerciseError::InvalidInput(
+            "XOR key must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caesar_round_trips_for_every_shift() {
+        let text = "The quick brown fox jumps over the lazy dog. Ünïcödé stays, 123!";
+        for shift in 0..=u8::MAX {
+            let secret = caesar_encode(text, shift);
+            assert_eq!(caesar_decode(&secret, shift), text, "shift {shift}");
+            assert_eq!(secret, caesar_encode(text, shift % 26));
+        }
+        assert_eq!(caesar_encode(text, 0), text);
+        assert_eq!(caesar_encode("Zz", 1), "Aa");
+    }
+
+    #[test]
+    fn test_rot13_is_an_involution() {
+        let text = "Hello, World! ÀBÇ";
+        assert_eq!(rot13(text), "Uryyb, Jbeyq! ÀOÇ");
+        assert_eq!(rot13(&rot13(text)), text);
+    }
+
+    #[test]
+    fn test_vigenere_skips_non_letters_and_ignores_key_case() {
+        assert_eq!(
+            vigenere_encode("attack at dawn", "LEMON").unwrap(),
+            "lxfopv ef rnhr"
+        );
+        // Same letters, different spacing: same letters out
+        assert_eq!(
+            vigenere_encode("attackatdawn", "lemon").unwrap(),
+            "lxfopvefrnhr"
+        );
+        // A one-letter key is a Caesar cipher
+        assert_eq!(
+            vigenere_encode("Hello!", "d").unwrap(),
+            caesar_encode("Hello!", 3)
+        );
+        let text = "Vigenère, 1553";
+        assert_eq!(
+            vigenere_decode(&vigenere_encode(text, "Bellaso").unwrap(), "bellaso").unwrap(),
+            text
+        );
+    }
+
+    #[test]
+    fn test_vigenere_rejects_bad_keys() {
+        for key in ["", "two words", "k3y", "clé"] {
+            assert!(vigenere_encode("text", key).is_err(), "{key:?}");
+            assert!(vigenere_decode("text", key).is_err(), "{key:?}");
+        }
+    }
+
+    #[test]
+    fn test_xor_round_trips_arbitrary_bytes() {
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        for key in [&b"k"[..], b"\x00", b"\xff\x01", b"a longer key than usual"] {
+            let hex = xor_encode(&data, key).unwrap();
+            assert_eq!(hex.len(), 2 * data.len());
+            assert_eq!(xor_decode(&hex, key).unwrap(), data);
+            assert_eq!(xor_decode(&hex.to_uppercase(), key).unwrap(), data);
+        }
+        assert_eq!(xor_encode(b"", b"key").unwrap(), "");
+        assert_eq!(xor_encode(b"\x0f\xf0", b"\xff").unwrap(), "f00f");
+    }
+
+    #[test]
+    fn test_xor_rejects_bad_input() {
+        assert!(xor_encode(b"data", b"").is_err());
+        assert!(xor_decode("00", b"").is_err());
+        for hex in ["0", "abc", "zz", "+f", "-1", "0g", "é0"] {
+            assert!(xor_decode(hex, b"k").is_err(), "{hex:?}");
+        }
+    }
+
+    #[test]
+    fn test_crack_caesar_recovers_every_shift() {
+        let plain = "Frequency analysis was first described by al-Kindi in the ninth \
+                     century, and it breaks every monoalphabetic substitution cipher.";
+        for shift in 0..26 {
+            let secret = caesar_encode(plain, shift);
+            assert_eq!(crack_caesar(&secret).unwrap(), (shift, plain.to_string()));
+        }
+        assert!(crack_caesar("").is_err());
+        assert!(crack_caesar("123 !?").is_err());
+    }
+}
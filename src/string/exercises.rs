@@ -0,0 +1,1851 @@
+//! String Algorithm Exercises
+//!
+//! Searching, comparing and transforming text. Unless stated otherwise,
+//! positions are byte offsets into the `&str`, as with `str::find`, so they
+//! can be used to slice the original string directly.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::RollingHash;
+use crate::{ExerciseError, Result};
+
+/// # Failure Function
+///
+/// ## Problem Statement
+/// For every prefix `pattern[..=i]`, compute the length of its longest
+/// *proper* prefix that is also a suffix of it (a "border").
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::failure_function;
+/// assert_eq!(failure_function(b"ababaca"), vec![0, 0, 1, 2, 3, 0, 1]);
+/// assert_eq!(failure_function(b"aaaa"), vec![0, 1, 2, 3]);
+/// ```
+///
+/// ## Approach
+/// Build left to right, reusing earlier entries. `len` is the border of the
+/// previous prefix. If the next character extends it, the border grows by
+/// one. If not, the next-longest border of `pattern[..len]` is
+/// `fail[len - 1]`, so fall back to it and try again, until a border
+/// extends or `len` reaches 0.
+///
+/// ## Complexity
+/// - Time: O(m); `len` grows by at most one per character, so it can only
+///   fall back that many times in total
+/// - Space: O(m)
+///
+/// ## Key Points
+/// 1. "Proper" excludes the whole prefix, otherwise every entry would be
+///    trivially `i + 1`
+/// 2. Works over any `PartialEq` elements, not just bytes
+pub fn failure_function<T: PartialEq>(pattern: &[T]) -> Vec<usize> {
+    let mut fail = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[i] != pattern[len] {
+            len = fail[len - 1];
+        }
+        if pattern[i] == pattern[len] {
+            len += 1;
+        }
+        fail[i] = len;
+    }
+    fail
+}
+
+/// # Knuth–Morris–Pratt Substring Search
+///
+/// ## Problem Statement
+/// Return the byte offsets of every occurrence of `needle` in `haystack`,
+/// including overlapping ones.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::kmp_search;
+/// assert_eq!(kmp_search("abababa", "aba"), vec![0, 2, 4]);
+/// assert_eq!(kmp_search("hello", "world"), vec![]);
+/// assert_eq!(kmp_search("naïve naïve", "ïv"), vec![2, 9]); // byte offsets
+/// ```
+///
+/// ## Approach
+/// The naive search restarts one position later after a mismatch and
+/// re-reads characters it has already seen, O(n · m) in the worst case
+/// (`"aaa…ab"` in `"aaa…a"`). KMP never moves backwards in the haystack:
+/// when `needle[..j]` has matched and the next byte does not, the longest
+/// prefix of the needle that is still matched is the border of
+/// `needle[..j]`, which [`failure_function`] has precomputed. After a full
+/// match it continues from the border as well, which finds overlapping
+/// occurrences.
+///
+/// ## Complexity
+/// - Time: O(n + m)
+/// - Space: O(m) for the failure function
+///
+/// ## Key Points
+/// 1. Matching bytes is safe for UTF-8: a valid needle can only match at a
+///    character boundary, because lead bytes and continuation bytes never
+///    look alike
+/// 2. An empty needle matches at every character boundary, like
+///    `str::match_indices("")`
+/// 3. [`find_all`] solves the same problem with the Z-function; see
+///    [`z_array`] for how the two preprocessing steps compare
+///
+/// ## Common Pitfalls
+/// 1. Resetting `j` to 0 after a match, which misses overlapping matches
+/// 2. Falling back only once on a mismatch instead of looping: the
+///    shorter border may not extend either
+pub fn kmp_search(haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return char_boundaries(haystack);
+    }
+    let needle = needle.as_bytes();
+    let fail = failure_function(needle);
+    let mut matches = Vec::new();
+    let mut j = 0;
+    for (i, &byte) in haystack.as_bytes().iter().enumerate() {
+        while j > 0 && byte != needle[j] {
+            j = fail[j - 1];
+        }
+        if byte == needle[j] {
+            j += 1;
+        }
+        if j == needle.len() {
+            matches.push(i + 1 - j);
+            j = fail[j - 1];
+        }
+    }
+    matches
+}
+
+/// # Z-Function
+///
+/// ## Problem Statement
+/// For every position `i` of `s`, compute `z[i]`: the length of the longest
+/// common prefix of `s` and `s[i..]`. By convention `z[0] = s.len()`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::z_array;
+/// assert_eq!(z_array(b"aabxaab"), vec![7, 1, 0, 0, 3, 1, 0]);
+/// assert_eq!(z_array(b"aaaa"), vec![4, 3, 2, 1]);
+/// ```
+///
+/// ## Approach
+/// Track the *Z-box* `l..r`: the match `s[l..r] == s[..r - l]` reaching
+/// furthest to the right so far. For `i` inside the box, `s[i..r]` is a
+/// copy of `s[i - l..r - l]`, so `z[i]` is at least
+/// `min(z[i - l], r - i)` without comparing anything. Only characters
+/// beyond `r` are compared explicitly, and each successful comparison
+/// pushes `r` further right.
+///
+/// ## Complexity
+/// - Time: O(n), since `r` only moves right
+/// - Space: O(n)
+///
+/// ## Compared With the Failure Function
+/// Both preprocess a string in O(n) by reusing earlier results, and both
+/// solve substring search in O(n + m):
+///
+/// | | [`failure_function`] | `z_array` |
+/// |---|---|---|
+/// | entry `i` describes | the prefix *ending* at `i` | the suffix *starting* at `i` |
+/// | value | longest border of `s[..=i]` | longest common prefix of `s` and `s[i..]` |
+/// | search | [`kmp_search`]: stream the haystack past the needle's table | [`find_all`]: one table over needle + haystack |
+/// | memory for search | O(m) | O(n + m) |
+///
+/// Each can be converted into the other in O(n), and KMP's table is the
+/// one to pick when the haystack arrives as a stream. The Z-function is
+/// often easier to reason about, since its entries do not depend on each
+/// other's fallbacks.
+pub fn z_array<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = (r - i).min(z[i - l]);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            (l, r) = (i, i + z[i]);
+        }
+    }
+    z
+}
+
+/// # Substring Search With the Z-Function
+///
+/// ## Problem Statement
+/// Return the byte offsets of every occurrence of `needle` in `haystack`,
+/// including overlapping ones; same contract as [`kmp_search`].
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::find_all;
+/// assert_eq!(find_all("abababa", "aba"), vec![0, 2, 4]);
+/// assert_eq!(find_all("mississippi", "issi"), vec![1, 4]);
+/// ```
+///
+/// ## Approach
+/// Compute [`z_array`] of the needle followed by the haystack. Offset `i`
+/// of the haystack is position `m + i` of the combined string, and the
+/// needle occurs there exactly when `z[m + i] >= m`.
+///
+/// ## Complexity
+/// - Time: O(n + m)
+/// - Space: O(n + m) for the combined string and its Z-array
+///
+/// ## Key Points
+/// 1. The textbook version joins `needle + "$" + haystack` with a separator
+///    that occurs in neither, so no match can run past the needle. Checking
+///    `z >= m` instead of `z == m` needs no separator, which matters when
+///    every byte value may appear in the input
+pub fn find_all(haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return char_boundaries(haystack);
+    }
+    let m = needle.len();
+    let joined: Vec<u8> = needle.bytes().chain(haystack.bytes()).collect();
+    let z = z_array(&joined);
+    (0..(haystack.len() + 1).saturating_sub(m))
+        .filter(|&i| z[m + i] >= m)
+        .collect()
+}
+
+/// # Rabin–Karp Substring Search
+///
+/// ## Problem Statement
+/// Return the byte offsets of every occurrence of `needle` in `haystack`,
+/// including overlapping ones, using hashing.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::rabin_karp;
+/// assert_eq!(rabin_karp("abracadabra", "abra"), vec![0, 7]);
+/// assert_eq!(rabin_karp("aaaa", "aa"), vec![0, 1, 2]);
+/// assert_eq!(rabin_karp("abc", "d"), vec![]);
+/// ```
+///
+/// ## Approach
+/// Hash the needle once. Slide a window of the same length over the
+/// haystack, updating its hash in O(1) per step with a [`RollingHash`].
+/// Only where the hashes agree are the bytes compared, to rule out a
+/// *collision*: two different strings with the same hash.
+///
+/// ## Complexity
+/// - Time: O(n + m) expected; O(n · m) if most windows collide with the
+///   needle (or actually match it, as with `"aa"` in `"aaaa…"`)
+/// - Space: O(1) besides the output
+///
+/// ## Key Points
+/// 1. Unlike [`kmp_search`] the idea extends to searching for many
+///    patterns at once, see [`rabin_karp_multi`], or to 2D patterns
+/// 2. [`rabin_karp_with`] takes the hash parameters, which makes collisions
+///    easy to provoke with a tiny modulus
+///
+/// ## Common Pitfalls
+/// 1. Reporting a match on equal hashes alone. There are only `M` hash
+///    values, so collisions are guaranteed to exist, and with a fixed,
+///    known hash an adversary can construct them on purpose
+pub fn rabin_karp(haystack: &str, needle: &str) -> Vec<usize> {
+    rabin_karp_with(&RollingHash::default(), haystack, needle)
+}
+
+/// [`rabin_karp`] with the given hash function instead of the default one
+pub fn rabin_karp_with(hasher: &RollingHash, haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return char_boundaries(haystack);
+    }
+    let (text, pattern) = (haystack.as_bytes(), needle.as_bytes());
+    let target = hasher.hash(pattern);
+    hasher
+        .window_hashes(text, pattern.len())
+        .filter(|&(start, hash)| hash == target && &text[start..start + pattern.len()] == pattern)
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// # Multi-Pattern Rabin–Karp
+///
+/// ## Problem Statement
+/// Find every occurrence of every needle in `haystack`. Return
+/// `(offset, needle_index)` pairs sorted by offset, then by index.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::rabin_karp_multi;
+/// let found = rabin_karp_multi("she sells seashells", &["she", "sea", "sells"]);
+/// assert_eq!(found, vec![(0, 0), (4, 2), (10, 1), (13, 0)]);
+/// ```
+///
+/// ## Approach
+/// A window can only match needles of its own length, so group the needles
+/// by length, and within a group put their hashes in a `HashMap`. Then make
+/// one rolling pass per distinct length, looking each window hash up in
+/// the map and verifying the candidates it lists.
+///
+/// ## Complexity
+/// - Time: O(n · L + total needle length) expected, for `L` distinct
+///   needle lengths, instead of O(n · k) for `k` separate searches
+/// - Space: O(k) for the hash tables
+///
+/// ## Key Points
+/// 1. Duplicate needles are reported once per index
+/// 2. For many needles of many lengths, Aho–Corasick does a single pass
+///    regardless of `L`
+pub fn rabin_karp_multi(haystack: &str, needles: &[&str]) -> Vec<(usize, usize)> {
+    let hasher = RollingHash::default();
+    let text = haystack.as_bytes();
+    let mut by_len: BTreeMap<usize, HashMap<u64, Vec<usize>>> = BTreeMap::new();
+    for (index, needle) in needles.iter().enumerate() {
+        by_len
+            .entry(needle.len())
+            .or_default()
+            .entry(hasher.hash(needle.as_bytes()))
+            .or_default()
+            .push(index);
+    }
+
+    let mut matches = Vec::new();
+    for (&len, by_hash) in &by_len {
+        if len == 0 {
+            let indices: Vec<usize> = by_hash.values().flatten().copied().collect();
+            for start in char_boundaries(haystack) {
+                matches.extend(indices.iter().map(|&index| (start, index)));
+            }
+            continue;
+        }
+        for (start, hash) in hasher.window_hashes(text, len) {
+            let Some(candidates) = by_hash.get(&hash) else {
+                continue;
+            };
+            let window = &text[start..start + len];
+            matches.extend(
+                candidates
+                    .iter()
+                    .filter(|&&index| needles[index].as_bytes() == window)
+                    .map(|&index| (start, index)),
+            );
+        }
+    }
+    matches.sort_unstable();
+    matches
+}
+
+/// Byte offset of every character, plus the end of the string: everywhere
+/// an empty needle matches
+fn char_boundaries(s: &str) -> Vec<usize> {
+    s.char_indices().map(|(i, _)| i).chain([s.len()]).collect()
+}
+
+/// One step of an edit script, see [`edit_distance_with_script`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// The character is the same in both strings
+    Keep(char),
+    /// Insert a character that only the target has
+    Insert(char),
+    /// Delete a character that only the source has
+    Delete(char),
+    /// Replace `from` in the source by `to` in the target
+    Substitute { from: char, to: char },
+}
+
+/// # Edit Distance (Levenshtein)
+///
+/// ## Problem Statement
+/// Return the minimum number of single-character insertions, deletions and
+/// substitutions that turn `a` into `b`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::edit_distance;
+/// assert_eq!(edit_distance("kitten", "sitting"), 3);
+/// assert_eq!(edit_distance("", "abc"), 3);
+/// assert_eq!(edit_distance("café", "cafe"), 1); // characters, not bytes
+/// ```
+///
+/// ## Approach
+/// `d[i][j]`, the distance between the first `i` characters of `a` and the
+/// first `j` of `b`, depends on how the last characters are handled:
+///
+/// `d[i][j] = min(d[i-1][j] + 1, d[i][j-1] + 1, d[i-1][j-1] + (a[i-1] != b[j-1]))`
+///
+/// for a deletion, an insertion, and a keep or substitution, with
+/// `d[i][0] = i` and `d[0][j] = j`. Every row only reads the row above it,
+/// so only one row is stored, indexed by the *shorter* string; the value
+/// `d[i-1][j-1]` about to be overwritten is kept in a variable.
+///
+/// ## Complexity
+/// - Time: O(n · m)
+/// - Space: O(min(n, m))
+///
+/// ## Key Points
+/// 1. Works on `char`s: `"é"` is one character but two bytes, so a
+///    byte-based distance would count one substitution as two edits
+/// 2. The rolling row gives the distance only; recovering the edits needs
+///    the whole table, see [`edit_distance_with_script`]
+///
+/// ## Common Pitfalls
+/// 1. Overwriting `row[j - 1]` before it is read as the diagonal for `j`
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut row: Vec<usize> = (0..=short.len()).collect();
+    for (i, &long_char) in long.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &short_char) in short.iter().enumerate() {
+            let replace = diagonal + usize::from(long_char != short_char);
+            diagonal = row[j + 1];
+            row[j + 1] = replace.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[short.len()]
+}
+
+/// # Edit Distance With Edit Script
+///
+/// ## Problem Statement
+/// Like [`edit_distance`], but also return one optimal sequence of edits
+/// that turns `a` into `b`, in order.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::{edit_distance_with_script, Edit};
+///
+/// let (distance, script) = edit_distance_with_script("cat", "cut");
+/// assert_eq!(distance, 1);
+/// assert_eq!(
+///     script,
+///     vec![Edit::Keep('c'), Edit::Substitute { from: 'a', to: 'u' }, Edit::Keep('t')]
+/// );
+/// ```
+///
+/// ## Approach
+/// Fill the whole `(n + 1) × (m + 1)` table with the recurrence from
+/// [`edit_distance`], then walk back from `d[n][m]` to `d[0][0]`. At each
+/// cell, step to a neighbour whose value explains the current one; that
+/// step is the edit. Collecting the steps backwards and reversing them
+/// gives the script.
+///
+/// ## Complexity
+/// - Time: O(n · m)
+/// - Space: O(n · m) for the table
+///
+/// ## Key Points
+/// 1. The number of edits other than [`Edit::Keep`] equals the distance
+/// 2. Several scripts are often optimal; ties here prefer keeping or
+///    substituting, then deleting, then inserting
+pub fn edit_distance_with_script(a: &str, b: &str) -> (usize, Vec<Edit>) {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let (n, m) = (a.len(), b.len());
+    let mut d = vec![vec![0; m + 1]; n + 1];
+    d[0] = (0..=m).collect();
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let replace = d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = replace.min(d[i - 1][j] + 1).min(d[i][j - 1] + 1);
+        }
+    }
+
+    let mut script = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]) {
+            script.push(if a[i - 1] == b[j - 1] {
+                Edit::Keep(a[i - 1])
+            } else {
+                Edit::Substitute {
+                    from: a[i - 1],
+                    to: b[j - 1],
+                }
+            });
+            (i, j) = (i - 1, j - 1);
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            script.push(Edit::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            script.push(Edit::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    script.reverse();
+    (d[n][m], script)
+}
+
+/// # Longest Common Subsequence
+///
+/// ## Problem Statement
+/// Return a longest string whose characters appear, in order but not
+/// necessarily adjacent, in both `a` and `b`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::longest_common_subsequence;
+/// assert_eq!(longest_common_subsequence("ABCBDAB", "BDCABA").chars().count(), 4);
+/// assert_eq!(longest_common_subsequence("dynamic", "programming"), "ami");
+/// assert_eq!(longest_common_subsequence("abc", "xyz"), "");
+/// ```
+///
+/// ## Approach
+/// `L[i][j]`, the LCS length of the first `i` characters of `a` and the
+/// first `j` of `b`, is `L[i-1][j-1] + 1` if `a[i-1] == b[j-1]` (both end
+/// the subsequence) and `max(L[i-1][j], L[i][j-1])` otherwise (one of them
+/// is not used). After filling the table, walk back from `L[n][m]`: a
+/// diagonal step over equal characters emits that character, any other
+/// step moves towards the larger neighbour. The characters come out last
+/// to first.
+///
+/// ## Complexity
+/// - Time: O(n · m)
+/// - Space: O(n · m) for the table; see [`lcs_hirschberg`] for O(n + m)
+///
+/// ## Key Points
+/// 1. The LCS is often not unique (`"ab"` and `"ba"` have two); ties here
+///    prefer dropping a character of `a`
+/// 2. Only the length needs just two rows; the string needs the full
+///    table, or Hirschberg's divide and conquer
+///
+/// ## Common Pitfalls
+/// 1. Confusing subsequences with substrings: the common *substring* of
+///    `"ABCBDAB"` and `"BDCABA"` is only 2 long
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let (n, m) = (a.len(), b.len());
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in 1..=n {
+        for j in 1..=m {
+            lengths[i][j] = if a[i - 1] == b[j - 1] {
+                lengths[i - 1][j - 1] + 1
+            } else {
+                lengths[i - 1][j].max(lengths[i][j - 1])
+            };
+        }
+    }
+
+    let mut reversed = Vec::with_capacity(lengths[n][m]);
+    let (mut i, mut j) = (n, m);
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            reversed.push(a[i - 1]);
+            (i, j) = (i - 1, j - 1);
+        } else if lengths[i - 1][j] >= lengths[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    reversed.into_iter().rev().collect()
+}
+
+/// # Longest Common Subsequence in Linear Space (Hirschberg)
+///
+/// ## Problem Statement
+/// Same as [`longest_common_subsequence`], without storing the O(n · m)
+/// table.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::{lcs_hirschberg, longest_common_subsequence};
+/// let (a, b) = ("AGGTAB", "GXTXAYB");
+/// assert_eq!(lcs_hirschberg(a, b), "GTAB");
+/// assert_eq!(lcs_hirschberg(a, b).len(), longest_common_subsequence(a, b).len());
+/// ```
+///
+/// ## Approach
+/// Split `a` in half at `mid`. Some LCS of `a` and `b` is an LCS of
+/// `a[..mid]` and `b[..k]` followed by an LCS of `a[mid..]` and `b[k..]`,
+/// for the right split point `k`. To find `k`, compute with two rolling
+/// rows
+/// - `forward[j]`: LCS length of `a[..mid]` and `b[..j]`
+/// - `backward[j]`: LCS length of `a[mid..]` and `b[j..]`, by running the
+///   same computation on both strings reversed
+///
+/// and take a `k` maximizing `forward[k] + backward[k]`. Then recurse on
+/// both halves; a single character of `a` is the base case.
+///
+/// ## Complexity
+/// - Time: O(n · m); each level of the recursion does at most half the
+///   work of the level above, so the total is about twice a single pass
+/// - Space: O(n + m) for the rows, the characters, and the recursion
+///
+/// ## Key Points
+/// 1. The same idea recovers optimal alignments for edit distance in
+///    linear space, which is how it is used in bioinformatics
+/// 2. Both functions return *an* LCS; with ties they may return different
+///    ones
+pub fn lcs_hirschberg(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let mut lcs = String::new();
+    hirschberg(&a, &b, &mut lcs);
+    lcs
+}
+
+fn hirschberg(a: &[char], b: &[char], lcs: &mut String) {
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+    if let [only] = a {
+        if b.contains(only) {
+            lcs.push(*only);
+        }
+        return;
+    }
+    let mid = a.len() / 2;
+    let forward = lcs_last_row(a[..mid].iter(), b.iter());
+    let backward = lcs_last_row(a[mid..].iter().rev(), b.iter().rev());
+    let split = (0..=b.len())
+        .max_by_key(|&k| (forward[k] + backward[b.len() - k], std::cmp::Reverse(k)))
+        .expect("0..=len is never empty");
+    hirschberg(&a[..mid], &b[..split], lcs);
+    hirschberg(&a[mid..], &b[split..], lcs);
+}
+
+/// `row[j]` = LCS length of all of `a` and the first `j` items of `b`
+fn lcs_last_row<'a>(
+    a: impl Iterator<Item = &'a char>,
+    b: impl Iterator<Item = &'a char> + Clone,
+) -> Vec<usize> {
+    let mut row = vec![0; b.clone().count() + 1];
+    for x in a {
+        let mut diagonal = 0;
+        for (j, y) in b.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// # Longest Palindromic Substring
+///
+/// ## Problem Statement
+/// Return the longest substring of `s` that reads the same forwards and
+/// backwards, character by character. If several are equally long, return
+/// the leftmost.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::longest_palindrome;
+/// assert_eq!(longest_palindrome("babad"), "bab");
+/// assert_eq!(longest_palindrome("cbbd"), "bb");
+/// assert_eq!(longest_palindrome("été"), "été");
+/// assert_eq!(longest_palindrome(""), "");
+/// ```
+///
+/// ## Approach
+/// Every palindrome has a center: a character (odd length) or the gap
+/// between two characters (even length). For each of the `2n - 1` centers,
+/// expand outwards while the characters on both sides agree, and keep the
+/// longest palindrome found.
+///
+/// ## Complexity
+/// - Time: O(n²) in the worst case (`"aaaa…"`), O(n) for text with few
+///   repeats
+/// - Space: O(n) for the characters
+///
+/// ## Key Points
+/// 1. Centers are visited left to right and only a strictly longer
+///    palindrome replaces the best one, which makes the leftmost win ties
+/// 2. The result borrows from `s`; character positions are translated back
+///    to byte offsets to slice it
+///
+/// ## Common Pitfalls
+/// 1. Only trying odd-length palindromes, which misses `"abba"`
+pub fn longest_palindrome(s: &str) -> &str {
+    let chars: Vec<char> = s.chars().collect();
+    let n = chars.len();
+    let (mut best_start, mut best_len) = (0, 0);
+    for center in 0..(2 * n).saturating_sub(1) {
+        let (mut left, mut right) = (center / 2, center / 2 + center % 2);
+        let mut len = 0;
+        while right < n && chars[left] == chars[right] {
+            len = right - left + 1;
+            if left == 0 {
+                break;
+            }
+            (left, right) = (left - 1, right + 1);
+        }
+        if len > best_len {
+            (best_start, best_len) = (center.div_ceil(2) - len / 2, len);
+        }
+    }
+    char_slice(s, best_start, best_len)
+}
+
+/// # Longest Palindromic Substring in Linear Time (Manacher)
+///
+/// ## Problem Statement
+/// Same as [`longest_palindrome`], including the tie-breaking, in O(n).
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::{longest_palindrome, longest_palindrome_manacher};
+/// assert_eq!(longest_palindrome_manacher("forgeeksskeegfor"), "geeksskeeg");
+/// let s = "abacdfgdcaba";
+/// assert_eq!(longest_palindrome_manacher(s), longest_palindrome(s));
+/// ```
+///
+/// ## Approach
+/// Put a gap marker between and around the characters (`"abba"` becomes
+/// `|a|b|b|a|`), so every palindrome has odd length and a single center.
+/// Then compute `radius[i]` for every position, reusing the work of
+/// expand-around-center: keep the palindrome reaching furthest right,
+/// `center ± radius[center]`. A position `i` inside it mirrors the
+/// position `2 · center - i`, whose palindrome is already known, so
+/// `radius[i]` starts at `min(radius[mirror], right - i)` instead of 0.
+/// Only characters beyond `right` are compared, and every successful
+/// comparison moves `right` further.
+///
+/// With the markers, `radius[i]` is exactly the length of the palindrome
+/// in the original string, starting at character `(i - radius[i]) / 2`.
+///
+/// ## Complexity
+/// - Time: O(n), since `right` only moves right
+/// - Space: O(n)
+///
+/// ## Common Pitfalls
+/// 1. Trusting the mirror beyond `right`: the mirrored palindrome may be
+///    longer than the part of it that is known to repeat, hence the `min`
+pub fn longest_palindrome_manacher(s: &str) -> &str {
+    // `None` is the gap marker; gaps always equal each other
+    let marked: Vec<Option<char>> = s
+        .chars()
+        .flat_map(|c| [None, Some(c)])
+        .chain([None])
+        .collect();
+    let mut radius = vec![0; marked.len()];
+    let (mut center, mut right) = (0, 0);
+    let (mut best_start, mut best_len) = (0, 0);
+    for i in 0..marked.len() {
+        if i < right {
+            radius[i] = radius[2 * center - i].min(right - i);
+        }
+        while i > radius[i]
+            && i + radius[i] + 1 < marked.len()
+            && marked[i - radius[i] - 1] == marked[i + radius[i] + 1]
+        {
+            radius[i] += 1;
+        }
+        if i + radius[i] > right {
+            (center, right) = (i, i + radius[i]);
+        }
+        if radius[i] > best_len {
+            (best_start, best_len) = ((i - radius[i]) / 2, radius[i]);
+        }
+    }
+    char_slice(s, best_start, best_len)
+}
+
+/// The substring of `s` made of `len` characters starting at character
+/// `start`
+fn char_slice(s: &str, start: usize, len: usize) -> &str {
+    let boundaries = char_boundaries(s);
+    &s[boundaries[start]..boundaries[start + len]]
+}
+
+/// # Valid Anagram
+///
+/// ## Problem Statement
+/// Return whether `b` is a rearrangement of the characters of `a`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::is_anagram;
+/// assert!(is_anagram("listen", "silent"));
+/// assert!(!is_anagram("rat", "car"));
+/// assert!(is_anagram("résumé", "émusér"));
+/// ```
+///
+/// ## Approach
+/// Count each character of `a` up and each character of `b` down; they are
+/// anagrams exactly when every count ends at zero. For ASCII input the
+/// counts live in a `[i32; 128]` array indexed by byte. Any other
+/// character could be one of over a million code points, so Unicode input
+/// counts in a `HashMap<char, i32>` instead, which only stores the
+/// characters that occur.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1) for ASCII (128 counters), O(k) for `k` distinct
+///   characters otherwise
+///
+/// ## Key Points
+/// 1. Sorting both strings and comparing also works, in O(n log n)
+/// 2. Characters are `char`s, so `"é"` written as one code point and as
+///    `e` plus a combining accent are *not* anagrams of each other
+///
+/// ## Common Pitfalls
+/// 1. Indexing a `[i32; 26]` with `c as u8 - b'a'`, which overflows or
+///    miscounts as soon as the input leaves lowercase ASCII
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.is_ascii() && b.is_ascii() {
+        let mut counts = [0i32; 128];
+        for (x, y) in a.bytes().zip(b.bytes()) {
+            counts[usize::from(x)] += 1;
+            counts[usize::from(y)] -= 1;
+        }
+        return counts.iter().all(|&count| count == 0);
+    }
+    let mut counts: HashMap<char, i32> = HashMap::new();
+    for c in a.chars() {
+        *counts.entry(c).or_default() += 1;
+    }
+    for c in b.chars() {
+        *counts.entry(c).or_default() -= 1;
+    }
+    counts.values().all(|&count| count == 0)
+}
+
+/// # Group Anagrams
+///
+/// ## Problem Statement
+/// Partition `words` into groups of anagrams of each other. Groups appear
+/// in the order of their first word, and words within a group keep their
+/// input order.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::group_anagrams;
+///
+/// let words = ["eat", "tea", "tan", "ate", "nat", "bat"].map(String::from);
+/// assert_eq!(
+///     group_anagrams(&words),
+///     vec![vec!["eat", "tea", "ate"], vec!["tan", "nat"], vec!["bat"]]
+/// );
+/// ```
+///
+/// ## Approach
+/// Give every word a *signature* that all of its anagrams share and no
+/// other word has: its characters in sorted order (`"tea"` → `['a', 'e',
+/// 't']`). A `HashMap` from signature to group index then collects each
+/// group in one pass.
+///
+/// ## Complexity
+/// - Time: O(n · k log k) for `n` words of up to `k` characters, for
+///   sorting each word
+/// - Space: O(n · k) for the signatures
+///
+/// ## Key Points
+/// 1. A *count key* (how often each letter occurs, e.g. `[u8; 26]`) avoids
+///    the sort, O(n · k) in total, but only works for a fixed alphabet;
+///    sorted characters work for any text
+/// 2. Storing group indices rather than the groups themselves in the map
+///    is what keeps the output in first-seen order; iterating a `HashMap`
+///    would give an arbitrary order
+pub fn group_anagrams(words: &[String]) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    let mut group_of: HashMap<Vec<char>, usize> = HashMap::new();
+    for word in words {
+        let mut signature: Vec<char> = word.chars().collect();
+        signature.sort_unstable();
+        let index = *group_of.entry(signature).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(word.clone());
+    }
+    groups
+}
+
+/// # String Compression
+///
+/// ## Problem Statement
+/// Replace every run of a repeated character by the character and the run
+/// length (`"aaabb"` → `"a3b2"`). If that does not make the string
+/// shorter, return it unchanged.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::{compress, decompress};
+/// assert_eq!(compress("aabcccccaaa"), "a2b1c5a3");
+/// assert_eq!(compress("abc"), "abc"); // "a1b1c1" would be longer
+/// assert_eq!(compress("aabb"), "aabb"); // "a2b2" is not shorter
+/// assert_eq!(decompress(&compress("zzzzzzzzzzzz")).unwrap(), "zzzzzzzzzzzz");
+/// ```
+///
+/// ## Approach
+/// [`run_length_encode`](crate::vector::run_length_encode) over the
+/// characters does the counting; what is left is formatting the runs and
+/// comparing lengths.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(n)
+///
+/// ## Key Points
+/// 1. Lengths are compared in bytes, the size the string actually takes
+/// 2. Because short strings stay uncompressed, [`decompress`] must accept
+///    both forms: a character without a count stands for a single one
+/// 3. Counts and ASCII digits in the text would be indistinguishable, so
+///    strings containing digits are returned unchanged, and they do not
+///    round-trip
+pub fn compress(s: &str) -> String {
+    if s.bytes().any(|byte| byte.is_ascii_digit()) {
+        return s.to_string();
+    }
+    let chars: Vec<char> = s.chars().collect();
+    let mut compressed = String::new();
+    for (c, count) in crate::vector::run_length_encode(&chars) {
+        compressed.push(c);
+        compressed.push_str(&count.to_string());
+    }
+    if compressed.len() < s.len() {
+        compressed
+    } else {
+        s.to_string()
+    }
+}
+
+/// Longest output, in bytes, that [`decompress`] will produce
+pub const MAX_DECOMPRESSED_LEN: usize = 1 << 24;
+
+/// # String Decompression
+///
+/// ## Problem Statement
+/// Invert [`compress`]: expand every character followed by a count into
+/// that many copies. A character without a count appears once.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::decompress;
+/// assert_eq!(decompress("a3b2").unwrap(), "aaabb");
+/// assert_eq!(decompress("x12").unwrap().len(), 12);
+/// assert_eq!(decompress("ab").unwrap(), "ab");
+/// assert!(decompress("3a").is_err());
+/// assert!(decompress("a0").is_err());
+/// ```
+///
+/// ## Approach
+/// Walk the characters with a peekable iterator. Each non-digit starts a
+/// run; `next_if` then takes the digits that follow it, if any. The runs
+/// are expanded by [`run_length_decode`](crate::vector::run_length_decode).
+///
+/// ## Complexity
+/// - Time: O(n + output length)
+/// - Space: O(output length)
+///
+/// ## Common Pitfalls
+/// 1. Trusting the counts: `"a99999999999"` is 12 bytes that expand to
+///    100 GB. Like any decompressor fed untrusted input, this one checks
+///    the output size before allocating it
+///
+/// ## Errors
+/// - `InvalidInput` for a count with no character before it, a count of 0
+///   or with a leading zero, and a count too large for `usize`. Error
+///   messages give the byte offset of the problem
+/// - `OperationFailed` if the output would be longer than
+///   [`MAX_DECOMPRESSED_LEN`] bytes
+pub fn decompress(s: &str) -> Result<String> {
+    let mut runs = Vec::new();
+    let mut output_len: usize = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        if c.is_ascii_digit() {
+            return Err(ExerciseError::InvalidInput(format!(
+                "count at byte {offset} has no character before it"
+            )));
+        }
+        let mut digits = String::new();
+        while let Some((_, digit)) = chars.next_if(|(_, next)| next.is_ascii_digit()) {
+            digits.push(digit);
+        }
+        let count: usize = match digits.as_str() {
+            "" => 1,
+            _ if digits.starts_with('0') => {
+                return Err(ExerciseError::InvalidInput(format!(
+                    "count {digits} after byte {offset} must be positive, without leading zeros"
+                )));
+            }
+            _ => digits.parse().map_err(|_| {
+                ExerciseError::InvalidInput(format!(
+                    "count {digits} after byte {offset} is too large"
+                ))
+            })?,
+        };
+        output_len = count
+            .checked_mul(c.len_utf8())
+            .and_then(|run_len| output_len.checked_add(run_len))
+            .filter(|&len| len <= MAX_DECOMPRESSED_LEN)
+            .ok_or_else(|| {
+                ExerciseError::OperationFailed(format!(
+                    "output would exceed {MAX_DECOMPRESSED_LEN} bytes at byte {offset}"
+                ))
+            })?;
+        runs.push((c, count));
+    }
+    Ok(crate::vector::run_length_decode(&runs)
+        .into_iter()
+        .collect())
+}
+
+/// # Word Break
+///
+/// ## Problem Statement
+/// Return whether `s` can be split into a sequence of one or more words
+/// from `dict`. Words may be reused.
+///
+/// ## Example
+/// ```
+/// use std::collections::HashSet;
+/// use rust_ds_learning::string::word_break;
+///
+/// let dict: HashSet<String> = ["apple", "pen"].map(String::from).into();
+/// assert!(word_break("applepenapple", &dict));
+///
+/// let dict: HashSet<String> = ["cats", "dog", "sand", "and", "cat"].map(String::from).into();
+/// assert!(!word_break("catsandog", &dict));
+/// ```
+///
+/// ## Approach
+/// `breakable[i]` says whether the prefix `s[..i]` can be split. The empty
+/// prefix can; a longer prefix can if some shorter breakable prefix
+/// `s[..j]` is followed by a dictionary word `s[j..i]`. Only ends `j` within
+/// the longest dictionary word of `i` need to be tried.
+///
+/// ## Complexity
+/// - Time: O(n · L) lookups for a longest word of `L` bytes, each hashing
+///   up to `L` bytes
+/// - Space: O(n)
+///
+/// ## Key Points
+/// 1. `HashSet<String>::contains` accepts a `&str` (via `Borrow<str>`), so
+///    candidate words are slices of `s`, never allocated
+/// 2. Offsets are bytes, and only `char` boundaries are tried, so non-ASCII
+///    text cannot be sliced mid-character
+///
+/// ## Common Pitfalls
+/// 1. Plain recursion ("try every first word, recurse on the rest") without
+///    remembering results: for `"aaaa…ab"` with words `a`, `aa`, `aaa` it
+///    explores exponentially many splits before concluding `false`
+pub fn word_break(s: &str, dict: &HashSet<String>) -> bool {
+    let longest = dict.iter().map(String::len).max().unwrap_or(0);
+    let mut breakable = vec![false; s.len() + 1];
+    breakable[0] = true;
+    for end in (1..=s.len()).filter(|&end| s.is_char_boundary(end)) {
+        breakable[end] = (end.saturating_sub(longest)..end).any(|start| {
+            breakable[start] && s.is_char_boundary(start) && dict.contains(&s[start..end])
+        });
+    }
+    breakable[s.len()]
+}
+
+/// # Word Break II: All Segmentations
+///
+/// ## Problem Statement
+/// Return every way to split `s` into words from `dict`, each as the list
+/// of its words, borrowed from `s`.
+///
+/// ## Example
+/// ```
+/// use std::collections::HashSet;
+/// use rust_ds_learning::string::word_break_all;
+///
+/// let dict: HashSet<String> = ["cat", "cats", "and", "sand", "dog"].map(String::from).into();
+/// let sentences: Vec<String> = word_break_all("catsanddog", &dict)
+///     .iter()
+///     .map(|words| words.join(" "))
+///     .collect();
+/// assert_eq!(sentences, ["cat sand dog", "cats and dog"]);
+/// ```
+///
+/// ## Approach
+/// Recursion on suffixes: the segmentations of `s[start..]` are every
+/// dictionary word `s[start..end]` followed by each segmentation of
+/// `s[end..]`. A suffix is reached along many paths, so its segmentations
+/// are memoized by `start` and computed only once; a suffix with no
+/// segmentation is memoized as an empty list, which prunes dead ends.
+///
+/// ## Complexity
+/// - Time and space: O(n · L + size of the output). The output itself can
+///   be exponential: `"aaaa…a"` with words `a` and `aa` has a
+///   Fibonacci number of segmentations
+///
+/// ## Common Pitfalls
+/// 1. Expecting memoization to make this polynomial. It removes repeated
+///    *work*, but no algorithm can list exponentially many answers in
+///    polynomial time; call [`word_break`] first if only feasibility is
+///    needed
+/// 2. Building the memo from the front instead of from suffixes, which
+///    stores partial sentences that may never be completed
+pub fn word_break_all<'a>(s: &'a str, dict: &HashSet<String>) -> Vec<Vec<&'a str>> {
+    let longest = dict.iter().map(String::len).max().unwrap_or(0);
+    let mut memo: HashMap<usize, Vec<Vec<&'a str>>> = HashMap::new();
+    segmentations(s, 0, dict, longest, &mut memo);
+    memo.remove(&0).unwrap_or_default()
+}
+
+/// Fills `memo[start]` with every segmentation of `s[start..]`
+fn segmentations<'a>(
+    s: &'a str,
+    start: usize,
+    dict: &HashSet<String>,
+    longest: usize,
+    memo: &mut HashMap<usize, Vec<Vec<&'a str>>>,
+) {
+    if memo.contains_key(&start) {
+        return;
+    }
+    if start == s.len() {
+        memo.insert(start, vec![Vec::new()]);
+        return;
+    }
+    let mut found = Vec::new();
+    let max_end = s.len().min(start + longest);
+    for end in (start + 1..=max_end).filter(|&end| s.is_char_boundary(end)) {
+        let word = &s[start..end];
+        if !dict.contains(word) {
+            continue;
+        }
+        segmentations(s, end, dict, longest, memo);
+        for rest in &memo[&end] {
+            let mut words = Vec::with_capacity(rest.len() + 1);
+            words.push(word);
+            words.extend(rest);
+            found.push(words);
+        }
+    }
+    memo.insert(start, found);
+}
+
+/// # Valid Palindrome
+///
+/// ## Problem Statement
+/// Return whether `s` reads the same forwards and backwards once everything
+/// but letters and digits is dropped and case is ignored.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::is_palindrome_relaxed;
+/// assert!(is_palindrome_relaxed("A man, a plan, a canal: Panama"));
+/// assert!(is_palindrome_relaxed("Été")); // 'É' matches 'é'
+/// assert!(!is_palindrome_relaxed("race a car"));
+/// assert!(is_palindrome_relaxed(".,!")); // nothing left to compare
+/// ```
+///
+/// ## Approach
+/// Two pointers, one at each end. `Chars` is a double-ended iterator, so
+/// `next` and `next_back` are exactly those pointers, each stepping over a
+/// whole UTF-8 sequence at a time; filtering it skips punctuation and
+/// spaces on both sides. Compare the two characters case-insensitively and
+/// stop when the pointers meet.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1), no filtered copy of the string is built
+///
+/// ## Key Points
+/// 1. On ASCII input the same scan works on bytes, with
+///    `u8::is_ascii_alphanumeric` and `eq_ignore_ascii_case`; stepping by
+///    `char` is what makes it correct for `é` and other multi-byte letters
+/// 2. `char::to_lowercase` returns an iterator, since some characters
+///    lowercase to more than one (`'İ'` becomes `"i̇"`), so the comparison
+///    is between iterators
+///
+/// ## Common Pitfalls
+/// 1. Indexing bytes from both ends of non-ASCII text, which compares the
+///    halves of different characters
+/// 2. Stopping the skip loop without checking that the pointers have not
+///    crossed, which reads past the middle
+pub fn is_palindrome_relaxed(s: &str) -> bool {
+    let mut chars = s.chars().filter(|c| c.is_alphanumeric());
+    while let (Some(front), Some(back)) = (chars.next(), chars.next_back()) {
+        if !front.to_lowercase().eq(back.to_lowercase()) {
+            return false;
+        }
+    }
+    true
+}
+
+/// # Valid Palindrome After At Most One Deletion
+///
+/// ## Problem Statement
+/// Return whether `s` is a palindrome, comparing characters exactly, after
+/// deleting at most one character.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::is_palindrome_after_one_deletion;
+/// assert!(is_palindrome_after_one_deletion("aba")); // no deletion needed
+/// assert!(is_palindrome_after_one_deletion("abca")); // delete 'b' or 'c'
+/// assert!(!is_palindrome_after_one_deletion("abc"));
+/// ```
+///
+/// ## Approach
+/// Move two pointers inwards while the ends match. At the first mismatch
+/// one of the two mismatched characters has to go, so the answer is whether
+/// the rest is a palindrome with the left one skipped, or with the right
+/// one skipped. The outer pairs already match, and since they mirror
+/// each other, a deletion among them could be traded for one at the
+/// mismatch anyway.
+///
+/// ## Complexity
+/// - Time: O(n), the scan plus at most two O(n) checks
+/// - Space: O(n) for the `char`s, to index from both ends
+///
+/// ## Common Pitfalls
+/// 1. Trying only one of the two deletions: in `"cuucu"` only deleting the
+///    right character works, in `"ucuuc"` only the left one
+/// 2. Deleting greedily and continuing the scan, allowing a second deletion
+///    further in
+pub fn is_palindrome_after_one_deletion(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+    let is_palindrome = |part: &[char]| part.iter().eq(part.iter().rev());
+    let (mut left, mut right) = (0, chars.len());
+    while left + 1 < right {
+        if chars[left] != chars[right - 1] {
+            return is_palindrome(&chars[left + 1..right])
+                || is_palindrome(&chars[left..right - 1]);
+        }
+        left += 1;
+        right -= 1;
+    }
+    true
+}
+
+/// # String to Integer (atoi)
+///
+/// ## Problem Statement
+/// Parse a decimal `i32` from `s`. "Parse an integer" hides several
+/// decisions, and this function makes each of them explicitly:
+///
+/// | Input                         | Decision                                   |
+/// |-------------------------------|--------------------------------------------|
+/// | Leading/trailing whitespace   | Skipped (`str::trim`, so Unicode spaces too) |
+/// | Whitespace inside the number  | Error: `"1 000"` is not a number           |
+/// | Sign                          | One optional `+` or `-`, before the digits |
+/// | No digits (`""`, `"-"`)       | Error                                      |
+/// | Leading zeros                 | Allowed: `"007"` is 7                      |
+/// | Trailing garbage (`"42abc"`)  | Error, not a silent 42                     |
+/// | Out of `i32` range            | Error, not clamped to `i32::MAX`/`MIN`     |
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::parse_int;
+/// assert_eq!(parse_int("  -42 ").unwrap(), -42);
+/// assert_eq!(parse_int("+007").unwrap(), 7);
+/// assert_eq!(parse_int("-2147483648").unwrap(), i32::MIN);
+/// assert!(parse_int("42abc").is_err());
+/// assert!(parse_int("2147483648").is_err());
+/// ```
+///
+/// ## Approach
+/// Trim, split off the sign, then fold the digits left to right with
+/// `value = value * 10 ± digit`, using checked arithmetic so overflow is
+/// detected at the digit that causes it instead of wrapping.
+///
+/// ## Why Errors Instead of Clamping
+/// The classic atoi exercise clamps overflow to `i32::MAX` or `i32::MIN`
+/// and stops at the first non-digit, so `"99999999999"` and `"42abc"` both
+/// parse. That suits C's `atoi`, which has no way to report failure, but
+/// with `Result` available it silently turns bad input into plausible
+/// numbers: a clamped `2147483647` is indistinguishable from a real one.
+/// A caller who wants the lenient behavior can build it on top of an
+/// error; a caller who needs to reject bad input cannot recover that from
+/// a clamped value.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Negative numbers are accumulated *downwards* (`value * 10 - digit`):
+///    `i32::MIN` has no positive counterpart, so parsing `2147483648` and
+///    negating it would overflow on the one valid input that needs it
+/// 2. Every error says what went wrong and where, as a byte offset into
+///    the original `s`, which a bare `None` could not
+///
+/// ## Common Pitfalls
+/// 1. Checking for overflow *after* `value * 10 + digit`, when it has
+///    already wrapped (or panicked, in a debug build)
+/// 2. Accepting a sign with no digits after it, or two signs
+///
+/// ## Errors
+/// `InvalidInput` if there are no digits, if anything other than ASCII
+/// digits follows the sign, or if the value does not fit in an `i32`.
+pub fn parse_int(s: &str) -> Result<i32> {
+    let trimmed = s.trim();
+    let (negative, digits) = match trimmed.as_bytes().first() {
+        Some(b'-') => (true, &trimmed[1..]),
+        Some(b'+') => (false, &trimmed[1..]),
+        _ => (false, trimmed),
+    };
+    if digits.is_empty() {
+        return Err(ExerciseError::InvalidInput(format!("no digits in {s:?}")));
+    }
+    let digits_start = s.len() - s.trim_start().len() + (trimmed.len() - digits.len());
+    let mut value: i32 = 0;
+    for (i, byte) in digits.bytes().enumerate() {
+        if !byte.is_ascii_digit() {
+            let unexpected = digits[i..].chars().next().expect("i is inside digits");
+            return Err(ExerciseError::InvalidInput(format!(
+                "unexpected {unexpected:?} at byte {} of {s:?}",
+                digits_start + i
+            )));
+        }
+        let digit = i32::from(byte - b'0');
+        value = value
+            .checked_mul(10)
+            .and_then(|value| {
+                if negative {
+                    value.checked_sub(digit)
+                } else {
+                    value.checked_add(digit)
+                }
+            })
+            .ok_or_else(|| ExerciseError::InvalidInput(format!("{s:?} does not fit in an i32")))?;
+    }
+    Ok(value)
+}
+
+/// # Minimum Window Substring
+///
+/// ## Problem Statement
+/// Return the shortest substring of `haystack` that contains every
+/// character of `charset`, counted with multiplicity (`"aab"` needs two
+/// `a`s), or `None` if there is none. Among equally short windows the
+/// leftmost wins; length is counted in `char`s. An empty `charset` is
+/// covered by the empty window.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::min_window;
+/// assert_eq!(min_window("ADOBECODEBANC", "ABC"), Some("BANC"));
+/// assert_eq!(min_window("aa", "aa"), Some("aa"));
+/// assert_eq!(min_window("a", "aa"), None); // only one 'a' available
+/// ```
+///
+/// ## Approach
+/// A sliding window `haystack[left..right]` with a frequency count:
+/// `need[c]` is how many more `c`s the window needs (negative for a
+/// surplus), and `missing` is the sum of the positive `need`s, so the
+/// window is valid exactly when `missing == 0`. Each step moves `right`
+/// over one more character, then moves `left` as far as it may go, then
+/// records the window if it is valid.
+///
+/// ## When Can the Left Pointer Advance
+/// The character at `left` can be dropped when the window does not lose
+/// anything it needs by dropping it:
+/// - it is not in `charset` at all, or
+/// - it is, but the window holds a surplus of it (`need[c] < 0`)
+///
+/// Otherwise it is the only copy the window can spare of a required
+/// character, and `left` stops there. This keeps the invariant that the
+/// window is the shortest one ending at `right` with as many required
+/// characters as it has: any window ending at `right` that starts further
+/// right is missing something. Every valid window therefore gets trimmed
+/// to the best window for its right end, and the answer is the best of
+/// those.
+///
+/// ## Complexity
+/// - Time: O(n + m), each pointer passes each character once
+/// - Space: O(k) for the `k` distinct characters of `charset`
+///
+/// ## Common Pitfalls
+/// 1. Tracking which characters are present instead of how many, which
+///    accepts a single `a` for a `charset` of `"aa"`
+/// 2. Recomputing "does the window cover the charset?" by comparing whole
+///    count tables, which is O(k) per step instead of O(1) with `missing`
+/// 3. Comparing window lengths in bytes, which prefers a window of ASCII
+///    characters over a shorter one with multi-byte characters
+pub fn min_window<'a>(haystack: &'a str, charset: &str) -> Option<&'a str> {
+    if charset.is_empty() {
+        return Some("");
+    }
+    let mut need: HashMap<char, isize> = HashMap::new();
+    for c in charset.chars() {
+        *need.entry(c).or_default() += 1;
+    }
+    let mut missing = charset.chars().count();
+    let (mut left, mut left_chars) = (0, 0);
+    // (start, end, length in chars) of the best window so far
+    let mut best: Option<(usize, usize, usize)> = None;
+    for (right_chars, (start, c)) in haystack.char_indices().enumerate() {
+        let right = start + c.len_utf8();
+        if let Some(count) = need.get_mut(&c) {
+            if *count > 0 {
+                missing -= 1;
+            }
+            *count -= 1;
+        }
+        while left < right {
+            let first = haystack[left..].chars().next().expect("left < right");
+            match need.get_mut(&first) {
+                Some(count) if *count < 0 => *count += 1,
+                Some(_) => break,
+                None => {}
+            }
+            left += first.len_utf8();
+            left_chars += 1;
+        }
+        let len = right_chars + 1 - left_chars;
+        if missing == 0 && best.is_none_or(|(_, _, best_len)| len < best_len) {
+            best = Some((left, right, len));
+        }
+    }
+    best.map(|(start, end, _)| &haystack[start..end])
+}
+
+/// # Text Justification
+///
+/// ## Problem Statement
+/// Lay `words` out in lines of exactly `width` characters, fully justified:
+/// - pack as many words into each line as fit, separated by at least one
+///   space
+/// - stretch each line to `width` by widening the gaps between its words,
+///   as evenly as possible, giving any extra spaces to the leftmost gaps
+/// - left-justify a line holding a single word, and the last line, with
+///   single spaces between words, padding them with spaces on the right
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::justify;
+/// let words = ["This", "is", "an", "example", "of", "text", "justification."];
+/// assert_eq!(
+///     justify(&words, 16).unwrap(),
+///     ["This    is    an", "example  of text", "justification.  "]
+/// );
+/// ```
+///
+/// ## Approach
+/// Greedy packing: a line of words `w₁ … wₖ` fits when its letters plus
+/// `k - 1` single spaces take at most `width`, so keep adding words while
+/// that holds. To justify a line with `letters` characters of words and
+/// `gaps = k - 1` gaps, there are `width - letters` spaces to hand out:
+/// every gap gets `spaces / gaps`, and the first `spaces % gaps` gaps one
+/// more.
+///
+/// ## Complexity
+/// - Time: O(lines · width), the size of the output
+/// - Space: O(lines · width)
+///
+/// ## Key Points
+/// 1. Greedy packing minimizes the number of lines, but not the
+///    raggedness: TeX's line breaker instead minimizes a cost over all
+///    break points with dynamic programming
+/// 2. Widths count `char`s, so `"café"` takes 4 columns, not 5
+///
+/// ## Common Pitfalls
+/// 1. Dividing by `gaps` on a one-word line, where it is 0
+/// 2. Handing the remainder to the rightmost gaps, or spreading it
+///    evenly, instead of filling the leftmost gaps first
+/// 3. Fully justifying the last line
+///
+/// ## Errors
+/// `InvalidInput` if `width` is 0, if a word is empty, or if a word is
+/// longer than `width` and cannot fit on any line.
+pub fn justify(words: &[&str], width: usize) -> Result<Vec<String>> {
+    if width == 0 {
+        return Err(ExerciseError::InvalidInput(
+            "line width must be positive".to_string(),
+        ));
+    }
+    let lengths: Vec<usize> = words.iter().map(|word| word.chars().count()).collect();
+    if let Some(i) = lengths.iter().position(|&len| len == 0 || len > width) {
+        return Err(ExerciseError::InvalidInput(format!(
+            "word {i} ({:?}) must be between 1 and {width} characters long",
+            words[i]
+        )));
+    }
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    while start < words.len() {
+        // Extend the line while the next word still fits after one space
+        let mut end = start + 1;
+        let mut letters = lengths[start];
+        while end < words.len() && letters + lengths[end] + (end - start) <= width {
+            letters += lengths[end];
+            end += 1;
+        }
+
+        let line_words = &words[start..end];
+        let gaps = line_words.len() - 1;
+        let mut line = String::with_capacity(width);
+        if gaps == 0 || end == words.len() {
+            line.push_str(&line_words.join(" "));
+        } else {
+            let spaces = width - letters;
+            for (i, word) in line_words.iter().enumerate() {
+                line.push_str(word);
+                if i < gaps {
+                    let gap = spaces / gaps + usize::from(i < spaces % gaps);
+                    line.extend(std::iter::repeat_n(' ', gap));
+                }
+            }
+        }
+        // Only left-justified lines fall short of `width`
+        let padding = width - line.chars().count();
+        line.extend(std::iter::repeat_n(' ', padding));
+        lines.push(line);
+        start = end;
+    }
+    Ok(lines)
+}
+
+/// # Suffix Array by Prefix Doubling
+///
+/// ## Problem Statement
+/// Return the start offsets of all suffixes of `s`, `s[i..]` for every
+/// byte offset `i`, in lexicographic order of the suffixes.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::suffix_array;
+/// // 0 banana, 1 anana, 2 nana, 3 ana, 4 na, 5 a
+/// assert_eq!(suffix_array("banana"), [5, 3, 1, 0, 4, 2]);
+/// ```
+///
+/// ## Approach
+/// Sorting the suffixes directly compares strings of length up to `n`.
+/// Prefix doubling instead sorts them by their first `k` bytes for
+/// `k = 1, 2, 4, …`, giving each suffix a *rank*: its position in that
+/// order, with ties for equal prefixes. The first `2k` bytes of `s[i..]`
+/// are its first `k` bytes followed by the first `k` bytes of `s[i + k..]`,
+/// so the pair `(rank[i], rank[i + k])` orders suffixes by `2k` bytes
+/// without looking at a single byte. Once all ranks differ, the order is
+/// final.
+///
+/// Each round sorts the pairs with two stable counting-sort passes (a
+/// radix sort), by the second component and then by the first, which
+/// makes a round O(n) instead of O(n log n).
+///
+/// ## Complexity
+/// - Time: O(n log n): at most ⌈log₂ n⌉ rounds of O(n)
+/// - Space: O(n)
+///
+/// ## Key Points
+/// 1. A suffix shorter than `i + k` has no second half; it ranks before
+///    every suffix that has one, as a prefix sorts before its extensions
+/// 2. The order of the second keys comes for free from the previous
+///    round: walking the current suffix array, `sa[j] - k` lists the
+///    suffixes with a second half in order of that half
+/// 3. Suffixes start at every *byte* offset, including the middle of a
+///    multi-byte character; byte order agrees with `char` order on UTF-8
+///
+/// ## Common Pitfalls
+/// 1. Comparing suffixes with `sort_by_key(|&i| &s[i..])`, which is simple
+///    but O(n² log n) on repetitive text like `"aaaa…"`
+pub fn suffix_array(s: &str) -> Vec<usize> {
+    let bytes = s.as_bytes();
+    let n = bytes.len();
+    if n < 2 {
+        return (0..n).collect();
+    }
+    let mut rank: Vec<usize> = bytes.iter().map(|&byte| usize::from(byte)).collect();
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_by_key(|&i| bytes[i]);
+    let mut buckets = 256;
+    let mut k = 1;
+    let mut next_rank = vec![0; n];
+    loop {
+        // Order by second key: suffixes without a second half first
+        let mut by_second: Vec<usize> = (n - k.min(n)..n).collect();
+        by_second.extend(sa.iter().filter(|&&i| i >= k).map(|&i| i - k));
+        // Stable counting sort by first key
+        let mut counts = vec![0; buckets + 1];
+        for &i in &by_second {
+            counts[rank[i] + 1] += 1;
+        }
+        for bucket in 1..=buckets {
+            counts[bucket] += counts[bucket - 1];
+        }
+        for &i in &by_second {
+            sa[counts[rank[i]]] = i;
+            counts[rank[i]] += 1;
+        }
+
+        let key = |i: usize| (rank[i], rank.get(i + k).map_or(0, |&r| r + 1));
+        next_rank[sa[0]] = 0;
+        for j in 1..n {
+            next_rank[sa[j]] = next_rank[sa[j - 1]] + usize::from(key(sa[j - 1]) != key(sa[j]));
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+        buckets = rank[sa[n - 1]] + 1;
+        if buckets == n || k >= n {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// # LCP Array (Kasai)
+///
+/// ## Problem Statement
+/// Given `s` and its [`suffix_array`] `sa`, return `lcp` where `lcp[j]` is
+/// the length in bytes of the longest common prefix of the suffixes
+/// `sa[j - 1]` and `sa[j]`, and `lcp[0] = 0`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::{lcp_array, suffix_array};
+/// let sa = suffix_array("banana"); // a, ana, anana, banana, na, nana
+/// assert_eq!(lcp_array("banana", &sa), [0, 1, 3, 0, 0, 2]);
+/// ```
+///
+/// ## Approach
+/// Visit the suffixes in text order, `s[0..]`, `s[1..]`, …, each compared
+/// with its predecessor in `sa`. If `s[i..]` shares `h` bytes with its
+/// predecessor, then `s[i + 1..]` shares at least `h - 1` with *its*
+/// predecessor (drop the first byte of both), so the comparison can
+/// resume at `h - 1` instead of 0.
+///
+/// ## Complexity
+/// - Time: O(n): `h` drops by at most 1 per suffix and never exceeds `n`,
+///   so it increases at most `2n` times in total
+/// - Space: O(n) for the inverse of `sa`
+///
+/// ## Key Points
+/// 1. The number of distinct substrings of `s` is
+///    `n(n + 1)/2 - Σ lcp`: each suffix contributes its prefixes except
+///    those shared with its predecessor
+/// 2. The longest repeated substring is a suffix prefix of length
+///    `max(lcp)`
+///
+/// ## Panics
+/// If `sa` is not a permutation of `0..s.len()`.
+pub fn lcp_array(s: &str, sa: &[usize]) -> Vec<usize> {
+    let bytes = s.as_bytes();
+    let n = bytes.len();
+    assert_eq!(
+        sa.len(),
+        n,
+        "suffix array length must equal the text length"
+    );
+    let mut position = vec![0; n];
+    for (j, &i) in sa.iter().enumerate() {
+        position[i] = j;
+    }
+    let mut lcp = vec![0; n];
+    let mut h: usize = 0;
+    for i in 0..n {
+        if position[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let previous = sa[position[i] - 1];
+        while i + h < n && previous + h < n && bytes[i + h] == bytes[previous + h] {
+            h += 1;
+        }
+        lcp[position[i]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/// # Substring Search With a Suffix Array
+///
+/// ## Problem Statement
+/// Given `haystack` and its [`suffix_array`] `sa`, return the byte offsets
+/// of every occurrence of `needle`, in increasing order.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::{suffix_array, suffix_array_search};
+/// let text = "abracadabra";
+/// let sa = suffix_array(text);
+/// assert_eq!(suffix_array_search(text, &sa, "abra"), [0, 7]);
+/// assert_eq!(suffix_array_search(text, &sa, "a"), [0, 3, 5, 7, 10]);
+/// assert!(suffix_array_search(text, &sa, "cab").is_empty());
+/// ```
+///
+/// ## Approach
+/// Every occurrence of `needle` is the start of a suffix that begins with
+/// `needle`, and in sorted order those suffixes form one contiguous
+/// block. Two binary searches find its ends: the first suffix that is
+/// `>= needle`, and the first one after that which does not start with
+/// `needle`.
+///
+/// ## Complexity
+/// - Time: O(m log n + occ log occ) for `occ` occurrences: each binary
+///   search step compares up to `m` bytes, and the block is sorted into
+///   text order at the end
+/// - Space: O(occ)
+///
+/// ## Key Points
+/// 1. Unlike [`kmp_search`], the preprocessing is of the *haystack*, so
+///    building `sa` once pays off when searching the same text for many
+///    needles
+/// 2. An empty needle matches at every character boundary, as in
+///    [`kmp_search`]
+pub fn suffix_array_search(haystack: &str, sa: &[usize], needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return char_boundaries(haystack);
+    }
+    let (bytes, needle) = (haystack.as_bytes(), needle.as_bytes());
+    let start = sa.partition_point(|&i| &bytes[i..] < needle);
+    let len = sa[start..].partition_point(|&i| bytes[i..].starts_with(needle));
+    let mut matches = sa[start..start + len].to_vec();
+    matches.sort_unstable();
+    matches
+}
+
+/// # Wildcard Matching
+///
+/// ## Problem Statement
+/// Return whether all of `s` matches `pattern`, where `?` matches any
+/// single character, `*` matches any sequence of characters (including
+/// none), and every other character matches itself.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::wildcard_match;
+/// assert!(wildcard_match("adceb", "*a*b"));
+/// assert!(wildcard_match("abc", "a?c"));
+/// assert!(!wildcard_match("acdcb", "a*c?b"));
+/// assert!(wildcard_match("", "***"));
+/// ```
+///
+/// ## Approach
+/// Two pointers, one into `s` and one into `pattern`, plus a bookmark at
+/// the most recent `*`. Literals and `?` advance both pointers. A `*`
+/// is first tried as matching nothing; when something later fails to
+/// match, go back to the bookmark and let that `*` swallow one more
+/// character. At the end, only `*`s may be left in the pattern.
+///
+/// ## Greedy Two Pointers vs DP
+/// The DP that [`regex_match`] uses works here too: `dp[i][j]` says
+/// whether `s[i..]` matches `pattern[j..]`, filled in O(n · m) time. The
+/// greedy scan gets away with O(1) extra space because of one fact about
+/// `*`: once the pattern up to a `*` has matched, backtracking into an
+/// *earlier* `*` is never needed. Whatever extra characters the earlier
+/// `*` would take, the later one can take instead, since it matches
+/// anything. So only the latest bookmark matters. In [`regex_match`],
+/// where a starred token like `a*` only matches runs of `a`, the argument
+/// breaks down and DP is the way to go.
+///
+/// ## Complexity
+/// - Time: O(n · m) in the worst case, O(n + m) for most patterns
+/// - Space: O(n + m) for the `char`s, O(1) besides
+///
+/// ## Common Pitfalls
+/// 1. Backtracking into every `*` recursively, which is exponential on
+///    patterns like `"a*a*a*a*b"` against `"aaaa…a"`
+/// 2. Forgetting that trailing `*`s match the empty rest of `s`
+pub fn wildcard_match(s: &str, pattern: &str) -> bool {
+    let s: Vec<char> = s.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut i, mut j) = (0, 0);
+    // (pattern position after the latest `*`, where in `s` it is matched up to)
+    let mut star: Option<(usize, usize)> = None;
+    while i < s.len() {
+        match pattern.get(j) {
+            // Checked first: a `*` in the pattern is always a wildcard, even
+            // where the text has a literal `*`
+            Some('*') => {
+                star = Some((j + 1, i));
+                j += 1;
+            }
+            Some(&p) if p != '*' && (p == '?' || p == s[i]) => {
+                i += 1;
+                j += 1;
+            }
+            _ => match star {
+                Some((after_star, matched_to)) => {
+                    star = Some((after_star, matched_to + 1));
+                    i = matched_to + 1;
+                    j = after_star;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[j..].iter().all(|&p| p == '*')
+}
+
+/// # Regular Expression Matching
+///
+/// ## Problem Statement
+/// Return whether all of `s` matches `pattern`, where `.` matches any
+/// single character, `x*` matches zero or more repetitions of `x` (a
+/// character or `.`), and every other character matches itself.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::regex_match;
+/// assert!(regex_match("aab", "c*a*b").unwrap());
+/// assert!(regex_match("mississippi", "mis*is*ip*.").unwrap());
+/// assert!(!regex_match("ab", ".*c").unwrap());
+/// assert!(regex_match("a", "*a").is_err()); // nothing to repeat
+/// ```
+///
+/// ## Approach
+/// Parse the pattern into tokens, each a character or `.`, optionally
+/// starred. Let `dp[i][t]` say whether `s[i..]` matches tokens `t..`,
+/// filled from the end, where `first` means `s[i]` exists and matches
+/// token `t`:
+/// - plain token: `dp[i][t] = first && dp[i + 1][t + 1]`
+/// - starred token: `dp[i][t] = dp[i][t + 1] || (first && dp[i + 1][t])`,
+///   either the star matches nothing, or it matches `s[i]` and maybe more
+///
+/// Column `t` only reads columns `t` and `t + 1`, so two columns suffice.
+///
+/// ## Complexity
+/// - Time: O(n · m)
+/// - Space: O(n + m)
+///
+/// ## Key Points
+/// 1. A backtracking matcher (as in many regex engines) tries the two
+///    choices of every starred token recursively, and on patterns like
+///    `a*a*a*a*c` against `"aaaa…a"` explores exponentially many ways to
+///    split the `a`s between the stars before failing. DP evaluates each
+///    `(i, t)` pair once
+/// 2. [`wildcard_match`] contrasts this with a greedy scan, which suffices
+///    when a star can match anything
+///
+/// ## Errors
+/// `InvalidInput` if a `*` has nothing to repeat: at the start of the
+/// pattern, or right after another `*`.
+pub fn regex_match(s: &str, pattern: &str) -> Result<bool> {
+    // (Some(c) for a literal or None for `.`, starred)
+    let mut tokens: Vec<(Option<char>, bool)> = Vec::new();
+    for (offset, c) in pattern.char_indices() {
+        match (c, tokens.last_mut()) {
+            ('*', Some((_, starred @ false))) => *starred = true,
+            ('*', _) => {
+                return Err(ExerciseError::InvalidInput(format!(
+                    "`*` at byte {offset} of {pattern:?} has nothing to repeat"
+                )));
+            }
+            ('.', _) => tokens.push((None, false)),
+            (c, _) => tokens.push((Some(c), false)),
+        }
+    }
+
+    let s: Vec<char> = s.chars().collect();
+    let n = s.len();
+    // next[i] = dp[i][t + 1], starting from the empty rest of the pattern
+    let mut next: Vec<bool> = (0..=n).map(|i| i == n).collect();
+    for &(token, starred) in tokens.iter().rev() {
+        let mut current = vec![false; n + 1];
+        for i in (0..=n).rev() {
+            let first = i < n && token.is_none_or(|c| c == s[i]);
+            current[i] = if starred {
+                next[i] || (first && current[i + 1])
+            } else {
+                first && next[i + 1]
+            };
+        }
+        next = current;
+    }
+    Ok(next[0])
+}
@@ -0,0 +1,147 @@
+//! Grapheme-aware string operations (enabled with the `unicode-segmentation`
+//! feature).
+//!
+//! A `char` is a Unicode *code point*, but what a reader sees as one
+//! character, a *grapheme cluster*, can be several code points:
+//! - `"é"` may be `e` followed by U+0301 COMBINING ACUTE ACCENT
+//! - a flag like 🇫🇷 is two "regional indicator" code points, 🇫 and 🇷
+//! - 👩‍👩‍👧 is three emoji glued together by ZERO WIDTH JOINERs (U+200D)
+//!
+//! So the usual answer to "reverse a string", `s.chars().rev().collect()`,
+//! is subtly wrong. It keeps every code point intact, which is why it
+//! looks right on ASCII and most accented text, but it moves a combining
+//! accent onto whatever followed its letter, turns 🇫🇷 into 🇷🇫, and takes the
+//! family apart:
+//!
+//! ```
+//! let s = "cafe\u{301}!"; // "café!" with a combining accent
+//! let reversed: String = s.chars().rev().collect();
+//! assert_eq!(reversed, "!\u{301}efac"); // the accent now sits on the `!`
+//! ```
+//!
+//! Finding cluster boundaries needs the Unicode segmentation rules
+//! (UAX #29) and their data tables, which is what the `unicode-segmentation`
+//! crate provides; the standard library deliberately leaves them out.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// # Grapheme-Aware Reversal
+///
+/// ## Problem Statement
+/// Reverse `s` as the reader sees it: by user-perceived characters, keeping
+/// each grapheme cluster's code points in their original order.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::reverse_graphemes;
+/// assert_eq!(reverse_graphemes("cafe\u{301}!"), "!e\u{301}fac"); // accent stays on the e
+/// assert_eq!(reverse_graphemes("🇫🇷🇩🇪"), "🇩🇪🇫🇷");
+/// ```
+///
+/// ## Approach
+/// Split into extended grapheme clusters with `graphemes(true)`, reverse
+/// the sequence of clusters, and concatenate.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(n)
+///
+/// ## Key Points
+/// 1. `graphemes(true)` selects *extended* clusters, the ones the Unicode
+///    standard recommends for user-facing text; `false` gives the legacy
+///    rules, which split some Indic and Hangul sequences
+pub fn reverse_graphemes(s: &str) -> String {
+    s.graphemes(true).rev().collect()
+}
+
+/// # Grapheme-Aware Truncation
+///
+/// ## Problem Statement
+/// Return the prefix of `s` holding its first `n` grapheme clusters, or all
+/// of `s` if it has fewer.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::take_graphemes;
+/// let family = "👩\u{200d}👩\u{200d}👧 family";
+/// assert_eq!(take_graphemes(family, 1), "👩\u{200d}👩\u{200d}👧");
+/// assert_eq!(take_graphemes("e\u{301}clair", 2), "e\u{301}c");
+/// assert_eq!(take_graphemes("ab", 5), "ab");
+/// ```
+///
+/// ## Approach
+/// `grapheme_indices` yields each cluster's starting byte offset. The
+/// `n`-th start is where the prefix ends, and because it is a cluster
+/// boundary it is also a `char` boundary, so slicing there cannot panic.
+///
+/// ## Complexity
+/// - Time: O(length of the prefix)
+/// - Space: O(1); the result borrows from `s`
+///
+/// ## Common Pitfalls
+/// 1. `&s[..n]` counts bytes and panics in the middle of a multi-byte
+///    `char`
+/// 2. Taking `n` `char`s never panics, but can cut a flag in half or
+///    separate a letter from its accent
+pub fn take_graphemes(s: &str, n: usize) -> &str {
+    match s.grapheme_indices(true).nth(n) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_accented_characters() {
+        // Precomposed é is one code point, so chars().rev() happens to work
+        assert_eq!(reverse_graphemes("café"), "éfac");
+        // Decomposed é is two, and has to stay in order
+        assert_eq!(reverse_graphemes("cafe\u{301}"), "e\u{301}fac");
+        let naive: String = "cafe\u{301}".chars().rev().collect();
+        assert_ne!(naive, reverse_graphemes("cafe\u{301}"));
+        // Stacked combining marks belong to the same letter
+        assert_eq!(reverse_graphemes("a\u{301}\u{323}b"), "ba\u{301}\u{323}");
+    }
+
+    #[test]
+    fn test_reverse_flags() {
+        assert_eq!(reverse_graphemes("🇫🇷🇩🇪🇯🇵"), "🇯🇵🇩🇪🇫🇷");
+        let naive: String = "🇫🇷".chars().rev().collect();
+        assert_eq!(naive, "🇷🇫");
+    }
+
+    #[test]
+    fn test_reverse_zwj_emoji() {
+        let family = "👩\u{200d}👩\u{200d}👧";
+        assert_eq!(
+            reverse_graphemes(&format!("a{family}b")),
+            format!("b{family}a")
+        );
+        // Skin tone modifiers attach to the emoji before them
+        assert_eq!(reverse_graphemes("👍🏽👋"), "👋👍🏽");
+    }
+
+    #[test]
+    fn test_reverse_is_an_involution() {
+        for s in ["", "a", "hello", "e\u{301}\u{302}x", "🇫🇷👩\u{200d}💻\r\n"] {
+            assert_eq!(reverse_graphemes(&reverse_graphemes(s)), s);
+        }
+        // CR LF is a single cluster
+        assert_eq!(reverse_graphemes("a\r\n"), "\r\na");
+    }
+
+    #[test]
+    fn test_take_graphemes() {
+        assert_eq!(take_graphemes("", 3), "");
+        assert_eq!(take_graphemes("abc", 0), "");
+        assert_eq!(take_graphemes("🇫🇷🇩🇪", 1), "🇫🇷");
+        assert_eq!(take_graphemes("👍🏽👋", 1), "👍🏽");
+        assert_eq!(take_graphemes("a\u{301}\u{323}b", 1), "a\u{301}\u{323}");
+        let s = "x👩\u{200d}👩\u{200d}👧y";
+        assert_eq!(take_graphemes(s, 2), "x👩\u{200d}👩\u{200d}👧");
+        assert_eq!(take_graphemes(s, 3), s);
+    }
+}
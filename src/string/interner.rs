@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Handle to a string stored in an [`Interner`]
+///
+/// Symbols are small and `Copy`, and compare and hash as a single `u32`,
+/// however long the strings behind them are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Position of the string in interning order: the `n`-th distinct
+    /// string interned gets index `n`
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// # String Interner
+///
+/// Stores each distinct string once and hands out a [`Symbol`] for it.
+/// Interning the same string again returns the same symbol, so two
+/// symbols from one interner are equal exactly when their strings are.
+/// Compilers and parsers intern identifiers this way: comparing names
+/// becomes an integer comparison, and a name that occurs a thousand times
+/// in the source is stored once.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::Interner;
+///
+/// let mut interner = Interner::new();
+/// let x = interner.intern("x");
+/// let y = interner.intern("y");
+/// assert_eq!(interner.intern("x"), x); // deduplicated
+/// assert_ne!(x, y);
+/// assert_eq!(interner.resolve(y), Some("y"));
+/// assert_eq!(interner.len(), 2);
+///
+/// let all: Vec<&str> = interner.iter().map(|(_, s)| s).collect();
+/// assert_eq!(all, ["x", "y"]); // in interning order
+/// ```
+///
+/// ## Approach
+/// Two views of the same strings: a `Vec` indexed by symbol, for
+/// `resolve`, and a `HashMap` from string to symbol, for `intern`. A new
+/// string is pushed onto the `Vec`, and its index becomes its symbol.
+/// Strings are never removed, so symbols stay valid for the interner's
+/// whole life.
+///
+/// ## Ownership Design
+/// Both collections need the string, but it should be stored once. The
+/// tempting `HashMap<&str, Symbol>` with keys borrowing from the
+/// `Vec<String>` is self-referential: the borrow checker cannot know that
+/// pushing onto the `Vec` leaves the strings' heap buffers where they are,
+/// and expressing it needs `unsafe`. Instead each string lives in one
+/// `Arc<str>` allocation that the `Vec` and the `HashMap` share, which
+/// costs a reference count per string and keeps the interner `Send` and
+/// `Sync` (an `Rc<str>` would not).
+///
+/// ## Complexity
+/// - `intern`: O(len) expected, to hash the string; a new string also
+///   allocates a copy
+/// - `get`: O(len) expected
+/// - `resolve`: O(1)
+/// - Space: each distinct string once, plus O(1) per string in each view
+///
+/// ## Key Points
+/// 1. `intern` takes `&str`, and looks it up before allocating anything:
+///    `HashMap<Arc<str>, _>::get` accepts a `&str` because
+///    `Arc<str>: Borrow<str>`, so only new strings are copied
+/// 2. `resolve` returns a `&str` borrowed from the interner, which cannot
+///    be held across a call to `intern` (that needs `&mut self`); keep
+///    the `Symbol` instead, and resolve it again when needed
+/// 3. A `Symbol` is just a number: resolving it in a different interner
+///    gives an unrelated string, or `None`
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    symbols: HashMap<Arc<str>, Symbol>,
+    strings: Vec<Arc<str>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Interner::default()
+    }
+
+    /// Returns the symbol for `s`, storing `s` first if it is new.
+    ///
+    /// # Panics
+    /// If more than `u32::MAX` distinct strings are interned.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(s) {
+            return symbol;
+        }
+        let symbol = Symbol(u32::try_from(self.strings.len()).expect("fewer than 2^32 strings"));
+        let stored: Arc<str> = Arc::from(s);
+        self.strings.push(Arc::clone(&stored));
+        self.symbols.insert(stored, symbol);
+        symbol
+    }
+
+    /// The symbol for `s`, if it has been interned
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.symbols.get(s).copied()
+    }
+
+    /// The string behind `symbol`, or `None` if it is out of range for
+    /// this interner
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.strings.get(symbol.index()).map(|s| &**s)
+    }
+
+    /// Every symbol and its string, in interning order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Symbol, &str)> + ExactSizeIterator + '_ {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(index, s)| (Symbol(index as u32), &**s))
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
@@ -0,0 +1,192 @@
+//! String exercises and examples module
+
+pub mod ciphers;
+mod exercises;
+#[cfg(feature = "unicode-segmentation")]
+mod graphemes;
+mod interner;
+mod rolling_hash;
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests;
+
+pub use exercises::*;
+#[cfg(feature = "unicode-segmentation")]
+pub use graphemes::{reverse_graphemes, take_graphemes};
+pub use interner::{Interner, Symbol};
+pub use rolling_hash::RollingHash;
+
+crate::exercise! {
+    /// Registry entry for [`kmp_search`]
+    pub struct KmpSearchExercise {
+        name: "string::kmp_search",
+        difficulty: Hard,
+        topics: ["string matching", "failure function"],
+        hints: [
+            "After a mismatch, the naive search forgets everything it just matched.",
+            "The matched part is a prefix of the needle; which shorter prefix is also its suffix?",
+            "Precompute that answer for every prefix of the needle once.",
+        ],
+        solver: kmp_search as fn(&str, &str) -> Vec<usize>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`rabin_karp`]
+    pub struct RabinKarpExercise {
+        name: "string::rabin_karp",
+        difficulty: Medium,
+        topics: ["string matching", "hashing", "rolling hash"],
+        hints: [
+            "Comparing hashes is O(1), but rehashing every window is still O(m).",
+            "How does the hash change when one byte leaves the window and one enters?",
+            "Equal hashes do not mean equal strings.",
+        ],
+        solver: rabin_karp as fn(&str, &str) -> Vec<usize>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`edit_distance`]
+    pub struct EditDistanceExercise {
+        name: "string::edit_distance",
+        difficulty: Medium,
+        topics: ["dynamic programming", "strings"],
+        hints: [
+            "Consider the last character of each string: keep, substitute, delete or insert?",
+            "d[i][j] only depends on the row above and the cell to its left.",
+        ],
+        solver: edit_distance as fn(&str, &str) -> usize,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`longest_palindrome`]
+    pub struct LongestPalindromeExercise {
+        name: "string::longest_palindrome",
+        difficulty: Medium,
+        topics: ["palindromes", "expand around center"],
+        hints: [
+            "Every palindrome is centered on a character or between two characters.",
+            "How many centers are there, and how far can each one expand?",
+        ],
+        solver: longest_palindrome as fn(&str) -> &str,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`word_break`]
+    pub struct WordBreakExercise {
+        name: "string::word_break",
+        difficulty: Medium,
+        topics: ["dynamic programming", "hashing"],
+        hints: [
+            "If a prefix can be split, which longer prefixes does that help with?",
+            "A candidate word is never longer than the longest word in the dictionary.",
+        ],
+        solver: word_break as fn(&str, &std::collections::HashSet<String>) -> bool,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`is_palindrome_relaxed`]
+    pub struct ValidPalindromeExercise {
+        name: "string::is_palindrome_relaxed",
+        difficulty: Easy,
+        topics: ["two pointers", "palindromes"],
+        hints: [
+            "Can you compare from both ends without building a cleaned-up copy?",
+            "A `Chars` iterator can be consumed from the back as well as the front.",
+        ],
+        solver: is_palindrome_relaxed as fn(&str) -> bool,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`parse_int`]
+    pub struct ParseIntExercise {
+        name: "string::parse_int",
+        difficulty: Medium,
+        topics: ["parsing", "overflow", "error handling"],
+        hints: [
+            "List every kind of malformed input first and decide what each should do.",
+            "Can you detect overflow before it happens, rather than after?",
+            "Why is `i32::MIN` harder to parse than `i32::MAX`?",
+        ],
+        solver: parse_int as fn(&str) -> crate::Result<i32>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`min_window`]
+    pub struct MinWindowExercise {
+        name: "string::min_window",
+        difficulty: Hard,
+        topics: ["sliding window", "two pointers", "hashing"],
+        hints: [
+            "Grow the window on the right until it covers the charset.",
+            "Which characters on the left can the window give up without losing coverage?",
+            "Keep a single counter of missing characters instead of comparing count tables.",
+        ],
+        solver: min_window as for<'a> fn(&'a str, &str) -> Option<&'a str>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`ciphers::crack_caesar`]
+    pub struct CrackCaesarExercise {
+        name: "string::ciphers::crack_caesar",
+        difficulty: Medium,
+        topics: ["frequency analysis", "bytes", "modular arithmetic"],
+        hints: [
+            "How many keys does a Caesar cipher have?",
+            "Which letter is most common in English, and where did it go?",
+            "Score each candidate decryption against the expected letter frequencies.",
+        ],
+        solver: ciphers::crack_caesar as fn(&str) -> crate::Result<(u8, String)>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`justify`]
+    pub struct JustifyExercise {
+        name: "string::justify",
+        difficulty: Hard,
+        topics: ["greedy", "string building"],
+        hints: [
+            "Decide which words go on a line before deciding where the spaces go.",
+            "With `s` spaces and `g` gaps, how many spaces does each gap get, and who gets the rest?",
+            "Which lines are not fully justified?",
+        ],
+        solver: justify as fn(&[&str], usize) -> crate::Result<Vec<String>>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`suffix_array`]
+    pub struct SuffixArrayExercise {
+        name: "string::suffix_array",
+        difficulty: Hard,
+        topics: ["suffix array", "radix sort", "prefix doubling"],
+        hints: [
+            "Sort the suffixes by their first byte, then by their first two, four, ...",
+            "The first 2k bytes of a suffix are two k-byte halves you have already ranked.",
+            "Sorting pairs of small integers does not need a comparison sort.",
+        ],
+        solver: suffix_array as fn(&str) -> Vec<usize>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`regex_match`]
+    pub struct RegexMatchExercise {
+        name: "string::regex_match",
+        difficulty: Hard,
+        topics: ["dynamic programming", "pattern matching"],
+        hints: [
+            "A starred token either matches nothing or matches one character and stays.",
+            "Whether `s[i..]` matches the rest of the pattern from token `t` never changes; compute it once.",
+        ],
+        solver: regex_match as fn(&str, &str) -> crate::Result<bool>,
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{ExerciseError, Result};
+
+/// # Polynomial Rolling Hash
+///
+/// Hashes a byte string `s` of length `m` as the polynomial
+///
+/// `h(s) = s[0]·B^(m-1) + s[1]·B^(m-2) + … + s[m-1]·B^0  (mod M)`
+///
+/// for a base `B` and modulus `M`. Its point is [`roll`](Self::roll): the
+/// hash of the window one byte further along follows from the current hash
+/// in O(1), without rehashing the `m` bytes in between.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::string::RollingHash;
+///
+/// let hasher = RollingHash::default();
+/// let high = hasher.high_power(3);
+/// let rolled = hasher.roll(hasher.hash(b"abc"), b'a', b'd', high);
+/// assert_eq!(rolled, hasher.hash(b"bcd"));
+/// ```
+///
+/// ## Approach
+/// Rolling drops the outgoing byte's term, shifts every remaining term up
+/// one power, and adds the incoming byte as the new `B^0` term:
+///
+/// `h' = (h - out·B^(m-1))·B + in  (mod M)`
+///
+/// `B^(m-1) mod M` is the same for every window, so it is computed once by
+/// [`high_power`](Self::high_power).
+///
+/// ## Key Points
+/// 1. Different strings can share a hash (there are only `M` values), so an
+///    equal hash only says "maybe equal"; the bytes must still be compared
+/// 2. `M < 2³²` keeps every intermediate product below 2⁶⁴
+/// 3. The default is `B = 256`, `M = 1_000_000_007`: with `B = 256` the
+///    hash is just the string read as a big-endian number, reduced mod `M`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollingHash {
+    base: u64,
+    modulus: u64,
+}
+
+impl Default for RollingHash {
+    fn default() -> Self {
+        RollingHash {
+            base: 256,
+            modulus: 1_000_000_007,
+        }
+    }
+}
+
+impl RollingHash {
+    /// # Errors
+    /// `InvalidInput` unless `1 <= base < modulus <= u32::MAX`.
+    pub fn new(base: u64, modulus: u64) -> Result<Self> {
+        if base == 0 || base >= modulus || modulus > u64::from(u32::MAX) {
+            return Err(ExerciseError::InvalidInput(format!(
+                "need 1 <= base < modulus <= 2^32 - 1, got base {base} and modulus {modulus}"
+            )));
+        }
+        Ok(RollingHash { base, modulus })
+    }
+
+    /// Hash of `bytes`
+    pub fn hash(&self, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0, |hash, &byte| {
+            (hash * self.base + u64::from(byte)) % self.modulus
+        })
+    }
+
+    /// `B^(len - 1) mod M`, the weight of the first byte of a window of
+    /// length `len`
+    pub fn high_power(&self, len: usize) -> u64 {
+        (1..len).fold(1 % self.modulus, |power, _| {
+            power * self.base % self.modulus
+        })
+    }
+
+    /// Hash of the next window, given the hash of the current one, the
+    /// byte leaving it, the byte entering it, and
+    /// [`high_power`](Self::high_power) of the window length
+    pub fn roll(&self, hash: u64, outgoing: u8, incoming: u8, high_power: u64) -> u64 {
+        let outgoing = u64::from(outgoing) % self.modulus * high_power % self.modulus;
+        let without = (hash + self.modulus - outgoing) % self.modulus;
+        (without * self.base + u64::from(incoming)) % self.modulus
+    }
+
+    /// `(start, hash)` for every window of `len` bytes in `text`, from
+    /// left to right, each hash rolled from the previous one
+    pub fn window_hashes<'a>(
+        &'a self,
+        text: &'a [u8],
+        len: usize,
+    ) -> impl Iterator<Item = (usize, u64)> + 'a {
+        let high_power = self.high_power(len);
+        let first = self.hash(&text[..len.min(text.len())]);
+        let count = (text.len() + 1).saturating_sub(len);
+        (0..count).scan(first, move |hash, start| {
+            if start > 0 && len > 0 {
+                *hash = self.roll(*hash, text[start - 1], text[start + len - 1], high_power);
+            }
+            Some((start, *hash))
+        })
+    }
+}
@@ -0,0 +1,1342 @@
+#[cfg(test)]
+mod tests {
+    use crate::string::{
+        compress, decompress, edit_distance, edit_distance_with_script, failure_function, find_all,
+        group_anagrams, is_anagram, is_palindrome_after_one_deletion, is_palindrome_relaxed,
+        justify, kmp_search, lcp_array, lcs_hirschberg, longest_common_subsequence,
+        longest_palindrome, longest_palindrome_manacher, min_window, parse_int, rabin_karp,
+        rabin_karp_multi, rabin_karp_with, regex_match, suffix_array, suffix_array_search,
+        wildcard_match, word_break, word_break_all, z_array, Edit, Interner, RollingHash, Symbol,
+        MAX_DECOMPRESSED_LEN,
+    };
+
+    /// Every occurrence of a non-empty needle, overlapping ones included
+    fn naive_search(haystack: &str, needle: &str) -> Vec<usize> {
+        (0..=haystack.len().saturating_sub(needle.len()))
+            .filter(|&i| haystack.as_bytes()[i..].starts_with(needle.as_bytes()))
+            .collect()
+    }
+
+    /// Strings over a small alphabet, so that repeats and overlaps are common
+    fn small_alphabet_strings(max_len: usize) -> Vec<String> {
+        let mut strings = vec![String::new()];
+        let mut frontier = vec![String::new()];
+        for _ in 0..max_len {
+            frontier = frontier
+                .iter()
+                .flat_map(|s| ["a", "b"].map(|c| format!("{s}{c}")))
+                .collect();
+            strings.extend(frontier.iter().cloned());
+        }
+        strings
+    }
+
+    mod kmp_tests {
+        use super::*;
+
+        fn failure_brute_force(pattern: &[u8]) -> Vec<usize> {
+            (0..pattern.len())
+                .map(|i| {
+                    let prefix = &pattern[..=i];
+                    (0..=i)
+                        .rev()
+                        .find(|&len| prefix.ends_with(&prefix[..len]))
+                        .unwrap()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_failure_function_matches_definition() {
+            for pattern in small_alphabet_strings(8) {
+                assert_eq!(
+                    failure_function(pattern.as_bytes()),
+                    failure_brute_force(pattern.as_bytes()),
+                    "{pattern}"
+                );
+            }
+        }
+        #[test]
+        fn test_failure_function_examples() {
+            assert_eq!(failure_function::<u8>(&[]), Vec::<usize>::new());
+            assert_eq!(failure_function(b"a"), vec![0]);
+            assert_eq!(failure_function(b"abcd"), vec![0, 0, 0, 0]);
+            assert_eq!(failure_function(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+            assert_eq!(failure_function(&[1, 2, 1, 2, 1]), vec![0, 0, 1, 2, 3]);
+        }
+        #[test]
+        fn test_search_matches_naive() {
+            let haystacks = small_alphabet_strings(9);
+            for needle in small_alphabet_strings(4).iter().skip(1) {
+                for haystack in haystacks.iter().step_by(7) {
+                    assert_eq!(
+                        kmp_search(haystack, needle),
+                        naive_search(haystack, needle),
+                        "{needle} in {haystack}"
+                    );
+                }
+            }
+        }
+        #[test]
+        fn test_search_edge_cases() {
+            assert_eq!(kmp_search("", "a"), Vec::<usize>::new());
+            assert_eq!(kmp_search("ab", "abc"), Vec::<usize>::new());
+            assert_eq!(kmp_search("abc", "abc"), vec![0]);
+            assert_eq!(kmp_search("aaaa", "aa"), vec![0, 1, 2]);
+            assert_eq!(kmp_search("", ""), vec![0]);
+            assert_eq!(kmp_search("añb", ""), vec![0, 1, 3, 4]);
+        }
+        #[test]
+        fn test_search_worst_case_for_naive() {
+            let haystack = "a".repeat(10_000);
+            let needle = format!("{}b", "a".repeat(999));
+            assert!(kmp_search(&haystack, &needle).is_empty());
+            assert_eq!(kmp_search(&haystack, &needle[..999]).len(), 10_000 - 998);
+        }
+        #[test]
+        fn test_search_unicode() {
+            let haystack = "日本語の日本";
+            assert_eq!(kmp_search(haystack, "日本"), vec![0, 12]);
+            assert_eq!(kmp_search(haystack, "本"), vec![3, 15]);
+            let found: Vec<usize> = haystack.match_indices("日本").map(|(i, _)| i).collect();
+            assert_eq!(kmp_search(haystack, "日本"), found);
+        }
+    }
+
+    mod rabin_karp_tests {
+        use super::*;
+
+        #[test]
+        fn test_roll_matches_rehash() {
+            let text = b"the quick brown fox";
+            for hasher in [RollingHash::default(), RollingHash::new(31, 101).unwrap()] {
+                for len in 1..=text.len() {
+                    for (start, hash) in hasher.window_hashes(text, len) {
+                        assert_eq!(hash, hasher.hash(&text[start..start + len]));
+                    }
+                }
+            }
+        }
+        #[test]
+        fn test_engineered_collision_is_rejected() {
+            // Found by a birthday search over random six-letter words: read
+            // as base-256 numbers, the two differ by a multiple of 1e9 + 7
+            let hasher = RollingHash::default();
+            assert_eq!(hasher.hash(b"cavsow"), hasher.hash(b"wxuuev"));
+            assert_eq!(rabin_karp("wxuuev cavsow", "cavsow"), vec![7]);
+            assert_eq!(rabin_karp("wxuuev", "cavsow"), Vec::<usize>::new());
+        }
+        #[test]
+        fn test_tiny_modulus_collides_everywhere() {
+            // Every window hashes to 0 or 1, so almost every window is a
+            // collision that only the byte comparison catches
+            let hasher = RollingHash::new(1, 2).unwrap();
+            assert_eq!(hasher.hash(b"ab"), hasher.hash(b"ba"));
+            for needle in small_alphabet_strings(4).iter().skip(1) {
+                for haystack in small_alphabet_strings(8).iter().step_by(5) {
+                    assert_eq!(
+                        rabin_karp_with(&hasher, haystack, needle),
+                        naive_search(haystack, needle),
+                        "{needle} in {haystack}"
+                    );
+                }
+            }
+        }
+        #[test]
+        fn test_search_matches_kmp() {
+            for needle in small_alphabet_strings(4) {
+                for haystack in small_alphabet_strings(8).iter().step_by(3) {
+                    assert_eq!(rabin_karp(haystack, &needle), kmp_search(haystack, &needle));
+                }
+            }
+            assert_eq!(rabin_karp("日本語の日本", "日本"), vec![0, 12]);
+        }
+        #[test]
+        fn test_multi_matches_single_searches() {
+            let needles = ["a", "ab", "ba", "abb", "ab", "bbb"];
+            for haystack in small_alphabet_strings(8).iter().step_by(3) {
+                let mut expected: Vec<(usize, usize)> = needles
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(index, needle)| {
+                        kmp_search(haystack, needle)
+                            .into_iter()
+                            .map(move |start| (start, index))
+                    })
+                    .collect();
+                expected.sort_unstable();
+                assert_eq!(rabin_karp_multi(haystack, &needles), expected, "{haystack}");
+            }
+        }
+        #[test]
+        fn test_multi_edge_cases() {
+            assert_eq!(rabin_karp_multi("abc", &[]), vec![]);
+            assert_eq!(
+                rabin_karp_multi("ab", &["", "b"]),
+                vec![(0, 0), (1, 0), (1, 1), (2, 0)]
+            );
+            assert_eq!(rabin_karp_multi("ab", &["abc"]), vec![]);
+        }
+        #[test]
+        fn test_invalid_hash_parameters() {
+            assert!(RollingHash::new(0, 7).is_err());
+            assert!(RollingHash::new(7, 7).is_err());
+            assert!(RollingHash::new(2, 1 << 32).is_err());
+            assert!(RollingHash::new(2, u64::from(u32::MAX)).is_ok());
+        }
+    }
+
+    mod z_array_tests {
+        use super::*;
+
+        fn z_brute_force(s: &[u8]) -> Vec<usize> {
+            (0..s.len())
+                .map(|i| s.iter().zip(&s[i..]).take_while(|(a, b)| a == b).count())
+                .collect()
+        }
+
+        /// The failure function derived from the Z-array: a Z-box at `i`
+        /// of length `z[i]` is a border of every prefix ending inside it
+        fn failure_from_z(z: &[usize]) -> Vec<usize> {
+            let mut fail = vec![0; z.len()];
+            for i in (1..z.len()).rev() {
+                if z[i] > 0 {
+                    fail[i + z[i] - 1] = fail[i + z[i] - 1].max(z[i]);
+                }
+            }
+            for j in (0..z.len().saturating_sub(1)).rev() {
+                fail[j] = fail[j].max(fail[j + 1].saturating_sub(1));
+            }
+            fail
+        }
+
+        #[test]
+        fn test_z_array_matches_definition() {
+            for s in small_alphabet_strings(9) {
+                assert_eq!(z_array(s.as_bytes()), z_brute_force(s.as_bytes()), "{s}");
+            }
+        }
+        #[test]
+        fn test_z_array_edge_cases() {
+            assert_eq!(z_array::<u8>(&[]), Vec::<usize>::new());
+            assert_eq!(z_array(b"x"), vec![1]);
+            assert_eq!(z_array(b"abc"), vec![3, 0, 0]);
+            assert_eq!(z_array(&[1, 2, 1, 2, 1]), vec![5, 0, 3, 0, 1]);
+        }
+        #[test]
+        fn test_z_array_agrees_with_failure_function() {
+            for s in small_alphabet_strings(9) {
+                let z = z_array(s.as_bytes());
+                assert_eq!(failure_from_z(&z), failure_function(s.as_bytes()), "{s}");
+            }
+        }
+        #[test]
+        fn test_find_all_matches_kmp() {
+            for needle in small_alphabet_strings(4) {
+                for haystack in small_alphabet_strings(8).iter().step_by(3) {
+                    assert_eq!(find_all(haystack, &needle), kmp_search(haystack, &needle));
+                }
+            }
+        }
+        #[test]
+        fn test_find_all_without_separator() {
+            // Bytes a separator would have to avoid: every match runs on
+            // into the haystack, and the needle contains `$`
+            assert_eq!(find_all("aaaa", "a"), vec![0, 1, 2, 3]);
+            assert_eq!(find_all("x$y$", "$"), vec![1, 3]);
+            assert_eq!(find_all("ab", "abc"), Vec::<usize>::new());
+            assert_eq!(find_all("日本語の日本", "日本"), vec![0, 12]);
+            assert_eq!(find_all("añb", ""), vec![0, 1, 3, 4]);
+        }
+    }
+
+    mod edit_distance_tests {
+        use super::*;
+
+        /// Plain recursion over the three choices, exponential time
+        fn edit_distance_brute_force(a: &[char], b: &[char]) -> usize {
+            match (a.split_last(), b.split_last()) {
+                (None, _) => b.len(),
+                (_, None) => a.len(),
+                (Some((x, a_rest)), Some((y, b_rest))) => {
+                    let replace = edit_distance_brute_force(a_rest, b_rest) + usize::from(x != y);
+                    let delete = edit_distance_brute_force(a_rest, b) + 1;
+                    let insert = edit_distance_brute_force(a, b_rest) + 1;
+                    replace.min(delete).min(insert)
+                }
+            }
+        }
+
+        /// Replays `script` on `source`, checking that it is consistent
+        fn apply(source: &str, script: &[Edit]) -> String {
+            let mut source = source.chars();
+            let mut target = String::new();
+            for &edit in script {
+                match edit {
+                    Edit::Keep(c) => {
+                        assert_eq!(source.next(), Some(c));
+                        target.push(c);
+                    }
+                    Edit::Substitute { from, to } => {
+                        assert_ne!(from, to);
+                        assert_eq!(source.next(), Some(from));
+                        target.push(to);
+                    }
+                    Edit::Delete(c) => assert_eq!(source.next(), Some(c)),
+                    Edit::Insert(c) => target.push(c),
+                }
+            }
+            assert_eq!(source.next(), None, "script must consume the whole source");
+            target
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let strings = small_alphabet_strings(5);
+            for a in strings.iter().step_by(3) {
+                for b in strings.iter().step_by(4) {
+                    let (a_chars, b_chars): (Vec<char>, Vec<char>) =
+                        (a.chars().collect(), b.chars().collect());
+                    let expected = edit_distance_brute_force(&a_chars, &b_chars);
+                    assert_eq!(edit_distance(a, b), expected, "{a} -> {b}");
+                    assert_eq!(edit_distance_with_script(a, b).0, expected, "{a} -> {b}");
+                }
+            }
+        }
+        #[test]
+        fn test_script_transforms_and_is_optimal() {
+            let strings = small_alphabet_strings(5);
+            for a in strings.iter().step_by(2) {
+                for b in strings.iter().step_by(3) {
+                    let (distance, script) = edit_distance_with_script(a, b);
+                    assert_eq!(apply(a, &script), *b);
+                    let edits = script
+                        .iter()
+                        .filter(|e| !matches!(e, Edit::Keep(_)))
+                        .count();
+                    assert_eq!(edits, distance, "{a} -> {b}: {script:?}");
+                }
+            }
+        }
+        #[test]
+        fn test_unicode_chars() {
+            assert_eq!(edit_distance("naïve", "naive"), 1);
+            assert_eq!(edit_distance("日本語", "日本"), 1);
+            assert_eq!(edit_distance("🦀", "🐍"), 1);
+            assert_eq!(edit_distance("straße", "strasse"), 2);
+            let (distance, script) = edit_distance_with_script("añb", "anb");
+            assert_eq!(distance, 1);
+            assert_eq!(
+                script[1],
+                Edit::Substitute {
+                    from: 'ñ', to: 'n'
+                }
+            );
+        }
+        #[test]
+        fn test_symmetric_and_bounded() {
+            let pairs = [
+                ("", ""),
+                ("abc", ""),
+                ("flaw", "lawn"),
+                ("intention", "execution"),
+            ];
+            for (a, b) in pairs {
+                let distance = edit_distance(a, b);
+                assert_eq!(distance, edit_distance(b, a));
+                assert!(distance <= a.chars().count().max(b.chars().count()));
+            }
+            assert_eq!(edit_distance("intention", "execution"), 5);
+            assert_eq!(edit_distance("flaw", "lawn"), 2);
+        }
+    }
+
+    mod lcs_tests {
+        use super::*;
+
+        fn is_subsequence(sub: &str, s: &str) -> bool {
+            let mut chars = s.chars();
+            sub.chars().all(|c| chars.any(|d| d == c))
+        }
+
+        fn lcs_len_brute_force(a: &[char], b: &[char]) -> usize {
+            match (a.split_first(), b.split_first()) {
+                (Some((x, a_rest)), Some((y, b_rest))) if x == y => {
+                    1 + lcs_len_brute_force(a_rest, b_rest)
+                }
+                (Some((_, a_rest)), Some((_, b_rest))) => {
+                    lcs_len_brute_force(a_rest, b).max(lcs_len_brute_force(a, b_rest))
+                }
+                _ => 0,
+            }
+        }
+
+        #[test]
+        fn test_empty_inputs() {
+            for lcs in [longest_common_subsequence, lcs_hirschberg] {
+                assert_eq!(lcs("", ""), "");
+                assert_eq!(lcs("abc", ""), "");
+                assert_eq!(lcs("", "abc"), "");
+            }
+        }
+        #[test]
+        fn test_identical_inputs() {
+            for s in ["a", "abcabc", "日本語", "aaaaaaaaaa"] {
+                assert_eq!(longest_common_subsequence(s, s), s);
+                assert_eq!(lcs_hirschberg(s, s), s);
+            }
+        }
+        #[test]
+        fn test_both_are_longest_common_subsequences() {
+            let strings = small_alphabet_strings(6);
+            for a in strings.iter().step_by(5) {
+                for b in strings.iter().step_by(7) {
+                    let (a_chars, b_chars): (Vec<char>, Vec<char>) =
+                        (a.chars().collect(), b.chars().collect());
+                    let expected = lcs_len_brute_force(&a_chars, &b_chars);
+                    for lcs in [longest_common_subsequence(a, b), lcs_hirschberg(a, b)] {
+                        assert_eq!(lcs.len(), expected, "{a} {b}: {lcs}");
+                        assert!(is_subsequence(&lcs, a) && is_subsequence(&lcs, b));
+                    }
+                }
+            }
+        }
+        #[test]
+        fn test_unicode_and_longer_inputs() {
+            assert_eq!(
+                longest_common_subsequence("naïve café", "native cafe"),
+                "nave caf"
+            );
+            assert_eq!(
+                lcs_hirschberg("naïve café", "native cafe").chars().count(),
+                8
+            );
+            let a: String = (0..300).map(|i| ['x', 'y', 'z'][i * 7 % 3]).collect();
+            let b: String = (0..200).map(|i| ['x', 'y', 'z'][i * i % 3]).collect();
+            let full = longest_common_subsequence(&a, &b);
+            let linear = lcs_hirschberg(&a, &b);
+            assert_eq!(full.len(), linear.len());
+            assert!(is_subsequence(&linear, &a) && is_subsequence(&linear, &b));
+        }
+    }
+
+    mod palindrome_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        /// Leftmost longest palindrome, by checking every substring
+        fn brute_force(s: &str) -> String {
+            let chars: Vec<char> = s.chars().collect();
+            let mut best: &[char] = &[];
+            for start in 0..chars.len() {
+                for end in start + 1..=chars.len() {
+                    let candidate = &chars[start..end];
+                    if candidate.len() > best.len() && candidate.iter().eq(candidate.iter().rev()) {
+                        best = candidate;
+                    }
+                }
+            }
+            best.iter().collect()
+        }
+
+        #[test]
+        fn test_both_match_brute_force() {
+            for s in small_alphabet_strings(10).iter().step_by(3) {
+                let expected = brute_force(s);
+                assert_eq!(longest_palindrome(s), expected, "{s}");
+                assert_eq!(longest_palindrome_manacher(s), expected, "{s}");
+            }
+        }
+        #[test]
+        fn test_expand_and_manacher_agree_on_random_strings() {
+            for seed in 0..300 {
+                let len = seed as usize % 60;
+                let bytes = random_vec(len, b'a'..b'a' + 1 + seed as u8 % 4, seed);
+                let s = String::from_utf8(bytes).unwrap();
+                assert_eq!(
+                    longest_palindrome(&s),
+                    longest_palindrome_manacher(&s),
+                    "{s}"
+                );
+            }
+        }
+        #[test]
+        fn test_edge_cases() {
+            for longest in [longest_palindrome, longest_palindrome_manacher] {
+                assert_eq!(longest(""), "");
+                assert_eq!(longest("x"), "x");
+                assert_eq!(longest("xy"), "x");
+                assert_eq!(longest("abcd"), "a");
+                assert_eq!(longest("aaaa"), "aaaa");
+                assert_eq!(longest("abba"), "abba");
+                assert_eq!(longest("xabay"), "aba");
+            }
+        }
+        #[test]
+        fn test_unicode() {
+            for longest in [longest_palindrome, longest_palindrome_manacher] {
+                assert_eq!(longest("日本本日"), "日本本日");
+                assert_eq!(longest("xñøñy"), "ñøñ");
+                assert_eq!(longest("🦀a🦀"), "🦀a🦀");
+            }
+        }
+    }
+
+    mod anagram_tests {
+        use super::*;
+
+        fn sorted_chars(s: &str) -> Vec<char> {
+            let mut chars: Vec<char> = s.chars().collect();
+            chars.sort_unstable();
+            chars
+        }
+
+        #[test]
+        fn test_is_anagram_matches_sorting() {
+            let strings = small_alphabet_strings(6);
+            for a in strings.iter().step_by(3) {
+                for b in strings.iter().step_by(5) {
+                    assert_eq!(
+                        is_anagram(a, b),
+                        sorted_chars(a) == sorted_chars(b),
+                        "{a} {b}"
+                    );
+                }
+            }
+        }
+        #[test]
+        fn test_is_anagram_edge_cases() {
+            assert!(is_anagram("", ""));
+            assert!(!is_anagram("a", ""));
+            assert!(!is_anagram("aab", "abb"));
+            assert!(is_anagram("Dormitory", "ryotimroD"));
+            assert!(!is_anagram("Listen", "silent")); // case-sensitive
+        }
+        #[test]
+        fn test_is_anagram_unicode() {
+            assert!(is_anagram("日本語", "語日本"));
+            assert!(is_anagram("🦀🐍🦀", "🦀🦀🐍"));
+            assert!(!is_anagram("🦀🐍🐍", "🦀🦀🐍"));
+            // Same number of bytes, different characters
+            assert!(!is_anagram("é", "ab"));
+            // Mixed ASCII and non-ASCII takes the HashMap path
+            assert!(is_anagram("abé", "éba"));
+            assert!(!is_anagram("abé", "ébb"));
+        }
+        #[test]
+        fn test_group_anagrams_order_and_membership() {
+            let words: Vec<String> = ["", "b", "", "ab", "ba", "abc", "cab", "b"]
+                .map(String::from)
+                .to_vec();
+            let groups = group_anagrams(&words);
+            assert_eq!(
+                groups,
+                vec![
+                    vec!["", ""],
+                    vec!["b", "b"],
+                    vec!["ab", "ba"],
+                    vec!["abc", "cab"]
+                ]
+            );
+            assert_eq!(group_anagrams(&[]), Vec::<Vec<String>>::new());
+        }
+        #[test]
+        fn test_group_anagrams_is_a_partition() {
+            let words: Vec<String> = small_alphabet_strings(5);
+            let groups = group_anagrams(&words);
+            assert_eq!(groups.iter().map(Vec::len).sum::<usize>(), words.len());
+            for group in &groups {
+                assert!(group.iter().all(|word| is_anagram(word, &group[0])));
+            }
+            for (i, a) in groups.iter().enumerate() {
+                for b in &groups[i + 1..] {
+                    assert!(!is_anagram(&a[0], &b[0]));
+                }
+            }
+        }
+    }
+
+    mod compression_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_round_trip_small_alphabet() {
+            for s in small_alphabet_strings(10) {
+                assert_eq!(decompress(&compress(&s)).unwrap(), s);
+                assert!(compress(&s).len() <= s.len());
+            }
+        }
+        #[test]
+        fn test_round_trip_random_runs() {
+            let alphabet = ['x', 'y', 'é', '日', ' '];
+            for seed in 0..200 {
+                let runs = random_vec(seed as usize % 10, 0..alphabet.len(), seed);
+                let lengths = random_vec(runs.len(), 1..30, seed + 1000);
+                let s: String = runs
+                    .iter()
+                    .zip(&lengths)
+                    .flat_map(|(&c, &len)| std::iter::repeat_n(alphabet[c], len))
+                    .collect();
+                assert_eq!(decompress(&compress(&s)).unwrap(), s, "{s:?}");
+            }
+        }
+        #[test]
+        fn test_only_applied_when_shorter() {
+            assert_eq!(compress(""), "");
+            assert_eq!(compress("a"), "a");
+            assert_eq!(compress("aa"), "aa");
+            assert_eq!(compress("aaa"), "a3");
+            assert_eq!(compress("aaabb"), "a3b2");
+            // Multi-byte characters: "ééé" is 6 bytes, "é3" is 3
+            assert_eq!(compress("ééé"), "é3");
+            assert_eq!(compress("éé"), "é2");
+        }
+        #[test]
+        fn test_digits_are_left_alone() {
+            assert_eq!(compress("aaaa1111"), "aaaa1111");
+            assert_eq!(compress("2024"), "2024");
+        }
+        #[test]
+        fn test_multi_digit_counts() {
+            let s = "b".repeat(1234);
+            assert_eq!(compress(&s), "b1234");
+            assert_eq!(decompress("b1234").unwrap(), s);
+            assert_eq!(decompress("a10b1c").unwrap(), "aaaaaaaaaabc");
+        }
+        #[test]
+        fn test_malformed_counts() {
+            assert!(decompress("").unwrap().is_empty());
+            for malformed in ["1", "3a", "a0", "a05", "ab00", "a99999999999999999999999"] {
+                assert!(decompress(malformed).is_err(), "{malformed}");
+            }
+        }
+        #[test]
+        fn test_output_size_is_capped() {
+            for bomb in ["a99999999999", "a16777217", "ab16777216", "é8388609"] {
+                assert!(
+                    matches!(decompress(bomb), Err(ExerciseError::OperationFailed(_))),
+                    "{bomb}"
+                );
+            }
+            let at_limit = format!("b{MAX_DECOMPRESSED_LEN}");
+            assert_eq!(decompress(&at_limit).unwrap().len(), MAX_DECOMPRESSED_LEN);
+        }
+    }
+
+    mod word_break_tests {
+        use super::*;
+        use std::collections::HashSet;
+
+        fn dict(words: &[&str]) -> HashSet<String> {
+            words.iter().map(|&word| word.to_string()).collect()
+        }
+
+        fn all_brute_force<'a>(s: &'a str, dict: &HashSet<String>) -> Vec<Vec<&'a str>> {
+            if s.is_empty() {
+                return vec![Vec::new()];
+            }
+            let mut all = Vec::new();
+            for end in (1..=s.len()).filter(|&end| s.is_char_boundary(end)) {
+                if dict.contains(&s[..end]) {
+                    for mut rest in all_brute_force(&s[end..], dict) {
+                        rest.insert(0, &s[..end]);
+                        all.push(rest);
+                    }
+                }
+            }
+            all
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let dict = dict(&["a", "b", "ab", "ba", "abb", "bbb"]);
+            for s in small_alphabet_strings(9).iter().step_by(2) {
+                let expected = all_brute_force(s, &dict);
+                assert_eq!(word_break_all(s, &dict), expected, "{s:?}");
+                assert_eq!(word_break(s, &dict), !expected.is_empty(), "{s:?}");
+            }
+        }
+        #[test]
+        fn test_edge_cases() {
+            let words = dict(&["a"]);
+            assert!(word_break("", &words));
+            assert_eq!(word_break_all("", &words), vec![Vec::<&str>::new()]);
+            assert!(!word_break("a", &dict(&[])));
+            assert!(word_break_all("a", &dict(&[])).is_empty());
+            assert!(word_break("", &dict(&[])));
+        }
+        #[test]
+        fn test_unicode_words() {
+            let dict = dict(&["日本", "語", "本語", "日"]);
+            assert!(word_break("日本語", &dict));
+            assert_eq!(
+                word_break_all("日本語", &dict),
+                [vec!["日", "本語"], vec!["日本", "語"]]
+            );
+            assert!(!word_break("日本人", &dict));
+        }
+        #[test]
+        fn test_exponential_dead_end_is_fast() {
+            // Without memoization this tries a tribonacci number of splits, ~1.84^200
+            let s = format!("{}b", "a".repeat(200));
+            let dict = dict(&["a", "aa", "aaa"]);
+            assert!(!word_break(&s, &dict));
+            assert!(word_break_all(&s, &dict).is_empty());
+        }
+        #[test]
+        fn test_exponentially_many_segmentations() {
+            let dict = dict(&["a", "aa"]);
+            let counts: Vec<usize> = (1..=10)
+                .map(|n| word_break_all(&"a".repeat(n), &dict).len())
+                .collect();
+            assert_eq!(counts, [1, 2, 3, 5, 8, 13, 21, 34, 55, 89]);
+        }
+    }
+
+    mod valid_palindrome_tests {
+        use super::*;
+
+        fn is_palindrome(s: &str) -> bool {
+            s.chars().eq(s.chars().rev())
+        }
+
+        #[test]
+        fn test_relaxed_matches_filtered_copy() {
+            let inputs = [
+                "",
+                " ",
+                "a",
+                "Aa",
+                "ab",
+                "No 'x' in Nixon",
+                "Was it a car or a cat I saw?",
+                "0P",
+                "1a1",
+                "été",
+                "Ésope reste ici et se repose",
+                "abc, CBA",
+                "ab, CB",
+            ];
+            for s in inputs {
+                let filtered: String = s
+                    .chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .flat_map(char::to_lowercase)
+                    .collect();
+                assert_eq!(is_palindrome_relaxed(s), is_palindrome(&filtered), "{s:?}");
+            }
+        }
+        #[test]
+        fn test_relaxed_ignores_punctuation_and_case() {
+            assert!(is_palindrome_relaxed("A man, a plan, a canal: Panama"));
+            assert!(is_palindrome_relaxed("No 'x' in Nixon"));
+            assert!(!is_palindrome_relaxed("0P")); // digits are kept
+            assert!(is_palindrome_relaxed("!!a??"));
+            assert!(is_palindrome_relaxed("日本日"));
+            assert!(!is_palindrome_relaxed("日本"));
+        }
+        #[test]
+        fn test_one_deletion_matches_brute_force() {
+            for s in small_alphabet_strings(10) {
+                let chars: Vec<char> = s.chars().collect();
+                let expected = is_palindrome(&s)
+                    || (0..chars.len()).any(|skip| {
+                        let rest: String = chars
+                            .iter()
+                            .enumerate()
+                            .filter(|&(i, _)| i != skip)
+                            .map(|(_, &c)| c)
+                            .collect();
+                        is_palindrome(&rest)
+                    });
+                assert_eq!(is_palindrome_after_one_deletion(&s), expected, "{s}");
+            }
+        }
+        #[test]
+        fn test_one_deletion_edge_cases() {
+            assert!(is_palindrome_after_one_deletion("cuucu"));
+            assert!(is_palindrome_after_one_deletion("ucuuc"));
+            assert!(!is_palindrome_after_one_deletion("abcdea"));
+            assert!(!is_palindrome_after_one_deletion("abcd"));
+            assert!(is_palindrome_after_one_deletion(""));
+            assert!(is_palindrome_after_one_deletion("é日"));
+            // Comparison is exact
+            assert!(!is_palindrome_after_one_deletion("Abca"));
+        }
+    }
+
+    mod parse_int_tests {
+        use super::*;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_valid_inputs() {
+            let cases = [
+                ("0", 0),
+                ("42", 42),
+                ("-42", -42),
+                ("+42", 42),
+                ("   42", 42),
+                ("42\t\n", 42),
+                ("\u{3000}7\u{a0}", 7), // ideographic and no-break spaces
+                ("007", 7),
+                ("-0", 0),
+                ("-000", 0),
+                ("2147483647", i32::MAX),
+                ("-2147483648", i32::MIN),
+                ("00000000002147483647", i32::MAX),
+            ];
+            for (input, expected) in cases {
+                assert_eq!(parse_int(input).unwrap(), expected, "{input:?}");
+            }
+        }
+        #[test]
+        fn test_invalid_inputs() {
+            let cases = [
+                ("", "no digits"),
+                ("   ", "no digits"),
+                ("-", "no digits"),
+                ("+", "no digits"),
+                ("+-1", "unexpected '-' at byte 1"),
+                ("--1", "unexpected '-' at byte 1"),
+                ("42abc", "unexpected 'a' at byte 2"),
+                ("  4 2", "unexpected ' ' at byte 3"),
+                ("1_000", "unexpected '_' at byte 1"),
+                ("12é", "unexpected 'é' at byte 2"),
+                ("3.0", "unexpected '.' at byte 1"),
+                ("0x1f", "unexpected 'x' at byte 1"),
+                ("abc", "unexpected 'a' at byte 0"),
+                ("- 1", "unexpected ' ' at byte 1"),
+                ("2147483648", "does not fit"),
+                ("-2147483649", "does not fit"),
+                ("99999999999999999999", "does not fit"),
+            ];
+            for (input, message) in cases {
+                match parse_int(input) {
+                    Err(ExerciseError::InvalidInput(error)) => {
+                        assert!(error.contains(message), "{input:?}: {error}")
+                    }
+                    other => panic!("{input:?}: expected an error, got {other:?}"),
+                }
+            }
+        }
+        #[test]
+        fn test_agrees_with_std_on_trimmed_input() {
+            let inputs = [
+                "1",
+                "-1",
+                "+1",
+                "++1",
+                "",
+                "-",
+                "12a",
+                "2147483647",
+                "2147483648",
+                "-2147483648",
+                "-2147483649",
+                "0000",
+                " 5",
+                "5 ",
+            ];
+            for input in inputs {
+                assert_eq!(
+                    parse_int(input).ok(),
+                    input.trim().parse::<i32>().ok(),
+                    "{input:?}"
+                );
+            }
+            for value in [i32::MIN, -1_000_000, -1, 0, 1, 65_536, i32::MAX] {
+                assert_eq!(parse_int(&value.to_string()).unwrap(), value);
+            }
+        }
+    }
+
+    mod min_window_tests {
+        use super::*;
+
+        /// Leftmost shortest covering window, by checking every substring
+        fn brute_force<'a>(haystack: &'a str, charset: &str) -> Option<&'a str> {
+            let covers = |window: &str| {
+                let mut window: Vec<char> = window.chars().collect();
+                charset
+                    .chars()
+                    .all(|c| match window.iter().position(|&w| w == c) {
+                        Some(i) => {
+                            window.swap_remove(i);
+                            true
+                        }
+                        None => false,
+                    })
+            };
+            let bounds: Vec<usize> = haystack
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([haystack.len()])
+                .collect();
+            (0..bounds.len())
+                .flat_map(|len| (0..bounds.len() - len).map(move |start| (start, start + len)))
+                .map(|(start, end)| &haystack[bounds[start]..bounds[end]])
+                .find(|window| covers(window))
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let charsets = ["a", "b", "ab", "aa", "aab", "abb", "bbb", "aabb"];
+            for haystack in small_alphabet_strings(9).iter().step_by(3) {
+                for charset in charsets {
+                    assert_eq!(
+                        min_window(haystack, charset),
+                        brute_force(haystack, charset),
+                        "{haystack} / {charset}"
+                    );
+                }
+            }
+        }
+        #[test]
+        fn test_repeated_required_characters() {
+            assert_eq!(min_window("abcabdebac", "aab"), Some("abca"));
+            assert_eq!(min_window("abcbdaxa", "aab"), Some("bdaxa"));
+            assert_eq!(min_window("aXbXaXXa", "aaa"), Some("aXbXaXXa"));
+            assert_eq!(min_window("abab", "aaa"), None);
+            // The left pointer must not drop a required 'a' just because an
+            // 'a' appears again later in the window
+            assert_eq!(min_window("aaabaa", "aab"), Some("aab"));
+            assert_eq!(min_window("baaab", "bb"), Some("baaab"));
+        }
+        #[test]
+        fn test_edge_cases() {
+            assert_eq!(min_window("", "a"), None);
+            assert_eq!(min_window("", ""), Some(""));
+            assert_eq!(min_window("abc", ""), Some(""));
+            assert_eq!(min_window("abc", "d"), None);
+            assert_eq!(min_window("abc", "cba"), Some("abc"));
+            assert_eq!(min_window("Aa", "a"), Some("a")); // case-sensitive
+        }
+        #[test]
+        fn test_counts_chars_not_bytes() {
+            // "y日日x" is 8 bytes against 5 for "xaaay", but one char shorter
+            assert_eq!(min_window("xaaay日日x", "xy"), Some("y日日x"));
+            assert_eq!(min_window("日本語の本", "本の"), Some("の本"));
+        }
+    }
+
+    mod justify_tests {
+        use super::*;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_canonical_cases() {
+            let cases: [(&[&str], usize, &[&str]); 4] = [
+                (
+                    &[
+                        "This",
+                        "is",
+                        "an",
+                        "example",
+                        "of",
+                        "text",
+                        "justification.",
+                    ],
+                    16,
+                    &["This    is    an", "example  of text", "justification.  "],
+                ),
+                // A single-word line in the middle is left-justified, and
+                // so is the last line even when it has several words
+                (
+                    &["What", "must", "be", "acknowledgment", "shall", "be"],
+                    16,
+                    &["What   must   be", "acknowledgment  ", "shall be        "],
+                ),
+                // Uneven gaps: the extra spaces go to the leftmost gaps
+                (
+                    &[
+                        "Science",
+                        "is",
+                        "what",
+                        "we",
+                        "understand",
+                        "well",
+                        "enough",
+                        "to",
+                        "explain",
+                        "to",
+                        "a",
+                        "computer.",
+                        "Art",
+                        "is",
+                        "everything",
+                        "else",
+                        "we",
+                        "do",
+                    ],
+                    20,
+                    &[
+                        "Science  is  what we",
+                        "understand      well",
+                        "enough to explain to",
+                        "a  computer.  Art is",
+                        "everything  else  we",
+                        "do                  ",
+                    ],
+                ),
+                // Words exactly as wide as the line
+                (&["abc", "de", "f"], 3, &["abc", "de ", "f  "]),
+            ];
+            for (words, width, expected) in cases {
+                assert_eq!(justify(words, width).unwrap(), expected, "{words:?}");
+            }
+        }
+        #[test]
+        fn test_every_line_is_full_width_and_keeps_the_words() {
+            let words: Vec<String> = (0..200)
+                .map(|i| "ab".repeat(1 + i * 7 % 5) + &"é".repeat(i % 3))
+                .collect();
+            let words: Vec<&str> = words.iter().map(String::as_str).collect();
+            for width in 14..40 {
+                let lines = justify(&words, width).unwrap();
+                assert!(lines.iter().all(|line| line.chars().count() == width));
+                let rejoined: Vec<&str> = lines
+                    .iter()
+                    .flat_map(|line| line.split_whitespace())
+                    .collect();
+                assert_eq!(rejoined, words);
+                // Greedy: the next line's first word would not have fit
+                for pair in lines.windows(2) {
+                    let used = pair[0]
+                        .split_whitespace()
+                        .map(|w| w.chars().count() + 1)
+                        .sum::<usize>();
+                    let next = pair[1].split_whitespace().next().unwrap().chars().count();
+                    assert!(used + next > width, "{pair:?}");
+                }
+            }
+        }
+        #[test]
+        fn test_edge_cases() {
+            assert!(justify(&[], 10).unwrap().is_empty());
+            assert_eq!(justify(&["a"], 1).unwrap(), ["a"]);
+            assert_eq!(justify(&["a", "b"], 3).unwrap(), ["a b"]);
+            assert_eq!(justify(&["a", "b", "c"], 3).unwrap(), ["a b", "c  "]);
+            assert_eq!(
+                justify(&["naïve", "café"], 6).unwrap(),
+                ["naïve ", "café  "]
+            );
+        }
+        #[test]
+        fn test_width_validation() {
+            for (words, width) in [(&["a"][..], 0), (&["toolong"], 5), (&["ok", ""], 5)] {
+                assert!(
+                    matches!(justify(words, width), Err(ExerciseError::InvalidInput(_))),
+                    "{words:?} / {width}"
+                );
+            }
+        }
+    }
+
+    mod suffix_array_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        fn naive_suffix_array(s: &str) -> Vec<usize> {
+            let mut sa: Vec<usize> = (0..s.len()).collect();
+            sa.sort_by_key(|&i| &s.as_bytes()[i..]);
+            sa
+        }
+
+        fn naive_lcp(s: &str, sa: &[usize]) -> Vec<usize> {
+            let bytes = s.as_bytes();
+            (0..sa.len())
+                .map(|j| match j {
+                    0 => 0,
+                    _ => bytes[sa[j - 1]..]
+                        .iter()
+                        .zip(&bytes[sa[j]..])
+                        .take_while(|(a, b)| a == b)
+                        .count(),
+                })
+                .collect()
+        }
+
+        fn random_strings() -> Vec<String> {
+            (0..300)
+                .map(|seed| {
+                    let alphabet = 1 + seed as u8 % 5;
+                    let bytes = random_vec(seed as usize % 80, b'a'..b'a' + alphabet, seed);
+                    String::from_utf8(bytes).unwrap()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_matches_naive_sort() {
+            for s in small_alphabet_strings(10).iter().chain(&random_strings()) {
+                assert_eq!(suffix_array(s), naive_suffix_array(s), "{s}");
+            }
+        }
+        #[test]
+        fn test_repetitive_and_unicode_text() {
+            for s in [
+                "a".repeat(1000),
+                "ab".repeat(300),
+                "abaababaabaab".repeat(20),
+                "mississippi".to_string(),
+                "naïve café, déjà vu ✓ 日本語".to_string(),
+                "\u{0}\u{7f}\u{80}\u{10ffff}".to_string(),
+            ] {
+                assert_eq!(suffix_array(&s), naive_suffix_array(&s), "{s}");
+            }
+            assert_eq!(suffix_array(""), Vec::<usize>::new());
+            assert_eq!(suffix_array("x"), [0]);
+        }
+        #[test]
+        fn test_lcp_matches_naive() {
+            for s in small_alphabet_strings(8).iter().chain(&random_strings()) {
+                let sa = suffix_array(s);
+                assert_eq!(lcp_array(s, &sa), naive_lcp(s, &sa), "{s}");
+            }
+            let s = "mississippi";
+            let sa = suffix_array(s);
+            assert_eq!(lcp_array(s, &sa), [0, 1, 1, 4, 0, 0, 1, 0, 2, 1, 3]);
+        }
+        #[test]
+        fn test_lcp_counts_distinct_substrings() {
+            for s in small_alphabet_strings(7) {
+                let n = s.len();
+                let lcp_sum: usize = lcp_array(&s, &suffix_array(&s)).iter().sum();
+                let distinct: std::collections::HashSet<&str> = (0..n)
+                    .flat_map(|i| (i + 1..=n).map(move |j| (i, j)))
+                    .map(|(i, j)| &s[i..j])
+                    .collect();
+                assert_eq!(n * (n + 1) / 2 - lcp_sum, distinct.len(), "{s}");
+            }
+        }
+        #[test]
+        fn test_search_matches_kmp() {
+            for haystack in random_strings().iter().step_by(5) {
+                let sa = suffix_array(haystack);
+                for needle in small_alphabet_strings(4) {
+                    assert_eq!(
+                        suffix_array_search(haystack, &sa, &needle),
+                        kmp_search(haystack, &needle),
+                        "{haystack} / {needle}"
+                    );
+                }
+            }
+            let text = "naïve naïve";
+            let sa = suffix_array(text);
+            assert_eq!(suffix_array_search(text, &sa, "ïv"), [2, 9]);
+            assert_eq!(
+                suffix_array_search(text, &sa, "naïve naïve!"),
+                Vec::<usize>::new()
+            );
+            assert_eq!(suffix_array_search("", &[], "a"), Vec::<usize>::new());
+        }
+    }
+
+    mod pattern_match_tests {
+        use super::*;
+
+        /// Exponential-time backtracking matchers, fine for short inputs
+        fn wildcard_backtracking(s: &[char], pattern: &[char]) -> bool {
+            match pattern.split_first() {
+                None => s.is_empty(),
+                Some(('*', rest)) => {
+                    (0..=s.len()).any(|skip| wildcard_backtracking(&s[skip..], rest))
+                }
+                Some((&p, rest)) => {
+                    !s.is_empty() && (p == '?' || p == s[0]) && wildcard_backtracking(&s[1..], rest)
+                }
+            }
+        }
+
+        fn regex_backtracking(s: &[char], pattern: &[char]) -> bool {
+            let Some(&p) = pattern.first() else {
+                return s.is_empty();
+            };
+            let first = !s.is_empty() && (p == '.' || p == s[0]);
+            if pattern.get(1) == Some(&'*') {
+                regex_backtracking(s, &pattern[2..])
+                    || (first && regex_backtracking(&s[1..], pattern))
+            } else {
+                first && regex_backtracking(&s[1..], &pattern[1..])
+            }
+        }
+
+        fn chars(s: &str) -> Vec<char> {
+            s.chars().collect()
+        }
+
+        #[test]
+        fn test_wildcard_matches_backtracking() {
+            let patterns = [
+                "", "*", "?", "a", "a*", "*b", "*a*", "?*?", "a?b*", "**a", "*ab*ba*", "b?*a",
+            ];
+            for s in small_alphabet_strings(7) {
+                for pattern in patterns {
+                    assert_eq!(
+                        wildcard_match(&s, pattern),
+                        wildcard_backtracking(&chars(&s), &chars(pattern)),
+                        "{s:?} / {pattern:?}"
+                    );
+                }
+            }
+        }
+        #[test]
+        fn test_wildcard_text_with_metacharacters() {
+            // Texts containing `*` and `?`, which are only special in the pattern
+            let mut texts = vec![String::new()];
+            for _ in 0..4 {
+                let longer: Vec<String> = texts
+                    .iter()
+                    .flat_map(|t| "a*?".chars().map(move |c| format!("{t}{c}")))
+                    .collect();
+                texts.extend(longer);
+            }
+            texts.sort();
+            texts.dedup();
+            let patterns = ["*", "a*", "*a", "?", "**", "*?*", "a?*", "?*a", "*a*?"];
+            for s in &texts {
+                for pattern in patterns {
+                    assert_eq!(
+                        wildcard_match(s, pattern),
+                        wildcard_backtracking(&chars(s), &chars(pattern)),
+                        "{s:?} / {pattern:?}"
+                    );
+                }
+            }
+            assert!(wildcard_match("a*b", "a*"));
+            assert!(wildcard_match("*x", "*"));
+            assert!(wildcard_match("?", "*"));
+            assert!(!wildcard_match("ab", "a?*?"));
+        }
+        #[test]
+        fn test_regex_matches_backtracking() {
+            let patterns = [
+                "", ".", ".*", "a*", "a*b*", "b*a", ".a*", "a.b", "a*.*b", "ab*a*", "..", ".*b.*",
+            ];
+            for s in small_alphabet_strings(7) {
+                for pattern in patterns {
+                    assert_eq!(
+                        regex_match(&s, pattern).unwrap(),
+                        regex_backtracking(&chars(&s), &chars(pattern)),
+                        "{s:?} / {pattern:?}"
+                    );
+                }
+            }
+        }
+        #[test]
+        fn test_classic_cases() {
+            assert!(!wildcard_match("aa", "a"));
+            assert!(wildcard_match("aa", "*"));
+            assert!(!wildcard_match("cb", "?a"));
+            assert!(wildcard_match("naïve", "na?ve"));
+            assert!(wildcard_match("日本語", "*語"));
+            assert!(!regex_match("aa", "a").unwrap());
+            assert!(regex_match("aa", "a*").unwrap());
+            assert!(regex_match("ab", ".*").unwrap());
+            assert!(!regex_match("mississippi", "mis*is*p*.").unwrap());
+            assert!(regex_match("", "a*b*.*").unwrap());
+            assert!(regex_match("é", ".").unwrap());
+            // `?` is a literal to the regex matcher, `.` to the wildcard one
+            assert!(regex_match("?", "?").unwrap());
+            assert!(!regex_match("a", "?").unwrap());
+            assert!(!wildcard_match("a", "."));
+        }
+        #[test]
+        fn test_regex_rejects_dangling_star() {
+            for pattern in ["*", "*a", "a**", ".**"] {
+                assert!(regex_match("a", pattern).is_err(), "{pattern:?}");
+            }
+        }
+        #[test]
+        fn test_backtracking_blow_up_patterns_are_fast() {
+            // The backtracking matchers above would try exponentially many
+            // ways to split the input among the stars before failing
+            let s = "a".repeat(5000);
+            assert!(!wildcard_match(&s, "*a*a*a*a*a*a*a*b"));
+            assert!(wildcard_match(&s, &"*a".repeat(50)));
+            assert!(!regex_match(&s, &format!("{}c", "a*".repeat(30))).unwrap());
+            assert!(regex_match(&s, &"a*".repeat(30)).unwrap());
+            let s = "ab".repeat(1000);
+            assert!(!regex_match(&s, ".*.*.*.*.*b.*.*c").unwrap());
+        }
+    }
+
+    mod interner_tests {
+        use super::*;
+        use std::collections::HashMap;
+
+        #[test]
+        fn test_symbols_are_stable_and_dense() {
+            let mut interner = Interner::new();
+            assert!(interner.is_empty());
+            let words = ["let", "x", "=", "x", "+", "let", "", "x"];
+            let symbols: Vec<Symbol> = words.iter().map(|word| interner.intern(word)).collect();
+            let indices: Vec<usize> = symbols.iter().map(|symbol| symbol.index()).collect();
+            assert_eq!(indices, [0, 1, 2, 1, 3, 0, 4, 1]);
+            assert_eq!(interner.len(), 5);
+            for (word, &symbol) in words.iter().zip(&symbols) {
+                assert_eq!(interner.resolve(symbol), Some(*word));
+                assert_eq!(interner.get(word), Some(symbol));
+            }
+        }
+        #[test]
+        fn test_matches_a_reference_map() {
+            let mut interner = Interner::new();
+            let mut reference: HashMap<String, Symbol> = HashMap::new();
+            for s in small_alphabet_strings(6)
+                .iter()
+                .chain(&small_alphabet_strings(5))
+            {
+                let symbol = interner.intern(s);
+                assert_eq!(*reference.entry(s.clone()).or_insert(symbol), symbol, "{s}");
+            }
+            assert_eq!(interner.len(), reference.len());
+            for (symbol, s) in interner.iter() {
+                assert_eq!(reference[s], symbol);
+            }
+        }
+        #[test]
+        fn test_get_does_not_intern() {
+            let mut interner = Interner::new();
+            assert_eq!(interner.get("missing"), None);
+            assert!(interner.is_empty());
+            let symbol = interner.intern("naïve");
+            assert_eq!(interner.get("naïve"), Some(symbol));
+            assert_eq!(interner.get("naive"), None);
+        }
+        #[test]
+        fn test_resolve_out_of_range() {
+            let mut big = Interner::new();
+            big.intern("a");
+            let b = big.intern("b");
+            let mut small = Interner::new();
+            small.intern("z");
+            assert_eq!(small.resolve(b), None);
+        }
+        #[test]
+        fn test_iteration_order_and_clone() {
+            let mut interner = Interner::new();
+            for word in ["c", "a", "b", "a"] {
+                interner.intern(word);
+            }
+            let forward: Vec<&str> = interner.iter().map(|(_, s)| s).collect();
+            assert_eq!(forward, ["c", "a", "b"]);
+            let backward: Vec<usize> = interner
+                .iter()
+                .rev()
+                .map(|(symbol, _)| symbol.index())
+                .collect();
+            assert_eq!(backward, [2, 1, 0]);
+            assert_eq!(interner.iter().len(), 3);
+
+            // A clone shares no state with the original
+            let mut copy = interner.clone();
+            let d = copy.intern("d");
+            assert_eq!(interner.resolve(d), None);
+            assert_eq!(copy.intern("a"), interner.intern("a"));
+        }
+    }
+}
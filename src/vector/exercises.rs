@@ -3,6 +3,15 @@
 //! This module contains challenging algorithmic problems that use vectors
 //! as their primary data structure.
 
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::ops::Range;
+
+use rand::Rng;
+
+use super::MonotonicQueue;
+use crate::{ExerciseError, Result};
+
 /// # Sliding Window Maximum
 ///
 /// ## Problem Statement
@@ -20,6 +29,9 @@
 /// ## Approach
 /// Uses a deque (double-ended queue) to maintain indices of potential maximum elements.
 /// The deque stores elements in decreasing order, with the front being the current maximum.
+/// That deque is packaged as the reusable [`MonotonicQueue`].
+///
+/// This is the `i32` case of the generic [`sliding_window_max`].
 ///
 /// ## Complexity
 /// - Time: O(n) where n is the length of the input array
@@ -36,60 +48,127 @@
 /// 3. Not removing elements outside current window
 /// 4. Not maintaining monotonic property of deque
 pub fn sliding_window_maximum(nums: &[i32], window_size: usize) -> Vec<i32> {
-    if nums.is_empty() || window_size == 0 {
+    sliding_window_max(nums, window_size)
+}
+
+/// Brute-force baseline for [`sliding_window_maximum`]: scans every window
+/// from scratch.
+///
+/// Time: O(n * k). Kept for comparison (see `benches/vector.rs`): for
+/// tiny windows (k around 4) the rescan actually wins, since it is a tight
+/// loop without deque bookkeeping; by k = 64 the deque is several times
+/// faster and the gap keeps growing with k.
+pub fn sliding_window_maximum_naive(nums: &[i32], window_size: usize) -> Vec<i32> {
+    if window_size == 0 {
         return vec![];
     }
-    if window_size == 1 {
-        return nums.to_vec();
-    }
+    nums.windows(window_size)
+        .filter_map(|window| window.iter().max().copied())
+        .collect()
+}
 
-    let mut result = Vec::with_capacity(nums.len() - window_size + 1);
-    let mut deque = std::collections::VecDeque::new();
-
-    // Process first window
-    for i in 0..window_size {
-        // Remove all elements smaller than current element from back
-        // This maintains monotonic decreasing property
-        while let Some(&back) = deque.back() {
-            if nums[back] <= nums[i] {
-                deque.pop_back();
-            } else {
-                break;
-            }
-        }
-        deque.push_back(i);
-    }
+/// # Generic Sliding Window Maximum
+///
+/// [`sliding_window_maximum`] for any `Ord` element type: strings, tuples,
+/// custom structs, or floats through a totally ordered wrapper.
+///
+/// ## Example
+/// ```
+/// use std::cmp::Ordering;
+/// use rust_ds_learning::vector::sliding_window_max;
+///
+/// let words = ["pear", "fig", "apple", "kiwi"];
+/// assert_eq!(sliding_window_max(&words, 2), vec!["pear", "fig", "kiwi"]);
+///
+/// // f64 is only PartialOrd (NaN), so wrap it in a type ordered by total_cmp
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Total(f64);
+/// impl Eq for Total {}
+/// impl PartialOrd for Total {
+///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+///         Some(self.cmp(other))
+///     }
+/// }
+/// impl Ord for Total {
+///     fn cmp(&self, other: &Self) -> Ordering {
+///         self.0.total_cmp(&other.0)
+///     }
+/// }
+///
+/// let temps = [Total(1.5), Total(-0.5), Total(2.25)];
+/// assert_eq!(sliding_window_max(&temps, 2), vec![Total(1.5), Total(2.25)]);
+/// ```
+///
+/// ## Approach
+/// A [`MonotonicQueue`] holds candidates whose values are strictly
+/// decreasing from front to back. A new element evicts every back entry it dominates (`<=`
+/// it), since those can never be a window maximum again, and the front is
+/// dropped once it slides out of the window.
+///
+/// ## Complexity
+/// - Time: O(n), each index enters and leaves the deque once
+/// - Space: O(k)
+///
+/// ## Key Points
+/// 1. Only `Clone` is needed, to copy each window's answer into the output;
+///    the queue stores references into `nums`
+/// 2. Returns one element per complete window, so nothing when
+///    `window_size` is 0 or larger than the input
+///
+/// For other aggregates over a moving window (sums, gcds, custom
+/// associative operations) see [`crate::streaming::SlidingWindow`].
+pub fn sliding_window_max<T: Ord + Clone>(nums: &[T], window_size: usize) -> Vec<T> {
+    sliding_window_by(nums, window_size, MonotonicQueue::max())
+}
+
+/// Minimum counterpart of [`sliding_window_max`]: the queue keeps values
+/// strictly increasing, and a new element evicts the back entries `>=` it.
+///
+/// ```
+/// use rust_ds_learning::vector::sliding_window_min;
+/// assert_eq!(sliding_window_min(&[4, 2, 12, 3, 8], 3), vec![2, 2, 3]);
+/// ```
+pub fn sliding_window_min<T: Ord + Clone>(nums: &[T], window_size: usize) -> Vec<T> {
+    sliding_window_by(nums, window_size, MonotonicQueue::min())
+}
 
-    // Process remaining elements
-    for i in window_size..nums.len() {
-        // Add maximum from current window
-        result.push(nums[deque[0]]);
+/// # Sliding Window Minimum
+///
+/// The mirror image of [`sliding_window_maximum`]: the minimum of every
+/// window of `window_size` consecutive elements.
+///
+/// ```
+/// use rust_ds_learning::vector::sliding_window_minimum;
+/// let nums = vec![1, 3, -1, -3, 5, 3, 6, 7];
+/// assert_eq!(sliding_window_minimum(&nums, 3), vec![-1, -3, -3, -3, 3, 3]);
+/// ```
+///
+/// Time: O(n); Space: O(k). This is the `i32` case of [`sliding_window_min`].
+pub fn sliding_window_minimum(nums: &[i32], window_size: usize) -> Vec<i32> {
+    sliding_window_min(nums, window_size)
+}
 
-        // Remove elements outside current window
-        while let Some(&front) = deque.front() {
-            if front <= i - window_size {
-                deque.pop_front();
-            } else {
-                break;
-            }
-        }
+/// Window scan shared by the max and min variants; `queue` decides which
+/// extreme is reported
+fn sliding_window_by<'a, T: Ord + Clone>(
+    nums: &'a [T],
+    window_size: usize,
+    mut queue: MonotonicQueue<&'a T>,
+) -> Vec<T> {
+    if window_size == 0 || window_size > nums.len() {
+        return vec![];
+    }
 
-        // Remove elements smaller than current element
-        while let Some(&back) = deque.back() {
-            if nums[back] <= nums[i] {
-                deque.pop_back();
-            } else {
-                break;
+    let mut result = Vec::with_capacity(nums.len() - window_size + 1);
+    for (i, value) in nums.iter().enumerate() {
+        queue.push(value);
+        if i + 1 >= window_size {
+            queue.pop_expired(i + 1 - window_size);
+            if let Some(&extreme) = queue.current_extreme() {
+                result.push(extreme.clone());
             }
         }
-        deque.push_back(i);
-    }
-
-    // Add maximum from last window
-    if !deque.is_empty() {
-        result.push(nums[deque[0]]);
     }
-
     result
 }
 
@@ -157,84 +236,1982 @@ pub fn merge_intervals(intervals: &[(i32, i32)]) -> Vec<(i32, i32)> {
     result
 }
 
-/// Given a vector of integers (positive and negative), find the contiguous subarray
-/// with the largest product.
+/// # Insert Interval
 ///
-/// Example:
-/// Input: [-2, 3, -4]
-/// Output: 24 (subarray [3, -4])
+/// ## Problem Statement
+/// Given a list of closed intervals sorted by start and pairwise
+/// non-overlapping, insert `new` and merge where necessary so the result is
+/// again sorted and non-overlapping.
 ///
-/// Challenge aspects:
-/// - Handle negative numbers
-/// - Handle zeros
-/// - Optimize space complexity
-/// - Handle integer overflow cases
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::insert_interval;
+/// let sorted = vec![(1, 2), (3, 5), (6, 7), (8, 10), (12, 16)];
+/// assert_eq!(
+///     insert_interval(&sorted, (4, 8)),
+///     vec![(1, 2), (3, 10), (12, 16)]
+/// );
+/// ```
 ///
-/// Expected complexity:
-/// Time: O(n)
-/// Space: O(1)
-pub fn max_product(v: &Vec<i32>) -> i32 {
-    if v.is_empty() {
-        return 0;
-    }
+/// ## Approach
+/// The input splits into three runs, so one pass suffices:
+/// 1. Intervals ending before `new` starts are copied unchanged
+/// 2. Intervals overlapping `new` are absorbed into it, widening it
+/// 3. Once `new` is pushed, the remaining intervals are copied unchanged
+///
+/// ## Complexity
+/// - Time: O(n), no sorting needed since the input is already sorted
+/// - Space: O(n) for the result
+///
+/// ## Key Points
+/// 1. As in [`merge_intervals`], intervals that only touch (`(1, 3)` and
+///    `(3, 5)`) are merged
+///
+/// ## Common Pitfalls
+/// 1. Appending `new` and calling [`merge_intervals`], which works but
+///    throws away the sortedness and costs O(n log n)
+/// 2. Forgetting to push `new` when it belongs after every interval
+pub fn insert_interval(sorted: &[(i32, i32)], new: (i32, i32)) -> Vec<(i32, i32)> {
+    let mut result = Vec::with_capacity(sorted.len() + 1);
+    let mut merged = new;
+    let mut rest = sorted.iter().peekable();
 
-    let first = v[0];
+    while let Some(&interval) = rest.next_if(|&&(_, end)| end < merged.0) {
+        result.push(interval);
+    }
+    while let Some(&(start, end)) = rest.next_if(|&&(start, _)| start <= merged.1) {
+        merged = (merged.0.min(start), merged.1.max(end));
+    }
+    result.push(merged);
+    result.extend(rest);
+    result
+}
 
-    if v.len() == 1 {
-        return first;
+/// # Interval List Intersection
+///
+/// ## Problem Statement
+/// Given two lists of closed intervals, each sorted and pairwise
+/// non-overlapping, return the intersection of the two lists: every
+/// interval of points covered by both.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::interval_intersection;
+/// let a = vec![(0, 2), (5, 10), (13, 23), (24, 25)];
+/// let b = vec![(1, 5), (8, 12), (15, 24), (25, 26)];
+/// assert_eq!(
+///     interval_intersection(&a, &b),
+///     vec![(1, 2), (5, 5), (8, 10), (15, 23), (24, 24), (25, 25)]
+/// );
+/// ```
+///
+/// ## Approach
+/// Two pointers, one per list. Two intervals intersect in
+/// `(max of the starts, min of the ends)` if that is non-empty. Then the
+/// interval that ends first cannot meet anything else in the other list,
+/// so advance its pointer.
+///
+/// ## Complexity
+/// - Time: O(n + m)
+/// - Space: O(n + m) for the result
+///
+/// ## Key Points
+/// 1. The intervals are closed, so touching endpoints intersect in a
+///    single point such as `(5, 5)`
+/// 2. Several intervals of one list can intersect the same interval of the
+///    other, which is why only the earlier-ending pointer moves
+///
+/// ## Common Pitfalls
+/// 1. Advancing both pointers after a match, which misses later overlaps
+///    with the longer interval
+pub fn interval_intersection(a: &[(i32, i32)], b: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start <= end {
+            result.push((start, end));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
     }
+    result
+}
 
-    let mut max = first;
-    let mut min = first;
-    let mut result = first;
+/// # Maximum Subarray Sum (Kadane's Algorithm)
+///
+/// ## Problem Statement
+/// Given an array of integers, find the non-empty contiguous subarray with
+/// the largest sum. Return the sum together with the subarray's index
+/// range, or `None` for an empty array.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::max_subarray_sum;
+/// let nums = vec![-2, 1, -3, 4, -1, 2, 1, -5, 4];
+/// assert_eq!(max_subarray_sum(&nums), Some((6, 3..7))); // [4, -1, 2, 1]
+/// ```
+///
+/// ## Approach
+/// Scan once, tracking the best sum of a subarray that *ends* at the
+/// current index. That subarray either extends the previous one or starts
+/// fresh here, and starting fresh wins exactly when the previous running
+/// sum is negative. The answer is the best running sum seen.
+///
+/// ## Complexity
+/// - Time: O(n) where n is the length of the input array
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Remembering where the current run started is enough to recover the
+///    range, no extra arrays needed
+/// 2. Sums are accumulated in `i64`, so no input of `i32`s can overflow
+/// 3. Ties keep the earliest range found
+///
+/// ## Common Pitfalls
+/// 1. Initializing the best sum to 0, which returns 0 (an empty subarray)
+///    for all-negative input instead of the largest element
+/// 2. Accumulating in `i32` and overflowing on long runs of large values
+/// 3. Updating the start index only when the best sum changes, which
+///    reports the wrong range
+pub fn max_subarray_sum(nums: &[i32]) -> Option<(i64, Range<usize>)> {
+    let &first = nums.first()?;
 
-    for &num in v.iter().skip(1) {
-        let cur_max = max;
-        let cur_min = min;
+    let mut best = (i64::from(first), 0..1);
+    let mut current = i64::from(first);
+    let mut start = 0;
 
-        max = num.max(num * cur_max).max(num * cur_min);
+    for (i, &num) in nums.iter().enumerate().skip(1) {
+        if current < 0 {
+            // Any run ending here does better without the negative prefix
+            current = 0;
+            start = i;
+        }
+        current += i64::from(num);
+        if current > best.0 {
+            best = (current, start..i + 1);
+        }
+    }
 
-        min = num.min(num * cur_max).min(num * cur_min);
+    Some(best)
+}
 
-        result = result.max(max);
+/// Brute-force baseline for [`max_subarray_sum`]: sums every subarray
+/// `i..j` by extending each start one element at a time.
+///
+/// Time: O(n²). Kept for comparison (see `benches/vector.rs`). The sum
+/// always matches; when several subarrays tie, the range may differ.
+pub fn max_subarray_sum_naive(nums: &[i32]) -> Option<(i64, Range<usize>)> {
+    let mut best: Option<(i64, Range<usize>)> = None;
+    for start in 0..nums.len() {
+        let mut sum = 0i64;
+        for (end, &num) in nums.iter().enumerate().skip(start) {
+            sum += i64::from(num);
+            if best.as_ref().is_none_or(|(best_sum, _)| sum > *best_sum) {
+                best = Some((sum, start..end + 1));
+            }
+        }
+    }
+    best
+}
+
+/// # Two Sum
+///
+/// ## Problem Statement
+/// Given an array of integers and a target, find two distinct indices
+/// `i < j` such that `nums[i] + nums[j] == target`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::two_sum;
+/// assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+/// assert_eq!(two_sum(&[1, 2, 3], 7), None);
+/// ```
+///
+/// ## Approach
+/// Walk the array once, remembering the index of each value seen so far
+/// in a hash map. For each element, look up its complement
+/// `target - num`; if it was seen earlier, that index pairs with this one.
+///
+/// ## Complexity
+/// - Time: O(n) expected
+/// - Space: O(n) for the hash map
+///
+/// ## Key Points
+/// 1. Looking up the complement *before* inserting the current value
+///    prevents pairing an element with itself
+/// 2. Returns the pair that completes first, i.e. with the smallest `j`
+///
+/// ## Common Pitfalls
+/// 1. Using the same element twice when `target == 2 * num`
+/// 2. Filling the map up front in a separate pass, which then needs an
+///    `i != j` check and keeps the *last* index of duplicate values
+/// 3. Computing `target - num` in `i32`, which overflows for extreme
+///    values; the complement is computed in `i64`
+pub fn two_sum(nums: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut seen: HashMap<i64, usize> = HashMap::with_capacity(nums.len());
+    for (j, &num) in nums.iter().enumerate() {
+        let complement = i64::from(target) - i64::from(num);
+        if let Some(&i) = seen.get(&complement) {
+            return Some((i, j));
+        }
+        seen.entry(i64::from(num)).or_insert(j);
     }
+    None
+}
+
+/// # Three Sum
+///
+/// ## Problem Statement
+/// Find all unique triplets of values `[a, b, c]` taken from three
+/// distinct positions of the array with `a + b + c == 0`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::three_sum;
+/// let nums = vec![-1, 0, 1, 2, -1, -4];
+/// assert_eq!(three_sum(&nums), vec![[-1, -1, 2], [-1, 0, 1]]);
+/// ```
+///
+/// ## Approach
+/// Sort a copy of the input. Fix the smallest element `a` at index `i`,
+/// then search the rest with two pointers: if the sum is too small move
+/// the left pointer right, if too large move the right pointer left.
+/// Skip over equal neighbours for `a`, `b` and `c` to avoid duplicates.
+///
+/// ## Complexity
+/// - Time: O(n²), O(n log n) sort plus O(n) two-pointer pass per `a`
+/// - Space: O(n) for the sorted copy
+///
+/// ## Key Points
+/// 1. Each triplet is returned sorted, and the list is in lexicographic
+///    order
+/// 2. Once `a > 0`, no triplet starting there can sum to zero
+///
+/// ## Common Pitfalls
+/// 1. Deduplicating with a `HashSet` of triplets instead of skipping
+///    duplicates, which works but hides the two-pointer insight
+/// 2. Skipping duplicates of `a` by comparing with the *next* element,
+///    which misses triplets like `[-1, -1, 2]`
+/// 3. Overflowing `i32` when adding three large values
+pub fn three_sum(nums: &[i32]) -> Vec<[i32; 3]> {
+    let mut sorted = nums.to_vec();
+    sorted.sort_unstable();
+
+    let mut result = Vec::new();
+    for i in 0..sorted.len() {
+        let a = sorted[i];
+        if a > 0 {
+            break;
+        }
+        if i > 0 && sorted[i - 1] == a {
+            continue;
+        }
 
+        let (mut lo, mut hi) = (i + 1, sorted.len().saturating_sub(1));
+        while lo < hi {
+            let (b, c) = (sorted[lo], sorted[hi]);
+            match (i64::from(a) + i64::from(b) + i64::from(c)).cmp(&0) {
+                Ordering::Less => lo += 1,
+                Ordering::Greater => hi -= 1,
+                Ordering::Equal => {
+                    result.push([a, b, c]);
+                    while lo < hi && sorted[lo] == b {
+                        lo += 1;
+                    }
+                    while lo < hi && sorted[hi] == c {
+                        hi -= 1;
+                    }
+                }
+            }
+        }
+    }
     result
 }
 
-pub fn max_product_functional(v: &Vec<i32>) -> i32 {
-    #[derive(Clone, Copy)]
-    struct State {
-        max: i32,
-        min: i32,
-        result: i32,
+/// # Rotate Array Right by k
+///
+/// ## Problem Statement
+/// Rotate an array `k` steps to the right in place: the element at index
+/// `i` moves to `(i + k) % n`, and the last `k` elements wrap around to
+/// the front.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::rotate_right;
+/// let mut nums = vec![1, 2, 3, 4, 5, 6, 7];
+/// rotate_right(&mut nums, 3);
+/// assert_eq!(nums, vec![5, 6, 7, 1, 2, 3, 4]);
+/// ```
+///
+/// ## Approach
+/// Triple reversal: reversing the whole array puts the last `k` elements
+/// first, but backwards; reversing the first `k` and the remaining `n - k`
+/// separately then restores the order within each part.
+///
+/// [`rotate_right_juggling`] instead moves every element directly to its
+/// final position by following cycles.
+///
+/// | Approach        | Reads/writes per element | Extra space | Access pattern |
+/// |-----------------|--------------------------|-------------|----------------|
+/// | Triple reversal | 2 swaps                  | O(1)        | sequential     |
+/// | Juggling cycles | 1 move                   | O(1)        | strided by `k` |
+/// | Copy to buffer  | 2 copies                 | O(n)        | sequential     |
+///
+/// Reversal usually wins in practice despite the extra writes, because
+/// sequential access is cache- and prefetcher-friendly. The standard
+/// library's `slice::rotate_right` uses yet another algorithm.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Reduce `k` modulo `n` first: rotating by `n` is the identity
+/// 2. Takes `&mut [i32]`, so a `&mut Vec<i32>` works via deref coercion
+///
+/// ## Common Pitfalls
+/// 1. Not handling empty input, where `k % n` divides by zero
+/// 2. Reversing the parts before the whole, which rotates left instead
+pub fn rotate_right(nums: &mut [i32], k: usize) {
+    if nums.is_empty() {
+        return;
     }
+    let k = k % nums.len();
+    nums.reverse();
+    nums[..k].reverse();
+    nums[k..].reverse();
+}
 
-    v.iter()
-        .skip(1)
-        .fold(
-            // Initial state
-            v.first().map_or(
-                State {
-                    max: 0,
-                    min: 0,
-                    result: 0,
-                },
-                |&first| State {
-                    max: first,
-                    min: first,
-                    result: first,
-                },
-            ),
-            |state, &num| {
-                let new_max = num.max(num * state.max).max(num * state.min);
-                let new_min = num.min(num * state.max).min(num * state.min);
-                State {
-                    max: new_max,
-                    min: new_min,
-                    result: state.result.max(new_max),
-                }
-            },
-        )
-        .result
+/// Juggling variant of [`rotate_right`]: moves each element straight to
+/// its destination.
+///
+/// Index `j` must receive the element from `j - k` (mod n). Starting at
+/// `start`, follow that chain backwards, shifting each element forward,
+/// until it returns to `start`. Each cycle has length `n / gcd(n, k)`, so
+/// there are `gcd(n, k)` cycles, starting at `0, 1, .., gcd(n, k) - 1`.
+///
+/// Time: O(n) with exactly one write per element; Space: O(1).
+pub fn rotate_right_juggling(nums: &mut [i32], k: usize) {
+    let n = nums.len();
+    if n == 0 {
+        return;
+    }
+    let k = k % n;
+    if k == 0 {
+        return;
+    }
+
+    for start in 0..gcd(n, k) {
+        let saved = nums[start];
+        let mut j = start;
+        loop {
+            let from = (j + n - k) % n;
+            if from == start {
+                break;
+            }
+            nums[j] = nums[from];
+            j = from;
+        }
+        nums[j] = saved;
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// # Kth Smallest Element (Quickselect)
+///
+/// ## Problem Statement
+/// Given an array and `k` (1-based), find the element that would be at
+/// position `k` if the array were sorted in ascending order, without fully
+/// sorting it. The slice is reordered in the process.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::kth_smallest;
+/// let mut nums = vec![7, 10, 4, 3, 20, 15];
+/// assert_eq!(kth_smallest(&mut nums, 3).unwrap(), 7);
+/// assert!(kth_smallest(&mut nums, 7).is_err());
+/// ```
+///
+/// ## Approach
+/// Quickselect: partition around a pivot like quicksort, then continue
+/// only in the side that contains index `k - 1`.
+///
+/// Partitioning uses Hoare's scheme: two cursors move inwards from both
+/// ends, swapping pairs that are on the wrong side. It returns `j` such
+/// that everything in `lo..=j` is `<= pivot` and everything in
+/// `j + 1..=hi` is `>= pivot`, with both parts non-empty. The pivot is
+/// chosen at random so no fixed input can force the quadratic worst case.
+///
+/// ## Complexity
+/// - Time: O(n) expected, O(n²) worst case
+/// - Space: O(1), the recursion is a loop
+///
+/// ## Key Points
+/// 1. Each round discards one side, so expected work is
+///    n + n/2 + n/4 + ... = 2n
+/// 2. Hoare partitioning makes about three times fewer swaps than
+///    Lomuto's and handles many duplicates gracefully
+/// 3. The standard library equivalent is `slice::select_nth_unstable`
+///
+/// ## Common Pitfalls
+/// 1. Mixing up 1-based `k` with the 0-based target index
+/// 2. Always choosing the first element as pivot, which is quadratic on
+///    sorted input
+/// 3. Hoare's `j` is not the pivot's final position, so recursing on
+///    `lo..j` (excluding `j`) loses elements
+///
+/// ## Errors
+/// `InvalidInput` if `k` is 0 or greater than the length of the slice.
+pub fn kth_smallest(nums: &mut [i32], k: usize) -> Result<i32> {
+    if k == 0 || k > nums.len() {
+        return Err(ExerciseError::InvalidInput(format!(
+            "k must be in 1..={}, got {k}",
+            nums.len()
+        )));
+    }
+
+    let target = k - 1;
+    let mut rng = rand::thread_rng();
+    let (mut lo, mut hi) = (0, nums.len() - 1);
+    while lo < hi {
+        nums.swap(lo, rng.gen_range(lo..=hi));
+        let j = hoare_partition(nums, lo, hi);
+        if target <= j {
+            hi = j;
+        } else {
+            lo = j + 1;
+        }
+    }
+    Ok(nums[target])
+}
+
+/// [`kth_smallest`] counted from the other end: `k = 1` is the maximum.
+///
+/// ## Errors
+/// `InvalidInput` if `k` is 0 or greater than the length of the slice.
+pub fn kth_largest(nums: &mut [i32], k: usize) -> Result<i32> {
+    if k == 0 || k > nums.len() {
+        return Err(ExerciseError::InvalidInput(format!(
+            "k must be in 1..={}, got {k}",
+            nums.len()
+        )));
+    }
+    kth_smallest(nums, nums.len() - k + 1)
+}
+
+/// Partitions `nums[lo..=hi]` around `nums[lo]` and returns the split
+/// point `j` (`lo <= j < hi`).
+fn hoare_partition(nums: &mut [i32], lo: usize, hi: usize) -> usize {
+    let pivot = nums[lo];
+    let (mut i, mut j) = (lo, hi);
+    loop {
+        while nums[i] < pivot {
+            i += 1;
+        }
+        while nums[j] > pivot {
+            j -= 1;
+        }
+        if i >= j {
+            return j;
+        }
+        nums.swap(i, j);
+        i += 1;
+        j -= 1;
+    }
+}
+
+/// # Partition Point (Predicate Binary Search)
+///
+/// ## Problem Statement
+/// Given a slice partitioned by `pred` (every element satisfying it comes
+/// before every element that does not), return the index of the first
+/// element for which `pred` is false, or `len` if there is none.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::partition_point;
+/// let nums = [1, 2, 3, 3, 5, 8];
+/// assert_eq!(partition_point(&nums, |&x| x < 4), 4);
+/// assert_eq!(partition_point(&nums, |&x| x < 100), 6);
+/// ```
+///
+/// ## Approach
+/// Keep a half-open window `lo..hi` with the loop invariant
+/// - `pred` is true for everything in `..lo`
+/// - `pred` is false for everything in `hi..`
+///
+/// Initially `lo = 0` and `hi = len` make both claims vacuous. Test the
+/// middle element: if `pred(mid)` holds, everything up to `mid` is true so
+/// `lo = mid + 1` keeps the invariant, otherwise `hi = mid` does. The
+/// window shrinks every step, and when `lo == hi` the invariant says
+/// exactly that `lo` is the partition point.
+///
+/// ## Complexity
+/// - Time: O(log n) calls to `pred`
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Every other search here is `partition_point` with a specific
+///    predicate, so the invariant only has to be proven once
+/// 2. Matches `slice::partition_point` in the standard library
+///
+/// ## Common Pitfalls
+/// 1. Computing `(lo + hi) / 2`, which overflows for huge indices; use
+///    `lo + (hi - lo) / 2`
+/// 2. Setting `hi = mid - 1` with a half-open window, which skips `mid`
+///    and underflows at 0
+/// 3. Calling it on a slice that is not partitioned by `pred`: the result
+///    is then some boundary, not necessarily the first `false`
+pub fn partition_point<T>(slice: &[T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, slice.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&slice[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// # Lower Bound
+///
+/// Index of the first element `>= target` in a sorted slice, i.e. where
+/// `target` would be inserted *before* any equal elements.
+///
+/// ```
+/// use rust_ds_learning::vector::lower_bound;
+/// let nums = [1, 2, 2, 2, 5];
+/// assert_eq!(lower_bound(&nums, &2), 1);
+/// assert_eq!(lower_bound(&nums, &3), 4);
+/// assert_eq!(lower_bound(&nums, &9), 5);
+/// ```
+///
+/// Invariant: everything in `..lo` is `< target` and everything in `hi..`
+/// is `>= target` ([`partition_point`] with `x < target`).
+///
+/// Unlike `slice::binary_search`, which may return the index of *any* of
+/// several equal elements, the answer with duplicates is always the first.
+///
+/// Time: O(log n)
+pub fn lower_bound<T: Ord>(sorted: &[T], target: &T) -> usize {
+    partition_point(sorted, |x| x < target)
+}
+
+/// # Upper Bound
+///
+/// Index of the first element `> target` in a sorted slice, i.e. where
+/// `target` would be inserted *after* any equal elements.
+///
+/// ```
+/// use rust_ds_learning::vector::upper_bound;
+/// let nums = [1, 2, 2, 2, 5];
+/// assert_eq!(upper_bound(&nums, &2), 4);
+/// assert_eq!(upper_bound(&nums, &0), 0);
+/// ```
+///
+/// Invariant: everything in `..lo` is `<= target` and everything in
+/// `hi..` is `> target` ([`partition_point`] with `x <= target`).
+///
+/// Time: O(log n)
+pub fn upper_bound<T: Ord>(sorted: &[T], target: &T) -> usize {
+    partition_point(sorted, |x| x <= target)
+}
+
+/// # Equal Range
+///
+/// The range of indices holding elements equal to `target` in a sorted
+/// slice: `lower_bound..upper_bound`. When `target` is absent the range is
+/// empty and starts at its insertion point.
+///
+/// ```
+/// use rust_ds_learning::vector::equal_range;
+/// let nums = [1, 2, 2, 2, 5];
+/// assert_eq!(equal_range(&nums, &2), 1..4);
+/// assert_eq!(equal_range(&nums, &3), 4..4);
+/// ```
+///
+/// The number of occurrences is `equal_range(..).len()`, in O(log n)
+/// regardless of how many duplicates there are.
+///
+/// Time: O(log n)
+pub fn equal_range<T: Ord>(sorted: &[T], target: &T) -> Range<usize> {
+    let start = lower_bound(sorted, target);
+    // Everything before `start` is smaller, so only search the rest
+    let end = start + upper_bound(&sorted[start..], target);
+    start..end
+}
+
+/// # Trapping Rain Water
+///
+/// ## Problem Statement
+/// Given bar heights of an elevation map where every bar has width 1,
+/// compute how many units of water are trapped after it rains.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::trap_rain_water;
+/// let heights = [0, 1, 0, 2, 1, 0, 1, 3, 2, 1, 2, 1];
+/// assert_eq!(trap_rain_water(&heights), 6);
+/// ```
+///
+/// ## Approach
+/// The water above bar `i` is `min(max_left(i), max_right(i)) - height[i]`.
+/// Two pointers walk inwards from both ends, each tracking the highest bar
+/// seen on its side. Always advance the side with the lower maximum: its
+/// water level is settled by its own maximum, because the other side is
+/// already known to have a bar at least that high.
+///
+/// [`trap_rain_water_stack`] computes the same total layer by layer with
+/// a monotonic stack.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Avoids the two O(n) prefix/suffix maximum arrays of the direct
+///    formula
+/// 2. The total is accumulated in `u64`: n bars of height up to
+///    `u32::MAX` overflow a `u32` quickly
+///
+/// ## Common Pitfalls
+/// 1. Advancing the side with the *higher* bar, whose level is not yet
+///    known
+/// 2. Subtracting before updating the running maximum, which underflows
+///    when the current bar is the new maximum
+/// 3. Counting water at the two ends, which can never hold any
+pub fn trap_rain_water(heights: &[u32]) -> u64 {
+    if heights.len() < 3 {
+        return 0;
+    }
+
+    let (mut left, mut right) = (0, heights.len() - 1);
+    let (mut left_max, mut right_max) = (0, 0);
+    let mut water = 0u64;
+
+    while left < right {
+        if heights[left] <= heights[right] {
+            left_max = left_max.max(heights[left]);
+            water += u64::from(left_max - heights[left]);
+            left += 1;
+        } else {
+            right_max = right_max.max(heights[right]);
+            water += u64::from(right_max - heights[right]);
+            right -= 1;
+        }
+    }
+    water
+}
+
+/// Stack-based variant of [`trap_rain_water`].
+///
+/// Keeps indices of bars with non-increasing heights on a stack. When a
+/// taller bar arrives, every shorter bar popped is the floor of a basin
+/// bounded by the new bar on the right and the next stack entry on the
+/// left; fill that basin up to the lower of the two walls, one horizontal
+/// layer at a time.
+///
+/// Time: O(n), each index is pushed and popped once; Space: O(n).
+pub fn trap_rain_water_stack(heights: &[u32]) -> u64 {
+    let mut stack: Vec<usize> = Vec::with_capacity(heights.len());
+    let mut water = 0u64;
+
+    for (right, &height) in heights.iter().enumerate() {
+        while let Some(&floor) = stack.last() {
+            if heights[floor] >= height {
+                break;
+            }
+            stack.pop();
+            // No left wall: the water would run off the left edge
+            let Some(&left) = stack.last() else {
+                break;
+            };
+            let level = heights[left].min(height);
+            let width = (right - left - 1) as u64;
+            water += width * u64::from(level - heights[floor]);
+        }
+        stack.push(right);
+    }
+    water
+}
+
+/// # Next Permutation
+///
+/// ## Problem Statement
+/// Rearrange the slice into the next permutation in lexicographic order.
+/// If it is already the last (descending) permutation, wrap around to the
+/// first (ascending) one and return `false`; otherwise return `true`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::next_permutation;
+/// let mut nums = vec![1, 2, 3];
+/// assert!(next_permutation(&mut nums));
+/// assert_eq!(nums, vec![1, 3, 2]);
+///
+/// let mut last = vec![3, 2, 1];
+/// assert!(!next_permutation(&mut last));
+/// assert_eq!(last, vec![1, 2, 3]);
+/// ```
+///
+/// ## Approach
+/// 1. Find the longest non-increasing suffix. It is already the largest
+///    arrangement of its elements, so the change must happen just before
+///    it, at the pivot `i`.
+/// 2. Swap the pivot with the rightmost suffix element greater than it:
+///    the smallest possible increase at position `i`.
+/// 3. Reverse the suffix, which is still non-increasing, to make it the
+///    smallest arrangement of its elements.
+///
+/// ## Complexity
+/// - Time: O(n), amortized O(1) when stepping through all permutations
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Repeated calls starting from sorted input visit every *distinct*
+///    permutation exactly once, even with duplicate elements
+/// 2. Matches C++'s `std::next_permutation`
+///
+/// ## Common Pitfalls
+/// 1. Using strict comparisons (`>` instead of `>=`) when scanning for the
+///    suffix, which loops forever or repeats permutations with duplicates
+/// 2. Sorting the suffix instead of reversing it: correct, but O(n log n)
+pub fn next_permutation(nums: &mut [i32]) -> bool {
+    let suffix_start = (1..nums.len())
+        .rev()
+        .find(|&i| nums[i - 1] < nums[i])
+        .unwrap_or(0);
+    if suffix_start == 0 {
+        // Entirely non-increasing: the last permutation
+        nums.reverse();
+        return false;
+    }
+    let pivot = suffix_start - 1;
+
+    // The suffix is non-increasing, so the rightmost greater element is
+    // the smallest one greater than the pivot
+    let successor = (pivot + 1..nums.len())
+        .rev()
+        .find(|&j| nums[j] > nums[pivot])
+        .expect("the suffix starts with an element greater than the pivot");
+    nums.swap(pivot, successor);
+    nums[pivot + 1..].reverse();
+    true
+}
+
+/// # Subarray Sum Equals K
+///
+/// ## Problem Statement
+/// Count the non-empty contiguous subarrays whose elements sum to `k`.
+/// Elements may be negative.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::subarray_sum_count;
+/// assert_eq!(subarray_sum_count(&[1, 1, 1], 2), 2);
+/// assert_eq!(subarray_sum_count(&[3, 4, -7, 3, 1, 3, 1, -4], 7), 3);
+/// ```
+///
+/// ## Approach
+/// With prefix sums `P[j] = nums[0] + .. + nums[j - 1]`, the subarray
+/// `i..j` sums to `P[j] - P[i]`. So the subarrays *ending* at `j` that sum
+/// to `k` are exactly the earlier prefixes equal to `P[j] - k`. Scan once,
+/// keeping a `HashMap` from each prefix sum seen so far to how often it
+/// occurred, and add up the matching counts.
+///
+/// ## Why Not a Sliding Window?
+/// A sliding window shrinks from the left when the sum exceeds `k`, which
+/// relies on every element being positive: only then does dropping an
+/// element always decrease the sum. With `[1, -1, 1]` and `k = 1`, a
+/// window that has grown past `[1]` can still return to sum 1 by
+/// absorbing the `-1` and the next `1`, so no shrinking rule is right.
+/// Prefix sums make no assumption about signs.
+///
+/// ## Complexity
+/// - Time: O(n) expected
+/// - Space: O(n) for the prefix-sum counts
+///
+/// ## Key Points
+/// 1. Seed the map with the empty prefix (`0` seen once), so subarrays
+///    starting at index 0 are counted
+/// 2. Look up before inserting the current prefix, so `k = 0` does not
+///    count the empty subarray
+/// 3. Prefix sums are kept in `i64` to rule out overflow
+///
+/// ## Common Pitfalls
+/// 1. Using a `HashSet` of prefixes, which undercounts when the same
+///    prefix sum appears several times (e.g. runs of zeros)
+/// 2. Applying the sliding-window technique to input with negatives
+///
+/// See the [`crate::hashmap`] module for more on `HashMap` itself.
+pub fn subarray_sum_count(nums: &[i32], k: i32) -> usize {
+    let mut prefix_counts: HashMap<i64, usize> = HashMap::with_capacity(nums.len() + 1);
+    prefix_counts.insert(0, 1);
+
+    let mut prefix = 0i64;
+    let mut count = 0;
+    for &num in nums {
+        prefix += i64::from(num);
+        count += prefix_counts
+            .get(&(prefix - i64::from(k)))
+            .copied()
+            .unwrap_or(0);
+        *prefix_counts.entry(prefix).or_insert(0) += 1;
+    }
+    count
+}
+
+/// # Merge K Sorted Vectors
+///
+/// ## Problem Statement
+/// Given `k` vectors, each sorted in ascending order, merge them into a
+/// single sorted vector containing every element.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::merge_k_sorted;
+/// let lists = vec![vec![1, 4, 5], vec![1, 3, 4], vec![2, 6]];
+/// assert_eq!(merge_k_sorted(&lists), vec![1, 1, 2, 3, 4, 4, 5, 6]);
+/// ```
+///
+/// ## Approach
+/// Keep the current head of every list in a min-heap (a
+/// [`BinaryHeap`] of [`Reverse`] entries). Repeatedly pop the smallest
+/// head, append it, and push the next element of the list it came from.
+/// The heap never holds more than `k` entries.
+///
+/// [`merge_k_sorted_pairwise`] solves the same problem by divide and
+/// conquer.
+///
+/// ## Complexity
+/// - Time: O(n log k) where n is the total number of elements
+/// - Space: O(k) for the heap, plus the O(n) output
+///
+/// ## Key Points
+/// 1. Heap entries are `(value, list index, position)` so that popping an
+///    entry says where the next candidate comes from
+/// 2. `BinaryHeap` is a max-heap; `Reverse` turns it into a min-heap
+/// 3. Ties between lists are broken by list index, so equal values keep
+///    the order of the input lists (the merge is stable)
+///
+/// ## Common Pitfalls
+/// 1. Concatenating and sorting: O(n log n), ignoring the sorted input
+/// 2. Merging lists one by one into an accumulator: O(n k), because early
+///    elements are copied again in every round
+/// 3. Forgetting empty input lists when seeding the heap
+pub fn merge_k_sorted(lists: &[Vec<i32>]) -> Vec<i32> {
+    let total = lists.iter().map(Vec::len).sum();
+    let mut result = Vec::with_capacity(total);
+
+    let mut heap: BinaryHeap<Reverse<(i32, usize, usize)>> = lists
+        .iter()
+        .enumerate()
+        .filter_map(|(list, values)| values.first().map(|&value| Reverse((value, list, 0))))
+        .collect();
+
+    while let Some(Reverse((value, list, position))) = heap.pop() {
+        result.push(value);
+        if let Some(&next) = lists[list].get(position + 1) {
+            heap.push(Reverse((next, list, position + 1)));
+        }
+    }
+    result
+}
+
+/// Divide-and-conquer variant of [`merge_k_sorted`].
+///
+/// Merge the lists in pairs with the two-pointer merge from merge sort,
+/// halving the number of lists each round until one remains. Every
+/// element takes part in `⌈log₂ k⌉` merges.
+///
+/// Time: O(n log k), like the heap. Each step is a plain comparison on
+/// contiguous memory rather than a heap operation, which wins for small
+/// `k`; as `k` grows, the intermediate vectors allocated every round make
+/// it fall behind (see `benches/vector.rs`). Space: O(n).
+pub fn merge_k_sorted_pairwise(lists: &[Vec<i32>]) -> Vec<i32> {
+    match lists {
+        [] => Vec::new(),
+        [only] => only.clone(),
+        _ => {
+            let (left, right) = lists.split_at(lists.len() / 2);
+            merge_two_sorted(
+                &merge_k_sorted_pairwise(left),
+                &merge_k_sorted_pairwise(right),
+            )
+        }
+    }
+}
+
+/// Stable two-pointer merge: on ties, `left` goes first
+fn merge_two_sorted(left: &[i32], right: &[i32]) -> Vec<i32> {
+    let mut result = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] <= right[j] {
+            result.push(left[i]);
+            i += 1;
+        } else {
+            result.push(right[j]);
+            j += 1;
+        }
+    }
+    result.extend_from_slice(&left[i..]);
+    result.extend_from_slice(&right[j..]);
+    result
+}
+
+/// # Remove Duplicates from Sorted Vector
+///
+/// ## Problem Statement
+/// Given a vector sorted in ascending order, remove duplicates in place so
+/// that each value appears once, keeping the original order. Return the
+/// number of unique values; the vector is truncated to that length.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::remove_duplicates_sorted;
+/// let mut nums = vec![0, 0, 1, 1, 1, 2, 2, 3, 3, 4];
+/// assert_eq!(remove_duplicates_sorted(&mut nums), 5);
+/// assert_eq!(nums, vec![0, 1, 2, 3, 4]);
+/// ```
+///
+/// ## Approach
+/// Two pointers over the same vector: a read pointer visits every element
+/// and a write pointer marks where the next kept element goes.
+///
+/// Write-pointer invariant: `nums[..write]` holds the unique values of
+/// `nums[..read]`, in order. A value is new exactly when it differs from
+/// the last kept one, `nums[write - 1]`, because equal values are
+/// adjacent in sorted input.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. `write <= read` always holds, so writing never clobbers an unread
+///    element
+/// 2. Equivalent to `Vec::dedup`, spelled out
+///
+/// ## Common Pitfalls
+/// 1. Comparing with the *previous read* element instead of the last kept
+///    one; both work here, but only the latter generalizes to "keep at
+///    most two copies"
+/// 2. An all-duplicates input such as `[7, 7, 7]` must leave one `7`
+/// 3. Removing with `Vec::remove` inside the loop, which is O(n²)
+pub fn remove_duplicates_sorted(nums: &mut Vec<i32>) -> usize {
+    if nums.is_empty() {
+        return 0;
+    }
+
+    let mut write = 1;
+    for read in 1..nums.len() {
+        if nums[read] != nums[write - 1] {
+            nums[write] = nums[read];
+            write += 1;
+        }
+    }
+    nums.truncate(write);
+    write
+}
+
+/// # Remove Element
+///
+/// ## Problem Statement
+/// Remove every occurrence of `val` from the vector in place, keeping the
+/// order of the remaining elements. Return how many elements remain; the
+/// vector is truncated to that length.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::remove_element;
+/// let mut nums = vec![0, 1, 2, 2, 3, 0, 4, 2];
+/// assert_eq!(remove_element(&mut nums, 2), 5);
+/// assert_eq!(nums, vec![0, 1, 3, 0, 4]);
+/// ```
+///
+/// ## Approach
+/// Write-pointer invariant: `nums[..write]` holds the elements of
+/// `nums[..read]` that are not `val`, in order. Each element that is kept
+/// is copied to `write`, which then advances.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Equivalent to `nums.retain(|&x| x != val)`
+///
+/// ## Common Pitfalls
+/// 1. Swapping matches with the last element instead: fewer writes, but
+///    the order of the remaining elements is lost
+/// 2. If every element equals `val` the result is empty
+pub fn remove_element(nums: &mut Vec<i32>, val: i32) -> usize {
+    let mut write = 0;
+    for read in 0..nums.len() {
+        if nums[read] != val {
+            nums[write] = nums[read];
+            write += 1;
+        }
+    }
+    nums.truncate(write);
+    write
+}
+
+/// # Move Zeros
+///
+/// ## Problem Statement
+/// Move every `0` to the end of the slice, keeping the relative order of
+/// the non-zero elements.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::move_zeros;
+/// let mut nums = [0, 1, 0, 3, 12];
+/// move_zeros(&mut nums);
+/// assert_eq!(nums, [1, 3, 12, 0, 0]);
+/// ```
+///
+/// ## Approach
+/// Write-pointer invariant: `nums[..write]` holds the non-zero elements
+/// of `nums[..read]` in order, and `nums[write..read]` is all zeros.
+/// Swapping each non-zero element into `write` keeps both halves true, so
+/// the zeros end up behind without a separate fill pass.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Unlike the two functions above the length does not change, so this
+///    works on any `&mut [i32]`
+///
+/// ## Common Pitfalls
+/// 1. Swapping when `read == write`, harmless but wasted writes
+/// 2. An all-zeros or no-zeros input must be left unchanged
+pub fn move_zeros(nums: &mut [i32]) {
+    let mut write = 0;
+    for read in 0..nums.len() {
+        if nums[read] != 0 {
+            if read != write {
+                nums.swap(read, write);
+            }
+            write += 1;
+        }
+    }
+}
+
+/// Given a vector of integers (positive and negative), find the contiguous subarray
+/// with the largest product.
+///
+/// Example:
+/// Input: [-2, 3, -4]
+/// Output: 24 (subarray [3, -4])
+///
+/// Challenge aspects:
+/// - Handle negative numbers
+/// - Handle zeros
+/// - Optimize space complexity
+/// - Handle integer overflow cases
+///
+/// Expected complexity:
+/// Time: O(n)
+/// Space: O(1)
+///
+/// Returns 0 for empty input and multiplies in `i32`, so large products
+/// overflow; [`max_product_checked`] reports both cases as errors.
+pub fn max_product(v: &[i32]) -> i32 {
+    if v.is_empty() {
+        return 0;
+    }
+
+    let first = v[0];
+
+    if v.len() == 1 {
+        return first;
+    }
+
+    let mut max = first;
+    let mut min = first;
+    let mut result = first;
+
+    for &num in v.iter().skip(1) {
+        let cur_max = max;
+        let cur_min = min;
+
+        max = num.max(num * cur_max).max(num * cur_min);
+
+        min = num.min(num * cur_max).min(num * cur_min);
+
+        result = result.max(max);
+    }
+
+    result
+}
+
+/// # Maximum Product Subarray (Checked)
+///
+/// ## Problem Statement
+/// Like [`max_product`], find the largest product of a non-empty
+/// contiguous subarray, but without ever returning a wrong answer: empty
+/// input and a largest product outside `i64` are reported as errors.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::max_product_checked;
+/// assert_eq!(max_product_checked(&[2, 3, -2, 4]).unwrap(), 6);
+/// assert_eq!(max_product_checked(&[i32::MAX, i32::MAX]).unwrap(), 4_611_686_014_132_420_609);
+/// // The running minimum leaves i64 here, but the answer does not
+/// assert_eq!(max_product_checked(&[i32::MIN, i32::MAX, i32::MAX]).unwrap(), 4_611_686_014_132_420_609);
+/// assert!(max_product_checked(&[]).is_err());
+/// ```
+///
+/// ## Approach
+/// The same max/min tracking as [`max_product`], in `i128` with
+/// `saturating_mul`, and only the final answer is range-checked. The
+/// running products may leave `i64` without the answer doing so: the
+/// running minimum of `[i32::MIN, i32::MAX, i32::MAX]` is about `-2⁹³`,
+/// while the answer is `i32::MAX²`. Saturating is safe because a product
+/// that saturates is far outside `i64` and keeps its sign, so anything
+/// built from it later is either out of range too or an exact `0`.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Errors
+/// - `InvalidInput` if `v` is empty: there is no non-empty subarray, and
+///   `0` would be indistinguishable from a genuine zero product
+/// - `OperationFailed` if the largest product does not fit in `i64`
+pub fn max_product_checked(v: &[i32]) -> Result<i64> {
+    let (&first, rest) = v.split_first().ok_or_else(|| {
+        ExerciseError::InvalidInput("max_product needs a non-empty slice".to_string())
+    })?;
+
+    let first = i128::from(first);
+    let (mut max, mut min, mut result) = (first, first, first);
+    for &num in rest {
+        let num = i128::from(num);
+        let with_max = num.saturating_mul(max);
+        let with_min = num.saturating_mul(min);
+        max = num.max(with_max).max(with_min);
+        min = num.min(with_max).min(with_min);
+        result = result.max(max);
+    }
+    i64::try_from(result)
+        .map_err(|_| ExerciseError::OperationFailed("product overflows i64".to_string()))
+}
+
+pub fn max_product_functional(v: &[i32]) -> i32 {
+    #[derive(Clone, Copy)]
+    struct State {
+        max: i32,
+        min: i32,
+        result: i32,
+    }
+
+    v.iter()
+        .skip(1)
+        .fold(
+            // Initial state
+            v.first().map_or(
+                State {
+                    max: 0,
+                    min: 0,
+                    result: 0,
+                },
+                |&first| State {
+                    max: first,
+                    min: first,
+                    result: first,
+                },
+            ),
+            |state, &num| {
+                let new_max = num.max(num * state.max).max(num * state.min);
+                let new_min = num.min(num * state.max).min(num * state.min);
+                State {
+                    max: new_max,
+                    min: new_min,
+                    result: state.result.max(new_max),
+                }
+            },
+        )
+        .result
+}
+
+/// # Best Time to Buy and Sell Stock I (One Transaction)
+///
+/// ## Problem Statement
+/// `prices[i]` is a stock's price on day `i`. Buy on one day and sell on a
+/// later day; return the largest possible profit, or 0 if no trade makes
+/// money.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::max_profit_single;
+/// assert_eq!(max_profit_single(&[7, 1, 5, 3, 6, 4]), 5); // buy at 1, sell at 6
+/// assert_eq!(max_profit_single(&[7, 6, 4, 3, 1]), 0);
+/// ```
+///
+/// ## Approach
+/// The first step of the series, and the only one that needs no explicit
+/// states: the best sale on day `i` buys at the cheapest price before it.
+/// Track that minimum while scanning.
+///
+/// Seen as a state machine with states *before buying* and *holding*, the
+/// minimum price is just the negated value of the best *holding* state.
+/// [`max_profit_multi`] and [`max_profit_cooldown`] make those states
+/// explicit.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Taking the global maximum minus the global minimum, which may sell
+///    before buying
+pub fn max_profit_single(prices: &[i32]) -> i64 {
+    let mut cheapest = i64::MAX;
+    let mut best = 0;
+    for &price in prices {
+        let price = i64::from(price);
+        cheapest = cheapest.min(price);
+        best = best.max(price - cheapest);
+    }
+    best
+}
+
+/// # Best Time to Buy and Sell Stock II (Unlimited Transactions)
+///
+/// ## Problem Statement
+/// As in [`max_profit_single`], but trade as often as you like, holding at
+/// most one share at a time.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::max_profit_multi;
+/// assert_eq!(max_profit_multi(&[7, 1, 5, 3, 6, 4]), 7); // 1→5, then 3→6
+/// assert_eq!(max_profit_multi(&[1, 2, 3, 4, 5]), 4);
+/// ```
+///
+/// ## Approach
+/// Two states, updated once per day from the previous day's values:
+///
+/// ```text
+/// free --buy (-price)--> held
+/// held --sell (+price)-> free
+/// (either state can also rest)
+/// ```
+///
+/// - `free = max(free, held + price)`: rest, or sell today
+/// - `held = max(held, free - price)`: rest, or buy today
+///
+/// Since trades are unlimited, this collapses to summing every positive
+/// day-to-day increase, but the state machine is what generalizes to
+/// cooldowns, fees and transaction limits.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Both updates must read *yesterday's* values; updating `free` first
+///    and then using it for `held` would buy and sell on the same day,
+///    which is harmless here but wrong for [`max_profit_cooldown`]
+pub fn max_profit_multi(prices: &[i32]) -> i64 {
+    let Some((&first, rest)) = prices.split_first() else {
+        return 0;
+    };
+    let (mut free, mut held) = (0, -i64::from(first));
+    for &price in rest {
+        let price = i64::from(price);
+        (free, held) = (free.max(held + price), held.max(free - price));
+    }
+    free
+}
+
+/// # Best Time to Buy and Sell Stock with Cooldown
+///
+/// ## Problem Statement
+/// As in [`max_profit_multi`], but after selling you must wait one day
+/// before buying again.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::max_profit_cooldown;
+/// // buy at 1, sell at 2, cool down, buy at 0, sell at 2
+/// assert_eq!(max_profit_cooldown(&[1, 2, 3, 0, 2]), 3);
+/// ```
+///
+/// ## Approach
+/// The *free* state of [`max_profit_multi`] splits in two, because a buy
+/// is only allowed from a state that did not just sell:
+///
+/// ```text
+/// rest    --buy (-price)--> held
+/// held    --sell (+price)-> cooling
+/// cooling --wait----------> rest
+/// (rest and held can also stay put)
+/// ```
+///
+/// - `held = max(held, rest - price)`
+/// - `cooling = held + price` (sold today)
+/// - `rest = max(rest, cooling)` (yesterday's sale has cooled down)
+///
+/// The answer is the better of ending in `rest` or `cooling`.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Buying from `cooling` (or from today's `rest`), which skips the
+///    cooldown
+pub fn max_profit_cooldown(prices: &[i32]) -> i64 {
+    let Some((&first, tail)) = prices.split_first() else {
+        return 0;
+    };
+    let (mut held, mut cooling, mut rest) = (-i64::from(first), 0, 0);
+    for &price in tail {
+        let price = i64::from(price);
+        (held, cooling, rest) = (held.max(rest - price), held + price, rest.max(cooling));
+    }
+    rest.max(cooling)
+}
+
+/// # Jump Game
+///
+/// ## Problem Statement
+/// Standing on index 0, each `jumps[i]` is the furthest you may jump
+/// forward from `i` (any shorter jump is allowed too). Can you reach the
+/// last index?
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::can_jump;
+/// assert!(can_jump(&[2, 3, 1, 1, 4]));
+/// assert!(!can_jump(&[3, 2, 1, 0, 4])); // every path lands on the 0
+/// ```
+///
+/// ## Approach
+/// Scan left to right keeping `reach`, the furthest index reachable so
+/// far. Index `i` is reachable iff `i <= reach`; from there, extend
+/// `reach` to `i + jumps[i]`. Stop early once the last index is covered,
+/// or fail once `i` passes `reach`.
+///
+/// ## Why Greedy Works
+/// Reachable indices always form a prefix `0..=reach`: if you can get to
+/// `j`, you can stop at any `i < j` on the way, since jumps may be
+/// shorter. So no choice of *which* jump to take ever matters, only how
+/// far the prefix extends, and a single scan computes exactly that.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. A single element is already at the last index, whatever its value
+/// 2. An empty slice has no last index to reach, so the answer is `false`
+pub fn can_jump(jumps: &[usize]) -> bool {
+    let Some(last) = jumps.len().checked_sub(1) else {
+        return false;
+    };
+    let mut reach = 0;
+    for (i, &jump) in jumps.iter().enumerate() {
+        if i > reach {
+            return false;
+        }
+        reach = reach.max(i.saturating_add(jump));
+        if reach >= last {
+            return true;
+        }
+    }
+    unreachable!("the loop returns once i reaches the last index")
+}
+
+/// # Jump Game II
+///
+/// ## Problem Statement
+/// As in [`can_jump`], but return the fewest jumps needed to reach the
+/// last index, or `None` if it cannot be reached.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::min_jumps;
+/// assert_eq!(min_jumps(&[2, 3, 1, 1, 4]), Some(2)); // 0 → 1 → 4
+/// assert_eq!(min_jumps(&[0]), Some(0));
+/// assert_eq!(min_jumps(&[1, 0, 2]), None);
+/// ```
+///
+/// ## Approach
+/// A breadth-first search over indices without a queue: the indices
+/// reachable in exactly `k` jumps form a contiguous *level*
+/// `start..=end`. Scanning a level computes the furthest index the next
+/// jump can reach, and that is where the next level ends.
+///
+/// ## Why Greedy Works
+/// An exchange argument: take any optimal path, and replace its first jump
+/// by the jump to the index in level 1 that reaches furthest. Everything
+/// the old path could reach after that jump, the new one can reach too
+/// (reachable sets are prefixes, see [`can_jump`]), so the new path is no
+/// longer. Repeating level by level turns any optimal path into the one
+/// the scan follows.
+///
+/// ## Complexity
+/// - Time: O(n), each index is scanned in exactly one level
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Counting a jump when the scan reaches the last index itself rather
+///    than when a level ends before it, which is off by one
+/// 2. Looping forever when a level cannot be extended (a wall of zeros)
+pub fn min_jumps(jumps: &[usize]) -> Option<usize> {
+    let last = jumps.len().checked_sub(1)?;
+    let (mut count, mut level_end, mut next_end) = (0, 0, 0);
+    for (i, &jump) in jumps.iter().enumerate().take(last) {
+        next_end = next_end.max(i.saturating_add(jump));
+        if i == level_end {
+            if next_end <= i {
+                return None;
+            }
+            count += 1;
+            level_end = next_end;
+            if level_end >= last {
+                break;
+            }
+        }
+    }
+    Some(count)
+}
+
+/// # Fisher–Yates Shuffle
+///
+/// ## Problem Statement
+/// Rearrange a slice into a uniformly random permutation: each of the
+/// `n!` orders must be equally likely.
+///
+/// ## Example
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_ds_learning::vector::shuffle;
+///
+/// let mut v = [1, 2, 3, 4, 5];
+/// shuffle(&mut v, &mut StdRng::seed_from_u64(7));
+/// let mut sorted = v;
+/// sorted.sort_unstable();
+/// assert_eq!(sorted, [1, 2, 3, 4, 5]); // same elements, new order
+///
+/// // The same seed always gives the same order
+/// let mut again = [1, 2, 3, 4, 5];
+/// shuffle(&mut again, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(again, v);
+/// ```
+///
+/// ## Approach
+/// Walk `i` from the back: swap `v[i]` with a uniformly random element of
+/// `v[..=i]`, then treat `v[i]` as final. Every element is equally likely
+/// to land in the last slot, then every remaining one in the slot before,
+/// and so on, giving `n · (n - 1) · … · 1 = n!` equally likely outcomes,
+/// one per permutation.
+///
+/// ## The Off-by-One Bias Bug
+/// The tempting version swaps every position with *any* position:
+///
+/// ```text
+/// for i in 0..n { v.swap(i, rng.gen_range(0..n)); }   // biased!
+/// ```
+///
+/// That makes `n^n` equally likely runs, and `n^n` is not divisible by
+/// `n!` for `n > 2`, so some permutations must come up more often than
+/// others. For `n = 3` there are 27 runs for 6 permutations: three of them
+/// appear 5 times in 27 and three only 4 times. The fix is the range
+/// `0..=i`, which shrinks with `i`.
+///
+/// The RNG is a parameter so callers choose between `thread_rng()` and a
+/// seeded `StdRng` for reproducible tests.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+pub fn shuffle<T>(v: &mut [T], rng: &mut impl Rng) {
+    for i in (1..v.len()).rev() {
+        let j = rng.gen_range(0..=i);
+        v.swap(i, j);
+    }
+}
+
+/// # Random Sample Without Replacement
+///
+/// ## Problem Statement
+/// Pick `k` distinct positions of `items` uniformly at random, so every
+/// `k`-subset is equally likely, and return those elements.
+///
+/// ## Example
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_ds_learning::vector::sample_k;
+///
+/// let items: Vec<u32> = (0..100).collect();
+/// let sample = sample_k(&items, 5, &mut StdRng::seed_from_u64(1)).unwrap();
+/// assert_eq!(sample.len(), 5);
+/// assert!(sample.iter().all(|x| items.contains(x)));
+/// assert!(sample_k(&items, 101, &mut StdRng::seed_from_u64(1)).is_err());
+/// ```
+///
+/// ## Approach
+/// Reservoir sampling (Algorithm R): keep the first `k` items, then let
+/// item `i` replace a random reservoir slot with probability `k / (i + 1)`.
+/// By induction, after seeing `i + 1` items each one is in the reservoir
+/// with probability `k / (i + 1)`. The same loop works on a stream whose
+/// length is unknown in advance.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(k)
+///
+/// ## Key Points
+/// 1. The order of the returned elements is not itself uniformly random;
+///    [`shuffle`] the result if that matters
+///
+/// ## Errors
+/// `InvalidInput` if `k > items.len()`.
+pub fn sample_k<T: Clone>(items: &[T], k: usize, rng: &mut impl Rng) -> Result<Vec<T>> {
+    if k > items.len() {
+        return Err(ExerciseError::InvalidInput(format!(
+            "cannot sample {k} elements from {}",
+            items.len()
+        )));
+    }
+    let mut reservoir = items[..k].to_vec();
+    for (i, item) in items.iter().enumerate().skip(k) {
+        let slot = rng.gen_range(0..=i);
+        if slot < k {
+            reservoir[slot] = item.clone();
+        }
+    }
+    Ok(reservoir)
+}
+
+/// # Wiggle Sort
+///
+/// ## Problem Statement
+/// Reorder `nums` in place so that
+/// `nums[0] <= nums[1] >= nums[2] <= nums[3] >= ...`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::wiggle_sort;
+/// let mut v = [3, 5, 2, 1, 6, 4];
+/// wiggle_sort(&mut v);
+/// assert_eq!(v, [3, 5, 1, 6, 2, 4]);
+/// ```
+///
+/// ## Approach
+/// One pass: for each adjacent pair, if it points the wrong way for its
+/// position (`nums[i] > nums[i + 1]` at even `i`, `<` at odd `i`), swap
+/// it. The swap cannot break the pair before: at even `i` we know
+/// `nums[i - 1] >= nums[i]`, and swapping in the smaller `nums[i + 1]`
+/// only strengthens that. Odd `i` is symmetric.
+///
+/// ## Complexity
+/// - Time: O(n), no sorting despite the name
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. With `<=` and `>=` a solution always exists; for strict
+///    inequalities see [`wiggle_sort_strict`]
+pub fn wiggle_sort(nums: &mut [i32]) {
+    for i in 1..nums.len() {
+        let wrong_way = if i % 2 == 1 {
+            nums[i - 1] > nums[i]
+        } else {
+            nums[i - 1] < nums[i]
+        };
+        if wrong_way {
+            nums.swap(i - 1, i);
+        }
+    }
+}
+
+/// # Wiggle Sort II (Strict)
+///
+/// ## Problem Statement
+/// Reorder `nums` so that `nums[0] < nums[1] > nums[2] < nums[3] > ...`,
+/// or report that no such order exists.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::wiggle_sort_strict;
+/// let mut v = [1, 5, 1, 1, 6, 4];
+/// wiggle_sort_strict(&mut v).unwrap();
+/// assert!(v[0] < v[1] && v[1] > v[2] && v[2] < v[3] && v[3] > v[4] && v[4] < v[5]);
+/// assert_eq!([v[0], v[2], v[4]], [1, 1, 1]);
+///
+/// assert!(wiggle_sort_strict(&mut [2, 2, 2, 1]).is_err());
+/// ```
+///
+/// ## Approach
+/// The single-pass swap of [`wiggle_sort`] fails on duplicates (`[1, 1]`
+/// can never be fixed by swapping), so this works from the median:
+/// 1. Find the median with `select_nth_unstable` in O(n) expected time,
+///    then three-way partition around it: `[< m][== m][> m]`
+/// 2. The first `⌈n/2⌉` elements are the *small* half, the rest the
+///    *large* half. Small elements go to the even "valleys", large ones to
+///    the odd "peaks"
+/// 3. Fill both in **reverse** order. Copies of the median sit at the end
+///    of the small half and the start of the large half; reversing places
+///    the former in the first valleys and the latter in the last peaks, so
+///    two medians never end up next to each other unless it is unavoidable
+///
+/// ## Complexity
+/// - Time: O(n) expected
+/// - Space: O(n) for the partitioned copy; an O(1) version exists using
+///   "virtual indexing" into the slice, at a large cost in readability
+///
+/// ## Common Pitfalls
+/// 1. Filling in forward order, which puts the median copies of both
+///    halves side by side: `[4, 5, 5, 6]` stays `[4, 5, 5, 6]` instead of
+///    becoming `[5, 6, 4, 5]`
+///
+/// ## Errors
+/// `InvalidInput` if no strict wiggle order exists, which happens when one
+/// value is too frequent. The slice is left unchanged.
+pub fn wiggle_sort_strict(nums: &mut [i32]) -> Result<()> {
+    let n = nums.len();
+    if n < 2 {
+        return Ok(());
+    }
+
+    let mut partitioned = nums.to_vec();
+    let (_, &mut median, _) = partitioned.select_nth_unstable((n - 1) / 2);
+    let (mut lt, mut i, mut gt) = (0, 0, n);
+    while i < gt {
+        match partitioned[i].cmp(&median) {
+            Ordering::Less => {
+                partitioned.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                partitioned.swap(i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+
+    let (small, large) = partitioned.split_at(n.div_ceil(2));
+    let mut arranged = Vec::with_capacity(n);
+    for k in 0..n {
+        let half = if k % 2 == 0 { small } else { large };
+        arranged.push(half[half.len() - 1 - k / 2]);
+    }
+
+    let strict = arranged.windows(2).enumerate().all(|(k, pair)| {
+        if k % 2 == 0 {
+            pair[0] < pair[1]
+        } else {
+            pair[0] > pair[1]
+        }
+    });
+    if !strict {
+        return Err(ExerciseError::InvalidInput(
+            "a value repeats too often for a strict wiggle order".to_string(),
+        ));
+    }
+    nums.copy_from_slice(&arranged);
+    Ok(())
+}
+
+/// # Run-Length Encoding
+///
+/// ## Problem Statement
+/// Compress a slice into `(value, count)` pairs, one per run of equal
+/// neighbours.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::{run_length_decode, run_length_encode};
+/// let runs = run_length_encode(&['a', 'a', 'b', 'c', 'c', 'c', 'a']);
+/// assert_eq!(runs, vec![('a', 2), ('b', 1), ('c', 3), ('a', 1)]);
+/// assert_eq!(run_length_decode(&runs), vec!['a', 'a', 'b', 'c', 'c', 'c', 'a']);
+/// ```
+///
+/// ## Approach
+/// Scan once, extending the last run while the value repeats and starting
+/// a new run otherwise. Only adjacent values are compared, so `T` needs
+/// `PartialEq` but not `Eq`, `Hash` or `Ord`.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(r) for `r` runs; `r == n` when no neighbours repeat, so
+///   the "compressed" form can be larger than the input
+///
+/// ## Key Points
+/// 1. Values that repeat non-adjacently (`'a'` above) get separate runs
+/// 2. Every count is at least 1, so decoding never produces empty runs
+pub fn run_length_encode<T: PartialEq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some((value, count)) if value == item => *count += 1,
+            _ => runs.push((item.clone(), 1)),
+        }
+    }
+    runs
+}
+
+/// Inverse of [`run_length_encode`]: repeats each value `count` times
+///
+/// Runs with a count of 0 decode to nothing, and adjacent runs of the same
+/// value simply concatenate, so `decode` accepts more inputs than `encode`
+/// produces.
+pub fn run_length_decode<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    let len = runs.iter().map(|(_, count)| count).sum();
+    let mut items = Vec::with_capacity(len);
+    for (value, count) in runs {
+        items.extend(std::iter::repeat_n(value.clone(), *count));
+    }
+    items
+}
+
+/// # Partition In Place
+///
+/// ## Problem Statement
+/// Reorder `v` so that every element satisfying `pred` comes before every
+/// element that does not, and return the number of elements satisfying it
+/// (the partition point).
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::partition_in_place;
+/// let mut v = [1, 2, 3, 4, 5, 6, 7];
+/// let split = partition_in_place(&mut v, |x| x % 2 == 0);
+/// assert_eq!(split, 3);
+/// assert!(v[..split].iter().all(|x| x % 2 == 0));
+/// assert!(v[split..].iter().all(|x| x % 2 == 1));
+/// assert_eq!(v, [6, 2, 4, 3, 5, 1, 7]); // original order is lost
+/// ```
+///
+/// ## Approach
+/// Hoare-style two pointers: `left` skips elements already on the correct
+/// (true) side, `right` skips elements already on the false side, and
+/// when both stop on misplaced elements they swap.
+///
+/// ## Complexity
+/// - Time: O(n), at most n / 2 swaps
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Not stable: a swap moves an element past many others, so elements
+///    within each side do not keep their relative order. Use
+///    [`stable_partition`] when that order matters
+/// 2. The result satisfies `pred` on `..split` exactly as
+///    [`partition_point`] expects, so the two compose
+pub fn partition_in_place<T>(v: &mut [T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let (mut left, mut right) = (0, v.len());
+    loop {
+        while left < right && pred(&v[left]) {
+            left += 1;
+        }
+        while left < right && !pred(&v[right - 1]) {
+            right -= 1;
+        }
+        if left == right {
+            return left;
+        }
+        v.swap(left, right - 1);
+        left += 1;
+        right -= 1;
+    }
+}
+
+/// # Stable Partition
+///
+/// ## Problem Statement
+/// As [`partition_in_place`], but elements on each side keep their
+/// original relative order.
+///
+/// ## Example
+/// Partitioning records by a key while keeping them sorted by another:
+/// ```
+/// use rust_ds_learning::vector::{partition_in_place, stable_partition};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Task { name: &'static str, urgent: bool }
+/// let task = |name, urgent| Task { name, urgent };
+///
+/// // Already sorted by name
+/// let tasks = vec![task("a", false), task("b", true), task("c", false), task("d", true)];
+///
+/// let mut stable = tasks.clone();
+/// let split = stable_partition(&mut stable, |t| t.urgent);
+/// let names: Vec<_> = stable.iter().map(|t| t.name).collect();
+/// assert_eq!((split, names), (2, vec!["b", "d", "a", "c"])); // still by name
+///
+/// let mut unstable = tasks.clone();
+/// partition_in_place(&mut unstable, |t| t.urgent);
+/// let names: Vec<_> = unstable.iter().map(|t| t.name).collect();
+/// assert_eq!(names, vec!["d", "b", "c", "a"]); // name order lost
+/// ```
+///
+/// ## Approach
+/// Copy the matching and non-matching elements into two buffers, in order,
+/// then write them back one after the other.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(n) for the buffers; a stable partition in O(1) extra space
+///   exists but takes O(n log n) time (recursive rotations)
+pub fn stable_partition<T: Clone>(v: &mut [T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let (matching, rest): (Vec<T>, Vec<T>) = v.iter().cloned().partition(|x| pred(x));
+    let split = matching.len();
+    v[..split].clone_from_slice(&matching);
+    v[split..].clone_from_slice(&rest);
+    split
+}
+
+/// # Meeting Rooms
+///
+/// ## Problem Statement
+/// Given meetings as `(start, end)` times, can one person attend all of
+/// them?
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::can_attend_all;
+/// assert!(!can_attend_all(&[(0, 30), (5, 10), (15, 20)]));
+/// assert!(can_attend_all(&[(7, 10), (2, 4)]));
+/// assert!(can_attend_all(&[(1, 5), (5, 8)])); // back to back is fine
+/// ```
+///
+/// ## Approach
+/// Sort by start; then only neighbours can clash, and a meeting clashes
+/// with the previous one exactly when it starts before that one ends.
+///
+/// ## Complexity
+/// - Time: O(n log n) for the sort
+/// - Space: O(n) for the sorted copy
+///
+/// ## Key Points
+/// 1. Meetings are half-open, `[start, end)`: one ending at 5 and another
+///    starting at 5 do not overlap. This differs from
+///    [`merge_intervals`], which merges touching intervals
+/// 2. Empty meetings (`start >= end`) are ignored here and in
+///    [`min_meeting_rooms`]
+pub fn can_attend_all(meetings: &[(i32, i32)]) -> bool {
+    let mut sorted = non_empty_meetings(meetings);
+    sorted.sort_unstable();
+    sorted.windows(2).all(|pair| pair[0].1 <= pair[1].0)
+}
+
+/// # Meeting Rooms II
+///
+/// ## Problem Statement
+/// Given meetings as half-open `(start, end)` times, how many rooms are
+/// needed so that no two overlapping meetings share a room?
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::{min_meeting_rooms, min_meeting_rooms_sweep};
+/// let meetings = [(0, 30), (5, 10), (15, 20)];
+/// assert_eq!(min_meeting_rooms(&meetings), 2);
+/// assert_eq!(min_meeting_rooms_sweep(&meetings), 2);
+/// ```
+///
+/// ## Approach
+/// Greedy room allocation: go through the meetings by start time and keep
+/// the end times of the rooms in use in a min-heap
+/// ([`BinaryHeap`] of [`Reverse`], see [`crate::binary_heap`]). If the
+/// earliest-ending room is free by the time the next meeting starts, reuse
+/// it (pop); either way the meeting occupies a room (push). The heap's
+/// largest size is the answer.
+///
+/// [`min_meeting_rooms_sweep`] counts the same thing without simulating
+/// rooms.
+///
+/// ## Complexity
+/// - Time: O(n log n)
+/// - Space: O(n)
+///
+/// ## Common Pitfalls
+/// 1. Reusing a room only when it ended strictly before the next start,
+///    which double-counts back-to-back meetings
+pub fn min_meeting_rooms(meetings: &[(i32, i32)]) -> usize {
+    let mut sorted = non_empty_meetings(meetings);
+    sorted.sort_unstable();
+
+    let mut ends: BinaryHeap<Reverse<i32>> = BinaryHeap::with_capacity(sorted.len());
+    let mut rooms = 0;
+    for (start, end) in sorted {
+        if ends
+            .peek()
+            .is_some_and(|&Reverse(earliest)| earliest <= start)
+        {
+            ends.pop();
+        }
+        ends.push(Reverse(end));
+        rooms = rooms.max(ends.len());
+    }
+    rooms
+}
+
+/// # Meeting Rooms II (Sweep Line)
+///
+/// Same answer as [`min_meeting_rooms`], computed as the largest number of
+/// meetings running at the same moment.
+///
+/// ```
+/// use rust_ds_learning::vector::min_meeting_rooms_sweep;
+/// assert_eq!(min_meeting_rooms_sweep(&[(1, 5), (5, 8), (2, 6)]), 2);
+/// ```
+///
+/// ## Approach
+/// Record `+1` at every start and `-1` at every end in a [`BTreeMap`] from
+/// time to change (see [`crate::btreemap`]). Iterating the map visits the
+/// times in order; the running sum is the number of meetings in progress,
+/// and its maximum is the answer. Summing all changes at one time before
+/// reading the total treats a meeting ending at `t` and one starting at
+/// `t` as not overlapping.
+///
+/// ## Complexity
+/// - Time: O(n log n)
+/// - Space: O(d) for `d` distinct times
+pub fn min_meeting_rooms_sweep(meetings: &[(i32, i32)]) -> usize {
+    let mut changes: BTreeMap<i32, i64> = BTreeMap::new();
+    for (start, end) in non_empty_meetings(meetings) {
+        *changes.entry(start).or_insert(0) += 1;
+        *changes.entry(end).or_insert(0) -= 1;
+    }
+
+    let (mut in_progress, mut rooms) = (0i64, 0i64);
+    for change in changes.into_values() {
+        in_progress += change;
+        rooms = rooms.max(in_progress);
+    }
+    rooms as usize
+}
+
+/// Meetings with `start >= end` take no time and need no room
+fn non_empty_meetings(meetings: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    meetings
+        .iter()
+        .copied()
+        .filter(|&(start, end)| start < end)
+        .collect()
 }
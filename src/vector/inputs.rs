@@ -0,0 +1,67 @@
+//! Reproducible random inputs for tests and benchmarks
+
+use std::ops::Range;
+
+use rand::distributions::uniform::SampleUniform;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `len` values drawn uniformly from `range`; the same `seed` always gives
+/// the same vector, like [`Matrix::random`](crate::matrix::Matrix::random)
+///
+/// ```
+/// use rust_ds_learning::vector::inputs::random_vec;
+///
+/// let v = random_vec(5, -10..10, 42);
+/// assert_eq!(v.len(), 5);
+/// assert!(v.iter().all(|x| (-10..10).contains(x)));
+/// assert_eq!(v, random_vec(5, -10..10, 42));
+/// ```
+pub fn random_vec<T: SampleUniform + PartialOrd + Clone>(
+    len: usize,
+    range: Range<T>,
+    seed: u64,
+) -> Vec<T> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..len).map(|_| rng.gen_range(range.clone())).collect()
+}
+
+/// `len` closed intervals with starts in `starts` and lengths in
+/// `0..max_len`, in random order and possibly overlapping
+///
+/// ```
+/// use rust_ds_learning::vector::inputs::random_intervals;
+///
+/// let intervals = random_intervals(100, 0..1000, 20, 7);
+/// assert!(intervals.iter().all(|&(start, end)| start <= end && end - start < 20));
+/// ```
+pub fn random_intervals(
+    len: usize,
+    starts: Range<i32>,
+    max_len: i32,
+    seed: u64,
+) -> Vec<(i32, i32)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..len)
+        .map(|_| {
+            let start = rng.gen_range(starts.clone());
+            (start, start + rng.gen_range(0..max_len))
+        })
+        .collect()
+}
+
+/// A random permutation of `0..len`, shuffled with
+/// [`shuffle`](super::shuffle): distinct values in no particular order
+///
+/// ```
+/// use rust_ds_learning::vector::inputs::random_permutation;
+///
+/// let mut p = random_permutation(10, 3);
+/// p.sort_unstable();
+/// assert_eq!(p, (0..10).collect::<Vec<_>>());
+/// ```
+pub fn random_permutation(len: usize, seed: u64) -> Vec<usize> {
+    let mut values: Vec<usize> = (0..len).collect();
+    super::shuffle(&mut values, &mut StdRng::seed_from_u64(seed));
+    values
+}
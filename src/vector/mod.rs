@@ -1,7 +1,357 @@
 //! Vector exercises and examples module
 
 mod exercises;
+pub mod inputs;
+mod monotonic_queue;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod prefix_sum;
+pub mod sorting;
 #[cfg(test)]
+#[allow(clippy::module_inception, clippy::useless_vec)]
 mod tests;
+pub mod two_pointers;
 
 pub use exercises::*;
+pub use monotonic_queue::MonotonicQueue;
+#[cfg(feature = "parallel")]
+pub use parallel::{par_merge_intervals, par_position, PAR_MIN_CHUNK};
+pub use prefix_sum::{DifferenceArray, PrefixSum, PrefixSum2D};
+
+crate::exercise! {
+    /// Registry entry for [`sliding_window_maximum`]
+    pub struct SlidingWindowMaximumExercise {
+        name: "vector::sliding_window_maximum",
+        difficulty: Hard,
+        topics: ["sliding window", "deque", "monotonic queue"],
+        hints: [
+            "A window of size k has n - k + 1 positions; can you avoid rescanning each one?",
+            "Once a larger element enters the window, smaller elements before it can never be the maximum again.",
+            "Keep candidate indices in a deque in decreasing order of value.",
+        ],
+        solver: sliding_window_maximum as fn(&[i32], usize) -> Vec<i32>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`merge_intervals`]
+    pub struct MergeIntervalsExercise {
+        name: "vector::merge_intervals",
+        difficulty: Medium,
+        topics: ["intervals", "sorting"],
+        hints: [
+            "Overlaps are easy to spot if neighbouring intervals start close together.",
+            "After sorting by start, an interval can only overlap the last merged one.",
+            "Intervals that merely touch also count as overlapping.",
+        ],
+        solver: merge_intervals as fn(&[(i32, i32)]) -> Vec<(i32, i32)>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`max_product`]
+    pub struct MaxProductExercise {
+        name: "vector::max_product",
+        difficulty: Medium,
+        topics: ["dynamic programming"],
+        hints: [
+            "A negative number turns the smallest product into the largest.",
+            "Track both the maximum and the minimum product ending at each position.",
+        ],
+        solver: max_product as fn(&[i32]) -> i32,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`max_subarray_sum`]
+    pub struct MaxSubarraySumExercise {
+        name: "vector::max_subarray_sum",
+        difficulty: Medium,
+        topics: ["dynamic programming", "kadane"],
+        hints: [
+            "What is the best sum of a subarray that ends exactly at index i?",
+            "A negative running sum can only hurt whatever comes after it.",
+        ],
+        solver: max_subarray_sum as fn(&[i32]) -> Option<(i64, std::ops::Range<usize>)>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`two_sum`]
+    pub struct TwoSumExercise {
+        name: "vector::two_sum",
+        difficulty: Easy,
+        topics: ["hash map"],
+        hints: [
+            "For each element, which other value would complete the pair?",
+            "Remember the values you've already passed, and where they were.",
+        ],
+        solver: two_sum as fn(&[i32], i32) -> Option<(usize, usize)>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`three_sum`]
+    pub struct ThreeSumExercise {
+        name: "vector::three_sum",
+        difficulty: Medium,
+        topics: ["two pointers", "sorting"],
+        hints: [
+            "Fix the smallest element; what problem is left for the other two?",
+            "In a sorted array, two pointers from both ends find pairs with a given sum in O(n).",
+            "Skip equal neighbours to avoid reporting the same triplet twice.",
+        ],
+        solver: three_sum as fn(&[i32]) -> Vec<[i32; 3]>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`rotate_right`]
+    pub struct RotateRightExercise {
+        name: "vector::rotate_right",
+        difficulty: Medium,
+        topics: ["in-place", "reversal"],
+        hints: [
+            "Rotating by k and by k + n give the same result.",
+            "What does reversing the whole array do to the last k elements?",
+            "Reverse the whole array, then each of the two parts.",
+        ],
+        solver: rotate_right as fn(&mut [i32], usize),
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`kth_smallest`]
+    pub struct KthSmallestExercise {
+        name: "vector::kth_smallest",
+        difficulty: Medium,
+        topics: ["quickselect", "partitioning"],
+        hints: [
+            "Sorting answers the question, but does more work than needed.",
+            "After partitioning around a pivot, which side can contain the answer?",
+            "Pick the pivot at random to avoid adversarial worst cases.",
+        ],
+        solver: kth_smallest as fn(&mut [i32], usize) -> crate::Result<i32>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`lower_bound`]
+    pub struct LowerBoundExercise {
+        name: "vector::lower_bound",
+        difficulty: Easy,
+        topics: ["binary search", "loop invariants"],
+        hints: [
+            "State what you know about the elements left of lo and right of hi, then keep it true.",
+            "With a half-open window lo..hi, the loop ends when lo == hi.",
+        ],
+        solver: lower_bound::<i32> as fn(&[i32], &i32) -> usize,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`trap_rain_water`]
+    pub struct TrapRainWaterExercise {
+        name: "vector::trap_rain_water",
+        difficulty: Hard,
+        topics: ["two pointers", "monotonic stack"],
+        hints: [
+            "How high can the water above a single bar rise?",
+            "It is bounded by the highest bar on each side; the lower of the two wins.",
+            "With two pointers, the side whose maximum is lower already knows its water level.",
+        ],
+        solver: trap_rain_water as fn(&[u32]) -> u64,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`next_permutation`]
+    pub struct NextPermutationExercise {
+        name: "vector::next_permutation",
+        difficulty: Medium,
+        topics: ["permutations", "in-place"],
+        hints: [
+            "A descending suffix is already as large as it can get.",
+            "Increase the element just before that suffix by as little as possible.",
+            "After the swap, the suffix is still descending; make it as small as possible.",
+        ],
+        solver: next_permutation as fn(&mut [i32]) -> bool,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`subarray_sum_count`]
+    pub struct SubarraySumCountExercise {
+        name: "vector::subarray_sum_count",
+        difficulty: Medium,
+        topics: ["prefix sums", "hash map"],
+        hints: [
+            "The sum of nums[i..j] is the difference of two prefix sums.",
+            "For each end j, how many earlier prefixes equal prefix(j) - k?",
+            "Count prefix sums in a HashMap as you go; start with the empty prefix.",
+        ],
+        solver: subarray_sum_count as fn(&[i32], i32) -> usize,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`merge_k_sorted`]
+    pub struct MergeKSortedExercise {
+        name: "vector::merge_k_sorted",
+        difficulty: Hard,
+        topics: ["heap", "merging", "divide and conquer"],
+        hints: [
+            "The next output element is the smallest of the k current list heads.",
+            "A min-heap finds the smallest of k candidates in O(log k).",
+            "Alternatively, merge the lists in pairs, like the merge step of merge sort.",
+        ],
+        solver: merge_k_sorted as fn(&[Vec<i32>]) -> Vec<i32>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`remove_duplicates_sorted`]
+    pub struct RemoveDuplicatesSortedExercise {
+        name: "vector::remove_duplicates_sorted",
+        difficulty: Easy,
+        topics: ["two pointers", "in-place"],
+        hints: [
+            "In sorted input, duplicates are always next to each other.",
+            "Use one index to read every element and another to write the ones you keep.",
+        ],
+        solver: remove_duplicates_sorted as fn(&mut Vec<i32>) -> usize,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`sorting::quicksort`]
+    pub struct QuicksortExercise {
+        name: "vector::quicksort",
+        difficulty: Medium,
+        topics: ["sorting", "partitioning", "divide and conquer"],
+        hints: [
+            "After partitioning, the pivot is in its final position.",
+            "Pick the pivot so that sorted input does not make every partition lopsided.",
+            "With many duplicates, group the elements equal to the pivot in the middle.",
+        ],
+        solver: sorting::quicksort::<i32> as fn(&mut [i32]),
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`insert_interval`]
+    pub struct InsertIntervalExercise {
+        name: "vector::insert_interval",
+        difficulty: Medium,
+        topics: ["intervals"],
+        hints: [
+            "The input is already sorted; sorting again is wasted work.",
+            "Which intervals come entirely before the new one, and which entirely after?",
+            "Everything in between overlaps the new interval and widens it.",
+        ],
+        solver: insert_interval as fn(&[(i32, i32)], (i32, i32)) -> Vec<(i32, i32)>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`max_profit_single`]
+    pub struct MaxProfitSingleExercise {
+        name: "vector::max_profit_single",
+        difficulty: Easy,
+        topics: ["dynamic programming", "stock trading"],
+        hints: [
+            "If you sell on day i, which day should you have bought on?",
+            "Keep the cheapest price seen so far.",
+        ],
+        solver: max_profit_single as fn(&[i32]) -> i64,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`max_profit_multi`]
+    pub struct MaxProfitMultiExercise {
+        name: "vector::max_profit_multi",
+        difficulty: Medium,
+        topics: ["dynamic programming", "state machine", "stock trading"],
+        hints: [
+            "At the end of each day you either hold a share or you don't.",
+            "Write the best profit in each state in terms of yesterday's two states.",
+        ],
+        solver: max_profit_multi as fn(&[i32]) -> i64,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`max_profit_cooldown`]
+    pub struct MaxProfitCooldownExercise {
+        name: "vector::max_profit_cooldown",
+        difficulty: Medium,
+        topics: ["dynamic programming", "state machine", "stock trading"],
+        hints: [
+            "Start from the two-state solution without a cooldown.",
+            "Not holding a share now means two different things: just sold, or free to buy.",
+            "A buy may only come from the state that did not sell yesterday.",
+        ],
+        solver: max_profit_cooldown as fn(&[i32]) -> i64,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`two_pointers::max_container_area`]
+    pub struct MaxContainerAreaExercise {
+        name: "vector::max_container_area",
+        difficulty: Medium,
+        topics: ["two pointers", "greedy"],
+        hints: [
+            "Start with the widest container; every other one is narrower.",
+            "Which of the two lines can never be part of a larger container?",
+            "Move the pointer at the shorter line.",
+        ],
+        solver: two_pointers::max_container_area as fn(&[u32]) -> u64,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`min_jumps`]
+    pub struct MinJumpsExercise {
+        name: "vector::min_jumps",
+        difficulty: Medium,
+        topics: ["greedy", "bfs"],
+        hints: [
+            "The indices reachable with k jumps form one contiguous block.",
+            "While scanning one block, track the furthest index the next jump can reach.",
+            "If a block cannot reach past its own end, the last index is unreachable.",
+        ],
+        solver: min_jumps as fn(&[usize]) -> Option<usize>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`wiggle_sort_strict`]
+    pub struct WiggleSortStrictExercise {
+        name: "vector::wiggle_sort_strict",
+        difficulty: Hard,
+        topics: ["partitioning", "quickselect"],
+        hints: [
+            "Swapping neighbours cannot fix two equal values next to each other.",
+            "Valleys should hold the smaller half of the values, peaks the larger half.",
+            "Fill both halves in reverse so the copies of the median end up far apart.",
+        ],
+        solver: wiggle_sort_strict as fn(&mut [i32]) -> crate::Result<()>,
+    }
+}
+
+crate::exercise! {
+    /// Registry entry for [`min_meeting_rooms`]
+    pub struct MinMeetingRoomsExercise {
+        name: "vector::min_meeting_rooms",
+        difficulty: Medium,
+        topics: ["intervals", "heap", "sweep line"],
+        hints: [
+            "Handle the meetings in order of start time.",
+            "A room can be reused once its last meeting has ended; which room frees up first?",
+            "Alternatively, count how many meetings are running at each start or end time.",
+        ],
+        solver: min_meeting_rooms as fn(&[(i32, i32)]) -> usize,
+    }
+}
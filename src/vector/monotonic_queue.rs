@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+
+/// # Monotonic Queue
+///
+/// A queue of "candidates" for the maximum (or minimum) of a sliding
+/// window. Values are pushed at the back and expire from the front; the
+/// front is always the extreme of everything pushed and not yet expired.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::MonotonicQueue;
+///
+/// let mut queue = MonotonicQueue::max();
+/// for value in [1, 3, -1] {
+///     queue.push(value);
+/// }
+/// assert_eq!(queue.current_extreme(), Some(&3));
+/// assert_eq!(queue.len(), 2); // 1 was evicted by 3
+///
+/// queue.push(-3);
+/// queue.pop_expired(2); // window is now indices 2..4: [-1, -3]
+/// assert_eq!(queue.current_extreme(), Some(&-1));
+/// ```
+///
+/// ## Approach
+/// When a new value arrives, every older value that is not better than it
+/// is dropped from the back: the new value outlives them and beats them,
+/// so none of them can ever be the answer again. What remains is sorted
+/// (strictly decreasing for a max-queue), so the front is the extreme.
+/// Values remember the index at which they were pushed, and
+/// [`pop_expired`](MonotonicQueue::pop_expired) drops front entries that
+/// have left the window.
+///
+/// ## Complexity
+/// - [`push`](MonotonicQueue::push): amortized O(1), every value is
+///   removed at most once
+/// - [`pop_expired`](MonotonicQueue::pop_expired): amortized O(1)
+/// - [`current_extreme`](MonotonicQueue::current_extreme): O(1)
+/// - Space: O(k) for a window of size `k`
+///
+/// ## Key Points
+/// 1. Ties evict the older value, since the newer one expires later
+/// 2. Only non-expired values are ever reported, so forgetting
+///    `pop_expired` yields the extreme of the whole prefix instead of the
+///    window
+#[derive(Debug, Clone)]
+pub struct MonotonicQueue<T> {
+    entries: VecDeque<(usize, T)>,
+    pushed: usize,
+    extreme: Extreme,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Extreme {
+    Max,
+    Min,
+}
+
+impl<T: Ord> MonotonicQueue<T> {
+    /// A queue whose front is the maximum
+    pub fn max() -> Self {
+        Self::with_extreme(Extreme::Max)
+    }
+
+    /// A queue whose front is the minimum
+    pub fn min() -> Self {
+        Self::with_extreme(Extreme::Min)
+    }
+
+    fn with_extreme(extreme: Extreme) -> Self {
+        MonotonicQueue {
+            entries: VecDeque::new(),
+            pushed: 0,
+            extreme,
+        }
+    }
+
+    /// Pushes `value` and returns its index, counting pushes from 0
+    pub fn push(&mut self, value: T) -> usize {
+        while let Some((_, back)) = self.entries.back() {
+            let evicted = match self.extreme {
+                Extreme::Max => *back <= value,
+                Extreme::Min => *back >= value,
+            };
+            if !evicted {
+                break;
+            }
+            self.entries.pop_back();
+        }
+        let index = self.pushed;
+        self.entries.push_back((index, value));
+        self.pushed += 1;
+        index
+    }
+
+    /// Drops every value pushed before index `window_start`
+    pub fn pop_expired(&mut self, window_start: usize) {
+        while self
+            .entries
+            .front()
+            .is_some_and(|&(index, _)| index < window_start)
+        {
+            self.entries.pop_front();
+        }
+    }
+
+    /// The extreme of the values that have not expired, if any
+    pub fn current_extreme(&self) -> Option<&T> {
+        self.entries.front().map(|(_, value)| value)
+    }
+
+    /// Number of values pushed so far, including evicted ones
+    pub fn pushed(&self) -> usize {
+        self.pushed
+    }
+
+    /// Number of candidates currently kept
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,223 @@
+//! Rayon-based variants of the vector exercises (enabled with the `parallel`
+//! feature).
+//!
+//! Every `par_` function returns exactly what its sequential counterpart
+//! returns; only the schedule differs.
+//!
+//! ## When Parallelism Doesn't Pay Off
+//! - Small inputs: handing work to the thread pool costs microseconds, the
+//!   same order as scanning a few thousand integers on one core. Below
+//!   [`PAR_MIN_CHUNK`] elements per task these functions stay sequential.
+//! - Memory-bound work: a prefix sum or a linear search does one addition
+//!   or comparison per element loaded, so a few cores already saturate
+//!   memory bandwidth and more threads add nothing.
+//! - Early exits: the sequential search stops at the first match, and a
+//!   match near the front is found before the parallel version has even
+//!   split the work.
+//!
+//! Measure with `cargo bench` before assuming a speedup.
+
+use rayon::prelude::*;
+
+use super::PrefixSum;
+
+/// Inputs are split into at most one chunk per thread, and no chunk is
+/// shorter than this
+pub const PAR_MIN_CHUNK: usize = 4096;
+
+fn chunk_len(len: usize) -> usize {
+    len.div_ceil(rayon::current_num_threads())
+        .max(PAR_MIN_CHUNK)
+}
+
+impl PrefixSum {
+    /// # Parallel Prefix Sum
+    ///
+    /// Builds the same table as [`PrefixSum::new`] with a two-pass
+    /// parallel scan.
+    ///
+    /// ## Example
+    /// ```
+    /// use rust_ds_learning::vector::PrefixSum;
+    ///
+    /// let nums: Vec<i32> = (0..100_000).map(|i| i % 7 - 3).collect();
+    /// assert_eq!(PrefixSum::par_new(&nums), PrefixSum::new(&nums));
+    /// ```
+    ///
+    /// ## Approach
+    /// A scan looks inherently sequential, since every prefix depends on
+    /// the one before it. But addition is associative, so:
+    /// 1. In parallel, sum each chunk on its own
+    /// 2. Sequentially, scan the few chunk totals into chunk offsets
+    /// 3. In parallel, scan each chunk again starting from its offset
+    ///
+    /// ## Complexity
+    /// - Time: O(n / threads + threads), about twice the additions of the
+    ///   sequential scan
+    /// - Space: O(n) for the table, O(threads) for the offsets
+    pub fn par_new(nums: &[i32]) -> Self {
+        let chunk = chunk_len(nums.len());
+        let totals: Vec<i64> = nums
+            .par_chunks(chunk)
+            .map(|part| part.iter().map(|&num| i64::from(num)).sum())
+            .collect();
+        let offsets = totals.iter().scan(0i64, |running, &total| {
+            let offset = *running;
+            *running += total;
+            Some(offset)
+        });
+
+        let mut prefix = vec![0i64; nums.len() + 1];
+        prefix[1..]
+            .par_chunks_mut(chunk)
+            .zip(nums.par_chunks(chunk))
+            .zip(offsets.collect::<Vec<_>>())
+            .for_each(|((out, part), offset)| {
+                let mut running = offset;
+                for (slot, &num) in out.iter_mut().zip(part) {
+                    running += i64::from(num);
+                    *slot = running;
+                }
+            });
+        PrefixSum { prefix }
+    }
+}
+
+/// # Parallel Merge Intervals
+///
+/// Same result as [`merge_intervals`](super::merge_intervals).
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::{merge_intervals, par_merge_intervals};
+///
+/// let intervals: Vec<(i32, i32)> = (0..50_000)
+///     .map(|i| (i * 3 % 9973, i * 3 % 9973 + 2))
+///     .collect();
+/// assert_eq!(par_merge_intervals(&intervals), merge_intervals(&intervals));
+/// ```
+///
+/// ## Approach
+/// 1. Sort with `par_sort_unstable_by_key`
+/// 2. Merge each chunk of the sorted list independently; overlaps inside a
+///    chunk are resolved in parallel
+/// 3. Stitch the chunk results together: only the last interval of one
+///    chunk and the first intervals of the next can still overlap
+///
+/// ## Complexity
+/// - Time: O(n log n / threads) for the sort, O(n / threads) for merging
+/// - Space: O(n)
+pub fn par_merge_intervals(intervals: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut sorted = intervals.to_vec();
+    sorted.par_sort_unstable_by_key(|&(start, _)| start);
+
+    let runs: Vec<Vec<(i32, i32)>> = sorted
+        .par_chunks(chunk_len(sorted.len()))
+        .map(|chunk| chunk.iter().copied().fold(Vec::new(), push_merged))
+        .collect();
+    runs.into_iter().flatten().fold(Vec::new(), push_merged)
+}
+
+/// Appends `interval` to `merged`, which is sorted by start and
+/// non-overlapping, extending the last interval instead if they overlap
+fn push_merged(mut merged: Vec<(i32, i32)>, interval: (i32, i32)) -> Vec<(i32, i32)> {
+    match merged.last_mut() {
+        Some(last) if interval.0 <= last.1 => last.1 = last.1.max(interval.1),
+        _ => merged.push(interval),
+    }
+    merged
+}
+
+/// # Parallel Element Search
+///
+/// Index of the first element equal to `target`, like
+/// `v.iter().position(|x| x == target)`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::par_position;
+///
+/// let v: Vec<i32> = (0..100_000).map(|i| i % 1000).collect();
+/// assert_eq!(par_position(&v, &999), Some(999));
+/// assert_eq!(par_position(&v, &1000), None);
+/// ```
+///
+/// ## Key Points
+/// 1. `position_first` rather than `position_any`: threads that find a
+///    match later in the slice keep looking until every earlier chunk has
+///    been ruled out, so the answer is deterministic
+///
+/// ## Complexity
+/// - Time: O(n / threads)
+/// - Space: O(1)
+pub fn par_position<T: PartialEq + Sync>(v: &[T], target: &T) -> Option<usize> {
+    v.par_iter()
+        .with_min_len(PAR_MIN_CHUNK)
+        .position_first(|x| x == target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::merge_intervals;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_par_prefix_sum_matches_sequential() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for len in [
+            0,
+            1,
+            PAR_MIN_CHUNK - 1,
+            PAR_MIN_CHUNK,
+            3 * PAR_MIN_CHUNK + 17,
+            100_000,
+        ] {
+            let nums: Vec<i32> = (0..len).map(|_| rng.gen()).collect();
+            let parallel = PrefixSum::par_new(&nums);
+            assert_eq!(parallel, PrefixSum::new(&nums));
+            assert_eq!(parallel.len(), len);
+        }
+    }
+
+    #[test]
+    fn test_par_merge_intervals_matches_sequential() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for (len, spread) in [
+            (0, 10),
+            (1, 10),
+            (100, 1_000),
+            (50_000, 1_000_000),
+            (50_000, 100),
+        ] {
+            let intervals: Vec<(i32, i32)> = (0..len)
+                .map(|_| {
+                    let start = rng.gen_range(-spread..spread);
+                    (start, start + rng.gen_range(0..20))
+                })
+                .collect();
+            assert_eq!(par_merge_intervals(&intervals), merge_intervals(&intervals));
+        }
+    }
+
+    #[test]
+    fn test_par_merge_intervals_across_chunk_boundaries() {
+        // One interval spanning everything must absorb every chunk's runs
+        let mut intervals: Vec<(i32, i32)> = (0..20_000).map(|i| (2 * i, 2 * i)).collect();
+        intervals.push((0, 40_000));
+        assert_eq!(par_merge_intervals(&intervals), vec![(0, 40_000)]);
+    }
+
+    #[test]
+    fn test_par_position_matches_sequential() {
+        let v: Vec<u8> = (0..50_000).map(|i| (i % 251) as u8).collect();
+        for target in [0, 17, 250, 255] {
+            assert_eq!(
+                par_position(&v, &target),
+                v.iter().position(|&x| x == target)
+            );
+        }
+        assert_eq!(par_position(&[] as &[u8], &0), None);
+    }
+}
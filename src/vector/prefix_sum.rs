@@ -0,0 +1,264 @@
+//! Prefix sums for O(1) range-sum queries, in one and two dimensions, and
+//! their inverse, the difference array, for O(1) range updates
+
+use std::ops::Range;
+
+use crate::matrix::Matrix;
+use crate::{ExerciseError, Result};
+
+/// # Prefix Sum
+///
+/// Precomputes `prefix[i] = nums[0] + .. + nums[i - 1]` once, so the sum of
+/// any range `l..r` is the difference `prefix[r] - prefix[l]`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::PrefixSum;
+///
+/// let sums = PrefixSum::new(&[-2, 0, 3, -5, 2, -1]);
+/// assert_eq!(sums.range_sum(0..3).unwrap(), 1);
+/// assert_eq!(sums.range_sum(2..6).unwrap(), -1);
+/// assert_eq!(sums.range_sum(4..4).unwrap(), 0);
+/// assert!(sums.range_sum(3..7).is_err());
+/// ```
+///
+/// ## Complexity
+/// - Construction: O(n) time, O(n) space
+/// - Each query: O(1)
+///
+/// ## Key Points
+/// 1. The table has `n + 1` entries, starting with the empty prefix `0`,
+///    so ranges starting at index 0 need no special case
+/// 2. Sums are kept in `i64`: the total of up to 2³² `i32`s cannot overflow
+/// 3. The input is copied into the table, so later changes to it are not
+///    reflected; for updates between queries see a Fenwick tree
+///
+/// [`subarray_sum_count`](super::subarray_sum_count) uses the same identity
+/// without storing the table, counting prefixes in a `HashMap` instead.
+///
+/// ## Common Pitfalls
+/// 1. Off-by-one between inclusive and half-open ranges: `l..r` covers
+///    `nums[l]` through `nums[r - 1]`, i.e. `prefix[r] - prefix[l]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSum {
+    pub(super) prefix: Vec<i64>,
+}
+
+impl PrefixSum {
+    pub fn new(nums: &[i32]) -> Self {
+        let mut prefix = Vec::with_capacity(nums.len() + 1);
+        prefix.push(0);
+        let mut running = 0i64;
+        for &num in nums {
+            running += i64::from(num);
+            prefix.push(running);
+        }
+        PrefixSum { prefix }
+    }
+
+    /// Number of elements in the original slice
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sum of all elements
+    pub fn total(&self) -> i64 {
+        self.prefix[self.len()]
+    }
+
+    /// Sum of the elements in the half-open range `range`
+    ///
+    /// # Errors
+    /// `InvalidInput` if `range.start > range.end` or `range.end > len()`.
+    pub fn range_sum(&self, range: Range<usize>) -> Result<i64> {
+        check_range(&range, self.len(), "range")?;
+        Ok(self.prefix[range.end] - self.prefix[range.start])
+    }
+}
+
+/// # 2D Prefix Sum
+///
+/// The two-dimensional version of [`PrefixSum`]: entry `(i, j)` of the
+/// table holds the sum of the sub-matrix `0..i` × `0..j`, so the sum of any
+/// rectangle takes four lookups.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::matrix::Matrix;
+/// use rust_ds_learning::vector::PrefixSum2D;
+///
+/// let m: Matrix<i32> = "3 0 1 4; 5 6 3 2; 1 2 0 1".parse().unwrap();
+/// let sums = PrefixSum2D::new(&m);
+/// assert_eq!(sums.region_sum(1..3, 1..3).unwrap(), 11);
+/// assert_eq!(sums.region_sum(0..3, 0..4).unwrap(), 28);
+/// assert!(sums.region_sum(0..4, 0..1).is_err());
+/// ```
+///
+/// ## Approach
+/// By inclusion–exclusion, with `P` the table:
+/// - building: `P[i+1][j+1] = m[i][j] + P[i][j+1] + P[i+1][j] - P[i][j]`
+/// - querying `r0..r1` × `c0..c1`:
+///   `P[r1][c1] - P[r0][c1] - P[r1][c0] + P[r0][c0]`
+///
+/// The corner `P[r0][c0]` is subtracted twice by the two strips, so it is
+/// added back once.
+///
+/// ## Complexity
+/// - Construction: O(rows · cols) time and space
+/// - Each query: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Forgetting to add the corner back, which undercounts every query
+///    not touching row or column 0
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrefixSum2D {
+    prefix: Matrix<i64>,
+}
+
+impl PrefixSum2D {
+    pub fn new(matrix: &Matrix<i32>) -> Self {
+        let mut prefix = Matrix::zeros(matrix.rows() + 1, matrix.cols() + 1);
+        let width = prefix.cols();
+        for (i, row) in matrix.iter_rows().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                let above = prefix.data[i * width + j + 1];
+                let left = prefix.data[(i + 1) * width + j];
+                let corner = prefix.data[i * width + j];
+                prefix.data[(i + 1) * width + j + 1] = i64::from(value) + above + left - corner;
+            }
+        }
+        PrefixSum2D { prefix }
+    }
+
+    /// Number of rows in the original matrix
+    pub fn rows(&self) -> usize {
+        self.prefix.rows() - 1
+    }
+
+    /// Number of columns in the original matrix
+    pub fn cols(&self) -> usize {
+        self.prefix.cols() - 1
+    }
+
+    /// Sum of the elements in rows `rows` and columns `cols`
+    ///
+    /// # Errors
+    /// `InvalidInput` if either range is reversed or exceeds the matrix.
+    pub fn region_sum(&self, rows: Range<usize>, cols: Range<usize>) -> Result<i64> {
+        check_range(&rows, self.rows(), "row range")?;
+        check_range(&cols, self.cols(), "column range")?;
+        let p = |row: usize, col: usize| self.prefix.data[row * self.prefix.cols() + col];
+        Ok(
+            p(rows.end, cols.end) - p(rows.start, cols.end) - p(rows.end, cols.start)
+                + p(rows.start, cols.start),
+        )
+    }
+}
+
+/// # Difference Array
+///
+/// Records many "add `delta` to every element of `l..r`" updates in O(1)
+/// each, and produces the resulting values in one final O(n) pass.
+///
+/// ## Example: Corporate Flight Bookings
+/// Flights are numbered `1..=n`; booking `(first, last, seats)` reserves
+/// `seats` on every flight from `first` to `last` inclusive. How many seats
+/// are reserved on each flight?
+/// ```
+/// use rust_ds_learning::vector::DifferenceArray;
+///
+/// let bookings = [(1, 2, 10), (2, 3, 20), (2, 5, 25)];
+/// let n = 5;
+///
+/// let mut seats = DifferenceArray::new(n);
+/// for (first, last, count) in bookings {
+///     seats.add_range(first - 1..last, count).unwrap();
+/// }
+/// assert_eq!(seats.materialize(), vec![10, 55, 45, 25, 25]);
+/// ```
+///
+/// ## Approach
+/// Store `diff[i] = value[i] - value[i - 1]` instead of the values. Adding
+/// `delta` to `l..r` then only changes two differences: `diff[l] += delta`
+/// where the raised stretch begins and `diff[r] -= delta` where it ends.
+/// The values are the prefix sums of the differences, which is exactly
+/// what [`PrefixSum`] computes: the two structures are inverses.
+///
+/// ## Complexity
+/// - Each update: O(1)
+/// - Materializing: O(n)
+/// - Space: O(n)
+///
+/// ## Key Points
+/// 1. `diff` has `n + 1` entries so a range ending at `n` needs no special
+///    case; the last entry never affects a value
+/// 2. Only worth it when updates are batched before reads; interleaved
+///    updates and queries call for a Fenwick or segment tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifferenceArray {
+    diff: Vec<i64>,
+}
+
+impl DifferenceArray {
+    /// `len` zeros
+    pub fn new(len: usize) -> Self {
+        DifferenceArray {
+            diff: vec![0; len + 1],
+        }
+    }
+
+    /// Starts from the given values instead of zeros
+    pub fn from_values(values: &[i64]) -> Self {
+        let mut diff = Vec::with_capacity(values.len() + 1);
+        let mut previous = 0;
+        for &value in values {
+            diff.push(value - previous);
+            previous = value;
+        }
+        diff.push(0);
+        DifferenceArray { diff }
+    }
+
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to every element in the half-open range `range`
+    ///
+    /// # Errors
+    /// `InvalidInput` if `range.start > range.end` or `range.end > len()`.
+    pub fn add_range(&mut self, range: Range<usize>, delta: i64) -> Result<()> {
+        check_range(&range, self.len(), "range")?;
+        self.diff[range.start] += delta;
+        self.diff[range.end] -= delta;
+        Ok(())
+    }
+
+    /// The current values, as the running sum of the differences
+    pub fn materialize(&self) -> Vec<i64> {
+        self.diff[..self.len()]
+            .iter()
+            .scan(0, |running, &delta| {
+                *running += delta;
+                Some(*running)
+            })
+            .collect()
+    }
+}
+
+fn check_range(range: &Range<usize>, len: usize, what: &str) -> Result<()> {
+    if range.start > range.end || range.end > len {
+        return Err(ExerciseError::InvalidInput(format!(
+            "{what} {range:?} is not valid for length {len}"
+        )));
+    }
+    Ok(())
+}
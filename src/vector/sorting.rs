@@ -0,0 +1,396 @@
+//! Sorting Laboratory
+//!
+//! Classic comparison sorts written out by hand over any `T: Ord`, to be
+//! compared with each other and with the standard library:
+//!
+//! | Algorithm          | Best       | Average    | Worst      | Extra space | Stable |
+//! |--------------------|------------|------------|------------|-------------|--------|
+//! | [`insertion_sort`] | O(n)       | O(n²)      | O(n²)      | O(1)        | yes    |
+//! | [`merge_sort`]     | O(n log n) | O(n log n) | O(n log n) | O(n)        | yes    |
+//! | [`quicksort`]      | O(n)       | O(n log n) | O(n²)      | O(log n)    | no     |
+//! | [`heapsort`]       | O(n log n) | O(n log n) | O(n log n) | O(1)        | no     |
+//!
+//! A sort is *stable* if elements that compare equal keep their relative
+//! order. That matters when sorting records by one key after another, e.g.
+//! by name and then stably by department.
+//!
+//! The standard library's `sort` is a stable merge-based sort and
+//! `sort_unstable` a pattern-defeating quicksort that falls back to
+//! heapsort, combining the strengths of the algorithms here.
+//!
+//! ## Comparison vs Non-Comparison Sorts
+//!
+//! The sorts above only ask "is `a < b`?", and any algorithm limited to
+//! such questions needs Ω(n log n) comparisons in the worst case: there are
+//! n! possible orders and each answer at best halves them.
+//!
+//! [`counting_sort`] and [`radix_sort`] sidestep that bound by looking *at*
+//! the values, using them (or their digits) as array indices. The price is
+//! generality: they only work for keys that map to small integers, and
+//! their cost depends on the key range or width rather than only on n.
+//!
+//! | Algorithm         | Time           | Extra space | Stable |
+//! |-------------------|----------------|-------------|--------|
+//! | [`counting_sort`] | O(n + max)     | O(max)      | yes*   |
+//! | [`radix_sort`]    | O(8 · (n + 256)) for `u64` | O(n) | yes |
+//!
+//! \* Stability only matters when records are sorted by a key; for plain
+//! integers the counting sort here just rewrites the values.
+//!
+//! Linear time is not automatically faster. `cargo bench --bench vector --
+//! integer_sorts` sorts one million uniform keys: counting sort over
+//! `0..=1000` beats `sort_unstable` several times over, while radix sort on
+//! full-width `u64`s loses to it, because each of its 8 passes scatters
+//! writes across 256 places in memory and is bound by cache misses.
+
+use std::cmp::Ordering;
+
+use crate::{ExerciseError, Result};
+
+/// # Insertion Sort
+///
+/// Grows a sorted prefix one element at a time: each new element is
+/// swapped leftwards past every larger element before it.
+///
+/// ```
+/// use rust_ds_learning::vector::sorting::insertion_sort;
+/// let mut v = [5, 2, 4, 6, 1, 3];
+/// insertion_sort(&mut v);
+/// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// ## Complexity
+/// - Time: O(n + inversions), so O(n) on sorted input and O(n²) on
+///   reversed input
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Stable: an element stops at the first element that is not larger,
+///    so it never passes an equal one
+/// 2. The fastest choice for tiny or nearly sorted slices, which is why
+///    hybrid sorts use it below a size cutoff
+pub fn insertion_sort<T: Ord>(v: &mut [T]) {
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && v[j - 1] > v[j] {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// # Merge Sort
+///
+/// Sorts both halves recursively, then merges them with two pointers into
+/// a buffer and copies the result back.
+///
+/// ```
+/// use rust_ds_learning::vector::sorting::merge_sort;
+/// let mut v = vec!["pear", "apple", "fig"];
+/// merge_sort(&mut v);
+/// assert_eq!(v, ["apple", "fig", "pear"]);
+/// ```
+///
+/// ## Complexity
+/// - Time: O(n log n) in every case: log n levels of O(n) merging
+/// - Space: O(n) for the merge buffer, plus O(log n) recursion
+///
+/// ## Key Points
+/// 1. Stable: on ties the merge takes from the left half first
+/// 2. Needs `T: Clone` to fill the buffer; an implementation with
+///    `unsafe` moves could avoid that
+///
+/// ## Common Pitfalls
+/// 1. Taking from the right half on ties (`<` instead of `<=`), which
+///    silently breaks stability
+pub fn merge_sort<T: Ord + Clone>(v: &mut [T]) {
+    if v.len() <= 1 {
+        return;
+    }
+    let mid = v.len() / 2;
+    merge_sort(&mut v[..mid]);
+    merge_sort(&mut v[mid..]);
+
+    let (left, right) = v.split_at(mid);
+    let mut merged = Vec::with_capacity(v.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] <= right[j] {
+            merged.push(left[i].clone());
+            i += 1;
+        } else {
+            merged.push(right[j].clone());
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    v.clone_from_slice(&merged);
+}
+
+/// # Quicksort
+///
+/// Partitions the slice around a pivot into `< pivot`, `== pivot` and
+/// `> pivot` parts, then sorts the outer two.
+///
+/// ```
+/// use rust_ds_learning::vector::sorting::quicksort;
+/// let mut v = [3, 1, 3, 9, 0, 3];
+/// quicksort(&mut v);
+/// assert_eq!(v, [0, 1, 3, 3, 3, 9]);
+/// ```
+///
+/// ## Approach
+/// - The pivot is the median of the first, middle and last elements, so
+///   sorted and reversed input split evenly
+/// - Three-way (Dutch national flag) partitioning groups every element
+///   equal to the pivot in the middle, where it is already in place; with
+///   many duplicates this is what keeps quicksort from going quadratic
+/// - Recursing into the smaller part and looping on the larger one bounds
+///   the stack depth by O(log n)
+///
+/// ## Complexity
+/// - Time: O(n log n) average, O(n²) worst case for adversarial input;
+///   O(n) when all elements are equal
+/// - Space: O(log n) stack
+///
+/// ## Key Points
+/// 1. Not stable: partitioning swaps elements across long distances
+///
+/// ## Common Pitfalls
+/// 1. Always picking the first element as pivot: O(n²) on sorted input
+/// 2. Two-way partitioning on all-equal input: O(n²)
+/// 3. Recursing on both sides: O(n) stack depth in the worst case
+pub fn quicksort<T: Ord>(v: &mut [T]) {
+    let mut v = v;
+    while v.len() > 1 {
+        let (lt, gt) = partition_three_way(v);
+        let (left, rest) = v.split_at_mut(lt);
+        let right = &mut rest[gt - lt..];
+        if left.len() < right.len() {
+            quicksort(left);
+            v = right;
+        } else {
+            quicksort(right);
+            v = left;
+        }
+    }
+}
+
+/// Returns `(lt, gt)` with `v[..lt] < pivot`, `v[lt..gt] == pivot` and
+/// `v[gt..] > pivot`
+fn partition_three_way<T: Ord>(v: &mut [T]) -> (usize, usize) {
+    let pivot = median_of_three(v, 0, v.len() / 2, v.len() - 1);
+    v.swap(0, pivot);
+
+    // Invariant: v[..lt] < pivot, v[lt..i] == pivot, v[gt..] > pivot,
+    // with v[lt] itself a copy of the pivot value
+    let (mut lt, mut i, mut gt) = (0, 1, v.len());
+    while i < gt {
+        match v[i].cmp(&v[lt]) {
+            Ordering::Less => {
+                v.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                v.swap(i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+pub(crate) fn median_of_three<T: Ord>(v: &[T], a: usize, b: usize, c: usize) -> usize {
+    let between = |lo: usize, mid: usize, hi: usize| {
+        (v[lo] <= v[mid] && v[mid] <= v[hi]) || (v[hi] <= v[mid] && v[mid] <= v[lo])
+    };
+    if between(a, b, c) {
+        b
+    } else if between(b, a, c) {
+        a
+    } else {
+        c
+    }
+}
+
+/// # Heapsort
+///
+/// Turns the slice into a max-heap in place, then repeatedly swaps the
+/// maximum (the root) to the end and restores the heap on the rest.
+///
+/// ```
+/// use rust_ds_learning::vector::sorting::heapsort;
+/// let mut v = [4, 10, 3, 5, 1];
+/// heapsort(&mut v);
+/// assert_eq!(v, [1, 3, 4, 5, 10]);
+/// ```
+///
+/// ## Complexity
+/// - Time: O(n log n) in every case; building the heap bottom-up is O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. The only algorithm here that is both in place and O(n log n) in the
+///    worst case, which is why introsort falls back to it
+/// 2. Not stable, and its jumps between parent and child indices make it
+///    cache-unfriendly, so it is usually slower than quicksort
+///
+/// ## Common Pitfalls
+/// 1. Children of `i` are `2i + 1` and `2i + 2` with 0-based indices
+/// 2. Sifting down past the shrinking heap boundary into the sorted tail
+pub fn heapsort<T: Ord>(v: &mut [T]) {
+    for root in (0..v.len() / 2).rev() {
+        sift_down(v, root, v.len());
+    }
+    for end in (1..v.len()).rev() {
+        v.swap(0, end);
+        sift_down(v, 0, end);
+    }
+}
+
+/// Restores the max-heap property for `v[..len]` below `root`
+fn sift_down<T: Ord>(v: &mut [T], mut root: usize, len: usize) {
+    loop {
+        let left = 2 * root + 1;
+        if left >= len {
+            return;
+        }
+        let right = left + 1;
+        let child = if right < len && v[right] > v[left] {
+            right
+        } else {
+            left
+        };
+        if v[root] >= v[child] {
+            return;
+        }
+        v.swap(root, child);
+        root = child;
+    }
+}
+
+/// # Counting Sort
+///
+/// Counts how often each value in `0..=max` occurs, then writes the values
+/// back in order, each repeated by its count.
+///
+/// ```
+/// use rust_ds_learning::vector::sorting::counting_sort;
+/// let mut v = [3, 0, 2, 3, 1, 0];
+/// counting_sort(&mut v, 3).unwrap();
+/// assert_eq!(v, [0, 0, 1, 2, 3, 3]);
+/// assert!(counting_sort(&mut [4], 3).is_err());
+/// ```
+///
+/// ## Complexity
+/// - Time: O(n + max)
+/// - Space: O(max) for the counts
+///
+/// ## Key Points
+/// 1. Beats O(n log n) sorts when `max` is small compared to n, e.g.
+///    bytes, ages, grades
+/// 2. With a huge `max` and few elements the count array dominates
+///
+/// ## Errors
+/// `InvalidInput` if an element is greater than `max`; the slice is left
+/// unchanged.
+pub fn counting_sort(v: &mut [u32], max: u32) -> Result<()> {
+    if let Some(&too_large) = v.iter().find(|&&x| x > max) {
+        return Err(ExerciseError::InvalidInput(format!(
+            "value {too_large} exceeds the declared maximum {max}"
+        )));
+    }
+
+    let mut counts = vec![0usize; max as usize + 1];
+    for &x in v.iter() {
+        counts[x as usize] += 1;
+    }
+
+    let mut write = 0;
+    for (value, &count) in counts.iter().enumerate() {
+        v[write..write + count].fill(value as u32);
+        write += count;
+    }
+    Ok(())
+}
+
+/// # LSD Radix Sort
+///
+/// Sorts by one byte at a time, least significant first, using a stable
+/// counting sort on that byte for each of the 8 passes.
+///
+/// ```
+/// use rust_ds_learning::vector::sorting::radix_sort;
+/// let mut v = [170, 45, 75, 90, 802, 24, 2, 66, u64::MAX];
+/// radix_sort(&mut v);
+/// assert_eq!(v, [2, 24, 45, 66, 75, 90, 170, 802, u64::MAX]);
+/// ```
+///
+/// ## Approach
+/// After the pass for byte `d`, the elements are sorted by their lowest
+/// `d + 1` bytes. That relies on every pass being *stable*: elements with
+/// the same byte `d` keep the order established by the lower bytes. Each
+/// pass counts the 256 possible byte values, turns the counts into
+/// starting offsets (prefix sums), and scatters into a second buffer.
+///
+/// ## Complexity
+/// - Time: O(8 · (n + 256)), linear in n for fixed-width keys
+/// - Space: O(n) for the scatter buffer
+///
+/// ## Key Points
+/// 1. Passes where every element has the same byte are skipped, so small
+///    values only pay for the bytes they use
+/// 2. All 8 histograms are built in a single read of the input, and the
+///    passes alternate between the slice and one scratch buffer
+///
+/// ## Common Pitfalls
+/// 1. Starting from the most significant byte without recursing into
+///    buckets (that is MSD radix sort, a different algorithm)
+/// 2. An unstable per-digit pass, which scrambles the lower bytes' order
+pub fn radix_sort(v: &mut [u64]) {
+    const RADIX: usize = 256;
+
+    // One pass over the input builds the histograms for all 8 bytes
+    let mut counts = [[0usize; RADIX]; 8];
+    for &x in v.iter() {
+        for (byte, histogram) in counts.iter_mut().enumerate() {
+            histogram[((x >> (byte * 8)) & 0xFF) as usize] += 1;
+        }
+    }
+
+    // Ping-pong between the input and one scratch buffer instead of copying
+    // back after every pass
+    let mut scratch = vec![0u64; v.len()];
+    let mut in_scratch = false;
+    for (byte, histogram) in counts.iter().enumerate() {
+        if histogram.contains(&v.len()) {
+            continue;
+        }
+
+        let mut offsets = [0usize; RADIX];
+        let mut start = 0;
+        for (offset, &count) in offsets.iter_mut().zip(histogram) {
+            *offset = start;
+            start += count;
+        }
+
+        let (src, dst) = if in_scratch {
+            (&scratch[..], &mut v[..])
+        } else {
+            (&v[..], &mut scratch[..])
+        };
+        for &x in src {
+            let slot = &mut offsets[((x >> (byte * 8)) & 0xFF) as usize];
+            dst[*slot] = x;
+            *slot += 1;
+        }
+        in_scratch = !in_scratch;
+    }
+
+    if in_scratch {
+        v.copy_from_slice(&scratch);
+    }
+}
@@ -2,7 +2,17 @@
 #[cfg(test)]
 mod tests {
     use crate::vector::{
-        max_product, max_product_functional, merge_intervals, sliding_window_maximum,
+        can_attend_all, can_jump, equal_range, insert_interval, interval_intersection, kth_largest,
+        kth_smallest, lower_bound, max_product, max_product_checked, max_product_functional,
+        max_profit_cooldown, max_profit_multi, max_profit_single, max_subarray_sum,
+        max_subarray_sum_naive, merge_intervals, merge_k_sorted, merge_k_sorted_pairwise,
+        min_jumps, min_meeting_rooms, min_meeting_rooms_sweep, move_zeros, next_permutation,
+        partition_in_place, partition_point, remove_duplicates_sorted, remove_element,
+        rotate_right, rotate_right_juggling, run_length_decode, run_length_encode, sample_k,
+        shuffle, sliding_window_max, sliding_window_maximum, sliding_window_maximum_naive,
+        sliding_window_min, sliding_window_minimum, stable_partition, subarray_sum_count,
+        three_sum, trap_rain_water, trap_rain_water_stack, two_sum, upper_bound, wiggle_sort,
+        wiggle_sort_strict, DifferenceArray, MonotonicQueue, PrefixSum, PrefixSum2D,
     };
 
     mod sliding_window_tests {
@@ -200,4 +210,1998 @@ mod tests {
             }
         }
     }
+
+    mod max_subarray_sum_tests {
+        use super::*;
+
+        #[test]
+        fn test_empty() {
+            assert_eq!(max_subarray_sum(&[]), None);
+        }
+
+        #[test]
+        fn test_typical_case() {
+            let nums = [-2, 1, -3, 4, -1, 2, 1, -5, 4];
+            assert_eq!(max_subarray_sum(&nums), Some((6, 3..7)));
+        }
+
+        #[test]
+        fn test_all_negative_picks_largest_element() {
+            assert_eq!(max_subarray_sum(&[-3, -1, -2]), Some((-1, 1..2)));
+            assert_eq!(max_subarray_sum(&[-5]), Some((-5, 0..1)));
+        }
+
+        #[test]
+        fn test_all_positive_takes_everything() {
+            assert_eq!(max_subarray_sum(&[1, 2, 3]), Some((6, 0..3)));
+        }
+
+        #[test]
+        fn test_zeros_and_ties() {
+            assert_eq!(max_subarray_sum(&[0, 0, 0]), Some((0, 0..1)));
+            // [3] and [3] with a -3 between: the earliest range wins
+            assert_eq!(max_subarray_sum(&[3, -3, 3]), Some((3, 0..1)));
+        }
+
+        #[test]
+        fn test_no_overflow() {
+            let nums = [i32::MAX, i32::MAX, i32::MAX];
+            assert_eq!(
+                max_subarray_sum(&nums),
+                Some((3 * i64::from(i32::MAX), 0..3))
+            );
+            assert_eq!(
+                max_subarray_sum(&[i32::MIN]),
+                Some((i64::from(i32::MIN), 0..1))
+            );
+        }
+
+        #[test]
+        fn test_range_sums_to_reported_sum() {
+            let nums = [5, -9, 6, -2, 3, -8, 4, 4, -1];
+            let (sum, range) = max_subarray_sum(&nums).unwrap();
+            assert_eq!(nums[range].iter().map(|&n| i64::from(n)).sum::<i64>(), sum);
+            assert_eq!(sum, 8);
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let nums = [2, -1, 2, 3, -9, 4, -2, 5, -1, -6, 1];
+            let brute = (0..nums.len())
+                .flat_map(|i| (i + 1..=nums.len()).map(move |j| i..j))
+                .map(|r| nums[r].iter().map(|&n| i64::from(n)).sum::<i64>())
+                .max();
+            assert_eq!(max_subarray_sum(&nums).map(|(sum, _)| sum), brute);
+        }
+    }
+
+    mod two_sum_tests {
+        use super::*;
+
+        #[test]
+        fn test_typical_case() {
+            assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+            assert_eq!(two_sum(&[3, 2, 4], 6), Some((1, 2)));
+        }
+
+        #[test]
+        fn test_no_solution() {
+            assert_eq!(two_sum(&[], 0), None);
+            assert_eq!(two_sum(&[5], 10), None);
+            assert_eq!(two_sum(&[1, 2, 3], 100), None);
+        }
+
+        #[test]
+        fn test_does_not_reuse_an_element() {
+            assert_eq!(two_sum(&[3, 4], 6), None);
+            assert_eq!(two_sum(&[3, 3], 6), Some((0, 1)));
+        }
+
+        #[test]
+        fn test_duplicates_return_earliest_pair() {
+            assert_eq!(two_sum(&[1, 1, 1, 1], 2), Some((0, 1)));
+            assert_eq!(two_sum(&[5, 1, 5, 9], 10), Some((0, 2)));
+        }
+
+        #[test]
+        fn test_negatives_and_zero() {
+            assert_eq!(two_sum(&[-3, 4, 3, 90], 0), Some((0, 2)));
+            assert_eq!(two_sum(&[0, -1, 0], 0), Some((0, 2)));
+            assert_eq!(two_sum(&[-5, -7, 2], -12), Some((0, 1)));
+        }
+
+        #[test]
+        fn test_extreme_values_do_not_overflow() {
+            assert_eq!(two_sum(&[i32::MAX, 1, -i32::MAX], 0), Some((0, 2)));
+            assert_eq!(two_sum(&[i32::MIN, 5], i32::MAX), None);
+            assert_eq!(two_sum(&[i32::MAX, 7, i32::MIN], -1), Some((0, 2)));
+        }
+    }
+
+    mod three_sum_tests {
+        use super::*;
+
+        #[test]
+        fn test_typical_case() {
+            assert_eq!(
+                three_sum(&[-1, 0, 1, 2, -1, -4]),
+                vec![[-1, -1, 2], [-1, 0, 1]]
+            );
+        }
+
+        #[test]
+        fn test_too_short_or_no_solution() {
+            assert!(three_sum(&[]).is_empty());
+            assert!(three_sum(&[0, 0]).is_empty());
+            assert!(three_sum(&[1, 2, 3]).is_empty());
+            assert!(three_sum(&[-1, -2, -3]).is_empty());
+        }
+
+        #[test]
+        fn test_duplicates_are_reported_once() {
+            assert_eq!(three_sum(&[0, 0, 0, 0, 0]), vec![[0, 0, 0]]);
+            assert_eq!(
+                three_sum(&[-2, 0, 0, 2, 2, -2, 1, 1]),
+                vec![[-2, 0, 2], [-2, 1, 1]]
+            );
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let nums = [-4, -1, -1, 0, 1, 2, 2, 3, -3, 5, -2];
+            let mut expected = Vec::new();
+            for i in 0..nums.len() {
+                for j in i + 1..nums.len() {
+                    for k in j + 1..nums.len() {
+                        if nums[i] + nums[j] + nums[k] == 0 {
+                            let mut triple = [nums[i], nums[j], nums[k]];
+                            triple.sort_unstable();
+                            expected.push(triple);
+                        }
+                    }
+                }
+            }
+            expected.sort_unstable();
+            expected.dedup();
+            assert_eq!(three_sum(&nums), expected);
+        }
+
+        #[test]
+        fn test_extreme_values_do_not_overflow() {
+            assert!(three_sum(&[i32::MIN, i32::MIN, i32::MAX]).is_empty());
+            assert_eq!(
+                three_sum(&[i32::MIN, i32::MAX, 1, 0]),
+                vec![[i32::MIN, 1, i32::MAX]]
+            );
+        }
+    }
+
+    mod rotate_right_tests {
+        use super::*;
+
+        const ROTATIONS: [fn(&mut [i32], usize); 2] = [rotate_right, rotate_right_juggling];
+
+        #[test]
+        fn test_typical_case() {
+            for rotate in ROTATIONS {
+                let mut nums = vec![1, 2, 3, 4, 5, 6, 7];
+                rotate(&mut nums, 3);
+                assert_eq!(nums, vec![5, 6, 7, 1, 2, 3, 4]);
+            }
+        }
+
+        #[test]
+        fn test_empty_and_single() {
+            for rotate in ROTATIONS {
+                let mut empty: Vec<i32> = vec![];
+                rotate(&mut empty, 5);
+                assert!(empty.is_empty());
+
+                let mut single = vec![42];
+                rotate(&mut single, 3);
+                assert_eq!(single, vec![42]);
+            }
+        }
+
+        #[test]
+        fn test_k_larger_than_len() {
+            for rotate in ROTATIONS {
+                let mut nums = vec![1, 2, 3];
+                rotate(&mut nums, 4);
+                assert_eq!(nums, vec![3, 1, 2]);
+            }
+        }
+
+        #[test]
+        fn test_rotating_by_len_is_identity() {
+            for rotate in ROTATIONS {
+                for n in 0..20 {
+                    let original: Vec<i32> = (0..n as i32).collect();
+                    for multiple in 0..3 {
+                        let mut nums = original.clone();
+                        rotate(&mut nums, multiple * n);
+                        assert_eq!(nums, original, "n = {n}, k = {}", multiple * n);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_matches_std_rotate_right() {
+            // Covers gcd(n, k) > 1, where juggling needs several cycles
+            for rotate in ROTATIONS {
+                for n in 1..16 {
+                    for k in 0..2 * n {
+                        let mut expected: Vec<i32> = (0..n as i32).collect();
+                        expected.rotate_right(k % n);
+                        let mut nums: Vec<i32> = (0..n as i32).collect();
+                        rotate(&mut nums, k);
+                        assert_eq!(nums, expected, "n = {n}, k = {k}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_rotations_compose() {
+            let mut nums: Vec<i32> = (0..10).collect();
+            rotate_right(&mut nums, 3);
+            rotate_right_juggling(&mut nums, 7);
+            assert_eq!(nums, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    mod kth_smallest_tests {
+        use super::*;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_typical_case() {
+            let mut nums = vec![7, 10, 4, 3, 20, 15];
+            assert_eq!(kth_smallest(&mut nums, 3).unwrap(), 7);
+            assert_eq!(kth_smallest(&mut nums, 1).unwrap(), 3);
+            assert_eq!(kth_smallest(&mut nums, 6).unwrap(), 20);
+            assert_eq!(kth_largest(&mut nums, 1).unwrap(), 20);
+            assert_eq!(kth_largest(&mut nums, 2).unwrap(), 15);
+        }
+
+        #[test]
+        fn test_invalid_k() {
+            assert!(matches!(
+                kth_smallest(&mut [], 1),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                kth_smallest(&mut [1, 2], 0),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                kth_smallest(&mut [1, 2], 3),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                kth_largest(&mut [1, 2], 0),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                kth_largest(&mut [1, 2], 3),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+        }
+
+        #[test]
+        fn test_duplicates_and_negatives() {
+            let mut nums = vec![3, -1, 3, 3, -1, 0, 3];
+            let expected = [-1, -1, 0, 3, 3, 3, 3];
+            for (k, &value) in expected.iter().enumerate() {
+                assert_eq!(kth_smallest(&mut nums, k + 1).unwrap(), value);
+            }
+
+            let mut all_equal = vec![5; 100];
+            assert_eq!(kth_smallest(&mut all_equal, 50).unwrap(), 5);
+        }
+
+        #[test]
+        fn test_matches_sorting() {
+            // A fixed pseudo-random sequence plus sorted and reversed inputs
+            let inputs = [
+                (0..200).map(|i| i * 7919 % 211 - 100).collect::<Vec<_>>(),
+                (0..50).collect(),
+                (0..50).rev().collect(),
+                vec![i32::MIN, i32::MAX, 0, i32::MIN, i32::MAX],
+            ];
+            for input in inputs {
+                let mut sorted = input.clone();
+                sorted.sort_unstable();
+                for k in 1..=input.len() {
+                    let mut nums = input.clone();
+                    assert_eq!(
+                        kth_smallest(&mut nums, k).unwrap(),
+                        sorted[k - 1],
+                        "k = {k}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_leaves_a_permutation() {
+            let mut nums = vec![9, 1, 8, 2, 7, 3, 6, 4, 5];
+            kth_smallest(&mut nums, 4).unwrap();
+            nums.sort_unstable();
+            assert_eq!(nums, (1..=9).collect::<Vec<_>>());
+        }
+    }
+
+    mod binary_search_tests {
+        use super::*;
+
+        #[test]
+        fn test_empty_slice() {
+            let empty: [i32; 0] = [];
+            assert_eq!(lower_bound(&empty, &1), 0);
+            assert_eq!(upper_bound(&empty, &1), 0);
+            assert_eq!(equal_range(&empty, &1), 0..0);
+            assert_eq!(partition_point(&empty, |_| true), 0);
+        }
+
+        #[test]
+        fn test_duplicates() {
+            let nums = [1, 2, 2, 2, 3, 3, 7];
+            assert_eq!(lower_bound(&nums, &2), 1);
+            assert_eq!(upper_bound(&nums, &2), 4);
+            assert_eq!(equal_range(&nums, &2), 1..4);
+            assert_eq!(equal_range(&nums, &3), 4..6);
+            assert_eq!(equal_range(&nums, &7), 6..7);
+
+            // std's binary_search may land on any of the equal elements
+            let found = nums.binary_search(&2).unwrap();
+            assert!(equal_range(&nums, &2).contains(&found));
+        }
+
+        #[test]
+        fn test_missing_targets_give_insertion_points() {
+            let nums = [10, 20, 30];
+            assert_eq!(equal_range(&nums, &5), 0..0);
+            assert_eq!(equal_range(&nums, &25), 2..2);
+            assert_eq!(equal_range(&nums, &35), 3..3);
+            assert_eq!(lower_bound(&nums, &25), upper_bound(&nums, &25));
+        }
+
+        #[test]
+        fn test_all_equal() {
+            let nums = [4; 10];
+            assert_eq!(equal_range(&nums, &4), 0..10);
+            assert_eq!(lower_bound(&nums, &5), 10);
+            assert_eq!(upper_bound(&nums, &3), 0);
+        }
+
+        #[test]
+        fn test_generic_over_ord() {
+            let words = ["apple", "banana", "banana", "cherry"];
+            assert_eq!(equal_range(&words, &"banana"), 1..3);
+
+            let pairs = [(1, 'a'), (1, 'b'), (2, 'a')];
+            assert_eq!(lower_bound(&pairs, &(1, 'b')), 1);
+        }
+
+        #[test]
+        fn test_partition_point_with_predicate() {
+            let nums = [2, 4, 6, 7, 9];
+            assert_eq!(partition_point(&nums, |&x| x % 2 == 0), 3);
+            assert_eq!(partition_point(&nums, |_| false), 0);
+            assert_eq!(partition_point(&nums, |_| true), 5);
+
+            // First square >= 50, searching an implicit sorted sequence
+            let squares: Vec<u64> = (0..100).map(|i| i * i).collect();
+            assert_eq!(partition_point(&squares, |&sq| sq < 50), 8);
+        }
+
+        #[test]
+        fn test_matches_linear_scan_and_std() {
+            let nums: Vec<i32> = (0..60).map(|i| i / 4 * 3).collect();
+            for target in -2..50 {
+                let first = nums.iter().position(|&x| x >= target).unwrap_or(nums.len());
+                let after = nums.iter().position(|&x| x > target).unwrap_or(nums.len());
+                assert_eq!(lower_bound(&nums, &target), first, "target = {target}");
+                assert_eq!(upper_bound(&nums, &target), after, "target = {target}");
+                assert_eq!(equal_range(&nums, &target), first..after);
+                assert_eq!(
+                    partition_point(&nums, |&x| x < target),
+                    nums.partition_point(|&x| x < target)
+                );
+            }
+        }
+    }
+
+    mod trap_rain_water_tests {
+        use super::*;
+
+        const SOLUTIONS: [fn(&[u32]) -> u64; 2] = [trap_rain_water, trap_rain_water_stack];
+
+        #[test]
+        fn test_typical_cases() {
+            for trap in SOLUTIONS {
+                assert_eq!(trap(&[0, 1, 0, 2, 1, 0, 1, 3, 2, 1, 2, 1]), 6);
+                assert_eq!(trap(&[4, 2, 0, 3, 2, 5]), 9);
+            }
+        }
+
+        #[test]
+        fn test_nothing_trapped() {
+            for trap in SOLUTIONS {
+                assert_eq!(trap(&[]), 0);
+                assert_eq!(trap(&[5]), 0);
+                assert_eq!(trap(&[5, 1]), 0);
+                assert_eq!(trap(&[1, 2, 3, 4]), 0);
+                assert_eq!(trap(&[4, 3, 2, 1]), 0);
+                assert_eq!(trap(&[1, 3, 1]), 0);
+                assert_eq!(trap(&[2, 2, 2]), 0);
+            }
+        }
+
+        #[test]
+        fn test_basins() {
+            for trap in SOLUTIONS {
+                assert_eq!(trap(&[3, 0, 3]), 3);
+                // Lower wall on the right limits the level
+                assert_eq!(trap(&[5, 0, 0, 2]), 4);
+                // Nested basins fill to the outer walls
+                assert_eq!(trap(&[5, 1, 3, 1, 5]), 10);
+                assert_eq!(trap(&[2, 0, 2, 0, 2]), 4);
+            }
+        }
+
+        #[test]
+        fn test_large_heights_do_not_overflow() {
+            for trap in SOLUTIONS {
+                let heights = [u32::MAX, 0, 0, 0, u32::MAX];
+                assert_eq!(trap(&heights), 3 * u64::from(u32::MAX));
+            }
+        }
+
+        #[test]
+        fn test_matches_prefix_max_formula() {
+            let heights: Vec<u32> = (0..200u32).map(|i| i * 37 % 23 + (i % 7) * 3).collect();
+            let expected: u64 = (0..heights.len())
+                .map(|i| {
+                    let left = heights[..=i].iter().max().unwrap();
+                    let right = heights[i..].iter().max().unwrap();
+                    u64::from(left.min(right) - heights[i])
+                })
+                .sum();
+            for trap in SOLUTIONS {
+                assert_eq!(trap(&heights), expected);
+            }
+        }
+    }
+
+    mod next_permutation_tests {
+        use super::*;
+
+        #[test]
+        fn test_typical_steps() {
+            let mut nums = vec![1, 2, 3];
+            let mut seen = vec![nums.clone()];
+            while next_permutation(&mut nums) {
+                seen.push(nums.clone());
+            }
+            assert_eq!(
+                seen,
+                vec![
+                    vec![1, 2, 3],
+                    vec![1, 3, 2],
+                    vec![2, 1, 3],
+                    vec![2, 3, 1],
+                    vec![3, 1, 2],
+                    vec![3, 2, 1],
+                ]
+            );
+            // Wrapped around to the smallest permutation
+            assert_eq!(nums, vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn test_pivot_in_the_middle() {
+            let mut nums = vec![1, 5, 8, 4, 7, 6, 5, 3, 1];
+            assert!(next_permutation(&mut nums));
+            assert_eq!(nums, vec![1, 5, 8, 5, 1, 3, 4, 6, 7]);
+        }
+
+        #[test]
+        fn test_empty_and_single() {
+            let mut empty: Vec<i32> = vec![];
+            assert!(!next_permutation(&mut empty));
+            let mut single = vec![7];
+            assert!(!next_permutation(&mut single));
+            assert_eq!(single, vec![7]);
+        }
+
+        #[test]
+        fn test_duplicates() {
+            let mut nums = vec![1, 1, 2];
+            assert!(next_permutation(&mut nums));
+            assert_eq!(nums, vec![1, 2, 1]);
+            assert!(next_permutation(&mut nums));
+            assert_eq!(nums, vec![2, 1, 1]);
+            assert!(!next_permutation(&mut nums));
+            assert_eq!(nums, vec![1, 1, 2]);
+
+            let mut same = vec![4, 4, 4];
+            assert!(!next_permutation(&mut same));
+            assert_eq!(same, vec![4, 4, 4]);
+        }
+
+        #[test]
+        fn test_visits_each_distinct_permutation_once() {
+            // 6! / (2! * 3!) = 60 distinct arrangements of [1, 1, 2, 2, 2, 3]
+            let mut nums = vec![1, 1, 2, 2, 2, 3];
+            let mut seen = std::collections::HashSet::new();
+            let mut previous = nums.clone();
+            seen.insert(nums.clone());
+            while next_permutation(&mut nums) {
+                assert!(nums > previous, "{nums:?} is not after {previous:?}");
+                assert!(seen.insert(nums.clone()), "repeated {nums:?}");
+                previous = nums.clone();
+            }
+            assert_eq!(seen.len(), 60);
+        }
+    }
+
+    mod subarray_sum_count_tests {
+        use super::*;
+
+        fn brute_force(nums: &[i32], k: i32) -> usize {
+            (0..nums.len())
+                .flat_map(|i| (i + 1..=nums.len()).map(move |j| i..j))
+                .filter(|r| {
+                    nums[r.clone()].iter().map(|&n| i64::from(n)).sum::<i64>() == i64::from(k)
+                })
+                .count()
+        }
+
+        #[test]
+        fn test_typical_cases() {
+            assert_eq!(subarray_sum_count(&[1, 1, 1], 2), 2);
+            assert_eq!(subarray_sum_count(&[1, 2, 3], 3), 2);
+            assert_eq!(subarray_sum_count(&[], 0), 0);
+            assert_eq!(subarray_sum_count(&[5], 5), 1);
+            assert_eq!(subarray_sum_count(&[5], 4), 0);
+        }
+
+        #[test]
+        fn test_negatives_break_sliding_window() {
+            // [1], [1, -1, 1] and the last [1]: a shrink-when-too-big window misses one
+            assert_eq!(subarray_sum_count(&[1, -1, 1], 1), 3);
+            assert_eq!(subarray_sum_count(&[-1, -1, 1], 0), 1);
+            assert_eq!(subarray_sum_count(&[3, 4, -7, 3, 1, 3, 1, -4], 7), 3);
+            assert_eq!(subarray_sum_count(&[-2, -3, -5], -5), 2);
+        }
+
+        #[test]
+        fn test_zero_target_and_zeros() {
+            // Every one of the 6 non-empty subarrays of [0, 0, 0] sums to 0
+            assert_eq!(subarray_sum_count(&[0, 0, 0], 0), 6);
+            assert_eq!(subarray_sum_count(&[1, -1, 1, -1], 0), 4);
+        }
+
+        #[test]
+        fn test_extreme_values_do_not_overflow() {
+            let nums = [i32::MAX, i32::MAX, i32::MIN, i32::MIN];
+            // Only the middle pair sums to -1; all four sum to -2
+            assert_eq!(subarray_sum_count(&nums, -1), 1);
+            assert_eq!(subarray_sum_count(&nums, -2), 1);
+            assert_eq!(subarray_sum_count(&nums, 0), 0);
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let nums: Vec<i32> = (0..80).map(|i| (i * 13 % 11) - 5).collect();
+            for k in -8..=8 {
+                assert_eq!(
+                    subarray_sum_count(&nums, k),
+                    brute_force(&nums, k),
+                    "k = {k}"
+                );
+            }
+        }
+    }
+
+    mod generic_sliding_window_tests {
+        use super::*;
+        use std::cmp::Reverse;
+
+        #[test]
+        fn test_matches_i32_version() {
+            let nums = [1, 3, -1, -3, 5, 3, 6, 7];
+            for k in 1..=nums.len() {
+                assert_eq!(
+                    sliding_window_max(&nums, k),
+                    sliding_window_maximum(&nums, k)
+                );
+            }
+        }
+
+        #[test]
+        fn test_min() {
+            assert_eq!(
+                sliding_window_min(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+                vec![-1, -3, -3, -3, 3, 3]
+            );
+            assert_eq!(sliding_window_min(&[2, 2, 2], 2), vec![2, 2]);
+            assert_eq!(sliding_window_min(&[5, 4, 3], 1), vec![5, 4, 3]);
+        }
+
+        #[test]
+        fn test_degenerate_windows() {
+            let nums = [3, 1, 2];
+            assert!(sliding_window_max(&nums, 0).is_empty());
+            assert!(sliding_window_min(&nums, 0).is_empty());
+            // Larger than the input: there is no complete window
+            assert!(sliding_window_max(&nums, 4).is_empty());
+            assert!(sliding_window_maximum(&nums, 4).is_empty());
+            assert_eq!(sliding_window_max(&nums, 3), vec![3]);
+            assert_eq!(sliding_window_min(&nums, 3), vec![1]);
+        }
+
+        #[test]
+        fn test_strings_and_custom_types() {
+            let names = vec![
+                "bob".to_string(),
+                "alice".into(),
+                "dave".into(),
+                "carol".into(),
+            ];
+            assert_eq!(sliding_window_max(&names, 2), vec!["bob", "dave", "dave"]);
+            assert_eq!(
+                sliding_window_min(&names, 2),
+                vec!["alice", "alice", "carol"]
+            );
+
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+            struct Version(u32, u32);
+            let versions = [Version(1, 2), Version(1, 10), Version(0, 9), Version(1, 3)];
+            assert_eq!(
+                sliding_window_max(&versions, 3),
+                vec![Version(1, 10), Version(1, 10)]
+            );
+
+            // Reverse turns a max into a min
+            let wrapped: Vec<Reverse<i32>> = [4, 2, 12, 3].into_iter().map(Reverse).collect();
+            let mins: Vec<i32> = sliding_window_max(&wrapped, 2)
+                .into_iter()
+                .map(|r| r.0)
+                .collect();
+            assert_eq!(mins, sliding_window_min(&[4, 2, 12, 3], 2));
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            let nums: Vec<i32> = (0..100).map(|i| (i * 37 % 101) - 50).collect();
+            for k in 1..=12 {
+                let max: Vec<i32> = nums.windows(k).map(|w| *w.iter().max().unwrap()).collect();
+                let min: Vec<i32> = nums.windows(k).map(|w| *w.iter().min().unwrap()).collect();
+                assert_eq!(sliding_window_max(&nums, k), max, "k = {k}");
+                assert_eq!(sliding_window_min(&nums, k), min, "k = {k}");
+            }
+        }
+    }
+
+    mod merge_k_sorted_tests {
+        use super::*;
+
+        type Merge = fn(&[Vec<i32>]) -> Vec<i32>;
+        const MERGES: [Merge; 2] = [merge_k_sorted, merge_k_sorted_pairwise];
+
+        #[test]
+        fn test_typical_case() {
+            let lists = vec![vec![1, 4, 5], vec![1, 3, 4], vec![2, 6]];
+            for merge in MERGES {
+                assert_eq!(merge(&lists), vec![1, 1, 2, 3, 4, 4, 5, 6]);
+            }
+        }
+
+        #[test]
+        fn test_empty_inputs() {
+            for merge in MERGES {
+                assert!(merge(&[]).is_empty());
+                assert!(merge(&[vec![], vec![]]).is_empty());
+                assert_eq!(merge(&[vec![], vec![3], vec![]]), vec![3]);
+                assert_eq!(merge(&[vec![1, 2, 3]]), vec![1, 2, 3]);
+            }
+        }
+
+        #[test]
+        fn test_negatives_duplicates_and_uneven_lengths() {
+            let lists = vec![
+                vec![-5, -5, 0, 9],
+                vec![-7],
+                vec![-5, 2, 2, 2, 2, 2, 10],
+                vec![i32::MIN, i32::MAX],
+            ];
+            let mut expected: Vec<i32> = lists.iter().flatten().copied().collect();
+            expected.sort_unstable();
+            for merge in MERGES {
+                assert_eq!(merge(&lists), expected);
+            }
+        }
+
+        #[test]
+        fn test_matches_sorting_for_many_lists() {
+            let lists: Vec<Vec<i32>> = (0..37)
+                .map(|k| {
+                    let mut list: Vec<i32> =
+                        (0..(k * 7 % 13)).map(|i| (i * k * 31 % 97) - 40).collect();
+                    list.sort_unstable();
+                    list
+                })
+                .collect();
+            let mut expected: Vec<i32> = lists.iter().flatten().copied().collect();
+            expected.sort_unstable();
+            for merge in MERGES {
+                assert_eq!(merge(&lists), expected);
+            }
+        }
+    }
+
+    mod compaction_tests {
+        use super::*;
+
+        #[test]
+        fn test_remove_duplicates_sorted() {
+            let mut nums = vec![1, 1, 2];
+            assert_eq!(remove_duplicates_sorted(&mut nums), 2);
+            assert_eq!(nums, vec![1, 2]);
+
+            let mut nums = vec![-3, -3, -1, 0, 0, 0, 5];
+            assert_eq!(remove_duplicates_sorted(&mut nums), 4);
+            assert_eq!(nums, vec![-3, -1, 0, 5]);
+        }
+
+        #[test]
+        fn test_remove_duplicates_edge_cases() {
+            let mut empty: Vec<i32> = vec![];
+            assert_eq!(remove_duplicates_sorted(&mut empty), 0);
+
+            let mut all_same = vec![7; 6];
+            assert_eq!(remove_duplicates_sorted(&mut all_same), 1);
+            assert_eq!(all_same, vec![7]);
+
+            let mut unique = vec![1, 2, 3];
+            assert_eq!(remove_duplicates_sorted(&mut unique), 3);
+            assert_eq!(unique, vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn test_remove_duplicates_matches_dedup() {
+            let mut nums: Vec<i32> = (0..200).map(|i| i * i % 37).collect();
+            nums.sort_unstable();
+            let mut expected = nums.clone();
+            expected.dedup();
+            assert_eq!(remove_duplicates_sorted(&mut nums), expected.len());
+            assert_eq!(nums, expected);
+        }
+
+        #[test]
+        fn test_remove_element() {
+            let mut nums = vec![3, 2, 2, 3];
+            assert_eq!(remove_element(&mut nums, 3), 2);
+            assert_eq!(nums, vec![2, 2]);
+
+            let mut nums = vec![1, 2, 3];
+            assert_eq!(remove_element(&mut nums, 9), 3);
+            assert_eq!(nums, vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn test_remove_element_all_matching() {
+            let mut nums = vec![4, 4, 4];
+            assert_eq!(remove_element(&mut nums, 4), 0);
+            assert!(nums.is_empty());
+
+            let mut empty: Vec<i32> = vec![];
+            assert_eq!(remove_element(&mut empty, 0), 0);
+        }
+
+        #[test]
+        fn test_remove_element_matches_retain() {
+            let nums: Vec<i32> = (0..100).map(|i| i % 5 - 2).collect();
+            for val in -3..=3 {
+                let mut actual = nums.clone();
+                let mut expected = nums.clone();
+                expected.retain(|&x| x != val);
+                assert_eq!(remove_element(&mut actual, val), expected.len());
+                assert_eq!(actual, expected);
+            }
+        }
+
+        #[test]
+        fn test_move_zeros() {
+            let mut nums = [0, 1, 0, 3, 12];
+            move_zeros(&mut nums);
+            assert_eq!(nums, [1, 3, 12, 0, 0]);
+
+            let mut nums = [-1, 0, 0, -2, 0, 3];
+            move_zeros(&mut nums);
+            assert_eq!(nums, [-1, -2, 3, 0, 0, 0]);
+        }
+
+        #[test]
+        fn test_move_zeros_edge_cases() {
+            let mut empty: [i32; 0] = [];
+            move_zeros(&mut empty);
+
+            let mut zeros = [0, 0, 0];
+            move_zeros(&mut zeros);
+            assert_eq!(zeros, [0, 0, 0]);
+
+            let mut none = [4, 5, 6];
+            move_zeros(&mut none);
+            assert_eq!(none, [4, 5, 6]);
+
+            let mut trailing = [1, 2, 0];
+            move_zeros(&mut trailing);
+            assert_eq!(trailing, [1, 2, 0]);
+        }
+    }
+
+    mod prefix_sum_tests {
+        use super::*;
+        use crate::matrix::Matrix;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_range_sums_match_naive() {
+            let nums = [5, -3, 8, 0, -1, 7, 2];
+            let sums = PrefixSum::new(&nums);
+            assert_eq!(sums.len(), nums.len());
+            assert_eq!(sums.total(), 18);
+            for l in 0..=nums.len() {
+                for r in l..=nums.len() {
+                    let expected: i64 = nums[l..r].iter().map(|&x| i64::from(x)).sum();
+                    assert_eq!(sums.range_sum(l..r).unwrap(), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn test_invalid_ranges() {
+            let sums = PrefixSum::new(&[1, 2, 3]);
+            #[allow(clippy::reversed_empty_ranges)]
+            let reversed = 2..1;
+            for range in [0..4, 3..4, reversed] {
+                assert!(matches!(
+                    sums.range_sum(range),
+                    Err(ExerciseError::InvalidInput(_))
+                ));
+            }
+        }
+
+        #[test]
+        fn test_empty_input() {
+            let sums = PrefixSum::new(&[]);
+            assert!(sums.is_empty());
+            assert_eq!(sums.range_sum(0..0).unwrap(), 0);
+            assert!(sums.range_sum(0..1).is_err());
+        }
+
+        #[test]
+        fn test_no_overflow() {
+            let sums = PrefixSum::new(&[i32::MAX, i32::MAX, i32::MIN]);
+            assert_eq!(sums.range_sum(0..2).unwrap(), 2 * i64::from(i32::MAX));
+            assert_eq!(sums.total(), i64::from(i32::MAX) - 1);
+        }
+
+        #[test]
+        fn test_region_sums_match_naive() {
+            let m: Matrix<i32> = "3 0 1 4 2; 5 6 3 2 1; 1 2 0 1 5; 4 1 0 1 7"
+                .parse()
+                .unwrap();
+            let sums = PrefixSum2D::new(&m);
+            assert_eq!((sums.rows(), sums.cols()), (4, 5));
+            for r0 in 0..=m.rows() {
+                for r1 in r0..=m.rows() {
+                    for c0 in 0..=m.cols() {
+                        for c1 in c0..=m.cols() {
+                            let expected: i64 = (r0..r1)
+                                .flat_map(|i| (c0..c1).map(move |j| (i, j)))
+                                .map(|(i, j)| i64::from(m.get(i, j).unwrap()))
+                                .sum();
+                            assert_eq!(sums.region_sum(r0..r1, c0..c1).unwrap(), expected);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_invalid_regions() {
+            let m: Matrix<i32> = "1 2; 3 4".parse().unwrap();
+            let sums = PrefixSum2D::new(&m);
+            assert!(matches!(
+                sums.region_sum(0..3, 0..2),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            assert!(sums.region_sum(0..2, 1..3).is_err());
+
+            let empty = PrefixSum2D::new(&Matrix::zeros(0, 0));
+            assert_eq!(empty.region_sum(0..0, 0..0).unwrap(), 0);
+        }
+    }
+
+    mod difference_array_tests {
+        use super::*;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_matches_naive_updates() {
+            let updates = [(0..4, 3), (2..6, -5), (5..6, 10), (0..6, 1), (3..3, 100)];
+            let mut naive = [0i64; 6];
+            let mut diff = DifferenceArray::new(6);
+            for (range, delta) in updates {
+                for value in &mut naive[range.clone()] {
+                    *value += delta;
+                }
+                diff.add_range(range, delta).unwrap();
+                assert_eq!(diff.materialize(), naive);
+            }
+        }
+
+        #[test]
+        fn test_from_values_round_trip() {
+            let values = [4, -2, -2, 7, 0];
+            let mut diff = DifferenceArray::from_values(&values);
+            assert_eq!(diff.len(), 5);
+            assert_eq!(diff.materialize(), values);
+
+            diff.add_range(1..5, 2).unwrap();
+            assert_eq!(diff.materialize(), vec![4, 0, 0, 9, 2]);
+        }
+
+        #[test]
+        fn test_prefix_sums_of_differences() {
+            let mut diff = DifferenceArray::new(4);
+            diff.add_range(1..3, 5).unwrap();
+            let values: Vec<i32> = diff.materialize().iter().map(|&v| v as i32).collect();
+            assert_eq!(PrefixSum::new(&values).range_sum(0..4).unwrap(), 10);
+        }
+
+        #[test]
+        fn test_invalid_ranges() {
+            let mut diff = DifferenceArray::new(3);
+            assert!(matches!(
+                diff.add_range(2..4, 1),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            #[allow(clippy::reversed_empty_ranges)]
+            let reversed = 2..1;
+            assert!(diff.add_range(reversed, 1).is_err());
+            assert_eq!(diff.materialize(), vec![0, 0, 0]);
+
+            let mut empty = DifferenceArray::new(0);
+            assert!(empty.is_empty());
+            empty.add_range(0..0, 5).unwrap();
+            assert_eq!(empty.materialize(), Vec::<i64>::new());
+        }
+    }
+
+    mod interval_toolkit_tests {
+        use super::*;
+
+        #[test]
+        fn test_insert_before_between_and_after() {
+            let sorted = [(3, 5), (8, 9)];
+            assert_eq!(
+                insert_interval(&sorted, (0, 1)),
+                vec![(0, 1), (3, 5), (8, 9)]
+            );
+            assert_eq!(
+                insert_interval(&sorted, (6, 7)),
+                vec![(3, 5), (6, 7), (8, 9)]
+            );
+            assert_eq!(
+                insert_interval(&sorted, (11, 12)),
+                vec![(3, 5), (8, 9), (11, 12)]
+            );
+            assert_eq!(insert_interval(&[], (1, 2)), vec![(1, 2)]);
+        }
+
+        #[test]
+        fn test_insert_touching_endpoints_merge() {
+            let sorted = [(1, 3), (6, 9)];
+            assert_eq!(insert_interval(&sorted, (3, 6)), vec![(1, 9)]);
+            assert_eq!(insert_interval(&sorted, (-2, 1)), vec![(-2, 3), (6, 9)]);
+            assert_eq!(insert_interval(&sorted, (9, 10)), vec![(1, 3), (6, 10)]);
+        }
+
+        #[test]
+        fn test_insert_containment() {
+            let sorted = [(1, 2), (4, 5), (7, 8)];
+            // new swallows everything
+            assert_eq!(insert_interval(&sorted, (0, 10)), vec![(0, 10)]);
+            // new lies inside an existing interval
+            assert_eq!(insert_interval(&[(1, 10)], (3, 4)), vec![(1, 10)]);
+        }
+
+        #[test]
+        fn test_insert_matches_merge_intervals() {
+            let sorted = [(-4, -1), (0, 2), (5, 7), (9, 12), (15, 20)];
+            for start in -6..22 {
+                for end in start..22 {
+                    let mut all = sorted.to_vec();
+                    all.push((start, end));
+                    assert_eq!(
+                        insert_interval(&sorted, (start, end)),
+                        merge_intervals(&all)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_intersection_touching_endpoints() {
+            assert_eq!(interval_intersection(&[(1, 3)], &[(3, 5)]), vec![(3, 3)]);
+            assert_eq!(interval_intersection(&[(1, 2)], &[(3, 5)]), vec![]);
+        }
+
+        #[test]
+        fn test_intersection_containment() {
+            let outer = [(0, 20)];
+            let inner = [(1, 3), (5, 5), (8, 12)];
+            assert_eq!(interval_intersection(&outer, &inner), inner.to_vec());
+            assert_eq!(interval_intersection(&inner, &outer), inner.to_vec());
+        }
+
+        #[test]
+        fn test_intersection_is_symmetric_and_handles_empty() {
+            let a = [(0, 2), (5, 10), (13, 23), (24, 25)];
+            let b = [(1, 5), (8, 12), (15, 24), (25, 26)];
+            assert_eq!(interval_intersection(&a, &b), interval_intersection(&b, &a));
+            assert_eq!(interval_intersection(&a, &[]), vec![]);
+        }
+    }
+
+    mod max_product_checked_tests {
+        use super::*;
+        use crate::ExerciseError;
+
+        #[test]
+        fn test_agrees_with_max_product() {
+            let inputs: [&[i32]; 6] = [
+                &[2, 3, -2, 4],
+                &[-2, 0, -1],
+                &[-2, 3, -4],
+                &[0],
+                &[-3],
+                &[-1, -2, -3, 0, 4, -5, -6],
+            ];
+            for v in inputs {
+                assert_eq!(max_product_checked(v).unwrap(), i64::from(max_product(v)));
+            }
+        }
+
+        #[test]
+        fn test_empty_is_invalid_input() {
+            assert!(matches!(
+                max_product_checked(&[]),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+        }
+
+        #[test]
+        fn test_products_beyond_i32_fit_in_i64() {
+            let max = i64::from(i32::MAX);
+            let min = i64::from(i32::MIN);
+            assert_eq!(max_product_checked(&[i32::MAX, 2]).unwrap(), 2 * max);
+            assert_eq!(
+                max_product_checked(&[i32::MAX, i32::MAX]).unwrap(),
+                max * max
+            );
+            assert_eq!(
+                max_product_checked(&[i32::MIN, i32::MIN]).unwrap(),
+                min * min
+            );
+            assert_eq!(max_product_checked(&[i32::MIN, -1]).unwrap(), -min);
+        }
+
+        #[test]
+        fn test_intermediate_overflow_with_answer_in_range() {
+            let max = i64::from(i32::MAX);
+            // The running minimum passes -2^93, the answer is i32::MAX²
+            assert_eq!(
+                max_product_checked(&[i32::MIN, i32::MAX, i32::MAX]).unwrap(),
+                max * max
+            );
+            // A zero does not hide an earlier out-of-range product
+            let mut v = vec![i32::MIN; 40];
+            v.extend([0, 3, -1, 5]);
+            assert!(max_product_checked(&v).is_err());
+            let mut v = vec![-7; 41];
+            v.extend([0, 3, 5]);
+            assert!(max_product_checked(&v).is_err());
+        }
+
+        #[test]
+        fn test_overflow_is_reported() {
+            assert!(matches!(
+                max_product_checked(&[i32::MAX, i32::MAX, 3]),
+                Err(ExerciseError::OperationFailed(_))
+            ));
+            assert!(matches!(
+                max_product_checked(&[i32::MIN, i32::MAX, i32::MIN]),
+                Err(ExerciseError::OperationFailed(_))
+            ));
+        }
+    }
+
+    mod naive_baseline_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        #[test]
+        fn test_window_maximum_naive_matches_deque() {
+            for (seed, len) in (0..20).zip([0, 1, 5, 17, 64].into_iter().cycle()) {
+                let nums = random_vec(len, -50..50, seed);
+                for k in 0..=len + 1 {
+                    assert_eq!(
+                        sliding_window_maximum_naive(&nums, k),
+                        sliding_window_maximum(&nums, k)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_max_subarray_naive_matches_kadane() {
+            for seed in 0..50 {
+                let nums = random_vec(seed as usize % 30, -20..20, seed);
+                let naive = max_subarray_sum_naive(&nums);
+                let kadane = max_subarray_sum(&nums);
+                assert_eq!(
+                    naive.as_ref().map(|(sum, _)| *sum),
+                    kadane.map(|(sum, _)| sum)
+                );
+                if let Some((sum, range)) = naive {
+                    let total: i64 = nums[range].iter().map(|&n| i64::from(n)).sum();
+                    assert_eq!(total, sum);
+                }
+            }
+        }
+
+        #[test]
+        fn test_random_vec_is_reproducible() {
+            assert_eq!(random_vec(100, 0..10, 3), random_vec(100, 0..10, 3));
+            assert_ne!(random_vec(100, 0..10, 3), random_vec(100, 0..10, 4));
+            assert!(random_vec(1000, 5..7, 1).iter().all(|x| (5..7).contains(x)));
+        }
+    }
+
+    mod stock_trading_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        /// Best profit from `day` on, trying every action on every day.
+        /// `cooldown` is the number of days to wait after a sale and
+        /// `trades` the number of buys still allowed.
+        fn brute_force(
+            prices: &[i32],
+            day: usize,
+            bought: Option<i32>,
+            cooldown: usize,
+            trades: usize,
+        ) -> i64 {
+            if day >= prices.len() {
+                return 0;
+            }
+            let wait = brute_force(prices, day + 1, bought, cooldown, trades);
+            let act = match bought {
+                Some(cost) => {
+                    i64::from(prices[day]) - i64::from(cost)
+                        + brute_force(prices, day + 1 + cooldown, None, cooldown, trades)
+                }
+                None if trades > 0 => {
+                    brute_force(prices, day + 1, Some(prices[day]), cooldown, trades - 1)
+                }
+                None => 0,
+            };
+            wait.max(act)
+        }
+
+        #[test]
+        fn test_against_brute_force() {
+            for seed in 0..200 {
+                let prices = random_vec(seed as usize % 11, 0..20, seed);
+                let unlimited = prices.len();
+                assert_eq!(
+                    max_profit_single(&prices),
+                    brute_force(&prices, 0, None, 0, 1)
+                );
+                assert_eq!(
+                    max_profit_multi(&prices),
+                    brute_force(&prices, 0, None, 0, unlimited)
+                );
+                assert_eq!(
+                    max_profit_cooldown(&prices),
+                    brute_force(&prices, 0, None, 1, unlimited),
+                    "prices {prices:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_progression_never_decreases() {
+            // More freedom can only help: cooldown <= multi, single <= multi
+            for seed in 0..100 {
+                let prices = random_vec(30, 0..100, seed);
+                let multi = max_profit_multi(&prices);
+                assert!(max_profit_single(&prices) <= multi);
+                assert!(max_profit_cooldown(&prices) <= multi);
+            }
+        }
+
+        #[test]
+        fn test_edge_cases() {
+            for f in [max_profit_single, max_profit_multi, max_profit_cooldown] {
+                assert_eq!(f(&[]), 0);
+                assert_eq!(f(&[5]), 0);
+                assert_eq!(f(&[5, 4, 3]), 0);
+                assert_eq!(
+                    f(&[i32::MIN, i32::MAX]),
+                    i64::from(i32::MAX) - i64::from(i32::MIN)
+                );
+            }
+        }
+    }
+
+    mod jump_game_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        /// Shortest path by plain BFS over the jump graph
+        fn min_jumps_bfs(jumps: &[usize]) -> Option<usize> {
+            let last = jumps.len().checked_sub(1)?;
+            let mut dist = vec![None; jumps.len()];
+            dist[0] = Some(0);
+            let mut queue = std::collections::VecDeque::from([0]);
+            while let Some(i) = queue.pop_front() {
+                let d = dist[i].unwrap();
+                let end = (i + jumps[i]).min(last);
+                for (j, slot) in dist.iter_mut().enumerate().take(end + 1).skip(i + 1) {
+                    if slot.is_none() {
+                        *slot = Some(d + 1);
+                        queue.push_back(j);
+                    }
+                }
+            }
+            dist[last]
+        }
+
+        #[test]
+        fn test_single_element() {
+            for jump in [0, 1, 5] {
+                assert!(can_jump(&[jump]));
+                assert_eq!(min_jumps(&[jump]), Some(0));
+            }
+        }
+
+        #[test]
+        fn test_zero_filled() {
+            for len in 2..6 {
+                let zeros = vec![0; len];
+                assert!(!can_jump(&zeros));
+                assert_eq!(min_jumps(&zeros), None);
+            }
+            // A zero is only a wall if nothing jumps over it
+            assert!(can_jump(&[2, 0, 0]));
+            assert_eq!(min_jumps(&[2, 0, 0]), Some(1));
+            assert_eq!(min_jumps(&[1, 0, 0]), None);
+        }
+
+        #[test]
+        fn test_empty_and_huge_jumps() {
+            assert!(!can_jump(&[]));
+            assert_eq!(min_jumps(&[]), None);
+            assert_eq!(min_jumps(&[usize::MAX, 0, 0, 0]), Some(1));
+            assert!(can_jump(&[1, usize::MAX, 0, 0]));
+        }
+
+        #[test]
+        fn test_against_bfs() {
+            for seed in 0..300 {
+                let jumps = random_vec(1 + seed as usize % 15, 0..4, seed);
+                let expected = min_jumps_bfs(&jumps);
+                assert_eq!(min_jumps(&jumps), expected, "jumps {jumps:?}");
+                assert_eq!(can_jump(&jumps), expected.is_some(), "jumps {jumps:?}");
+            }
+        }
+    }
+
+    mod random_sampling_tests {
+        use super::*;
+        use crate::ExerciseError;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        use std::collections::HashMap;
+
+        const TRIALS: usize = 60_000;
+
+        fn permutation_counts(shuffle: impl Fn(&mut [u8], &mut StdRng)) -> HashMap<[u8; 3], usize> {
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut counts = HashMap::new();
+            for _ in 0..TRIALS {
+                let mut v = [0, 1, 2];
+                shuffle(&mut v, &mut rng);
+                *counts.entry(v).or_insert(0) += 1;
+            }
+            counts
+        }
+
+        #[test]
+        fn test_shuffle_is_uniform() {
+            let counts = permutation_counts(shuffle);
+            assert_eq!(counts.len(), 6);
+            let expected = TRIALS / 6;
+            for (perm, count) in counts {
+                assert!(
+                    count.abs_diff(expected) < expected / 20,
+                    "{perm:?}: {count}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_naive_shuffle_is_biased() {
+            let naive = |v: &mut [u8], rng: &mut StdRng| {
+                for i in 0..v.len() {
+                    let j = rng.gen_range(0..v.len());
+                    v.swap(i, j);
+                }
+            };
+            let counts = permutation_counts(naive);
+            let (min, max) = (
+                counts.values().min().unwrap(),
+                counts.values().max().unwrap(),
+            );
+            // Expected 4/27 vs 5/27 of the trials: a 25% gap
+            assert!(*max as f64 / *min as f64 > 1.15, "min {min}, max {max}");
+        }
+
+        #[test]
+        fn test_shuffle_edge_cases() {
+            let mut rng = StdRng::seed_from_u64(0);
+            let mut empty: [i32; 0] = [];
+            shuffle(&mut empty, &mut rng);
+            let mut one = [9];
+            shuffle(&mut one, &mut rng);
+            assert_eq!(one, [9]);
+        }
+
+        #[test]
+        fn test_sample_k_is_uniform() {
+            let items = [0usize, 1, 2, 3, 4];
+            let mut rng = StdRng::seed_from_u64(5);
+            let mut hits = [0usize; 5];
+            for _ in 0..TRIALS {
+                let mut sample = sample_k(&items, 2, &mut rng).unwrap();
+                sample.sort_unstable();
+                sample.dedup();
+                assert_eq!(sample.len(), 2);
+                for x in sample {
+                    hits[x] += 1;
+                }
+            }
+            // Each item is in the sample with probability 2/5
+            let expected = TRIALS * 2 / 5;
+            for count in hits {
+                assert!(count.abs_diff(expected) < expected / 20, "{hits:?}");
+            }
+        }
+
+        #[test]
+        fn test_sample_k_bounds() {
+            let mut rng = StdRng::seed_from_u64(3);
+            assert_eq!(
+                sample_k(&[1, 2, 3], 0, &mut rng).unwrap(),
+                Vec::<i32>::new()
+            );
+            let mut all = sample_k(&[3, 1, 2], 3, &mut rng).unwrap();
+            all.sort_unstable();
+            assert_eq!(all, [1, 2, 3]);
+            assert!(matches!(
+                sample_k(&[1, 2], 3, &mut rng),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+        }
+    }
+
+    mod wiggle_sort_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+        use crate::ExerciseError;
+
+        fn is_wiggle(v: &[i32], strict: bool) -> bool {
+            v.windows(2)
+                .enumerate()
+                .all(|(i, pair)| match (i % 2 == 0, strict) {
+                    (true, false) => pair[0] <= pair[1],
+                    (false, false) => pair[0] >= pair[1],
+                    (true, true) => pair[0] < pair[1],
+                    (false, true) => pair[0] > pair[1],
+                })
+        }
+
+        fn sorted(v: &[i32]) -> Vec<i32> {
+            let mut v = v.to_vec();
+            v.sort_unstable();
+            v
+        }
+
+        /// Whether any permutation is a strict wiggle, by trying them all
+        fn strict_wiggle_exists(v: &[i32]) -> bool {
+            let mut perm = sorted(v);
+            loop {
+                if is_wiggle(&perm, true) {
+                    return true;
+                }
+                if !next_permutation(&mut perm) {
+                    return false;
+                }
+            }
+        }
+
+        #[test]
+        fn test_wiggle_sort_invariant() {
+            for seed in 0..300 {
+                let input = random_vec(seed as usize % 20, 0..5, seed);
+                let mut v = input.clone();
+                wiggle_sort(&mut v);
+                assert!(is_wiggle(&v, false), "{input:?} -> {v:?}");
+                assert_eq!(sorted(&v), sorted(&input));
+            }
+        }
+
+        #[test]
+        fn test_wiggle_sort_strict_against_brute_force() {
+            for seed in 0..400 {
+                let input = random_vec(seed as usize % 8, 0..4, seed);
+                let mut v = input.clone();
+                match wiggle_sort_strict(&mut v) {
+                    Ok(()) => {
+                        assert!(is_wiggle(&v, true), "{input:?} -> {v:?}");
+                        assert_eq!(sorted(&v), sorted(&input));
+                    }
+                    Err(ExerciseError::InvalidInput(_)) => {
+                        assert!(!strict_wiggle_exists(&input), "gave up on {input:?}");
+                        assert_eq!(v, input);
+                    }
+                    Err(e) => panic!("unexpected error {e}"),
+                }
+            }
+        }
+
+        #[test]
+        fn test_wiggle_sort_strict_large_inputs() {
+            for seed in 0..20 {
+                // Distinct values always have a strict wiggle order
+                let input: Vec<i32> = crate::vector::inputs::random_permutation(1001, seed)
+                    .into_iter()
+                    .map(|x| x as i32)
+                    .collect();
+                let mut v = input.clone();
+                wiggle_sort_strict(&mut v).unwrap();
+                assert!(is_wiggle(&v, true));
+            }
+        }
+
+        #[test]
+        fn test_wiggle_sort_strict_duplicates() {
+            let mut v = [4, 5, 5, 6];
+            wiggle_sort_strict(&mut v).unwrap();
+            assert!(is_wiggle(&v, true), "{v:?}");
+
+            assert!(wiggle_sort_strict(&mut [1, 1]).is_err());
+            assert!(wiggle_sort_strict(&mut [1, 2, 2]).is_err());
+            let mut ok = [2, 1, 1];
+            wiggle_sort_strict(&mut ok).unwrap();
+            assert_eq!(ok, [1, 2, 1]);
+
+            let mut empty: [i32; 0] = [];
+            wiggle_sort_strict(&mut empty).unwrap();
+            let mut one = [7];
+            wiggle_sort_strict(&mut one).unwrap();
+        }
+    }
+
+    mod run_length_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        #[test]
+        fn test_round_trip() {
+            for seed in 0..200 {
+                let items = random_vec(seed as usize % 40, 0..3, seed);
+                let runs = run_length_encode(&items);
+                assert_eq!(run_length_decode(&runs), items);
+                // Runs are maximal: no count is zero and neighbours differ
+                assert!(runs.iter().all(|&(_, count)| count > 0));
+                assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+            }
+        }
+
+        #[test]
+        fn test_encode_edge_cases() {
+            assert_eq!(run_length_encode::<i32>(&[]), vec![]);
+            assert_eq!(run_length_encode(&[7]), vec![(7, 1)]);
+            assert_eq!(run_length_encode(&[0; 5]), vec![(0, 5)]);
+            assert_eq!(run_length_encode(&[1, 2, 3]), vec![(1, 1), (2, 1), (3, 1)]);
+        }
+
+        #[test]
+        fn test_partial_eq_only() {
+            // f64 is PartialEq but not Eq; NaN never equals itself
+            let runs = run_length_encode(&[0.5, 0.5, f64::NAN, f64::NAN]);
+            assert_eq!(runs.len(), 3);
+            assert_eq!(runs[0], (0.5, 2));
+
+            let words = run_length_encode(&["to", "to", "be"].map(String::from));
+            assert_eq!(words, vec![("to".to_string(), 2), ("be".to_string(), 1)]);
+        }
+
+        #[test]
+        fn test_decode_accepts_non_canonical_runs() {
+            assert_eq!(
+                run_length_decode(&[('x', 0), ('y', 2), ('y', 1)]),
+                vec!['y'; 3]
+            );
+            assert_eq!(run_length_decode::<char>(&[]), vec![]);
+        }
+    }
+
+    mod monotonic_queue_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        #[test]
+        fn test_max_queue_front_is_window_maximum() {
+            let nums = random_vec(200, -20..20, 1);
+            for k in 1..10 {
+                let mut queue = MonotonicQueue::max();
+                for (i, &value) in nums.iter().enumerate() {
+                    assert_eq!(queue.push(value), i);
+                    let start = (i + 1).saturating_sub(k);
+                    queue.pop_expired(start);
+                    assert_eq!(queue.current_extreme(), nums[start..=i].iter().max());
+                }
+                assert_eq!(queue.pushed(), nums.len());
+            }
+        }
+
+        #[test]
+        fn test_min_queue_front_is_window_minimum() {
+            let nums = random_vec(200, -20..20, 2);
+            let mut queue = MonotonicQueue::min();
+            for (i, &value) in nums.iter().enumerate() {
+                queue.push(value);
+                let start = (i + 1).saturating_sub(4);
+                queue.pop_expired(start);
+                assert_eq!(queue.current_extreme(), nums[start..=i].iter().min());
+            }
+        }
+
+        #[test]
+        fn test_candidates_stay_monotonic() {
+            let mut queue = MonotonicQueue::max();
+            for value in [5, 3, 4, 4, 1] {
+                queue.push(value);
+            }
+            // 3 and the first 4 were evicted; ties evict the older value
+            assert_eq!(queue.len(), 3);
+            queue.pop_expired(4);
+            assert_eq!(queue.current_extreme(), Some(&1));
+            queue.pop_expired(5);
+            assert!(queue.is_empty());
+            assert_eq!(queue.current_extreme(), None);
+        }
+
+        #[test]
+        fn test_sliding_window_minimum() {
+            assert_eq!(sliding_window_minimum(&[], 1), Vec::<i32>::new());
+            assert_eq!(sliding_window_minimum(&[2, 1], 3), Vec::<i32>::new());
+            assert_eq!(sliding_window_minimum(&[4, 2, 12, 3, 8], 3), vec![2, 2, 3]);
+            for seed in 0..20 {
+                let nums = random_vec(50, -10..10, seed);
+                let negated: Vec<i32> = nums.iter().map(|x| -x).collect();
+                let maxima: Vec<i32> = sliding_window_maximum(&negated, 5)
+                    .iter()
+                    .map(|x| -x)
+                    .collect();
+                assert_eq!(sliding_window_minimum(&nums, 5), maxima);
+            }
+        }
+    }
+
+    mod partition_tests {
+        use super::*;
+        use crate::vector::inputs::random_vec;
+
+        #[test]
+        fn test_partition_in_place_invariant() {
+            for seed in 0..200 {
+                let input = random_vec(seed as usize % 30, 0..10, seed);
+                let mut v = input.clone();
+                let split = partition_in_place(&mut v, |&x| x < 4);
+                assert_eq!(split, input.iter().filter(|&&x| x < 4).count());
+                assert!(v[..split].iter().all(|&x| x < 4));
+                assert!(v[split..].iter().all(|&x| x >= 4));
+                assert_eq!(partition_point(&v, |&x| x < 4), split);
+
+                let (mut a, mut b) = (v, input);
+                a.sort_unstable();
+                b.sort_unstable();
+                assert_eq!(a, b);
+            }
+        }
+
+        #[test]
+        fn test_stable_partition_keeps_order() {
+            for seed in 0..200 {
+                let input = random_vec(seed as usize % 30, 0..10, seed);
+                // Tag each value with its position to observe stability
+                let mut tagged: Vec<(i32, usize)> = input.iter().copied().zip(0..).collect();
+                let split = stable_partition(&mut tagged, |&(x, _)| x % 3 == 0);
+
+                let expected: Vec<(i32, usize)> = input
+                    .iter()
+                    .copied()
+                    .zip(0..)
+                    .filter(|&(x, _)| x % 3 == 0)
+                    .chain(input.iter().copied().zip(0..).filter(|&(x, _)| x % 3 != 0))
+                    .collect();
+                assert_eq!(tagged, expected);
+                assert_eq!(split, input.iter().filter(|&&x| x % 3 == 0).count());
+            }
+        }
+
+        #[test]
+        fn test_partition_edge_cases() {
+            let mut empty: [i32; 0] = [];
+            assert_eq!(partition_in_place(&mut empty, |_| true), 0);
+            assert_eq!(stable_partition(&mut empty, |_| true), 0);
+
+            let mut all = [1, 2, 3];
+            assert_eq!(partition_in_place(&mut all, |_| true), 3);
+            assert_eq!(all, [1, 2, 3]);
+            assert_eq!(partition_in_place(&mut all, |_| false), 0);
+            assert_eq!(all, [1, 2, 3]);
+            assert_eq!(stable_partition(&mut all, |&x| x == 3), 1);
+            assert_eq!(all, [3, 1, 2]);
+        }
+    }
+
+    mod meeting_rooms_tests {
+        use super::*;
+        use crate::vector::inputs::random_intervals;
+
+        /// Largest number of meetings covering a single time unit
+        fn max_overlap_brute_force(meetings: &[(i32, i32)]) -> usize {
+            let times = meetings.iter().flat_map(|&(start, end)| start..end);
+            times
+                .map(|t| {
+                    meetings
+                        .iter()
+                        .filter(|&&(start, end)| start <= t && t < end)
+                        .count()
+                })
+                .max()
+                .unwrap_or(0)
+        }
+
+        #[test]
+        fn test_room_counts_agree_with_brute_force() {
+            for seed in 0..200 {
+                let meetings = random_intervals(seed as usize % 12, 0..40, 10, seed);
+                let expected = max_overlap_brute_force(&meetings);
+                assert_eq!(min_meeting_rooms(&meetings), expected, "{meetings:?}");
+                assert_eq!(min_meeting_rooms_sweep(&meetings), expected, "{meetings:?}");
+                assert_eq!(can_attend_all(&meetings), expected <= 1, "{meetings:?}");
+            }
+        }
+
+        #[test]
+        fn test_back_to_back_meetings_share_a_room() {
+            let meetings = [(1, 3), (3, 5), (5, 7)];
+            assert!(can_attend_all(&meetings));
+            assert_eq!(min_meeting_rooms(&meetings), 1);
+            assert_eq!(min_meeting_rooms_sweep(&meetings), 1);
+        }
+
+        #[test]
+        fn test_nested_and_empty_meetings() {
+            let nested = [(0, 10), (1, 9), (2, 8), (3, 4)];
+            assert_eq!(min_meeting_rooms(&nested), 4);
+            assert_eq!(min_meeting_rooms_sweep(&nested), 4);
+
+            let empty = [(2, 2), (5, 5), (2, 6)];
+            assert!(can_attend_all(&empty));
+            assert_eq!(min_meeting_rooms(&empty), 1);
+            assert_eq!(min_meeting_rooms_sweep(&empty), 1);
+            assert_eq!(min_meeting_rooms(&[]), 0);
+            assert_eq!(min_meeting_rooms_sweep(&[]), 0);
+        }
+    }
+
+    mod sorting_tests {
+        use crate::vector::sorting::{
+            counting_sort, heapsort, insertion_sort, median_of_three, merge_sort, quicksort,
+            radix_sort,
+        };
+        use crate::ExerciseError;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        use std::cmp::Ordering;
+
+        const SORTS: [fn(&mut [i32]); 4] = [insertion_sort, merge_sort, quicksort, heapsort];
+
+        /// Ordered by `key` only, so `tag` reveals whether equal keys moved
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Record {
+            key: u8,
+            tag: usize,
+        }
+
+        impl PartialOrd for Record {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Record {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        fn shapes(rng: &mut StdRng, len: usize) -> Vec<Vec<i32>> {
+            vec![
+                (0..len).map(|_| rng.gen_range(-1000..1000)).collect(),
+                (0..len).map(|_| rng.gen_range(0..4)).collect(),
+                (0..len as i32).collect(),
+                (0..len as i32).rev().collect(),
+                vec![7; len],
+                (0..len as i32)
+                    .map(|i| if i % 2 == 0 { i } else { -i })
+                    .collect(),
+            ]
+        }
+
+        #[test]
+        fn test_agree_with_sort_unstable() {
+            let mut rng = StdRng::seed_from_u64(7);
+            for len in [0, 1, 2, 3, 10, 100, 1000] {
+                for input in shapes(&mut rng, len) {
+                    let mut expected = input.clone();
+                    expected.sort_unstable();
+                    for sort in SORTS {
+                        let mut v = input.clone();
+                        sort(&mut v);
+                        assert_eq!(v, expected, "len {len}, input {input:?}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_randomized_lengths() {
+            let mut rng = StdRng::seed_from_u64(8);
+            for _ in 0..300 {
+                let len = rng.gen_range(0..64);
+                let input: Vec<i32> = (0..len).map(|_| rng.gen_range(-20..20)).collect();
+                let mut expected = input.clone();
+                expected.sort_unstable();
+                for sort in SORTS {
+                    let mut v = input.clone();
+                    sort(&mut v);
+                    assert_eq!(v, expected);
+                }
+            }
+        }
+
+        #[test]
+        fn test_stable_sorts_keep_equal_keys_in_order() {
+            let mut rng = StdRng::seed_from_u64(9);
+            let input: Vec<Record> = (0..500)
+                .map(|tag| Record {
+                    key: rng.gen_range(0..5),
+                    tag,
+                })
+                .collect();
+            let mut expected = input.clone();
+            expected.sort_by_key(|r| r.key);
+
+            let stable: [fn(&mut [Record]); 2] = [insertion_sort, merge_sort];
+            for sort in stable {
+                let mut v = input.clone();
+                sort(&mut v);
+                let tags: Vec<usize> = v.iter().map(|r| r.tag).collect();
+                let expected_tags: Vec<usize> = expected.iter().map(|r| r.tag).collect();
+                assert_eq!(tags, expected_tags);
+            }
+        }
+
+        #[test]
+        fn test_unstable_sorts_still_sort_records() {
+            let input: Vec<Record> = (0..200)
+                .map(|tag| Record {
+                    key: (tag * 7 % 3) as u8,
+                    tag,
+                })
+                .collect();
+            let unstable: [fn(&mut [Record]); 2] = [quicksort, heapsort];
+            for sort in unstable {
+                let mut v = input.clone();
+                sort(&mut v);
+                assert!(v.windows(2).all(|w| w[0].key <= w[1].key));
+                let mut tags: Vec<usize> = v.iter().map(|r| r.tag).collect();
+                tags.sort_unstable();
+                assert_eq!(tags, (0..200).collect::<Vec<_>>());
+            }
+        }
+
+        #[test]
+        fn test_generic_over_ord() {
+            let words = ["delta", "alpha", "charlie", "bravo"];
+            let mut expected = words;
+            expected.sort_unstable();
+
+            let mut v = words.map(String::from);
+            quicksort(&mut v);
+            assert_eq!(v, expected);
+            let mut v = words;
+            heapsort(&mut v);
+            assert_eq!(v, expected);
+            let mut v = words;
+            merge_sort(&mut v);
+            assert_eq!(v, expected);
+        }
+
+        #[test]
+        fn test_counting_sort() {
+            let mut rng = StdRng::seed_from_u64(10);
+            for (len, max) in [(0, 0), (1, 0), (50, 3), (1000, 255), (100, 100_000)] {
+                let input: Vec<u32> = (0..len).map(|_| rng.gen_range(0..=max)).collect();
+                let mut expected = input.clone();
+                expected.sort_unstable();
+                let mut v = input;
+                counting_sort(&mut v, max).unwrap();
+                assert_eq!(v, expected);
+            }
+        }
+
+        #[test]
+        fn test_counting_sort_rejects_values_above_max() {
+            let mut v = [1, 5, 2];
+            assert!(matches!(
+                counting_sort(&mut v, 4),
+                Err(ExerciseError::InvalidInput(_))
+            ));
+            assert_eq!(v, [1, 5, 2]);
+        }
+
+        #[test]
+        fn test_radix_sort() {
+            let mut rng = StdRng::seed_from_u64(11);
+            let inputs: Vec<Vec<u64>> = vec![
+                vec![],
+                vec![42],
+                (0..1000).map(|_| rng.gen()).collect(),
+                (0..1000).map(|_| rng.gen_range(0..300)).collect(),
+                (0..100).rev().collect(),
+                vec![u64::MAX, 0, 1 << 63, u64::MAX, 255, 256],
+                vec![9; 20],
+            ];
+            for input in inputs {
+                let mut expected = input.clone();
+                expected.sort_unstable();
+                let mut v = input;
+                radix_sort(&mut v);
+                assert_eq!(v, expected);
+            }
+        }
+
+        #[test]
+        fn test_median_of_three() {
+            let v = [1, 2, 3];
+            for (a, b, c) in [
+                (0, 1, 2),
+                (0, 2, 1),
+                (1, 0, 2),
+                (1, 2, 0),
+                (2, 0, 1),
+                (2, 1, 0),
+            ] {
+                assert_eq!(v[median_of_three(&v, a, b, c)], 2);
+            }
+            let ties = [5, 5, 1];
+            assert_eq!(ties[median_of_three(&ties, 0, 1, 2)], 5);
+        }
+    }
+
+    mod two_pointers_tests {
+        use crate::vector::inputs::random_vec;
+        use crate::vector::two_pointers::{max_container_area, reverse_vowels, two_sum_sorted};
+
+        fn max_container_area_brute_force(heights: &[u32]) -> u64 {
+            let mut best = 0;
+            for i in 0..heights.len() {
+                for j in i + 1..heights.len() {
+                    best = best.max(u64::from(heights[i].min(heights[j])) * (j - i) as u64);
+                }
+            }
+            best
+        }
+
+        #[test]
+        fn test_max_container_area_matches_brute_force() {
+            for seed in 0..100 {
+                let heights = random_vec(seed as usize % 25, 0..50, seed);
+                assert_eq!(
+                    max_container_area(&heights),
+                    max_container_area_brute_force(&heights),
+                    "heights {heights:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_max_container_area_edge_cases() {
+            assert_eq!(max_container_area(&[]), 0);
+            assert_eq!(max_container_area(&[1, 1]), 1);
+            assert_eq!(
+                max_container_area(&[u32::MAX, 0, u32::MAX]),
+                2 * u64::from(u32::MAX)
+            );
+        }
+
+        #[test]
+        fn test_two_sum_sorted() {
+            for seed in 0..100 {
+                let mut sorted = random_vec(seed as usize % 20, -30..30, seed);
+                sorted.sort_unstable();
+                for target in -60..60 {
+                    match two_sum_sorted(&sorted, target) {
+                        Some((i, j)) => {
+                            assert!(i < j);
+                            assert_eq!(sorted[i] + sorted[j], target);
+                        }
+                        None => {
+                            let exists = (0..sorted.len()).any(|i| {
+                                (i + 1..sorted.len()).any(|j| sorted[i] + sorted[j] == target)
+                            });
+                            assert!(!exists, "missed {target} in {sorted:?}");
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_two_sum_sorted_extremes() {
+            assert_eq!(two_sum_sorted(&[], 0), None);
+            assert_eq!(two_sum_sorted(&[5], 10), None);
+            assert_eq!(two_sum_sorted(&[i32::MIN, i32::MAX], -1), Some((0, 1)));
+            assert_eq!(two_sum_sorted(&[i32::MAX, i32::MAX], -2), None);
+        }
+
+        #[test]
+        fn test_reverse_vowels() {
+            assert_eq!(reverse_vowels(""), "");
+            assert_eq!(reverse_vowels("a"), "a");
+            assert_eq!(reverse_vowels("xyz"), "xyz");
+            assert_eq!(reverse_vowels("aA"), "Aa");
+            assert_eq!(reverse_vowels("race car"), "race car");
+            // Non-ASCII characters are neither vowels nor split apart
+            assert_eq!(reverse_vowels("héllo wörld"), "héllo wörld");
+            assert_eq!(reverse_vowels("añob"), "oñab");
+        }
+    }
 }
@@ -0,0 +1,150 @@
+//! Two-Pointer Problem Set
+//!
+//! Problems solved by two indices walking towards each other from both
+//! ends of a sequence, each step discarding one candidate for good. The
+//! technique turns an O(n²) search over all pairs into a single O(n) pass,
+//! but only when there is an argument that the discarded candidate can
+//! never be part of the answer. Each function below spells that argument
+//! out.
+//!
+//! See also [`three_sum`](super::three_sum) and
+//! [`trap_rain_water`](super::trap_rain_water), which use the same idea.
+
+/// # Container With Most Water
+///
+/// ## Problem Statement
+/// `heights[i]` is the height of a vertical line at position `i`. Pick two
+/// lines that, together with the x-axis, hold the most water: the area
+/// `(j - i) * min(heights[i], heights[j])`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::two_pointers::max_container_area;
+/// assert_eq!(max_container_area(&[1, 8, 6, 2, 5, 4, 8, 3, 7]), 49); // lines 1 and 8
+/// assert_eq!(max_container_area(&[4]), 0);
+/// ```
+///
+/// ## Approach
+/// Start with the widest container, `left = 0` and `right = n - 1`, and
+/// move the pointer at the *shorter* line inwards until they meet.
+///
+/// ## Why Moving the Smaller Pointer Is Safe
+/// Say `heights[left] <= heights[right]`. Every container that still uses
+/// `left` pairs it with some `right' < right`. That container is narrower,
+/// and its height is `min(heights[left], heights[right'])`, which can never
+/// exceed `heights[left]`. So none of them beats the current container,
+/// whose area we have already recorded, and `left` can be dropped without
+/// losing the answer. Moving the taller pointer instead gives no such
+/// guarantee: the next line might be taller than the short one.
+///
+/// ## Complexity
+/// - Time: O(n), each step discards one line
+/// - Space: O(1)
+///
+/// ## Common Pitfalls
+/// 1. Moving the taller pointer, which can skip the optimum
+/// 2. Computing the area in `u32`, which overflows for wide, tall inputs
+pub fn max_container_area(heights: &[u32]) -> u64 {
+    let mut best = 0;
+    let (mut left, mut right) = (0, heights.len().saturating_sub(1));
+    while left < right {
+        let height = heights[left].min(heights[right]);
+        best = best.max(u64::from(height) * (right - left) as u64);
+        if heights[left] <= heights[right] {
+            left += 1;
+        } else {
+            right -= 1;
+        }
+    }
+    best
+}
+
+/// # Two Sum on a Sorted Array
+///
+/// ## Problem Statement
+/// Given an ascending array and a target, return indices `i < j` with
+/// `sorted[i] + sorted[j] == target`, or `None`.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::two_pointers::two_sum_sorted;
+/// assert_eq!(two_sum_sorted(&[2, 7, 11, 15], 9), Some((0, 1)));
+/// assert_eq!(two_sum_sorted(&[-3, -1, 0, 4], 1), Some((0, 3)));
+/// assert_eq!(two_sum_sorted(&[1, 2], 5), None);
+/// ```
+///
+/// ## Approach
+/// Compare `sorted[left] + sorted[right]` with the target: too small means
+/// `left` moves right, too large means `right` moves left.
+///
+/// ## Why Moving a Pointer Is Safe
+/// If the sum is too small, pairing `sorted[left]` with anything at or
+/// before `right` gives at most the current sum, since `sorted[right]` is
+/// the largest remaining value. So `left` is in no solution and can go.
+/// The too-large case is symmetric for `right`.
+///
+/// Unlike [`two_sum`](super::two_sum) this needs no `HashMap`: the
+/// sortedness replaces the lookup table.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// ## Key Points
+/// 1. Sums are compared in `i64` so extreme values cannot overflow
+pub fn two_sum_sorted(sorted: &[i32], target: i32) -> Option<(usize, usize)> {
+    let target = i64::from(target);
+    let (mut left, mut right) = (0, sorted.len().checked_sub(1)?);
+    while left < right {
+        let sum = i64::from(sorted[left]) + i64::from(sorted[right]);
+        match sum.cmp(&target) {
+            std::cmp::Ordering::Less => left += 1,
+            std::cmp::Ordering::Greater => right -= 1,
+            std::cmp::Ordering::Equal => return Some((left, right)),
+        }
+    }
+    None
+}
+
+/// # Reverse Vowels
+///
+/// ## Problem Statement
+/// Reverse the order of the vowels (`a e i o u`, either case) in a string,
+/// leaving every other character in place.
+///
+/// ## Example
+/// ```
+/// use rust_ds_learning::vector::two_pointers::reverse_vowels;
+/// assert_eq!(reverse_vowels("hello"), "holle");
+/// assert_eq!(reverse_vowels("Leetcode"), "Leotcede");
+/// assert_eq!(reverse_vowels("naïve"), "neïva");
+/// ```
+///
+/// ## Approach
+/// Work on the `char`s: each pointer skips consonants, and when both rest
+/// on vowels they swap and move inwards.
+///
+/// ## Complexity
+/// - Time: O(n)
+/// - Space: O(n) for the `char` buffer
+///
+/// ## Common Pitfalls
+/// 1. Swapping bytes of the `&str`, which breaks multi-byte characters;
+///    collecting into `Vec<char>` first keeps the indices meaningful
+pub fn reverse_vowels(s: &str) -> String {
+    let is_vowel = |c: char| "aeiouAEIOU".contains(c);
+    let mut chars: Vec<char> = s.chars().collect();
+    let (mut left, mut right) = (0, chars.len().saturating_sub(1));
+    while left < right {
+        if !is_vowel(chars[left]) {
+            left += 1;
+        } else if !is_vowel(chars[right]) {
+            right -= 1;
+        } else {
+            chars.swap(left, right);
+            left += 1;
+            right -= 1;
+        }
+    }
+    chars.into_iter().collect()
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Settings for a verification run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckConfig {
+    /// Number of randomly generated cases to run after the edge cases
+    pub cases: usize,
+    /// Seed for the case generator, so failures are reproducible
+    pub seed: u64,
+}
+
+impl Default for CheckConfig {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: 0x5EED,
+        }
+    }
+}
+
+/// The first input on which a user implementation disagreed with the reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counterexample<I, O> {
+    /// Name of the exercise that was checked
+    pub exercise: &'static str,
+    /// Index of the failing case (edge cases come first)
+    pub case: usize,
+    pub input: I,
+    pub expected: O,
+    pub actual: O,
+}
+
+impl<I: fmt::Debug, O: fmt::Debug> fmt::Display for Counterexample<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} failed on case #{}: input {:?}, expected {:?}, got {:?}",
+            self.exercise, self.case, self.input, self.expected, self.actual
+        )
+    }
+}
+
+/// An exercise that can be verified against its reference implementation.
+///
+/// Implementors provide the reference solution and an input generator; the
+/// provided methods take care of running a user closure over the cases and
+/// reporting the first mismatch.
+pub trait Verify {
+    type Input: Clone + fmt::Debug;
+    type Output: PartialEq + fmt::Debug;
+
+    /// Human-readable exercise name used in reports
+    const NAME: &'static str;
+
+    /// The trusted solution
+    fn reference(input: &Self::Input) -> Self::Output;
+
+    /// Produces one random input
+    fn generate(rng: &mut StdRng) -> Self::Input;
+
+    /// Hand-picked inputs that are always checked before the random ones
+    fn edge_cases() -> Vec<Self::Input> {
+        Vec::new()
+    }
+
+    /// All inputs for a run: edge cases first, then `config.cases` random ones
+    fn cases(config: &CheckConfig) -> Vec<Self::Input> {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut cases = Self::edge_cases();
+        cases.extend((0..config.cases).map(|_| Self::generate(&mut rng)));
+        cases
+    }
+
+    /// Runs `f` against the reference using the default configuration.
+    fn check_user_impl<F>(f: F) -> Result<(), Counterexample<Self::Input, Self::Output>>
+    where
+        F: FnMut(&Self::Input) -> Self::Output,
+    {
+        Self::check_user_impl_with(&CheckConfig::default(), f)
+    }
+
+    /// Runs `f` against the reference and returns the first counterexample.
+    fn check_user_impl_with<F>(
+        config: &CheckConfig,
+        mut f: F,
+    ) -> Result<(), Counterexample<Self::Input, Self::Output>>
+    where
+        F: FnMut(&Self::Input) -> Self::Output,
+    {
+        for (case, input) in Self::cases(config).into_iter().enumerate() {
+            let expected = Self::reference(&input);
+            let actual = f(&input);
+            if actual != expected {
+                return Err(Counterexample {
+                    exercise: Self::NAME,
+                    case,
+                    input,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+}
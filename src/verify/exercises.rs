@@ -0,0 +1,176 @@
+//! Verifiable wrappers around the crate's exercises
+//!
+//! Each unit struct below names one exercise. Inputs with several arguments
+//! are passed as tuples, so a user closure destructures them:
+//! `|(nums, k)| my_window_max(nums, *k)`.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::checker::Verify;
+use crate::matrix::{find_postition_sorted_square_matrix, Matrix};
+
+fn random_vec(rng: &mut StdRng, max_len: usize, lo: i32, hi: i32) -> Vec<i32> {
+    let len = rng.gen_range(0..=max_len);
+    (0..len).map(|_| rng.gen_range(lo..=hi)).collect()
+}
+
+/// Verifies [`crate::vector::sliding_window_maximum`]. Input: `(nums, window_size)`.
+pub struct SlidingWindowMaximum;
+
+impl Verify for SlidingWindowMaximum {
+    type Input = (Vec<i32>, usize);
+    type Output = Vec<i32>;
+
+    const NAME: &'static str = "vector::sliding_window_maximum";
+
+    fn reference(input: &Self::Input) -> Self::Output {
+        crate::vector::sliding_window_maximum(&input.0, input.1)
+    }
+
+    fn generate(rng: &mut StdRng) -> Self::Input {
+        let nums = random_vec(rng, 20, -50, 50);
+        // Window sizes range from 1 to the full length of the input
+        let window_size = rng.gen_range(1..=nums.len().max(1));
+        (nums, window_size)
+    }
+
+    fn edge_cases() -> Vec<Self::Input> {
+        vec![
+            (vec![], 1),
+            (vec![7], 1),
+            (vec![1, 2, 3], 3),
+            (vec![3, 2, 1], 2),
+            (vec![2, 2, 2, 2], 2),
+        ]
+    }
+}
+
+/// Verifies [`crate::vector::merge_intervals`]. Input: the list of intervals.
+pub struct MergeIntervals;
+
+impl Verify for MergeIntervals {
+    type Input = Vec<(i32, i32)>;
+    type Output = Vec<(i32, i32)>;
+
+    const NAME: &'static str = "vector::merge_intervals";
+
+    fn reference(input: &Self::Input) -> Self::Output {
+        crate::vector::merge_intervals(input)
+    }
+
+    fn generate(rng: &mut StdRng) -> Self::Input {
+        let len = rng.gen_range(0..=10);
+        (0..len)
+            .map(|_| {
+                let start = rng.gen_range(-20..=20);
+                (start, start + rng.gen_range(0..=8))
+            })
+            .collect()
+    }
+
+    fn edge_cases() -> Vec<Self::Input> {
+        vec![
+            vec![],
+            vec![(1, 3)],
+            vec![(1, 4), (4, 5)],
+            vec![(1, 5), (2, 3)],
+            vec![(4, 6), (1, 3)],
+        ]
+    }
+}
+
+/// Verifies [`crate::vector::max_product`]. Input: the numbers.
+///
+/// Generated values are kept small so that no product overflows `i32`.
+pub struct MaxProduct;
+
+impl Verify for MaxProduct {
+    type Input = Vec<i32>;
+    type Output = i32;
+
+    const NAME: &'static str = "vector::max_product";
+
+    fn reference(input: &Self::Input) -> Self::Output {
+        crate::vector::max_product(input)
+    }
+
+    fn generate(rng: &mut StdRng) -> Self::Input {
+        random_vec(rng, 8, -10, 10)
+    }
+
+    fn edge_cases() -> Vec<Self::Input> {
+        vec![vec![], vec![0], vec![-3], vec![-2, 0, -1], vec![-2, 3, -4]]
+    }
+}
+
+/// Verifies [`crate::array::find_element`]. Input: `(arr, el)`.
+pub struct FindElement;
+
+impl Verify for FindElement {
+    type Input = (Vec<i32>, i32);
+    type Output = Option<usize>;
+
+    const NAME: &'static str = "array::find_element";
+
+    fn reference(input: &Self::Input) -> Self::Output {
+        crate::array::find_element(&input.0, &input.1)
+    }
+
+    fn generate(rng: &mut StdRng) -> Self::Input {
+        // A narrow value range makes both hits and duplicates likely
+        (random_vec(rng, 10, 0, 9), rng.gen_range(0..=9))
+    }
+
+    fn edge_cases() -> Vec<Self::Input> {
+        vec![
+            (vec![], 0),
+            (vec![1], 1),
+            (vec![1, 2, 2], 2),
+            (vec![1, 2, 3], 4),
+        ]
+    }
+}
+
+/// Verifies [`crate::matrix::find_postition_sorted_square_matrix`].
+/// Input: `(matrix, value)`; output is `None` when the value is absent.
+///
+/// Generated matrices are strictly increasing along rows and columns, so every
+/// value has exactly one valid position.
+pub struct SortedSquareMatrixSearch;
+
+impl Verify for SortedSquareMatrixSearch {
+    type Input = (Matrix, f64);
+    type Output = Option<(usize, usize)>;
+
+    const NAME: &'static str = "matrix::find_postition_sorted_square_matrix";
+
+    fn reference(input: &Self::Input) -> Self::Output {
+        find_postition_sorted_square_matrix(&input.0, input.1).ok()
+    }
+
+    fn generate(rng: &mut StdRng) -> Self::Input {
+        let n = rng.gen_range(0..=6);
+        let mut m: Matrix = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let up = if i > 0 { m.data[(i - 1) * n + j] } else { 0.0 };
+                let left = if j > 0 { m.data[i * n + j - 1] } else { 0.0 };
+                m.data[i * n + j] = up.max(left) + rng.gen_range(1..=3) as f64;
+            }
+        }
+        let val = rng.gen_range(0..=(3 * 2 * n + 1)) as f64;
+        (m, val)
+    }
+
+    fn edge_cases() -> Vec<Self::Input> {
+        vec![
+            (Matrix::zeros(0, 0), 1.0),
+            (Matrix::from_vec(1, 1, vec![1.0]).unwrap(), 1.0),
+            (
+                Matrix::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap(),
+                4.0,
+            ),
+        ]
+    }
+}
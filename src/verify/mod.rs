@@ -0,0 +1,24 @@
+//! Reference-solution verification module
+//!
+//! Every exercise in the crate ships with a solution, but reading it spoils
+//! the fun. This module lets learners write their own implementation and
+//! check it against a trusted reference on generated inputs instead:
+//!
+//! ```
+//! use rust_ds_learning::verify::{MergeIntervals, Verify};
+//!
+//! let result = MergeIntervals::check_user_impl(|intervals| {
+//!     // A (wrong) attempt that forgets to merge anything
+//!     intervals.clone()
+//! });
+//! let counterexample = result.unwrap_err();
+//! println!("{}", counterexample);
+//! ```
+
+mod checker;
+mod exercises;
+#[cfg(test)]
+mod tests;
+
+pub use checker::*;
+pub use exercises::*;
@@ -0,0 +1,127 @@
+use crate::verify::{
+    CheckConfig, FindElement, MaxProduct, MergeIntervals, SlidingWindowMaximum,
+    SortedSquareMatrixSearch, Verify,
+};
+
+mod reference_tests {
+    use super::*;
+
+    #[test]
+    fn test_references_pass_their_own_check() {
+        assert!(SlidingWindowMaximum::check_user_impl(SlidingWindowMaximum::reference).is_ok());
+        assert!(MergeIntervals::check_user_impl(MergeIntervals::reference).is_ok());
+        assert!(MaxProduct::check_user_impl(MaxProduct::reference).is_ok());
+        assert!(FindElement::check_user_impl(FindElement::reference).is_ok());
+        assert!(
+            SortedSquareMatrixSearch::check_user_impl(SortedSquareMatrixSearch::reference).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_generated_sorted_matrices_are_tableaux() {
+        for (m, _) in SortedSquareMatrixSearch::cases(&CheckConfig::default()) {
+            let n = m.rows();
+            for i in 0..n {
+                for j in 0..n {
+                    if j + 1 < n {
+                        assert!(m.data[i * n + j] < m.data[i * n + j + 1]);
+                    }
+                    if i + 1 < n {
+                        assert!(m.data[i * n + j] < m.data[(i + 1) * n + j]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+mod user_impl_tests {
+    use super::*;
+
+    #[test]
+    fn test_correct_user_impl_passes() {
+        let brute_force = |(nums, k): &(Vec<i32>, usize)| -> Vec<i32> {
+            nums.windows(*k).map(|w| *w.iter().max().unwrap()).collect()
+        };
+        assert!(SlidingWindowMaximum::check_user_impl(brute_force).is_ok());
+
+        let linear_scan = |(arr, el): &(Vec<i32>, i32)| -> Option<usize> {
+            (0..arr.len()).find(|&i| arr[i] == *el)
+        };
+        assert!(FindElement::check_user_impl(linear_scan).is_ok());
+    }
+
+    #[test]
+    fn test_wrong_user_impl_reports_first_counterexample() {
+        // Forgets that touching intervals must also be merged
+        let strict_overlap = |intervals: &Vec<(i32, i32)>| -> Vec<(i32, i32)> {
+            let mut sorted = intervals.clone();
+            sorted.sort_unstable();
+            let mut result: Vec<(i32, i32)> = Vec::new();
+            for (start, end) in sorted {
+                match result.last_mut() {
+                    Some(last) if start < last.1 => last.1 = last.1.max(end),
+                    _ => result.push((start, end)),
+                }
+            }
+            result
+        };
+
+        let counterexample = MergeIntervals::check_user_impl(strict_overlap).unwrap_err();
+        assert_eq!(counterexample.exercise, "vector::merge_intervals");
+        assert_eq!(counterexample.case, 2);
+        assert_eq!(counterexample.input, vec![(1, 4), (4, 5)]);
+        assert_eq!(counterexample.expected, vec![(1, 5)]);
+        assert_eq!(counterexample.actual, vec![(1, 4), (4, 5)]);
+    }
+
+    #[test]
+    fn test_counterexample_from_random_cases() {
+        // Ignores negative numbers entirely, which only random inputs expose
+        let positives_only =
+            |nums: &Vec<i32>| -> i32 { nums.iter().filter(|&&x| x > 0).product::<i32>().max(0) };
+        let config = CheckConfig {
+            cases: 100,
+            seed: 7,
+        };
+        let counterexample = MaxProduct::check_user_impl_with(&config, positives_only).unwrap_err();
+        assert_ne!(counterexample.expected, counterexample.actual);
+        assert_eq!(
+            MaxProduct::reference(&counterexample.input),
+            counterexample.expected
+        );
+    }
+
+    #[test]
+    fn test_counterexample_display() {
+        let counterexample = FindElement::check_user_impl(|_| None).unwrap_err();
+        assert_eq!(
+            counterexample.to_string(),
+            "array::find_element failed on case #1: input ([1], 1), expected Some(0), got None"
+        );
+    }
+}
+
+mod config_tests {
+    use super::*;
+
+    #[test]
+    fn test_cases_are_reproducible() {
+        let config = CheckConfig {
+            cases: 50,
+            seed: 42,
+        };
+        assert_eq!(
+            MergeIntervals::cases(&config),
+            MergeIntervals::cases(&config)
+        );
+    }
+
+    #[test]
+    fn test_edge_cases_come_first() {
+        let config = CheckConfig { cases: 3, seed: 1 };
+        let cases = MaxProduct::cases(&config);
+        assert_eq!(cases.len(), MaxProduct::edge_cases().len() + 3);
+        assert_eq!(cases[..5], MaxProduct::edge_cases()[..]);
+    }
+}